@@ -0,0 +1,6 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+fn main() {
+  #[cfg(feature = "napi")]
+  napi_build::setup();
+}
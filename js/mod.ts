@@ -1,7 +1,14 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
 import { instantiate } from "./deno_doc.generated.js";
-import type { DocNode } from "./types.d.ts";
+import type {
+  ApiDiff,
+  DocNode,
+  DocNodeKind,
+  LoadError,
+  ScoreReport,
+  ScoreWeights,
+} from "./types.d.ts";
 import { load as defaultLoad } from "https://deno.land/x/deno_graph@0.53.0/loader.ts";
 import type {
   CacheSetting,
@@ -31,6 +38,10 @@ export interface DocOptions {
    * of the `DocNode` to determine if the doc node is private, exported,
    * imported, or declared. */
   includeAll?: boolean;
+  /** If set, only include the documentation nodes of these kinds in the
+   * output, and within namespaces only their elements of these kinds. The
+   * filtering is done before the nodes are transferred from WebAssembly. */
+  kinds?: DocNodeKind[];
   /**
    * An optional callback that is called with the URL string of the resource to
    * be loaded and a flag indicating if the module was required dynamically. The
@@ -61,6 +72,12 @@ export interface DocOptions {
    * When an `importMap` URL string and this method is specifier, a warning
    * will be issued and the import map will be used. */
   resolve?(specifier: string, referrer: string): string;
+  /** An optional callback which is called for each import which could not be
+   * resolved or loaded, with the importing module and the position of the
+   * import, and for each module which could not be parsed. When set, such
+   * modules are left out and the rest is documented instead of the promise
+   * being rejected. */
+  onLoadError?(error: LoadError): void;
 }
 
 /**
@@ -93,6 +110,8 @@ export async function doc(
     resolve,
     importMap,
     printImportMapDiagnostics = true,
+    kinds,
+    onLoadError,
   } = options;
 
   const wasm = await instantiate();
@@ -103,5 +122,84 @@ export async function doc(
     resolve,
     importMap,
     printImportMapDiagnostics,
+    kinds,
+    onLoadError,
   );
 }
+
+export interface ScoreOptions
+  extends Omit<DocOptions, "includeAll" | "kinds" | "onLoadError"> {
+  /** The relative weight of each factor of the score, where missing factors
+   * keep their default weight. */
+  weights?: Partial<ScoreWeights>;
+}
+
+/**
+ * Compute asynchronously the documentation score of the supplied module and
+ * its reexports, without transferring the documentation nodes out of
+ * WebAssembly.
+ *
+ * ### Example
+ *
+ * ```ts
+ * import { score } from "https://deno.land/x/deno_doc/mod.ts";
+ *
+ * const report = await score("https://deno.land/std/fmt/colors.ts");
+ * console.log(`score: ${report.score}`);
+ * ```
+ *
+ * @param specifier The URL string of the specifier to score
+ * @param options A set of options for computing the score
+ * @returns A promise that resolves with the score report
+ */
+export async function score(
+  specifier: string,
+  options: ScoreOptions = {},
+): Promise<ScoreReport> {
+  const {
+    load = defaultLoad,
+    resolve,
+    importMap,
+    printImportMapDiagnostics = true,
+    weights,
+  } = options;
+
+  const wasm = await instantiate();
+  return wasm.score(
+    specifier,
+    load,
+    resolve,
+    importMap,
+    printImportMapDiagnostics,
+    weights,
+  );
+}
+
+/**
+ * Compare two versions of an API, given as the documentation nodes returned
+ * by `doc()`, classifying each added, removed or changed symbol by the part
+ * of the version it requires to bump under semantic versioning.
+ *
+ * ### Example
+ *
+ * ```ts
+ * import { diff, doc } from "https://deno.land/x/deno_doc/mod.ts";
+ *
+ * const { impact } = await diff(
+ *   await doc("https://deno.land/std@0.100.0/fmt/colors.ts"),
+ *   await doc("https://deno.land/std@0.101.0/fmt/colors.ts"),
+ * );
+ * console.log(`required bump: ${impact ?? "none"}`);
+ * ```
+ *
+ * @param oldNodes The documentation nodes of the old version
+ * @param newNodes The documentation nodes of the new version
+ * @returns A promise that resolves with the changes
+ */
+export async function diff(
+  oldNodes: DocNode[],
+  newNodes: DocNode[],
+): Promise<ApiDiff> {
+  const wasm = await instantiate();
+  return wasm.diff(oldNodes, newNodes);
+}
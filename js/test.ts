@@ -5,7 +5,8 @@ import {
   assertEquals,
   assertThrowsAsync,
 } from "https://deno.land/std@0.104.0/testing/asserts.ts";
-import { doc } from "./mod.ts";
+import { doc, score } from "./mod.ts";
+import type { LoadError } from "./types.d.ts";
 
 Deno.test({
   name: "doc()",
@@ -80,6 +81,89 @@ Deno.test({
   },
 });
 
+Deno.test({
+  name: "doc() - kinds",
+  async fn() {
+    const entries = await doc("https://example.com/a", {
+      load(specifier) {
+        return Promise.resolve({
+          kind: "module",
+          specifier,
+          headers: {
+            "content-type": "application/typescript; charset=utf-8",
+          },
+          content: `export interface A {}
+          export type B = string;
+          export function c() {}
+          export namespace D {
+            export interface E {}
+            export const f = 1;
+          }`,
+        });
+      },
+      kinds: ["interface", "typeAlias", "namespace"],
+    });
+    assertEquals(entries.map((n) => n.name), ["A", "B", "D"]);
+    const ns = entries[2];
+    assert(ns.kind === "namespace");
+    assertEquals(ns.namespaceDef.elements.map((n) => n.name), ["E"]);
+  },
+});
+
+Deno.test({
+  name: "doc() - onLoadError",
+  async fn() {
+    const errors: LoadError[] = [];
+    const entries = await doc("https://example.com/a", {
+      load(specifier) {
+        if (specifier !== "https://example.com/a") {
+          return Promise.resolve(undefined);
+        }
+        return Promise.resolve({
+          kind: "module",
+          specifier,
+          headers: {
+            "content-type": "application/typescript; charset=utf-8",
+          },
+          content: `export * from "./missing.ts";
+          export function a() {}`,
+        });
+      },
+      onLoadError(error) {
+        errors.push(error);
+      },
+    });
+    assertEquals(entries.map((n) => n.name), ["a"]);
+    assertEquals(errors.length, 1);
+    assertEquals(errors[0].specifier, "./missing.ts");
+    assertEquals(errors[0].referrer, "https://example.com/a");
+    assertEquals(errors[0].line, 1);
+  },
+});
+
+Deno.test({
+  name: "score()",
+  async fn() {
+    const report = await score("https://example.com/a", {
+      load(specifier) {
+        return Promise.resolve({
+          kind: "module",
+          specifier,
+          headers: {
+            "content-type": "application/typescript; charset=utf-8",
+          },
+          content: `/** Documented. */
+          export function a() {}
+          export function b() {}`,
+        });
+      },
+    });
+    assertEquals(report.modules.length, 1);
+    assertEquals(report.modules[0].documentedSymbols, 1);
+    assertEquals(report.modules[0].totalSymbols, 2);
+  },
+});
+
 Deno.test({
   name: "doc() - missing specifier",
   // TODO(@kitsonk) - remove when new deno_graph crate published
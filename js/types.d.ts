@@ -9,7 +9,8 @@ export type DocNode =
   | DocNodeTypeAlias
   | DocNodeNamespace
   | DocNodeInterface
-  | DocNodeImport;
+  | DocNodeImport
+  | DocNodeUmdNamespace;
 
 /** Indicates how the documentation node was declared. `"private"` indicates
  * the node is un-exported. `"export"` indicates it is exported from the current
@@ -19,11 +20,27 @@ export type DeclarationKind = "private" | "export" | "declare";
 interface DocNodeBase {
   kind: DocNodeKind;
   name: string;
+  qualifiedName?: string;
   location: Location;
   declarationKind: DeclarationKind;
   jsDoc?: JsDoc;
+  effectiveJsDoc?: JsDoc;
+  stability?: Stability;
+  releaseTag?: ReleaseTag;
+  /** The modules imported by the module of the node which its signature
+   * refers to. */
+  dependencies?: string[];
+  /** The comments preceding the declaration of the node, as written. */
+  leadingComments?: LeadingComment[];
+  /** Whether the declaration of the node is marked `@ignore`. */
+  hidden?: boolean;
 }
 
+export type Stability = "internal" | "experimental";
+
+/** The TSDoc release tag of a node. */
+export type ReleaseTag = "internal" | "alpha" | "beta" | "public";
+
 export type DocNodeKind =
   | "moduleDoc"
   | "function"
@@ -33,11 +50,31 @@ export type DocNodeKind =
   | "typeAlias"
   | "namespace"
   | "interface"
-  | "import";
+  | "import"
+  | "umdNamespace";
 
 export interface DocNodeModuleDoc extends DocNodeBase {
   kind: "moduleDoc";
-  jsDoc: JsDoc;
+  /** Unset for a module doc which only holds the metadata of the module. */
+  jsDoc?: JsDoc;
+  moduleDocDef?: ModuleDocDef;
+}
+
+/** A comment preceding the declaration of a node. */
+export interface LeadingComment {
+  kind: "line" | "block";
+  /** The text between the delimiters of the comment. */
+  text: string;
+  location: Location;
+  end: Location;
+}
+
+/** Metadata of a module given by the headers it was served with. */
+export interface ModuleDocDef {
+  /** The warning of the registry, from the `x-deno-warning` header. */
+  warning?: string;
+  /** The `x-doc-*` headers, by their name without the prefix. */
+  metadata?: Record<string, string>;
 }
 
 export interface DocNodeFunction extends DocNodeBase {
@@ -80,8 +117,22 @@ export interface DocNodeImport extends DocNodeBase {
   importDef: ImportDef;
 }
 
+/** The global name of a UMD module, declared with `export as namespace`. */
+export interface DocNodeUmdNamespace extends DocNodeBase {
+  kind: "umdNamespace";
+}
+
 export type Accessibility = "public" | "protected" | "private";
 
+export type MemberGroup =
+  | "constructor"
+  | "staticProperty"
+  | "staticAccessor"
+  | "staticMethod"
+  | "property"
+  | "accessor"
+  | "method";
+
 export interface ClassDef {
   isAbstract: boolean;
   constructors: ClassConstructorDef[];
@@ -93,6 +144,8 @@ export interface ClassDef {
   typeParams: TsTypeParamDef[];
   superTypeParams: TsTypeDef[];
   decorators?: DecoratorDef[];
+  /** The events declared by the overloads of the listener methods. */
+  events?: EventDef[];
 }
 
 export type ClassConstructorParamDef = ParamDef & {
@@ -105,13 +158,16 @@ export interface ClassConstructorDef {
   jsDoc?: JsDoc;
   accessibility?: Accessibility;
   isOptional?: boolean;
-  hasBody?: boolean;
+  hasBody: boolean;
   name: string;
+  qualifiedName?: string;
+  group?: MemberGroup;
   params: ClassConstructorParamDef[];
   location: Location;
 }
 
 export interface ClassIndexSignatureDef {
+  jsDoc?: JsDoc;
   readonly: boolean;
   params: ParamDef[];
   tsType?: TsTypeDef;
@@ -119,12 +175,20 @@ export interface ClassIndexSignatureDef {
 
 export interface ClassMethodDef {
   jsDoc?: JsDoc;
+  effectiveJsDoc?: JsDoc;
   accessibility?: Accessibility;
   optional: boolean;
   isAbstract: boolean;
   isStatic: boolean;
   isOverride?: boolean;
   name: string;
+  qualifiedName?: string;
+  group?: MemberGroup;
+  /** The base class or interface the member is inherited from. */
+  inheritedFrom?: string;
+  /** Whether the method returns the object it is called on. */
+  chainable?: boolean;
+  wellKnownSymbol?: boolean;
   kind: MethodKind;
   functionDef: FunctionDef;
   location: Location;
@@ -132,6 +196,7 @@ export interface ClassMethodDef {
 
 export interface ClassPropertyDef {
   jsDoc?: JsDoc;
+  effectiveJsDoc?: JsDoc;
   tsType?: TsTypeDef;
   readonly: boolean;
   accessibility?: Accessibility;
@@ -140,6 +205,11 @@ export interface ClassPropertyDef {
   isStatic: boolean;
   isOverride?: boolean;
   name: string;
+  qualifiedName?: string;
+  group?: MemberGroup;
+  /** The base class or interface the member is inherited from. */
+  inheritedFrom?: string;
+  wellKnownSymbol?: boolean;
   decorators?: DecoratorDef[];
   location: Location;
 }
@@ -156,6 +226,7 @@ export interface EnumDef {
 
 export interface EnumMemberDef {
   name: string;
+  qualifiedName?: string;
   init?: TsTypeDef;
   jsDoc?: JsDoc;
   location: Location;
@@ -164,7 +235,7 @@ export interface EnumMemberDef {
 export interface FunctionDef {
   params: ParamDef[];
   returnType?: TsTypeDef;
-  hasBody?: boolean;
+  hasBody: boolean;
   isAsync: boolean;
   isGenerator: boolean;
   typeParams: TsTypeParamDef[];
@@ -174,6 +245,19 @@ export interface FunctionDef {
 export interface ImportDef {
   src: string;
   imported?: string;
+  requestedSrc?: string;
+  namespace?: boolean;
+  /** Whether this is a CommonJS import, like `import fs = require("fs")`. */
+  require?: boolean;
+}
+
+export interface EventDef {
+  name: string;
+  /** The type of the first param of the listener. */
+  payload?: TsTypeDef;
+  /** The method the event is listened to with, like `on`. */
+  method: string;
+  jsDoc?: JsDoc;
 }
 
 export interface InterfaceDef {
@@ -183,6 +267,8 @@ export interface InterfaceDef {
   callSignatures: InterfaceCallSignatureDef[];
   indexSignatures: InterfaceIndexSignatureDef[];
   typeParams: TsTypeParamDef[];
+  /** The events declared by the overloads of the listener methods. */
+  events?: EventDef[];
 }
 
 export interface InterfaceCallSignatureDef {
@@ -194,6 +280,7 @@ export interface InterfaceCallSignatureDef {
 }
 
 export interface InterfaceIndexSignatureDef {
+  jsDoc?: JsDoc;
   readonly: boolean;
   params: ParamDef[];
   tsType?: TsTypeDef;
@@ -201,10 +288,18 @@ export interface InterfaceIndexSignatureDef {
 
 export interface InterfaceMethodDef {
   name: string;
+  qualifiedName?: string;
+  group?: MemberGroup;
+  /** The base class or interface the member is inherited from. */
+  inheritedFrom?: string;
+  /** Whether the method returns the object it is called on. */
+  chainable?: boolean;
   kind: MethodKind;
   location: Location;
   jsDoc?: JsDoc;
+  effectiveJsDoc?: JsDoc;
   computed?: boolean;
+  wellKnownSymbol?: boolean;
   optional: boolean;
   params: ParamDef[];
   returnType?: TsTypeDef;
@@ -213,11 +308,17 @@ export interface InterfaceMethodDef {
 
 export interface InterfacePropertyDef {
   name: string;
+  qualifiedName?: string;
+  group?: MemberGroup;
+  /** The base class or interface the member is inherited from. */
+  inheritedFrom?: string;
   location: Location;
   jsDoc?: JsDoc;
+  effectiveJsDoc?: JsDoc;
   params: ParamDef[];
   readonly?: boolean;
   computed: boolean;
+  wellKnownSymbol?: boolean;
   optional: boolean;
   tsType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
@@ -226,9 +327,37 @@ export interface InterfacePropertyDef {
 export interface JsDoc {
   doc?: string;
   tags?: JsDocTag[];
+  docSegments?: DocSegment[];
+  /** The comment the documentation was parsed from. */
+  raw?: RawJsDoc;
+}
+
+/** A JSDoc comment as it is written in the source. */
+export interface RawJsDoc {
+  /** The text of the comment, from its `/**` to its `*\/`. */
+  text: string;
+  location: Location;
+  end: Location;
+}
+
+export type DocSegment = DocSegmentText | DocSegmentLink;
+
+export interface DocSegmentText {
+  kind: "text";
+  text: string;
+}
+
+export interface DocSegmentLink {
+  kind: "link";
+  target: string;
+  label?: string;
+  /** Set for `{@linkcode}` and `{@linkplain}` tags. */
+  style?: "code" | "plain";
 }
 
 export type JsDocTagKind =
+  | "alpha"
+  | "beta"
   | "callback"
   | "category"
   | "constructor"
@@ -236,9 +365,14 @@ export type JsDocTagKind =
   | "deprecated"
   | "enum"
   | "example"
+  | "experimental"
   | "extends"
   | "ignore"
+  | "inheritdoc"
+  | "internal"
   | "module"
+  | "namespace"
+  | "packageDocumentation"
   | "param"
   | "public"
   | "private"
@@ -246,23 +380,33 @@ export type JsDocTagKind =
   | "protected"
   | "readonly"
   | "return"
+  | "see"
+  | "since"
   | "tags"
   | "template"
   | "this"
   | "typedef"
   | "type"
+  | "yields"
   | "unsupported";
 
 export type JsDocTag =
   | JsDocTagOnly
   | JsDocTagDoc
+  | JsDocTagExample
   | JsDocTagNamed
   | JsDocTagValued
   | JsDocTagTyped
   | JsDocTagNamedTyped
+  | JsDocTagNamespace
   | JsDocTagParam
+  | JsDocTagProperty
   | JsDocTagReturn
+  | JsDocTagSee
+  | JsDocTagSince
   | JsDocTagTags
+  | JsDocTagTemplate
+  | JsDocTagYields
   | JsDocTagUnsupported;
 
 export interface JsDocTagBase {
@@ -271,9 +415,15 @@ export interface JsDocTagBase {
 
 export interface JsDocTagOnly extends JsDocTagBase {
   kind:
+    | "alpha"
+    | "beta"
     | "constructor"
+    | "experimental"
     | "ignore"
+    | "inheritdoc"
+    | "internal"
     | "module"
+    | "packageDocumentation"
     | "public"
     | "private"
     | "protected"
@@ -281,16 +431,30 @@ export interface JsDocTagOnly extends JsDocTagBase {
 }
 
 export interface JsDocTagDoc extends JsDocTagBase {
-  kind: "category" | "deprecated" | "example";
+  kind: "category" | "deprecated";
+  doc?: string;
+}
+
+export interface JsDocTagExample extends JsDocTagBase {
+  kind: "example";
+  caption?: string;
   doc?: string;
 }
 
 export interface JsDocTagNamed extends JsDocTagBase {
-  kind: "callback" | "template";
+  kind: "callback";
   name: string;
   doc?: string;
 }
 
+export interface JsDocTagTemplate extends JsDocTagBase {
+  kind: "template";
+  name: string;
+  constraint?: string;
+  default?: string;
+  doc?: string;
+}
+
 export interface JsDocTagValued extends JsDocTagBase {
   kind: "default";
   value: string;
@@ -304,12 +468,17 @@ export interface JsDocTagTyped extends JsDocTagBase {
 }
 
 export interface JsDocTagNamedTyped extends JsDocTagBase {
-  kind: "property" | "typedef";
+  kind: "typedef";
   name: string;
   type: string;
   doc?: string;
 }
 
+export interface JsDocTagNamespace extends JsDocTagBase {
+  kind: "namespace";
+  name?: string;
+}
+
 export interface JsDocTagParam extends JsDocTagBase {
   kind: "param";
   name: string;
@@ -319,12 +488,39 @@ export interface JsDocTagParam extends JsDocTagBase {
   doc?: string;
 }
 
+export interface JsDocTagProperty extends JsDocTagBase {
+  kind: "property";
+  name: string;
+  type: string;
+  optional?: true;
+  default?: string;
+  doc?: string;
+}
+
 export interface JsDocTagReturn extends JsDocTagBase {
   kind: "return";
   type?: string;
   doc?: string;
 }
 
+export interface JsDocTagYields extends JsDocTagBase {
+  kind: "yields";
+  type?: string;
+  doc?: string;
+}
+
+export interface JsDocTagSee extends JsDocTagBase {
+  kind: "see";
+  target: string;
+  doc?: string;
+}
+
+export interface JsDocTagSince extends JsDocTagBase {
+  kind: "since";
+  version: string;
+  doc?: string;
+}
+
 export interface JsDocTagTags extends JsDocTagBase {
   kind: "tags";
   tags: string[];
@@ -385,6 +581,7 @@ export interface LiteralDefBoolean extends LiteralDefBase {
 }
 
 export interface LiteralIndexSignatureDef {
+  jsDoc?: JsDoc;
   readonly: boolean;
   params: ParamDef[];
   tsType?: TsTypeDef;
@@ -392,9 +589,11 @@ export interface LiteralIndexSignatureDef {
 
 export interface LiteralMethodDef {
   name: string;
+  jsDoc?: JsDoc;
   kind: MethodKind;
   params: ParamDef[];
   computed?: boolean;
+  wellKnownSymbol?: boolean;
   optional: boolean;
   returnType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
@@ -402,9 +601,11 @@ export interface LiteralMethodDef {
 
 export interface LiteralPropertyDef {
   name: string;
+  jsDoc?: JsDoc;
   params: ParamDef[];
   readonly?: boolean;
   computed: boolean;
+  wellKnownSymbol?: boolean;
   optional: boolean;
   tsType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
@@ -414,12 +615,14 @@ export interface Location {
   filename: string;
   line: number;
   col: number;
+  requestedFilename?: string;
 }
 
 export type MethodKind = "method" | "getter" | "setter";
 
 export interface NamespaceDef {
   elements: DocNode[];
+  ambientModule?: boolean;
 }
 
 export type ObjectPatPropDef =
@@ -431,6 +634,7 @@ export interface ObjectPatPropAssignDef {
   kind: "assign";
   key: string;
   value?: string;
+  doc?: string;
 }
 
 export interface ObjectPatPropKeyValueDef {
@@ -457,6 +661,7 @@ export interface ParamArrayDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  doc?: string;
 }
 
 export interface ParamAssignDef {
@@ -465,6 +670,7 @@ export interface ParamAssignDef {
   right: string;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  doc?: string;
 }
 
 export interface ParamIdentifierDef {
@@ -473,6 +679,7 @@ export interface ParamIdentifierDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  doc?: string;
 }
 
 export interface ParamObjectDef {
@@ -481,6 +688,7 @@ export interface ParamObjectDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  doc?: string;
 }
 
 export interface ParamRestDef {
@@ -488,6 +696,7 @@ export interface ParamRestDef {
   arg: ParamDef;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  doc?: string;
 }
 
 export type TruePlusMinus = true | "+" | "-";
@@ -726,3 +935,57 @@ export interface VariableDef {
   tsType?: TsTypeDef;
   kind: VariableDeclKind;
 }
+
+export interface ScoreWeights {
+  moduleDoc: number;
+  symbolDocs: number;
+  paramDocs: number;
+  examples: number;
+}
+
+export interface ModuleScore {
+  specifier: string;
+  hasModuleDoc: boolean;
+  hasExamples: boolean;
+  documentedSymbols: number;
+  totalSymbols: number;
+  documentedParams: number;
+  totalParams: number;
+  /** The weighted score, between `0` and `1`. */
+  score: number;
+}
+
+export interface ScoreReport {
+  modules: ModuleScore[];
+  /** The mean of the module scores, between `0` and `1`. */
+  score: number;
+}
+
+export type SemverImpact = "patch" | "minor" | "major";
+
+export type ApiChangeKind = "added" | "removed" | "changed" | "docsChanged";
+
+export interface ApiChange {
+  /** The dotted path of the symbol, like `Deno.Conn`. */
+  name: string;
+  nodeKind: DocNodeKind;
+  kind: ApiChangeKind;
+  impact: SemverImpact;
+}
+
+export interface ApiDiff {
+  changes: ApiChange[];
+  /** The largest impact of the changes, unset when nothing changed. */
+  impact?: SemverImpact;
+}
+
+export interface LoadError {
+  /** The specifier as written in the import, or the URL of the module which
+   * could not be parsed. */
+  specifier: string;
+  /** The URL of the importing module, unset for parse errors. */
+  referrer?: string;
+  line: number;
+  col: number;
+  message: string;
+}
@@ -0,0 +1,67 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Creates the URL safe anchor of the symbol or member at `path`, which is
+/// the names of its enclosing namespaces and symbols followed by its own
+/// name, like `["Deno", "Conn", "closeWrite"]`.
+///
+/// The names are joined with `.`. Characters other than ASCII letters,
+/// digits, `_` and `$` are escaped as `~` followed by the hexadecimal value of
+/// each of their UTF-8 bytes, so `#secret` becomes `~23secret` and distinct
+/// paths never share an anchor. Overloads and merged declarations have the
+/// same path, and so the same anchor.
+pub fn slug(path: &[&str]) -> String {
+  let mut slug = String::new();
+  for (i, name) in path.iter().enumerate() {
+    if i > 0 {
+      slug.push('.');
+    }
+    for c in name.chars() {
+      if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+        slug.push(c);
+      } else {
+        let mut bytes = [0; 4];
+        for byte in c.encode_utf8(&mut bytes).bytes() {
+          write!(slug, "~{:02X}", byte).unwrap();
+        }
+      }
+    }
+  }
+  slug
+}
+
+/// Generates the anchors of the symbols of a page, like [`slug`], while
+/// keeping anchors which differ only in case apart, as some hosts and
+/// browsers match anchors case insensitively. The anchor of a path which
+/// collides with the anchor of a path seen before gets a `-1`, `-2`, etc.
+/// suffix, so anchors are stable as long as symbols are visited in the same
+/// order.
+#[derive(Debug, Default)]
+pub struct AnchorGenerator {
+  anchors: HashMap<String, String>,
+  collisions: HashMap<String, usize>,
+}
+
+impl AnchorGenerator {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn anchor(&mut self, path: &[&str]) -> String {
+    let slug = slug(path);
+    if let Some(anchor) = self.anchors.get(&slug) {
+      return anchor.clone();
+    }
+    let count = self.collisions.entry(slug.to_lowercase()).or_insert(0);
+    let anchor = if *count == 0 {
+      slug.clone()
+    } else {
+      format!("{}-{}", slug, count)
+    };
+    *count += 1;
+    self.anchors.insert(slug, anchor.clone());
+    anchor
+  }
+}
@@ -0,0 +1,212 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+
+use crate::messages::MessageCatalog;
+use crate::node::DocNode;
+use crate::printer::DocPrinter;
+use crate::tags::to_ctags;
+use crate::text::to_plain_text;
+use crate::tokens::semantic_signatures;
+
+/// The options passed to an [`OutputBackend`].
+#[derive(Debug, Default, Clone)]
+pub struct BackendOptions {
+  /// Include private (non-exported) nodes in the output.
+  pub private: bool,
+  pub use_color: bool,
+  /// The labels of the terminal output.
+  pub messages: MessageCatalog,
+  /// Settings specific to a backend.
+  pub settings: BTreeMap<String, String>,
+}
+
+/// A file produced by an [`OutputBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+  /// The path of the file, relative to the output directory.
+  pub path: String,
+  pub content: String,
+}
+
+/// Renders doc nodes into artifacts, like the terminal output or JSON.
+pub trait OutputBackend {
+  /// The name the backend is registered under.
+  fn name(&self) -> &str;
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error>;
+}
+
+/// The output of [`DocPrinter`].
+pub struct TerminalBackend;
+
+impl OutputBackend for TerminalBackend {
+  fn name(&self) -> &str {
+    "terminal"
+  }
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    let printer =
+      DocPrinter::new(doc_nodes, options.use_color, options.private)
+        .with_messages(options.messages.clone());
+    Ok(vec![Artifact {
+      path: "doc.txt".to_string(),
+      content: printer.to_string(),
+    }])
+  }
+}
+
+/// The doc nodes serialized as JSON. The `pretty` setting enables pretty
+/// printing.
+pub struct JsonBackend;
+
+impl OutputBackend for JsonBackend {
+  fn name(&self) -> &str {
+    "json"
+  }
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    let content =
+      if options.settings.get("pretty").map(|v| v.as_str()) == Some("true") {
+        serde_json::to_string_pretty(doc_nodes)?
+      } else {
+        serde_json::to_string(doc_nodes)?
+      };
+    Ok(vec![Artifact {
+      path: "doc.json".to_string(),
+      content,
+    }])
+  }
+}
+
+/// The output of [`to_plain_text`].
+pub struct TextBackend;
+
+impl OutputBackend for TextBackend {
+  fn name(&self) -> &str {
+    "text"
+  }
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    _options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    Ok(vec![Artifact {
+      path: "doc.md".to_string(),
+      content: to_plain_text(doc_nodes),
+    }])
+  }
+}
+
+/// The output of [`semantic_signatures`], serialized as JSON, for editors
+/// which colorize signatures.
+pub struct SemanticTokensBackend;
+
+impl OutputBackend for SemanticTokensBackend {
+  fn name(&self) -> &str {
+    "semanticTokens"
+  }
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    _options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    Ok(vec![Artifact {
+      path: "tokens.json".to_string(),
+      content: serde_json::to_string(&semantic_signatures(doc_nodes))?,
+    }])
+  }
+}
+
+/// The output of [`to_ctags`], a tags file for editors.
+pub struct CtagsBackend;
+
+impl OutputBackend for CtagsBackend {
+  fn name(&self) -> &str {
+    "ctags"
+  }
+
+  fn render(
+    &self,
+    doc_nodes: &[DocNode],
+    _options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    Ok(vec![Artifact {
+      path: "tags".to_string(),
+      content: to_ctags(doc_nodes),
+    }])
+  }
+}
+
+/// A set of output backends, looked up by name.
+pub struct BackendRegistry {
+  backends: Vec<Box<dyn OutputBackend>>,
+}
+
+impl Default for BackendRegistry {
+  /// A registry with the built-in backends.
+  fn default() -> Self {
+    let mut registry = Self::empty();
+    registry.register(Box::new(TerminalBackend));
+    registry.register(Box::new(JsonBackend));
+    registry.register(Box::new(TextBackend));
+    registry.register(Box::new(SemanticTokensBackend));
+    registry.register(Box::new(CtagsBackend));
+    registry
+  }
+}
+
+impl BackendRegistry {
+  pub fn empty() -> Self {
+    Self {
+      backends: Vec::new(),
+    }
+  }
+
+  /// Registers a backend, replacing any backend with the same name.
+  pub fn register(&mut self, backend: Box<dyn OutputBackend>) {
+    self.backends.retain(|b| b.name() != backend.name());
+    self.backends.push(backend);
+  }
+
+  pub fn get(&self, name: &str) -> Option<&dyn OutputBackend> {
+    self
+      .backends
+      .iter()
+      .find(|backend| backend.name() == name)
+      .map(|backend| backend.as_ref())
+  }
+
+  pub fn names(&self) -> Vec<&str> {
+    self.backends.iter().map(|backend| backend.name()).collect()
+  }
+
+  /// Renders the doc nodes with the backend registered under `name`.
+  pub fn render(
+    &self,
+    name: &str,
+    doc_nodes: &[DocNode],
+    options: &BackendOptions,
+  ) -> Result<Vec<Artifact>, anyhow::Error> {
+    let backend = self
+      .get(name)
+      .ok_or_else(|| anyhow!("Unknown output backend: \"{}\"", name))?;
+    backend.render(doc_nodes, options)
+  }
+}
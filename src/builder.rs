@@ -0,0 +1,265 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::class::ClassDef;
+use crate::function::FunctionDef;
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+use crate::node::NamespaceDef;
+use crate::r#enum::EnumDef;
+use crate::type_alias::TypeAliasDef;
+use crate::variable::VariableDef;
+
+/// Why a doc node built with [`DocNodeBuilder`], or merged with
+/// [`merge_doc_nodes`], is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocNodeBuildError {
+  /// The name is empty or is not an identifier.
+  InvalidName(String),
+  /// The node has no location filename.
+  MissingFilename(String),
+  /// The definition of the node does not match its kind.
+  MismatchedDefinition(String),
+  /// A node with the same name is already documented, and the two can not
+  /// be merged.
+  Conflict(String),
+}
+
+impl Error for DocNodeBuildError {}
+
+impl fmt::Display for DocNodeBuildError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidName(name) => write!(f, "Invalid name \"{}\".", name),
+      Self::MissingFilename(name) => {
+        write!(f, "The location of \"{}\" has no filename.", name)
+      }
+      Self::MismatchedDefinition(name) => {
+        write!(f, "The definition of \"{}\" does not match its kind.", name)
+      }
+      Self::Conflict(name) => {
+        write!(f, "\"{}\" conflicts with a documented node.", name)
+      }
+    }
+  }
+}
+
+/// Builds a doc node programmatically, for example for a global which is
+/// provided by a runtime instead of declared in a module, to be merged into
+/// parsed doc nodes with [`merge_doc_nodes`]. The definitions can also be
+/// deserialized from the JSON output.
+#[derive(Debug, Clone)]
+pub struct DocNodeBuilder {
+  doc_node: DocNode,
+}
+
+impl DocNodeBuilder {
+  fn new(kind: DocNodeKind, name: impl Into<String>) -> Self {
+    Self {
+      doc_node: DocNode {
+        kind,
+        name: name.into(),
+        declaration_kind: DeclarationKind::Export,
+        ..Default::default()
+      },
+    }
+  }
+
+  pub fn function(name: impl Into<String>, function_def: FunctionDef) -> Self {
+    let mut builder = Self::new(DocNodeKind::Function, name);
+    builder.doc_node.function_def = Some(function_def);
+    builder
+  }
+
+  pub fn variable(name: impl Into<String>, variable_def: VariableDef) -> Self {
+    let mut builder = Self::new(DocNodeKind::Variable, name);
+    builder.doc_node.variable_def = Some(variable_def);
+    builder
+  }
+
+  pub fn class(name: impl Into<String>, class_def: ClassDef) -> Self {
+    let mut builder = Self::new(DocNodeKind::Class, name);
+    builder.doc_node.class_def = Some(class_def);
+    builder
+  }
+
+  pub fn r#enum(name: impl Into<String>, enum_def: EnumDef) -> Self {
+    let mut builder = Self::new(DocNodeKind::Enum, name);
+    builder.doc_node.enum_def = Some(enum_def);
+    builder
+  }
+
+  pub fn interface(
+    name: impl Into<String>,
+    interface_def: InterfaceDef,
+  ) -> Self {
+    let mut builder = Self::new(DocNodeKind::Interface, name);
+    builder.doc_node.interface_def = Some(interface_def);
+    builder
+  }
+
+  pub fn type_alias(
+    name: impl Into<String>,
+    type_alias_def: TypeAliasDef,
+  ) -> Self {
+    let mut builder = Self::new(DocNodeKind::TypeAlias, name);
+    builder.doc_node.type_alias_def = Some(type_alias_def);
+    builder
+  }
+
+  pub fn namespace(
+    name: impl Into<String>,
+    namespace_def: NamespaceDef,
+  ) -> Self {
+    let mut builder = Self::new(DocNodeKind::Namespace, name);
+    builder.doc_node.namespace_def = Some(namespace_def);
+    builder
+  }
+
+  /// Sets the location, with a 1-indexed line and a 0-indexed column.
+  pub fn location(
+    mut self,
+    filename: impl Into<String>,
+    line: usize,
+    col: usize,
+  ) -> Self {
+    self.doc_node.location = Location {
+      filename: filename.into(),
+      line,
+      col,
+      requested_filename: None,
+    };
+    self
+  }
+
+  /// Sets the declaration kind, which is `export` by default.
+  pub fn declaration_kind(mut self, declaration_kind: DeclarationKind) -> Self {
+    self.doc_node.declaration_kind = declaration_kind;
+    self
+  }
+
+  /// Sets the documentation from the text of a JSDoc comment, without the
+  /// comment markers, parsing its tags.
+  pub fn doc(self, text: impl Into<String>) -> Self {
+    self.js_doc(JsDoc::from(text.into()))
+  }
+
+  pub fn js_doc(mut self, js_doc: JsDoc) -> Self {
+    self.doc_node.js_doc = js_doc;
+    self
+  }
+
+  /// Validates the node, including the elements of a namespace.
+  pub fn build(self) -> Result<DocNode, DocNodeBuildError> {
+    validate_doc_node(&self.doc_node)?;
+    Ok(self.doc_node)
+  }
+}
+
+/// Checks that a doc node which was not created by the parser is valid:
+/// that it is named by an identifier, has a location and has the definition
+/// of its kind, and only that one.
+fn validate_doc_node(doc_node: &DocNode) -> Result<(), DocNodeBuildError> {
+  let name = &doc_node.name;
+  let is_ambient_module = doc_node
+    .namespace_def
+    .as_ref()
+    .map(|namespace_def| namespace_def.ambient_module)
+    .unwrap_or(false);
+  let is_valid_name = match doc_node.kind {
+    DocNodeKind::ModuleDoc => name.is_empty(),
+    _ if is_ambient_module => !name.is_empty(),
+    _ => is_identifier(name),
+  };
+  if !is_valid_name {
+    return Err(DocNodeBuildError::InvalidName(name.clone()));
+  }
+  if doc_node.location.filename.is_empty() {
+    return Err(DocNodeBuildError::MissingFilename(name.clone()));
+  }
+  let definitions = [
+    (DocNodeKind::Function, doc_node.function_def.is_some()),
+    (DocNodeKind::Variable, doc_node.variable_def.is_some()),
+    (DocNodeKind::Class, doc_node.class_def.is_some()),
+    (DocNodeKind::Enum, doc_node.enum_def.is_some()),
+    (DocNodeKind::Interface, doc_node.interface_def.is_some()),
+    (DocNodeKind::TypeAlias, doc_node.type_alias_def.is_some()),
+    (DocNodeKind::Namespace, doc_node.namespace_def.is_some()),
+    (DocNodeKind::Import, doc_node.import_def.is_some()),
+  ];
+  let is_matching = definitions
+    .iter()
+    .all(|(kind, is_some)| *is_some == (*kind == doc_node.kind));
+  if !is_matching {
+    return Err(DocNodeBuildError::MismatchedDefinition(name.clone()));
+  }
+  if let Some(namespace_def) = &doc_node.namespace_def {
+    for element in &namespace_def.elements {
+      validate_doc_node(element)?;
+    }
+  }
+  Ok(())
+}
+
+fn is_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+  match chars.next() {
+    Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {}
+    _ => return false,
+  }
+  chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Merges hand-authored doc nodes into the doc nodes returned by the parser,
+/// after validating them.
+///
+/// A node whose name is already documented is merged when both are
+/// namespaces, in which case its elements are merged in turn, or both are
+/// functions, which are then overloads. Otherwise the merge fails, and
+/// `doc_nodes` is left unchanged.
+pub fn merge_doc_nodes(
+  doc_nodes: &mut Vec<DocNode>,
+  synthetic: Vec<DocNode>,
+) -> Result<(), DocNodeBuildError> {
+  for doc_node in &synthetic {
+    validate_doc_node(doc_node)?;
+  }
+  let mut merged = doc_nodes.clone();
+  merge_validated(&mut merged, synthetic)?;
+  *doc_nodes = merged;
+  Ok(())
+}
+
+fn merge_validated(
+  doc_nodes: &mut Vec<DocNode>,
+  synthetic: Vec<DocNode>,
+) -> Result<(), DocNodeBuildError> {
+  for doc_node in synthetic {
+    let existing = doc_nodes.iter_mut().find(|existing| {
+      existing.name == doc_node.name
+        && !matches!(existing.kind, DocNodeKind::ModuleDoc)
+    });
+    let Some(existing) = existing else {
+      doc_nodes.push(doc_node);
+      continue;
+    };
+    match (existing.kind.clone(), doc_node.kind.clone()) {
+      (DocNodeKind::Namespace, DocNodeKind::Namespace) => {
+        let elements = doc_node.namespace_def.unwrap().elements;
+        let existing_def = existing.namespace_def.as_mut().unwrap();
+        merge_validated(&mut existing_def.elements, elements)?;
+      }
+      (DocNodeKind::Function, DocNodeKind::Function) => {
+        doc_nodes.push(doc_node);
+      }
+      _ => return Err(DocNodeBuildError::Conflict(doc_node.name)),
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,118 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use deno_graph::ModuleSpecifier;
+use serde::Serialize;
+
+use crate::manifest::PackageRoot;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+
+/// The user facing path of a symbol: the entrypoint it is imported from and
+/// its name there.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanonicalPath {
+  /// The public name of the entrypoint, like `.` or `./path`.
+  pub root: String,
+  pub specifier: ModuleSpecifier,
+  /// The dotted path of the symbol from the entrypoint, like `ns.Foo` for a
+  /// symbol reexported with `export * as ns`.
+  pub qualified_name: String,
+}
+
+/// The canonical public paths of the symbols of a package, by the location
+/// of their declarations, so links to a symbol in the internal module it is
+/// declared in can be redirected to the path it is imported with.
+///
+/// A symbol which is exported under several paths, by reexports from
+/// several entrypoints or under several names, gets the shortest one, which
+/// is the one nested in the fewest namespaces, then the one of the first
+/// entrypoint, then the first one exported by it.
+#[derive(Debug, Clone, Default)]
+pub struct CanonicalPaths {
+  paths: HashMap<(String, usize, usize), (usize, usize, CanonicalPath)>,
+}
+
+impl CanonicalPaths {
+  /// Indexes the doc nodes of the entrypoints of a package, as returned by
+  /// [`crate::DocParser::parse_package_roots`], in order of preference.
+  pub fn new(roots: &[(PackageRoot, Vec<DocNode>)]) -> Self {
+    let mut paths = Self::default();
+    for (order, (root, doc_nodes)) in roots.iter().enumerate() {
+      paths.add_doc_nodes(order, root, None, 0, doc_nodes);
+    }
+    paths
+  }
+
+  /// The canonical path of the symbol declared at `location`, which is the
+  /// location of a doc node returned by the same parser, for the module the
+  /// symbol is declared in or for any module reexporting it. Symbols which
+  /// are not reachable from an entrypoint have none.
+  pub fn get(&self, location: &Location) -> Option<&CanonicalPath> {
+    self
+      .paths
+      .get(&key(location))
+      .map(|(_, _, canonical_path)| canonical_path)
+  }
+
+  fn add_doc_nodes(
+    &mut self,
+    order: usize,
+    root: &PackageRoot,
+    namespace: Option<&str>,
+    depth: usize,
+    doc_nodes: &[DocNode],
+  ) {
+    for doc_node in doc_nodes {
+      if matches!(
+        doc_node.kind,
+        DocNodeKind::ModuleDoc
+          | DocNodeKind::Import
+          | DocNodeKind::UmdNamespace
+      ) || doc_node.declaration_kind == DeclarationKind::Private
+      {
+        continue;
+      }
+      let qualified_name = match namespace {
+        Some(namespace) => format!("{}.{}", namespace, doc_node.name),
+        None => doc_node.name.clone(),
+      };
+      let is_shorter = self
+        .paths
+        .get(&key(&doc_node.location))
+        .map(|(d, o, _)| (depth, order) < (*d, *o))
+        .unwrap_or(true);
+      if is_shorter {
+        self.paths.insert(
+          key(&doc_node.location),
+          (
+            depth,
+            order,
+            CanonicalPath {
+              root: root.name.clone(),
+              specifier: root.specifier.clone(),
+              qualified_name: qualified_name.clone(),
+            },
+          ),
+        );
+      }
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        self.add_doc_nodes(
+          order,
+          root,
+          Some(&qualified_name),
+          depth + 1,
+          &namespace_def.elements,
+        );
+      }
+    }
+  }
+}
+
+fn key(location: &Location) -> (String, usize, usize) {
+  (location.filename.clone(), location.line, location.col)
+}
@@ -0,0 +1,53 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+/// The doc nodes which are in a category, given with `@category` or
+/// `@group` tags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryGroup<'a> {
+  /// The name of the category, or `None` for the nodes without a category.
+  pub category: Option<String>,
+  pub doc_nodes: Vec<&'a DocNode>,
+}
+
+/// Buckets doc nodes by their categories, for category driven navigation.
+///
+/// A node with several categories is in each of their groups. The groups are
+/// sorted by the name of the category, with the uncategorized nodes last,
+/// and the nodes of a group are in the order given. Module docs are left
+/// out, and the elements of namespaces are not grouped separately.
+pub fn group_nodes_by_category(doc_nodes: &[DocNode]) -> Vec<CategoryGroup> {
+  let mut groups: Vec<CategoryGroup> = Vec::new();
+  let mut uncategorized = Vec::new();
+  for doc_node in doc_nodes {
+    if matches!(doc_node.kind, DocNodeKind::ModuleDoc) {
+      continue;
+    }
+    let categories = doc_node.js_doc.categories();
+    if categories.is_empty() {
+      uncategorized.push(doc_node);
+    }
+    for category in categories {
+      match groups
+        .iter_mut()
+        .find(|group| group.category.as_deref() == Some(category))
+      {
+        Some(group) => group.doc_nodes.push(doc_node),
+        None => groups.push(CategoryGroup {
+          category: Some(category.to_string()),
+          doc_nodes: vec![doc_node],
+        }),
+      }
+    }
+  }
+  groups.sort_by(|a, b| a.category.cmp(&b.category));
+  if !uncategorized.is_empty() {
+    groups.push(CategoryGroup {
+      category: None,
+      doc_nodes: uncategorized,
+    });
+  }
+  groups
+}
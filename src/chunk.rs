@@ -0,0 +1,158 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::node::DocNode;
+
+/// How doc nodes are split into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind", content = "value")]
+pub enum ChunkBy {
+  /// One chunk per module, based on the filename of each node's location.
+  Module,
+  /// Chunks of at most the provided number of top level symbols.
+  SymbolCount(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkManifestEntry {
+  /// The identifier of the chunk, which is also its index in
+  /// [`ChunkedOutput::chunks`].
+  pub id: usize,
+  /// The module the chunk was created for when chunking by module.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module: Option<String>,
+  /// The names of the top level symbols in the chunk.
+  pub symbols: Vec<String>,
+  /// The size in bytes of the serialized chunk.
+  pub size: usize,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkManifest {
+  pub chunks: Vec<ChunkManifestEntry>,
+}
+
+impl ChunkManifest {
+  /// Gets the ids of the chunks that contain a top level symbol with the
+  /// provided name.
+  pub fn find_symbol(&self, name: &str) -> Vec<usize> {
+    self
+      .chunks
+      .iter()
+      .filter(|entry| entry.symbols.iter().any(|s| s == name))
+      .map(|entry| entry.id)
+      .collect()
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChunkedOutput {
+  pub manifest: ChunkManifest,
+  /// The serialized JSON array of doc nodes for each chunk.
+  pub chunks: Vec<String>,
+}
+
+/// Splits the doc nodes into chunks and serializes each of them, along with a
+/// manifest describing which symbols ended up in which chunk.
+pub fn serialize_chunks(
+  doc_nodes: &[DocNode],
+  chunk_by: ChunkBy,
+) -> Result<ChunkedOutput, serde_json::Error> {
+  let groups: Vec<(Option<String>, Vec<&DocNode>)> = match chunk_by {
+    ChunkBy::Module => {
+      let mut groups: Vec<(Option<String>, Vec<&DocNode>)> = Vec::new();
+      for doc_node in doc_nodes {
+        let filename = &doc_node.location.filename;
+        match groups
+          .iter_mut()
+          .find(|(module, _)| module.as_ref() == Some(filename))
+        {
+          Some((_, nodes)) => nodes.push(doc_node),
+          None => groups.push((Some(filename.clone()), vec![doc_node])),
+        }
+      }
+      groups
+    }
+    ChunkBy::SymbolCount(count) => doc_nodes
+      .chunks(count.max(1))
+      .map(|nodes| (None, nodes.iter().collect()))
+      .collect(),
+  };
+
+  let mut manifest = ChunkManifest::default();
+  let mut chunks = Vec::with_capacity(groups.len());
+  for (id, (module, nodes)) in groups.into_iter().enumerate() {
+    let json = serde_json::to_string(&nodes)?;
+    let mut symbols: Vec<String> = Vec::new();
+    for node in &nodes {
+      if !node.name.is_empty() && !symbols.contains(&node.name) {
+        symbols.push(node.name.clone());
+      }
+    }
+    manifest.chunks.push(ChunkManifestEntry {
+      id,
+      module,
+      symbols,
+      size: json.len(),
+    });
+    chunks.push(json);
+  }
+
+  Ok(ChunkedOutput { manifest, chunks })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::node::Location;
+
+  fn node(name: &str, filename: &str) -> DocNode {
+    DocNode {
+      name: name.to_string(),
+      location: Location {
+        filename: filename.to_string(),
+        line: 1,
+        col: 0,
+        requested_filename: None,
+      },
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_chunk_by_module() {
+    let doc_nodes = vec![
+      node("a", "file:///a.ts"),
+      node("b", "file:///b.ts"),
+      node("c", "file:///a.ts"),
+    ];
+    let output = serialize_chunks(&doc_nodes, ChunkBy::Module).unwrap();
+    assert_eq!(output.chunks.len(), 2);
+    assert_eq!(
+      output.manifest.chunks[0].module.as_deref(),
+      Some("file:///a.ts")
+    );
+    assert_eq!(output.manifest.chunks[0].symbols, vec!["a", "c"]);
+    assert_eq!(output.manifest.find_symbol("b"), vec![1]);
+    let chunk: serde_json::Value =
+      serde_json::from_str(&output.chunks[1]).unwrap();
+    assert_eq!(chunk[0]["name"], "b");
+  }
+
+  #[test]
+  fn test_chunk_by_symbol_count() {
+    let doc_nodes = vec![
+      node("a", "file:///a.ts"),
+      node("b", "file:///a.ts"),
+      node("c", "file:///a.ts"),
+    ];
+    let output = serialize_chunks(&doc_nodes, ChunkBy::SymbolCount(2)).unwrap();
+    assert_eq!(output.chunks.len(), 2);
+    assert_eq!(output.manifest.chunks[1].symbols, vec!["c"]);
+    assert_eq!(output.manifest.chunks[1].size, output.chunks[1].len());
+  }
+}
@@ -7,21 +7,27 @@ use serde::Serialize;
 
 use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
+use crate::events::EventDef;
 use crate::function::function_to_function_def;
 use crate::function::FunctionDef;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::members::MemberGroup;
 use crate::node::DeclarationKind;
 use crate::params::assign_pat_to_param_def;
 use crate::params::ident_to_param_def;
 use crate::params::param_to_param_def;
 use crate::params::prop_name_to_string;
+use crate::params::prop_name_well_known_symbol;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::get_location;
 use crate::swc_util::is_false;
+use crate::swc_util::is_javascript;
 use crate::swc_util::js_doc_for_range;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::maybe_type_param_instantiation_to_type_defs;
 use crate::ts_type::ts_type_ann_to_def;
+use crate::ts_type::ts_type_from_str;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
@@ -55,11 +61,11 @@ cfg_if! {
 pub struct ClassConstructorParamDef {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
   #[serde(flatten)]
   pub param: ParamDef,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub readonly: bool,
 }
 
@@ -80,14 +86,20 @@ impl Display for ClassConstructorParamDef {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassConstructorDef {
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub is_optional: bool,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(default)]
   pub has_body: bool,
   pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  /// The group the member is ordered in, when members are ordered with
+  /// [`crate::DocParserOptions::member_order`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub group: Option<MemberGroup>,
   pub params: Vec<ClassConstructorParamDef>,
   pub location: Location,
 }
@@ -108,19 +120,38 @@ impl Display for ClassConstructorDef {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassPropertyDef {
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  /// The documentation which applies to the member, including inherited
+  /// documentation, when requested with
+  /// [`crate::DocParserOptions::effective_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub effective_js_doc: Option<JsDoc>,
   pub ts_type: Option<TsTypeDef>,
   pub readonly: bool,
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub decorators: Vec<DecoratorDef>,
   pub optional: bool,
   pub is_abstract: bool,
   pub is_static: bool,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
   pub name: String,
+  /// Whether the name is a well-known symbol, like `[Symbol.iterator]`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  /// The group the member is ordered in, when members are ordered with
+  /// [`crate::DocParserOptions::member_order`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub group: Option<MemberGroup>,
+  /// The base class or interface the member is inherited from, when
+  /// inherited members are copied with
+  /// [`crate::DocParserOptions::inherited_members`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub inherited_from: Option<String>,
   pub location: Location,
 }
 
@@ -163,6 +194,8 @@ impl Display for ClassPropertyDef {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassIndexSignatureDef {
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
   pub readonly: bool,
   pub params: Vec<ParamDef>,
   pub ts_type: Option<TsTypeDef>,
@@ -187,15 +220,39 @@ impl Display for ClassIndexSignatureDef {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassMethodDef {
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  /// The documentation which applies to the member, including inherited
+  /// documentation, when requested with
+  /// [`crate::DocParserOptions::effective_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub effective_js_doc: Option<JsDoc>,
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
   pub optional: bool,
   pub is_abstract: bool,
   pub is_static: bool,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
   pub name: String,
+  /// Whether the name is a well-known symbol, like `[Symbol.iterator]`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  /// The group the member is ordered in, when members are ordered with
+  /// [`crate::DocParserOptions::member_order`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub group: Option<MemberGroup>,
+  /// The base class or interface the member is inherited from, when
+  /// inherited members are copied with
+  /// [`crate::DocParserOptions::inherited_members`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub inherited_from: Option<String>,
+  /// Whether the method returns the object it is called on, like the
+  /// methods of a builder, when requested with
+  /// [`crate::DocParserOptions::chainable_methods`].
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub chainable: bool,
   pub kind: deno_ast::swc::ast::MethodKind,
   pub function_def: FunctionDef,
   pub location: Location,
@@ -249,8 +306,12 @@ pub struct ClassDef {
   pub implements: Vec<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
   pub super_type_params: Vec<TsTypeDef>,
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub decorators: Vec<DecoratorDef>,
+  /// The events of the class, from the overloads of its listener methods,
+  /// when requested with [`crate::DocParserOptions::events`].
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub events: Vec<EventDef>,
 }
 
 pub fn class_to_class_def(
@@ -346,6 +407,8 @@ pub fn class_to_class_def(
             is_optional: ctor.is_optional,
             has_body: ctor.body.is_some(),
             name: constructor_name,
+            qualified_name: None,
+            group: None,
             params,
             location: get_location(parsed_source, ctor.start()),
           };
@@ -362,12 +425,19 @@ pub fn class_to_class_def(
             function_to_function_def(parsed_source, &class_method.function);
           let method_def = ClassMethodDef {
             js_doc: method_js_doc,
+            effective_js_doc: None,
             accessibility: class_method.accessibility,
             optional: class_method.is_optional,
             is_abstract: class_method.is_abstract,
             is_static: class_method.is_static,
             is_override: class_method.is_override,
             name: method_name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
+            well_known_symbol: prop_name_well_known_symbol(&class_method.key)
+              .is_some(),
             kind: class_method.kind,
             function_def: fn_def,
             location: get_location(parsed_source, class_method.start()),
@@ -398,6 +468,7 @@ pub fn class_to_class_def(
 
           let prop_def = ClassPropertyDef {
             js_doc: prop_js_doc,
+            effective_js_doc: None,
             ts_type,
             readonly: class_prop.readonly,
             optional: class_prop.is_optional,
@@ -406,6 +477,11 @@ pub fn class_to_class_def(
             is_override: class_prop.is_override,
             accessibility: class_prop.accessibility,
             name: prop_name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            well_known_symbol: prop_name_well_known_symbol(&class_prop.key)
+              .is_some(),
             decorators,
             location: get_location(parsed_source, class_prop.start()),
           };
@@ -413,28 +489,93 @@ pub fn class_to_class_def(
         }
       }
       TsIndexSignature(ts_index_sig) => {
-        let mut params = vec![];
-        for param in &ts_index_sig.params {
-          // todo(kitsonk): investigate why `None` is provided here
-          let param_def = ts_fn_param_to_param_def(None, param);
-          params.push(param_def);
+        if let Some(js_doc) =
+          js_doc_for_range(parsed_source, &ts_index_sig.range())
+        {
+          let mut params = vec![];
+          for param in &ts_index_sig.params {
+            // todo(kitsonk): investigate why `None` is provided here
+            let param_def = ts_fn_param_to_param_def(None, param);
+            params.push(param_def);
+          }
+
+          let ts_type = ts_index_sig
+            .type_ann
+            .as_ref()
+            .map(|rt| (&*rt.type_ann).into());
+
+          let index_sig_def = ClassIndexSignatureDef {
+            js_doc,
+            readonly: ts_index_sig.readonly,
+            params,
+            ts_type,
+          };
+          index_signatures.push(index_sig_def);
+        }
+      }
+      PrivateMethod(private_method) => {
+        if let Some(method_js_doc) =
+          js_doc_for_range(parsed_source, &private_method.range())
+        {
+          let fn_def =
+            function_to_function_def(parsed_source, &private_method.function);
+          let method_def = ClassMethodDef {
+            js_doc: method_js_doc,
+            effective_js_doc: None,
+            accessibility: private_method.accessibility,
+            optional: private_method.is_optional,
+            is_abstract: private_method.is_abstract,
+            is_static: private_method.is_static,
+            is_override: private_method.is_override,
+            name: format!("#{}", private_method.key.id.sym),
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
+            well_known_symbol: false,
+            kind: private_method.kind,
+            function_def: fn_def,
+            location: get_location(parsed_source, private_method.start()),
+          };
+          methods.push(method_def);
         }
+      }
+      PrivateProp(private_prop) => {
+        if let Some(prop_js_doc) =
+          js_doc_for_range(parsed_source, &private_prop.range())
+        {
+          let ts_type = if let Some(type_ann) = &private_prop.type_ann {
+            Some(ts_type_ann_to_def(type_ann))
+          } else if let Some(value) = &private_prop.value {
+            infer_ts_type_from_expr(parsed_source, value, false)
+          } else {
+            None
+          };
+
+          let decorators =
+            decorators_to_defs(parsed_source, &private_prop.decorators);
 
-        let ts_type = ts_index_sig
-          .type_ann
-          .as_ref()
-          .map(|rt| (&*rt.type_ann).into());
-
-        let index_sig_def = ClassIndexSignatureDef {
-          readonly: ts_index_sig.readonly,
-          params,
-          ts_type,
-        };
-        index_signatures.push(index_sig_def);
+          let prop_def = ClassPropertyDef {
+            js_doc: prop_js_doc,
+            effective_js_doc: None,
+            ts_type,
+            readonly: private_prop.readonly,
+            optional: private_prop.is_optional,
+            is_abstract: false,
+            is_static: private_prop.is_static,
+            is_override: private_prop.is_override,
+            accessibility: private_prop.accessibility,
+            name: format!("#{}", private_prop.key.id.sym),
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            well_known_symbol: false,
+            decorators,
+            location: get_location(parsed_source, private_prop.start()),
+          };
+          properties.push(prop_def);
+        }
       }
-      // TODO(bartlomieju):
-      PrivateMethod(_) => {}
-      PrivateProp(_) => {}
       _ => {}
     }
   }
@@ -468,11 +609,46 @@ pub fn class_to_class_def(
       type_params,
       super_type_params,
       decorators,
+      events: Vec::new(),
     },
     js_doc,
   )
 }
 
+/// Sets the type arguments of the superclass of a JavaScript class, which
+/// the syntax cannot carry, from an `@extends {Base<T>}` or
+/// `@augments {Base<T>}` tag of its JSDoc. The superclass is set from the
+/// tag too when the class has no `extends` clause.
+pub(crate) fn set_super_type_params_from_js_doc(
+  parsed_source: &ParsedSource,
+  class_def: &mut ClassDef,
+  js_doc: &JsDoc,
+) {
+  if !is_javascript(parsed_source) || !class_def.super_type_params.is_empty() {
+    return;
+  }
+  let Some(type_ref) = js_doc
+    .tags
+    .iter()
+    .find_map(|tag| match tag {
+      JsDocTag::Extends { type_ref, .. } => ts_type_from_str(type_ref),
+      _ => None,
+    })
+    .and_then(|ts_type| ts_type.type_ref)
+  else {
+    return;
+  };
+  if class_def
+    .extends
+    .as_ref()
+    .is_some_and(|extends| *extends != type_ref.type_name)
+  {
+    return;
+  }
+  class_def.extends = Some(type_ref.type_name);
+  class_def.super_type_params = type_ref.type_params.unwrap_or_default();
+}
+
 pub fn get_doc_for_class_decl(
   parsed_source: &ParsedSource,
   class_decl: &deno_ast::swc::ast::ClassDecl,
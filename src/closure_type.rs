@@ -0,0 +1,297 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+/// Converts a type in the syntax of the Closure Compiler, as found in the
+/// JSDoc of older JavaScript, to TypeScript, like `{?string}` to
+/// `string | null` or `{function(string): number}` to
+/// `(arg0: string) => number`. Returns `None` when the type is not valid
+/// Closure syntax.
+pub(crate) fn closure_type_to_ts(type_str: &str) -> Option<String> {
+  let mut parser = ClosureTypeParser {
+    text: type_str,
+    pos: 0,
+  };
+  let ts_type = parser.parse_type()?;
+  parser.skip_whitespace();
+  if parser.pos == parser.text.len() {
+    Some(ts_type)
+  } else {
+    None
+  }
+}
+
+struct ClosureTypeParser<'a> {
+  text: &'a str,
+  pos: usize,
+}
+
+impl<'a> ClosureTypeParser<'a> {
+  fn rest(&self) -> &'a str {
+    &self.text[self.pos..]
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.len() - rest.trim_start().len();
+  }
+
+  fn eat(&mut self, token: &str) -> bool {
+    self.skip_whitespace();
+    if self.rest().starts_with(token) {
+      self.pos += token.len();
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Whether the type ends here, which makes a `?` the unknown type instead
+  /// of a nullable prefix.
+  fn at_type_end(&mut self) -> bool {
+    self.skip_whitespace();
+    self.rest().is_empty()
+      || self.rest().starts_with(&[',', ')', '>', '|', '}', '='][..])
+  }
+
+  /// `A|B`, where each type may be optional, like `string=`.
+  fn parse_type(&mut self) -> Option<String> {
+    let mut types = vec![self.parse_optional()?];
+    while self.eat("|") {
+      types.push(self.parse_optional()?);
+    }
+    if types.len() == 1 {
+      return types.pop();
+    }
+    Some(
+      types
+        .into_iter()
+        .map(parenthesize_fn)
+        .collect::<Vec<_>>()
+        .join(" | "),
+    )
+  }
+
+  fn parse_optional(&mut self) -> Option<String> {
+    let ts_type = self.parse_prefixed()?;
+    if self.eat("=") {
+      Some(format!("{} | undefined", parenthesize_fn(ts_type)))
+    } else {
+      Some(ts_type)
+    }
+  }
+
+  /// A type with the nullable `?`, non-nullable `!` or variadic `...`
+  /// prefix, or none.
+  fn parse_prefixed(&mut self) -> Option<String> {
+    if self.eat("...") {
+      return Some(format!("{}[]", parenthesize(self.parse_prefixed()?)));
+    }
+    if self.eat("!") {
+      return self.parse_prefixed();
+    }
+    if self.eat("?") {
+      if self.at_type_end() {
+        return Some("any".to_string());
+      }
+      return Some(format!(
+        "{} | null",
+        parenthesize_fn(self.parse_prefixed()?)
+      ));
+    }
+    let ts_type = self.parse_primary()?;
+    // the deprecated nullable suffix, like `string?`
+    if self.eat("?") {
+      return Some(format!("{} | null", parenthesize_fn(ts_type)));
+    }
+    Some(ts_type)
+  }
+
+  fn parse_primary(&mut self) -> Option<String> {
+    if self.eat("(") {
+      let ts_type = self.parse_type()?;
+      return self.eat(")").then(|| format!("({})", ts_type));
+    }
+    if self.eat("*") {
+      return Some("any".to_string());
+    }
+    if self.eat("{") {
+      return self.parse_record();
+    }
+    let name = self.parse_name()?;
+    if name == "function" && self.eat("(") {
+      return self.parse_function();
+    }
+    if self.eat(".<") || self.eat("<") {
+      let mut type_args = vec![self.parse_type()?];
+      while self.eat(",") {
+        type_args.push(self.parse_type()?);
+      }
+      if !self.eat(">") {
+        return None;
+      }
+      let name = if name == "Object" && type_args.len() == 2 {
+        "Record"
+      } else {
+        name
+      };
+      return Some(format!("{}<{}>", name, type_args.join(", ")));
+    }
+    Some(name.to_string())
+  }
+
+  /// A dotted name, like `goog.events.Event`.
+  fn parse_name(&mut self) -> Option<&'a str> {
+    self.skip_whitespace();
+    let rest = self.rest();
+    let mut len = 0;
+    for (i, c) in rest.char_indices() {
+      if c.is_alphanumeric() || c == '_' || c == '$' {
+        len = i + c.len_utf8();
+      } else if c == '.' && len == i && !rest[i + 1..].starts_with('<') {
+        continue;
+      } else {
+        break;
+      }
+    }
+    if len == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+      return None;
+    }
+    self.pos += len;
+    Some(&rest[..len])
+  }
+
+  /// The rest of `{a: number, b}` after the `{`, where a key without a type
+  /// is of any type.
+  fn parse_record(&mut self) -> Option<String> {
+    let mut props = Vec::new();
+    if !self.eat("}") {
+      loop {
+        let key = self.parse_name()?;
+        let ts_type = if self.eat(":") {
+          self.parse_type()?
+        } else {
+          "any".to_string()
+        };
+        props.push(format!("{}: {}", key, ts_type));
+        if self.eat("}") {
+          break;
+        }
+        if !self.eat(",") {
+          return None;
+        }
+      }
+    }
+    if props.is_empty() {
+      Some("{}".to_string())
+    } else {
+      Some(format!("{{ {} }}", props.join("; ")))
+    }
+  }
+
+  /// The rest of `function(this:T, string, number=, ...*): R` after the
+  /// `function(`, where `new:T` makes it a constructor of `T`.
+  fn parse_function(&mut self) -> Option<String> {
+    let mut params = Vec::new();
+    let mut arg_count = 0;
+    let mut maybe_new_type = None;
+    if !self.eat(")") {
+      loop {
+        if self.eat("...") {
+          let ts_type = if self.at_type_end() {
+            "any".to_string()
+          } else {
+            self.parse_prefixed()?
+          };
+          params.push(format!("...args: {}[]", parenthesize(ts_type)));
+        } else if self.eat("this:") {
+          params.insert(0, format!("this: {}", self.parse_type()?));
+        } else if self.eat("new:") {
+          maybe_new_type = Some(self.parse_type()?);
+        } else {
+          let name = format!("arg{}", arg_count);
+          arg_count += 1;
+          let ts_type = self.parse_prefixed()?;
+          if self.eat("=") {
+            params.push(format!("{}?: {}", name, ts_type));
+          } else {
+            params.push(format!("{}: {}", name, ts_type));
+          }
+        }
+        if self.eat(")") {
+          break;
+        }
+        if !self.eat(",") {
+          return None;
+        }
+      }
+    }
+    let params = params.join(", ");
+    if let Some(new_type) = maybe_new_type {
+      return Some(format!("new ({}) => {}", params, new_type));
+    }
+    let return_type = if self.eat(":") {
+      self.parse_prefixed()?
+    } else {
+      "void".to_string()
+    };
+    Some(format!("({}) => {}", params, return_type))
+  }
+}
+
+/// Wraps the type in parentheses when it is made of several parts, so that
+/// it can be an array element.
+fn parenthesize(ts_type: String) -> String {
+  if ts_type.contains(' ') {
+    format!("({})", ts_type)
+  } else {
+    ts_type
+  }
+}
+
+/// Wraps a function type in parentheses, so that it can be a member of a
+/// union.
+fn parenthesize_fn(ts_type: String) -> String {
+  if ts_type.contains("=>") {
+    format!("({})", ts_type)
+  } else {
+    ts_type
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_closure_type_to_ts() {
+    let cases = [
+      ("?string", "string | null"),
+      ("!Object", "Object"),
+      ("?", "any"),
+      ("*", "any"),
+      ("string=", "string | undefined"),
+      ("...number", "number[]"),
+      ("Array.<string>", "Array<string>"),
+      ("Object.<string, number>", "Record<string, number>"),
+      ("goog.events.Event", "goog.events.Event"),
+      ("(string|number)", "(string | number)"),
+      ("{a: number, b}", "{ a: number; b: any }"),
+      ("function(string): number", "(arg0: string) => number"),
+      (
+        "function(this:Element, string=, ...number)",
+        "(this: Element, arg0?: string, ...args: number[]) => void",
+      ),
+      ("function(new:Date)", "new () => Date"),
+      ("?function(): void", "(() => void) | null"),
+    ];
+    for (closure_type, ts_type) in cases {
+      assert_eq!(
+        closure_type_to_ts(closure_type).as_deref(),
+        Some(ts_type),
+        "{}",
+        closure_type
+      );
+    }
+    assert_eq!(closure_type_to_ts("string:"), None);
+    assert_eq!(closure_type_to_ts("Array.<string"), None);
+  }
+}
@@ -0,0 +1,352 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+/// The part of the version of a package which a change requires to bump
+/// under semantic versioning.
+#[derive(
+  Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum SemverImpact {
+  Patch,
+  Minor,
+  Major,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApiChangeKind {
+  /// The symbol is new, which is a minor change.
+  Added,
+  /// The symbol was removed, which is a major change.
+  Removed,
+  /// The declaration of the symbol changed, which is considered a major
+  /// change.
+  Changed,
+  /// Only the documentation of the symbol changed, which is a patch change.
+  DocsChanged,
+}
+
+impl ApiChangeKind {
+  pub fn impact(&self) -> SemverImpact {
+    match self {
+      Self::Added => SemverImpact::Minor,
+      Self::Removed | Self::Changed => SemverImpact::Major,
+      Self::DocsChanged => SemverImpact::Patch,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiChange {
+  /// The dotted path of the symbol, like `Deno.Conn`.
+  pub name: String,
+  pub node_kind: DocNodeKind,
+  pub kind: ApiChangeKind,
+  pub impact: SemverImpact,
+}
+
+/// The changes between two versions of a public API, sorted by symbol.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiDiff {
+  pub changes: Vec<ApiChange>,
+  /// The largest impact of the changes, which is `None` when nothing
+  /// changed.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub impact: Option<SemverImpact>,
+}
+
+/// Compares the public doc nodes of two versions of an API. Symbols are
+/// matched by name and kind, where the overloads of a function make up a
+/// single symbol, and the elements of namespaces are compared one by one.
+/// Locations are ignored, so moving a declaration is no change.
+pub fn diff_doc_nodes(old: &[DocNode], new: &[DocNode]) -> ApiDiff {
+  let mut changes = Vec::new();
+  diff_symbols(old, new, None, &mut changes);
+  changes.sort_by(|a, b| (&a.name, &a.node_kind).cmp(&(&b.name, &b.node_kind)));
+  let impact = changes.iter().map(|change| change.impact).max();
+  ApiDiff { changes, impact }
+}
+
+fn diff_symbols(
+  old: &[DocNode],
+  new: &[DocNode],
+  parent: Option<&str>,
+  changes: &mut Vec<ApiChange>,
+) {
+  let old = group_symbols(old);
+  let mut new = group_symbols(new);
+  let mut push = |(name, node_kind): (&str, DocNodeKind),
+                  kind: ApiChangeKind| {
+    let name = match parent {
+      Some(parent) => format!("{}.{}", parent, name),
+      None => name.to_string(),
+    };
+    changes.push(ApiChange {
+      name,
+      node_kind,
+      kind,
+      impact: kind.impact(),
+    });
+  };
+  let mut namespaces = Vec::new();
+  for (key, old_nodes) in old {
+    let Some(new_nodes) = new.remove(&key) else {
+      push(key, ApiChangeKind::Removed);
+      continue;
+    };
+    if old_nodes.len() == 1 && new_nodes.len() == 1 {
+      let (old_node, new_node) = (old_nodes[0], new_nodes[0]);
+      if let (Some(old_def), Some(new_def)) =
+        (&old_node.namespace_def, &new_node.namespace_def)
+      {
+        namespaces.push((key.0, &old_def.elements, &new_def.elements));
+      }
+    }
+    let old_value = comparable_value(&old_nodes);
+    let new_value = comparable_value(&new_nodes);
+    if declaration(&old_value) != declaration(&new_value) {
+      push(key, ApiChangeKind::Changed);
+    } else if old_value != new_value {
+      push(key, ApiChangeKind::DocsChanged);
+    }
+  }
+  for key in new.into_keys() {
+    push(key, ApiChangeKind::Added);
+  }
+  for (name, old_elements, new_elements) in namespaces {
+    let name = match parent {
+      Some(parent) => format!("{}.{}", parent, name),
+      None => name.to_string(),
+    };
+    diff_symbols(old_elements, new_elements, Some(&name), changes);
+  }
+}
+
+/// Groups the public doc nodes by name and kind.
+fn group_symbols(
+  doc_nodes: &[DocNode],
+) -> BTreeMap<(&str, DocNodeKind), Vec<&DocNode>> {
+  let mut symbols: BTreeMap<_, Vec<_>> = BTreeMap::new();
+  for doc_node in doc_nodes {
+    if matches!(
+      doc_node.kind,
+      DocNodeKind::ModuleDoc | DocNodeKind::Import | DocNodeKind::UmdNamespace
+    ) || doc_node.declaration_kind == DeclarationKind::Private
+    {
+      continue;
+    }
+    symbols
+      .entry((doc_node.name.as_str(), doc_node.kind.clone()))
+      .or_default()
+      .push(doc_node);
+  }
+  symbols
+}
+
+/// The doc nodes as JSON without their locations and the elements of
+/// namespaces, which are compared separately.
+fn comparable_value(doc_nodes: &[&DocNode]) -> Value {
+  let mut value = serde_json::to_value(doc_nodes).unwrap();
+  strip_keys(&mut value, &["location", "qualifiedName"]);
+  for doc_node in value.as_array_mut().unwrap() {
+    if let Some(namespace_def) = doc_node.get_mut("namespaceDef") {
+      namespace_def.as_object_mut().unwrap().remove("elements");
+    }
+  }
+  value
+}
+
+/// The declaration described by the comparable value of doc nodes, without
+/// their documentation.
+fn declaration(value: &Value) -> Value {
+  let mut value = value.clone();
+  strip_keys(
+    &mut value,
+    &["jsDoc", "effectiveJsDoc", "stability", "declarationKind"],
+  );
+  value
+}
+
+/// Renders the differences between expected and actual doc nodes, like the
+/// ones of a golden test fixture, symbol by symbol and field by field, or
+/// `None` when they are equal. Unlike [`diff_doc_nodes`], every node and
+/// field is compared, including locations and private nodes.
+///
+/// ```text
+/// ~ Deno.open (function)
+///   - functionDef.returnType.repr: "File"
+///   + functionDef.returnType.repr: "FsFile"
+/// - Deno.close (function)
+/// + Deno.File (class)
+/// ```
+pub fn render_doc_node_diff(
+  expected: &[DocNode],
+  actual: &[DocNode],
+) -> Option<String> {
+  let mut output = String::new();
+  render_symbols_diff(expected, actual, None, &mut output);
+  if output.is_empty() {
+    None
+  } else {
+    Some(output)
+  }
+}
+
+fn render_symbols_diff(
+  expected: &[DocNode],
+  actual: &[DocNode],
+  parent: Option<&str>,
+  output: &mut String,
+) {
+  let mut symbols: BTreeMap<_, (Vec<&DocNode>, Vec<&DocNode>)> =
+    BTreeMap::new();
+  for doc_node in expected {
+    let key = (doc_node.name.as_str(), doc_node.kind.clone());
+    symbols.entry(key).or_default().0.push(doc_node);
+  }
+  for doc_node in actual {
+    let key = (doc_node.name.as_str(), doc_node.kind.clone());
+    symbols.entry(key).or_default().1.push(doc_node);
+  }
+  for ((name, kind), (expected, actual)) in symbols {
+    let name = match parent {
+      Some(parent) => format!("{}.{}", parent, name),
+      None => name.to_string(),
+    };
+    let kind = serde_json::to_value(kind).unwrap();
+    let kind = kind.as_str().unwrap();
+    // overloads are compared in order
+    for i in 0..expected.len().max(actual.len()) {
+      let symbol = if expected.len().max(actual.len()) > 1 {
+        format!("{} ({}) #{}", name, kind, i + 1)
+      } else {
+        format!("{} ({})", name, kind)
+      };
+      let (expected, actual) = match (expected.get(i), actual.get(i)) {
+        (Some(expected), Some(actual)) => (*expected, *actual),
+        (Some(_), None) => {
+          output.push_str(&format!("- {}\n", symbol));
+          continue;
+        }
+        (None, Some(_)) => {
+          output.push_str(&format!("+ {}\n", symbol));
+          continue;
+        }
+        (None, None) => unreachable!(),
+      };
+      let mut fields = Vec::new();
+      render_value_diff(
+        "",
+        &node_value(expected),
+        &node_value(actual),
+        &mut fields,
+      );
+      if !fields.is_empty() {
+        output.push_str(&format!("~ {}\n", symbol));
+        for field in fields {
+          output.push_str(&format!("  {}\n", field));
+        }
+      }
+      if let (Some(expected_def), Some(actual_def)) =
+        (&expected.namespace_def, &actual.namespace_def)
+      {
+        render_symbols_diff(
+          &expected_def.elements,
+          &actual_def.elements,
+          Some(&name),
+          output,
+        );
+      }
+    }
+  }
+}
+
+/// A doc node as JSON without the elements of its namespace, which are
+/// compared as symbols of their own.
+fn node_value(doc_node: &DocNode) -> Value {
+  let mut value = serde_json::to_value(doc_node).unwrap();
+  if let Some(namespace_def) = value.get_mut("namespaceDef") {
+    namespace_def.as_object_mut().unwrap().remove("elements");
+  }
+  value
+}
+
+/// Renders the fields which differ between two values as `-` and `+` lines
+/// prefixed by their path, like `functionDef.params[0].name`.
+fn render_value_diff(
+  path: &str,
+  expected: &Value,
+  actual: &Value,
+  lines: &mut Vec<String>,
+) {
+  match (expected, actual) {
+    (Value::Object(expected), Value::Object(actual)) => {
+      let mut keys = expected.keys().chain(actual.keys()).collect::<Vec<_>>();
+      keys.sort();
+      keys.dedup();
+      for key in keys {
+        let path = if path.is_empty() {
+          key.to_string()
+        } else {
+          format!("{}.{}", path, key)
+        };
+        render_value_diff(
+          &path,
+          expected.get(key).unwrap_or(&Value::Null),
+          actual.get(key).unwrap_or(&Value::Null),
+          lines,
+        );
+      }
+    }
+    (Value::Array(expected), Value::Array(actual)) => {
+      for i in 0..expected.len().max(actual.len()) {
+        render_value_diff(
+          &format!("{}[{}]", path, i),
+          expected.get(i).unwrap_or(&Value::Null),
+          actual.get(i).unwrap_or(&Value::Null),
+          lines,
+        );
+      }
+    }
+    (expected, actual) if expected != actual => {
+      if !expected.is_null() {
+        lines.push(format!("- {}: {}", path, expected));
+      }
+      if !actual.is_null() {
+        lines.push(format!("+ {}: {}", path, actual));
+      }
+    }
+    _ => {}
+  }
+}
+
+fn strip_keys(value: &mut Value, keys: &[&str]) {
+  match value {
+    Value::Object(map) => {
+      for key in keys {
+        map.remove(*key);
+      }
+      for value in map.values_mut() {
+        strip_keys(value, keys);
+      }
+    }
+    Value::Array(values) => {
+      for value in values {
+        strip_keys(value, keys);
+      }
+    }
+    _ => {}
+  }
+}
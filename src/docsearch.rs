@@ -0,0 +1,187 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::anchor::AnchorGenerator;
+use crate::js_doc::JsDoc;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+
+/// The hierarchy of a DocSearch record, going from the module (`lvl0`) to
+/// namespaces, symbols and their members. Unused levels are `null`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocSearchHierarchy {
+  pub lvl0: Option<String>,
+  pub lvl1: Option<String>,
+  pub lvl2: Option<String>,
+  pub lvl3: Option<String>,
+  pub lvl4: Option<String>,
+  pub lvl5: Option<String>,
+  pub lvl6: Option<String>,
+}
+
+impl DocSearchHierarchy {
+  fn from_levels(levels: &[String]) -> Self {
+    let mut levels = levels.iter().cloned();
+    Self {
+      lvl0: levels.next(),
+      lvl1: levels.next(),
+      lvl2: levels.next(),
+      lvl3: levels.next(),
+      lvl4: levels.next(),
+      lvl5: levels.next(),
+      lvl6: levels.next(),
+    }
+  }
+}
+
+/// A record compatible with the Algolia DocSearch record format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocSearchRecord {
+  #[serde(rename = "objectID")]
+  pub object_id: String,
+  pub hierarchy: DocSearchHierarchy,
+  /// The deepest level of the hierarchy this record represents, for example
+  /// `"lvl2"`.
+  #[serde(rename = "type")]
+  pub record_type: String,
+  pub content: Option<String>,
+  pub url: String,
+  pub anchor: Option<String>,
+}
+
+/// The maximum number of levels supported by DocSearch.
+const MAX_LEVELS: usize = 7;
+
+/// Creates DocSearch records for the doc nodes of a module. The module is
+/// the top level of the hierarchy and `url` is the page the module is
+/// documented at, to which the anchor of each symbol, as created by an
+/// [`AnchorGenerator`], is appended.
+pub fn docsearch_records(
+  module_name: &str,
+  url: &str,
+  doc_nodes: &[DocNode],
+) -> Vec<DocSearchRecord> {
+  let mut records = Vec::new();
+  let module_doc = doc_nodes
+    .iter()
+    .find(|node| node.kind == DocNodeKind::ModuleDoc)
+    .and_then(|node| node.js_doc.doc.clone());
+  records.push(DocSearchRecord {
+    object_id: url.to_string(),
+    hierarchy: DocSearchHierarchy::from_levels(&[module_name.to_string()]),
+    record_type: "lvl0".to_string(),
+    content: module_doc,
+    url: url.to_string(),
+    anchor: None,
+  });
+  let mut levels = vec![module_name.to_string()];
+  let mut anchors = AnchorGenerator::new();
+  push_records_for_nodes(
+    &mut records,
+    &mut anchors,
+    &mut levels,
+    &[],
+    url,
+    doc_nodes,
+  );
+  records
+}
+
+fn push_records_for_nodes(
+  records: &mut Vec<DocSearchRecord>,
+  anchors: &mut AnchorGenerator,
+  levels: &mut Vec<String>,
+  path: &[String],
+  url: &str,
+  doc_nodes: &[DocNode],
+) {
+  for doc_node in doc_nodes {
+    if matches!(
+      doc_node.kind,
+      DocNodeKind::ModuleDoc | DocNodeKind::Import | DocNodeKind::UmdNamespace
+    ) {
+      continue;
+    }
+    let mut path = path.to_vec();
+    path.push(doc_node.name.clone());
+    levels.push(doc_node.name.clone());
+    push_record(records, anchors, levels, &path, url, &doc_node.js_doc);
+
+    for (name, js_doc) in members(doc_node) {
+      let mut member_path = path.clone();
+      member_path.push(name.to_string());
+      levels.push(name.to_string());
+      push_record(records, anchors, levels, &member_path, url, js_doc);
+      levels.pop();
+    }
+
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      push_records_for_nodes(
+        records,
+        anchors,
+        levels,
+        &path,
+        url,
+        &namespace_def.elements,
+      );
+    }
+    levels.pop();
+  }
+}
+
+fn push_record(
+  records: &mut Vec<DocSearchRecord>,
+  anchors: &mut AnchorGenerator,
+  levels: &[String],
+  path: &[String],
+  url: &str,
+  js_doc: &JsDoc,
+) {
+  // DocSearch only supports a fixed number of levels, so deeper items are
+  // folded into the deepest one
+  let levels = &levels[..levels.len().min(MAX_LEVELS)];
+  let path = path.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+  let anchor = anchors.anchor(&path);
+  let url = format!("{}#{}", url, anchor);
+  // overloads and merged declarations share the same anchor
+  if records.iter().any(|record| record.url == url) {
+    return;
+  }
+  records.push(DocSearchRecord {
+    object_id: url.clone(),
+    hierarchy: DocSearchHierarchy::from_levels(levels),
+    record_type: format!("lvl{}", levels.len() - 1),
+    content: js_doc.doc.clone(),
+    url,
+    anchor: Some(anchor),
+  });
+}
+
+fn members(doc_node: &DocNode) -> Vec<(&str, &JsDoc)> {
+  let mut members = Vec::new();
+  if let Some(class_def) = &doc_node.class_def {
+    for property in &class_def.properties {
+      members.push((property.name.as_str(), &property.js_doc));
+    }
+    for method in &class_def.methods {
+      members.push((method.name.as_str(), &method.js_doc));
+    }
+  }
+  if let Some(interface_def) = &doc_node.interface_def {
+    for property in &interface_def.properties {
+      members.push((property.name.as_str(), &property.js_doc));
+    }
+    for method in &interface_def.methods {
+      members.push((method.name.as_str(), &method.js_doc));
+    }
+  }
+  if let Some(enum_def) = &doc_node.enum_def {
+    for member in &enum_def.members {
+      members.push((member.name.as_str(), &member.js_doc));
+    }
+  }
+  members
+}
@@ -8,6 +8,7 @@ use serde::Serialize;
 use crate::js_doc::JsDoc;
 use crate::swc_util::get_location;
 use crate::swc_util::js_doc_for_range;
+use crate::swc_util::trailing_line_comment_for_range;
 use crate::ts_type::infer_ts_type_from_expr;
 use crate::ts_type::TsTypeDef;
 use crate::Location;
@@ -17,8 +18,10 @@ use crate::Location;
 pub struct EnumMemberDef {
   pub name: String,
   #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub init: Option<TsTypeDef>,
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
   pub location: Location,
 }
@@ -53,6 +56,7 @@ pub fn get_doc_for_ts_enum_decl(
 
       let member_def = EnumMemberDef {
         name,
+        qualified_name: None,
         init,
         js_doc,
         location: get_location(parsed_source, enum_member.start()),
@@ -65,3 +69,30 @@ pub fn get_doc_for_ts_enum_decl(
 
   (enum_name, enum_def)
 }
+
+/// Documents the members of the enum which have no JSDoc with the `//`
+/// comment which trails them on their line, if any.
+pub fn set_trailing_comment_docs(
+  parsed_source: &ParsedSource,
+  enum_decl: &deno_ast::swc::ast::TsEnumDecl,
+  enum_def: &mut EnumDef,
+) {
+  for enum_member in &enum_decl.members {
+    let location = get_location(parsed_source, enum_member.start());
+    let Some(member_def) = enum_def
+      .members
+      .iter_mut()
+      .find(|member_def| member_def.location == location)
+    else {
+      continue;
+    };
+    if !member_def.js_doc.is_empty() {
+      continue;
+    }
+    if let Some(comment) =
+      trailing_line_comment_for_range(parsed_source, &enum_member.range())
+    {
+      member_def.js_doc.doc = Some(comment);
+    }
+  }
+}
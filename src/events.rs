@@ -0,0 +1,197 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::node::DocNode;
+use crate::params::ParamDef;
+use crate::ts_type::LiteralDefKind;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type_param::TsTypeParamDef;
+
+/// The methods whose overloads declare the events of a class or interface.
+const LISTENER_METHODS: &[&str] = &[
+  "addEventListener",
+  "addListener",
+  "on",
+  "once",
+  "prependListener",
+  "prependOnceListener",
+];
+
+/// An event of a class or interface, found in the overloads of its listener
+/// methods, like `on(event: "data", listener: (chunk: Uint8Array) => void)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventDef {
+  pub name: String,
+  /// The type of the first param of the listener, if it has one.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub payload: Option<TsTypeDef>,
+  /// The method the event is listened to with, like `on` or
+  /// `addEventListener`.
+  pub method: String,
+  /// The documentation of the overload which declares the event.
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
+}
+
+/// Sets the `events` of the classes and interfaces of the doc nodes and of
+/// the elements of namespaces from the overloads of their listener methods,
+/// like `on` and `addEventListener`, whose first param is typed with a
+/// string literal or a union of them. A param typed with a type param
+/// constrained to `keyof` an interface among the doc nodes, like
+/// `K extends keyof WebSocketEventMap`, declares the properties of that
+/// interface as events. An event declared by several overloads is listed
+/// once.
+pub(crate) fn set_events(doc_nodes: &mut [DocNode]) {
+  let snapshot = doc_nodes.to_vec();
+  let mut event_maps = HashMap::new();
+  collect_interfaces(&mut event_maps, &snapshot);
+  set_events_with_maps(&event_maps, doc_nodes);
+}
+
+fn collect_interfaces<'a>(
+  interfaces: &mut HashMap<String, &'a InterfaceDef>,
+  doc_nodes: &'a [DocNode],
+) {
+  for doc_node in doc_nodes {
+    if let Some(interface_def) = &doc_node.interface_def {
+      interfaces
+        .entry(doc_node.name.clone())
+        .or_insert(interface_def);
+    }
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_interfaces(interfaces, &namespace_def.elements);
+    }
+  }
+}
+
+fn set_events_with_maps(
+  event_maps: &HashMap<String, &InterfaceDef>,
+  doc_nodes: &mut [DocNode],
+) {
+  for doc_node in doc_nodes {
+    if let Some(class_def) = &mut doc_node.class_def {
+      let mut events = Vec::new();
+      for method in &class_def.methods {
+        collect_events(
+          &mut events,
+          event_maps,
+          &method.name,
+          &method.function_def.params,
+          &method.function_def.type_params,
+          &method.js_doc,
+        );
+      }
+      class_def.events = events;
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      let mut events = Vec::new();
+      for method in &interface_def.methods {
+        collect_events(
+          &mut events,
+          event_maps,
+          &method.name,
+          &method.params,
+          &method.type_params,
+          &method.js_doc,
+        );
+      }
+      interface_def.events = events;
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_events_with_maps(event_maps, &mut namespace_def.elements);
+    }
+  }
+}
+
+/// Adds the events declared by an overload of a method, if it is a listener
+/// method, which are not among the events yet.
+fn collect_events(
+  events: &mut Vec<EventDef>,
+  event_maps: &HashMap<String, &InterfaceDef>,
+  method_name: &str,
+  params: &[ParamDef],
+  type_params: &[TsTypeParamDef],
+  js_doc: &JsDoc,
+) {
+  if !LISTENER_METHODS.contains(&method_name) {
+    return;
+  }
+  let Some(event_type) = params.first().and_then(|param| param.ts_type())
+  else {
+    return;
+  };
+  let payload = params
+    .get(1)
+    .and_then(|param| param.ts_type())
+    .and_then(|listener| listener.fn_or_constructor.as_ref())
+    .and_then(|listener| listener.params.first())
+    .and_then(|param| param.ts_type())
+    .cloned();
+
+  let mut declared = Vec::new();
+  if let Some(names) = string_literals(event_type) {
+    declared.extend(names.into_iter().map(|name| (name, payload.clone())));
+  } else if let Some(event_map) = keyof_type_param(event_type, type_params)
+    .and_then(|name| event_maps.get(name))
+  {
+    declared.extend(
+      event_map
+        .properties
+        .iter()
+        .map(|property| (property.name.clone(), property.ts_type.clone())),
+    );
+  }
+  for (name, payload) in declared {
+    if !events.iter().any(|event| event.name == name) {
+      events.push(EventDef {
+        name,
+        payload,
+        method: method_name.to_string(),
+        js_doc: js_doc.clone(),
+      });
+    }
+  }
+}
+
+/// The strings of a string literal type or of a union of them.
+fn string_literals(ts_type: &TsTypeDef) -> Option<Vec<String>> {
+  if let Some(union) = &ts_type.union {
+    return union.iter().map(string_literal).collect();
+  }
+  string_literal(ts_type).map(|name| vec![name])
+}
+
+fn string_literal(ts_type: &TsTypeDef) -> Option<String> {
+  let literal = ts_type.literal.as_ref()?;
+  if literal.kind == LiteralDefKind::String {
+    literal.string.clone()
+  } else {
+    None
+  }
+}
+
+/// The name of the type `T` when the type refers to a type param constrained
+/// to `keyof T`.
+fn keyof_type_param<'a>(
+  ts_type: &TsTypeDef,
+  type_params: &'a [TsTypeParamDef],
+) -> Option<&'a str> {
+  let type_name = &ts_type.type_ref.as_ref()?.type_name;
+  let constraint = type_params
+    .iter()
+    .find(|type_param| type_param.name == *type_name)?
+    .constraint
+    .as_ref()?;
+  let type_operator = constraint.type_operator.as_ref()?;
+  if type_operator.operator != "keyof" {
+    return None;
+  }
+  Some(&type_operator.ts_type.type_ref.as_ref()?.type_name)
+}
@@ -0,0 +1,118 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+//! A C interface to the documentation engine, for embedding it in tools which
+//! are written in neither Rust nor JavaScript.
+//!
+//! Strings are passed in and out as NUL terminated UTF-8. Every string
+//! returned by [`deno_doc_parse`] must be released with [`deno_doc_free`].
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use anyhow::anyhow;
+use deno_graph::source::MemoryLoader;
+use deno_graph::source::Source;
+use deno_graph::BuildOptions;
+use deno_graph::CapturingModuleAnalyzer;
+use deno_graph::GraphKind;
+use deno_graph::ModuleGraph;
+use deno_graph::ModuleSpecifier;
+use serde_json::json;
+
+use crate::parser::DocParser;
+
+/// Documents the module `root_specifier`, loading it and its dependencies
+/// from `sources_json`, a JSON object mapping specifiers to their source
+/// text. Remote modules which are not in the object are not loaded.
+///
+/// Returns a JSON object, either `{ "nodes": [...] }` with the doc nodes, or
+/// `{ "error": "..." }`. Only returns null if the result could not be
+/// allocated.
+///
+/// # Safety
+///
+/// `root_specifier` and `sources_json` must be valid pointers to NUL
+/// terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn deno_doc_parse(
+  root_specifier: *const c_char,
+  sources_json: *const c_char,
+  include_all: bool,
+) -> *mut c_char {
+  let result = parse(root_specifier, sources_json, include_all);
+  let value = match result {
+    Ok(nodes) => json!({ "nodes": nodes }),
+    Err(err) => json!({ "error": err.to_string() }),
+  };
+  match CString::new(value.to_string()) {
+    Ok(s) => s.into_raw(),
+    Err(_) => std::ptr::null_mut(),
+  }
+}
+
+/// Releases a string returned by [`deno_doc_parse`]. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `s` must have been returned by [`deno_doc_parse`], and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn deno_doc_free(s: *mut c_char) {
+  if !s.is_null() {
+    drop(CString::from_raw(s));
+  }
+}
+
+unsafe fn parse(
+  root_specifier: *const c_char,
+  sources_json: *const c_char,
+  include_all: bool,
+) -> Result<serde_json::Value, anyhow::Error> {
+  if root_specifier.is_null() || sources_json.is_null() {
+    return Err(anyhow!("Unexpected null argument."));
+  }
+  let root_specifier = CStr::from_ptr(root_specifier).to_str()?;
+  let sources_json = CStr::from_ptr(sources_json).to_str()?;
+  let sources: HashMap<String, String> = serde_json::from_str(sources_json)?;
+  let root_specifier = ModuleSpecifier::parse(root_specifier)?;
+
+  let mut loader = MemoryLoader::new(
+    sources
+      .into_iter()
+      .map(|(specifier, content)| {
+        (
+          specifier.clone(),
+          Source::Module {
+            specifier,
+            maybe_headers: None,
+            content,
+          },
+        )
+      })
+      .collect(),
+    vec![],
+  );
+  let analyzer = CapturingModuleAnalyzer::default();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  futures::executor::block_on(trace_instrument!(
+    graph.build(
+      vec![root_specifier.clone()],
+      &mut loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        ..Default::default()
+      },
+    ),
+    INFO,
+    "build_graph",
+    root = %root_specifier
+  ));
+  let entries = {
+    trace_span!(INFO, "parse", root = %root_specifier);
+    DocParser::new(&graph, include_all, analyzer.as_capturing_parser())?
+      .parse_with_reexports(&root_specifier)?
+  };
+  trace_span!(INFO, "serialize");
+  Ok(serde_json::to_value(entries)?)
+}
@@ -3,7 +3,6 @@
 use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::params::param_to_param_def;
-use crate::swc_util::is_false;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
@@ -17,12 +16,12 @@ use serde::{Deserialize, Serialize};
 pub struct FunctionDef {
   pub params: Vec<ParamDef>,
   pub return_type: Option<TsTypeDef>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(default)]
   pub has_body: bool,
   pub is_async: bool,
   pub is_generator: bool,
   pub type_params: Vec<TsTypeParamDef>,
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub decorators: Vec<DecoratorDef>,
 }
 
@@ -0,0 +1,161 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use crate::class::ClassConstructorDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::node::DocNode;
+use crate::r#enum::EnumMemberDef;
+
+/// A node of the documentation, either a doc node or a member of one.
+#[derive(Debug, Clone, Copy)]
+pub enum IndexedNode<'a> {
+  DocNode(&'a DocNode),
+  ClassConstructor(&'a ClassConstructorDef),
+  ClassProperty(&'a ClassPropertyDef),
+  ClassMethod(&'a ClassMethodDef),
+  InterfaceProperty(&'a InterfacePropertyDef),
+  InterfaceMethod(&'a InterfaceMethodDef),
+  EnumMember(&'a EnumMemberDef),
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexEntry<'a> {
+  /// The id of the entry, which is its position in the index.
+  pub id: usize,
+  /// The id of the entry of the namespace, class, interface or enum which
+  /// owns this one.
+  pub parent: Option<usize>,
+  /// The dotted path of the node from the root of the module, for example
+  /// `Deno.Conn.closeWrite`.
+  pub qualified_name: String,
+  pub node: IndexedNode<'a>,
+}
+
+/// An index of doc nodes and their members, where every entry knows its
+/// owner, so the tree can be navigated upward.
+#[derive(Debug, Clone, Default)]
+pub struct DocNodeIndex<'a> {
+  entries: Vec<IndexEntry<'a>>,
+  by_qualified_name: HashMap<String, Vec<usize>>,
+}
+
+impl<'a> DocNodeIndex<'a> {
+  pub fn new(doc_nodes: &'a [DocNode]) -> Self {
+    let mut index = Self::default();
+    index.add_doc_nodes(None, doc_nodes);
+    index
+  }
+
+  pub fn entries(&self) -> &[IndexEntry<'a>] {
+    &self.entries
+  }
+
+  pub fn get(&self, id: usize) -> Option<&IndexEntry<'a>> {
+    self.entries.get(id)
+  }
+
+  pub fn parent(&self, id: usize) -> Option<&IndexEntry<'a>> {
+    self.get(id)?.parent.and_then(|parent| self.get(parent))
+  }
+
+  /// The ids of the owners of an entry, from its parent up to the root.
+  pub fn owner_chain(&self, id: usize) -> Vec<usize> {
+    let mut chain = Vec::new();
+    let mut current = self.get(id).and_then(|entry| entry.parent);
+    while let Some(id) = current {
+      chain.push(id);
+      current = self.entries[id].parent;
+    }
+    chain
+  }
+
+  /// The entries with a qualified name, of which there can be several for
+  /// overloads and merged declarations.
+  pub fn find(&self, qualified_name: &str) -> Vec<&IndexEntry<'a>> {
+    self
+      .by_qualified_name
+      .get(qualified_name)
+      .map(|ids| ids.iter().map(|id| &self.entries[*id]).collect())
+      .unwrap_or_default()
+  }
+
+  fn add(
+    &mut self,
+    parent: Option<usize>,
+    name: &str,
+    node: IndexedNode<'a>,
+  ) -> usize {
+    let id = self.entries.len();
+    let qualified_name = match parent {
+      Some(parent) => {
+        format!("{}.{}", self.entries[parent].qualified_name, name)
+      }
+      None => name.to_string(),
+    };
+    self
+      .by_qualified_name
+      .entry(qualified_name.clone())
+      .or_default()
+      .push(id);
+    self.entries.push(IndexEntry {
+      id,
+      parent,
+      qualified_name,
+      node,
+    });
+    id
+  }
+
+  fn add_doc_nodes(&mut self, parent: Option<usize>, doc_nodes: &'a [DocNode]) {
+    for doc_node in doc_nodes {
+      let id = self.add(parent, &doc_node.name, IndexedNode::DocNode(doc_node));
+      if let Some(class_def) = &doc_node.class_def {
+        for constructor in &class_def.constructors {
+          self.add(
+            Some(id),
+            &constructor.name,
+            IndexedNode::ClassConstructor(constructor),
+          );
+        }
+        for property in &class_def.properties {
+          self.add(
+            Some(id),
+            &property.name,
+            IndexedNode::ClassProperty(property),
+          );
+        }
+        for method in &class_def.methods {
+          self.add(Some(id), &method.name, IndexedNode::ClassMethod(method));
+        }
+      }
+      if let Some(interface_def) = &doc_node.interface_def {
+        for property in &interface_def.properties {
+          self.add(
+            Some(id),
+            &property.name,
+            IndexedNode::InterfaceProperty(property),
+          );
+        }
+        for method in &interface_def.methods {
+          self.add(
+            Some(id),
+            &method.name,
+            IndexedNode::InterfaceMethod(method),
+          );
+        }
+      }
+      if let Some(enum_def) = &doc_node.enum_def {
+        for member in &enum_def.members {
+          self.add(Some(id), &member.name, IndexedNode::EnumMember(member));
+        }
+      }
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        self.add_doc_nodes(Some(id), &namespace_def.elements);
+      }
+    }
+  }
+}
@@ -0,0 +1,568 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::interface::InterfaceMethodDef;
+use crate::interface::InterfacePropertyDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::params::ParamDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type_param::TsTypeParamDef;
+
+/// Which members of their bases are copied into classes and interfaces, with
+/// [`crate::DocParserOptions::inherited_members`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InheritedMembers {
+  /// The names of bases whose members, and the members they inherit in
+  /// turn, are not copied, like `Error` or `EventTarget`, so that a class
+  /// shows the API it adds to them.
+  pub exclude_bases: Vec<String>,
+  /// Substitute the type arguments a base is instantiated with, like
+  /// `Map<string, Item>`, for its type params in the inherited members, so
+  /// `get(key: K): V | undefined` is documented as
+  /// `get(key: string): Item | undefined`.
+  pub substitute_type_params: bool,
+}
+
+/// Sets the `effective_js_doc` of the doc nodes, and of the methods and
+/// properties of classes and interfaces, which is the documentation that
+/// applies to them once inheritance is resolved.
+///
+/// The own documentation of a node, which already includes the
+/// documentation of the export statement it was re-exported with, comes
+/// first. When it has no description, or is tagged with `@inheritDoc`, the
+/// description is taken from the first base class or interface, in the order
+/// they are extended or implemented, which has one, and tags which the node
+/// does not have are appended. Members inherit from the member of the same
+/// name. Only bases which are among the doc nodes can be inherited from.
+pub(crate) fn set_effective_js_docs(doc_nodes: &mut [DocNode]) {
+  let snapshot = doc_nodes.to_vec();
+  let mut index = HashMap::new();
+  index_nodes(&mut index, None, &snapshot);
+  let inheritance = Inheritance { index };
+  inheritance.set_effective_js_docs(None, doc_nodes);
+}
+
+/// Copies the methods and properties which classes and interfaces inherit
+/// from their bases, and do not declare themselves, into them, setting their
+/// `inherited_from`. Classes inherit from the classes they extend, and
+/// interfaces from the interfaces they extend, nearest bases first. Only
+/// bases which are among the doc nodes can be inherited from.
+pub(crate) fn set_inherited_members(
+  doc_nodes: &mut [DocNode],
+  options: &InheritedMembers,
+) {
+  let snapshot = doc_nodes.to_vec();
+  let mut index = HashMap::new();
+  index_nodes(&mut index, None, &snapshot);
+  let inheritance = Inheritance { index };
+  inheritance.set_inherited_members(None, doc_nodes, options);
+}
+
+fn index_nodes<'a>(
+  index: &mut HashMap<String, Vec<&'a DocNode>>,
+  namespace: Option<&str>,
+  doc_nodes: &'a [DocNode],
+) {
+  for doc_node in doc_nodes {
+    let qualified_name = qualify(namespace, &doc_node.name);
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      index_nodes(index, Some(&qualified_name), &namespace_def.elements);
+    }
+    if doc_node.class_def.is_some() || doc_node.interface_def.is_some() {
+      index.entry(qualified_name).or_default().push(doc_node);
+    }
+  }
+}
+
+fn qualify(namespace: Option<&str>, name: &str) -> String {
+  match namespace {
+    Some(namespace) => format!("{}.{}", namespace, name),
+    None => name.to_string(),
+  }
+}
+
+/// The names of the classes and interfaces a class or interface extends or
+/// implements, without type arguments.
+fn base_names(doc_node: &DocNode) -> Vec<String> {
+  let mut names = Vec::new();
+  if let Some(class_def) = &doc_node.class_def {
+    names.extend(class_def.extends.clone());
+    names.extend(class_def.implements.iter().map(|t| t.repr.clone()));
+  }
+  if let Some(interface_def) = &doc_node.interface_def {
+    names.extend(interface_def.extends.iter().map(|t| t.repr.clone()));
+  }
+  names
+}
+
+/// Replaces the references to the type params in `type_args` with the
+/// type arguments they are instantiated with.
+fn substitute_type_params(
+  ts_type: &mut TsTypeDef,
+  type_args: &HashMap<String, TsTypeDef>,
+) {
+  if let Some(type_ref) = &ts_type.type_ref {
+    let has_type_args = type_ref
+      .type_params
+      .as_ref()
+      .map(|type_params| !type_params.is_empty())
+      .unwrap_or(false);
+    if !has_type_args {
+      if let Some(type_arg) = type_args.get(&type_ref.type_name) {
+        *ts_type = type_arg.clone();
+        return;
+      }
+    }
+  }
+  ts_type
+    .for_each_child_mut(&mut |child| substitute_type_params(child, type_args));
+}
+
+/// Substitutes the type arguments in the types of params, a return or
+/// property type and type params of a member, leaving out the type params
+/// the member declares itself, which shadow those of its class or
+/// interface.
+fn substitute_member_type_params(
+  params: &mut [ParamDef],
+  ts_type: Option<&mut TsTypeDef>,
+  type_params: &mut [TsTypeParamDef],
+  type_args: &HashMap<String, TsTypeDef>,
+) {
+  let mut type_args = Cow::Borrowed(type_args);
+  for type_param in type_params.iter() {
+    if type_args.contains_key(&type_param.name) {
+      type_args.to_mut().remove(&type_param.name);
+    }
+  }
+  if type_args.is_empty() {
+    return;
+  }
+  let f: &mut dyn FnMut(&mut TsTypeDef) =
+    &mut |ts_type| substitute_type_params(ts_type, &type_args);
+  for param in params {
+    param.for_each_ts_type_mut(f);
+  }
+  if let Some(ts_type) = ts_type {
+    f(ts_type);
+  }
+  for type_param in type_params {
+    type_param.for_each_ts_type_mut(f);
+  }
+}
+
+/// Maps the type params of a class or interface to the type arguments it is
+/// instantiated with, or else to their defaults.
+fn instantiate(
+  doc_node: &DocNode,
+  type_args: &[TsTypeDef],
+) -> HashMap<String, TsTypeDef> {
+  let type_params = doc_node
+    .class_def
+    .as_ref()
+    .map(|class_def| &class_def.type_params)
+    .or_else(|| {
+      doc_node
+        .interface_def
+        .as_ref()
+        .map(|interface_def| &interface_def.type_params)
+    });
+  let mut instantiated = HashMap::new();
+  for (i, type_param) in type_params.into_iter().flatten().enumerate() {
+    let mut type_arg = match type_args.get(i) {
+      Some(type_arg) => type_arg.clone(),
+      None => match &type_param.default {
+        Some(default) => default.clone(),
+        None => continue,
+      },
+    };
+    substitute_type_params(&mut type_arg, &instantiated);
+    instantiated.insert(type_param.name.clone(), type_arg);
+  }
+  instantiated
+}
+
+/// The members a class or interface inherits from its bases.
+#[derive(Default)]
+struct InheritedMemberDefs {
+  class_properties: Vec<ClassPropertyDef>,
+  class_methods: Vec<ClassMethodDef>,
+  interface_properties: Vec<InterfacePropertyDef>,
+  interface_methods: Vec<InterfaceMethodDef>,
+}
+
+/// The own documentation of the members of a class or interface by name.
+fn member_js_docs(doc_node: &DocNode) -> Vec<(&str, &JsDoc)> {
+  let mut members = Vec::new();
+  if let Some(class_def) = &doc_node.class_def {
+    members.extend(class_def.properties.iter().map(|p| (&*p.name, &p.js_doc)));
+    members.extend(class_def.methods.iter().map(|m| (&*m.name, &m.js_doc)));
+  }
+  if let Some(interface_def) = &doc_node.interface_def {
+    members.extend(
+      interface_def
+        .properties
+        .iter()
+        .map(|p| (&*p.name, &p.js_doc)),
+    );
+    members.extend(interface_def.methods.iter().map(|m| (&*m.name, &m.js_doc)));
+  }
+  members
+}
+
+/// Combines own documentation with the inherited documentation, if the own
+/// documentation calls for it.
+fn combine(js_doc: &JsDoc, inherited: impl FnOnce() -> Option<JsDoc>) -> JsDoc {
+  let inherit_doc = js_doc.tags.contains(&JsDocTag::InheritDoc);
+  let mut effective = js_doc.clone();
+  effective.tags.retain(|tag| *tag != JsDocTag::InheritDoc);
+  if inherit_doc || js_doc.doc.is_none() {
+    if let Some(inherited) = inherited() {
+      if inherited.doc.is_some() {
+        effective.doc = inherited.doc;
+      }
+      for tag in inherited.tags {
+        if !effective.tags.contains(&tag) {
+          effective.tags.push(tag);
+        }
+      }
+    }
+  }
+  effective
+}
+
+struct Inheritance<'a> {
+  index: HashMap<String, Vec<&'a DocNode>>,
+}
+
+impl<'a> Inheritance<'a> {
+  fn set_effective_js_docs(
+    &self,
+    namespace: Option<&str>,
+    doc_nodes: &mut [DocNode],
+  ) {
+    for doc_node in doc_nodes {
+      let qualified_name = qualify(namespace, &doc_node.name);
+      let bases = self.resolve_bases(namespace, &base_names(doc_node));
+      let visited = HashSet::from([qualified_name.clone()]);
+
+      let effective = combine(&doc_node.js_doc, || {
+        self.inherited_js_doc(&bases, &mut visited.clone())
+      });
+      doc_node.effective_js_doc = Some(effective).filter(|d| !d.is_empty());
+
+      let member_js_doc = |name: &str, js_doc: &JsDoc| {
+        let effective = combine(js_doc, || {
+          self.inherited_member_js_doc(&bases, name, &mut visited.clone())
+        });
+        Some(effective).filter(|d| !d.is_empty())
+      };
+      if let Some(class_def) = &mut doc_node.class_def {
+        for property in &mut class_def.properties {
+          property.effective_js_doc =
+            member_js_doc(&property.name, &property.js_doc);
+        }
+        for method in &mut class_def.methods {
+          method.effective_js_doc = member_js_doc(&method.name, &method.js_doc);
+        }
+      }
+      if let Some(interface_def) = &mut doc_node.interface_def {
+        for property in &mut interface_def.properties {
+          property.effective_js_doc =
+            member_js_doc(&property.name, &property.js_doc);
+        }
+        for method in &mut interface_def.methods {
+          method.effective_js_doc = member_js_doc(&method.name, &method.js_doc);
+        }
+      }
+
+      if let Some(namespace_def) = &mut doc_node.namespace_def {
+        self.set_effective_js_docs(
+          Some(&qualified_name),
+          &mut namespace_def.elements,
+        );
+      }
+    }
+  }
+
+  fn set_inherited_members(
+    &self,
+    namespace: Option<&str>,
+    doc_nodes: &mut [DocNode],
+    options: &InheritedMembers,
+  ) {
+    for doc_node in doc_nodes {
+      let qualified_name = qualify(namespace, &doc_node.name);
+      if doc_node.class_def.is_some() || doc_node.interface_def.is_some() {
+        let mut names = member_js_docs(doc_node)
+          .into_iter()
+          .map(|(name, _)| name.to_string())
+          .collect::<HashSet<_>>();
+        let bases = self.resolve_extends(namespace, doc_node, options);
+        let mut visited = HashSet::from([qualified_name.clone()]);
+        let mut inherited = InheritedMemberDefs::default();
+        self.collect_inherited_members(
+          bases,
+          &HashMap::new(),
+          options,
+          &mut names,
+          &mut visited,
+          &mut inherited,
+        );
+        if let Some(class_def) = &mut doc_node.class_def {
+          class_def.properties.extend(inherited.class_properties);
+          class_def.methods.extend(inherited.class_methods);
+        }
+        if let Some(interface_def) = &mut doc_node.interface_def {
+          interface_def
+            .properties
+            .extend(inherited.interface_properties);
+          interface_def.methods.extend(inherited.interface_methods);
+        }
+      }
+
+      if let Some(namespace_def) = &mut doc_node.namespace_def {
+        self.set_inherited_members(
+          Some(&qualified_name),
+          &mut namespace_def.elements,
+          options,
+        );
+      }
+    }
+  }
+
+  /// Resolves the classes or interfaces a class or interface extends,
+  /// leaving out the excluded bases, with the type arguments they are
+  /// instantiated with.
+  fn resolve_extends(
+    &self,
+    namespace: Option<&str>,
+    doc_node: &DocNode,
+    options: &InheritedMembers,
+  ) -> Vec<(String, &[&'a DocNode], Vec<TsTypeDef>)> {
+    let mut extends = Vec::new();
+    if let Some(class_def) = &doc_node.class_def {
+      if let Some(name) = &class_def.extends {
+        extends.push((name.clone(), class_def.super_type_params.clone()));
+      }
+    }
+    if let Some(interface_def) = &doc_node.interface_def {
+      for ts_type in &interface_def.extends {
+        extends.push(match &ts_type.type_ref {
+          Some(type_ref) => (
+            type_ref.type_name.clone(),
+            type_ref.type_params.clone().unwrap_or_default(),
+          ),
+          None => (ts_type.repr.clone(), Vec::new()),
+        });
+      }
+    }
+    extends
+      .into_iter()
+      .filter(|(name, _)| !options.exclude_bases.contains(name))
+      .filter_map(|(name, type_args)| {
+        let (qualified_name, nodes) =
+          self.resolve_bases(namespace, &[name]).pop()?;
+        Some((qualified_name, nodes, type_args))
+      })
+      .collect()
+  }
+
+  /// Collects the members of the bases, and in turn of their bases, whose
+  /// names are not among `names`, adding the names of the collected members
+  /// to it. All overloads of an inherited method are collected. The type
+  /// arguments of the bases refer to the type params in `type_args`.
+  fn collect_inherited_members(
+    &self,
+    bases: Vec<(String, &[&'a DocNode], Vec<TsTypeDef>)>,
+    type_args: &HashMap<String, TsTypeDef>,
+    options: &InheritedMembers,
+    names: &mut HashSet<String>,
+    visited: &mut HashSet<String>,
+    inherited: &mut InheritedMemberDefs,
+  ) {
+    for (qualified_name, nodes, mut base_type_args) in bases {
+      if !visited.insert(qualified_name.clone()) {
+        continue;
+      }
+      for type_arg in &mut base_type_args {
+        substitute_type_params(type_arg, type_args);
+      }
+      let inherited_from = Some(qualified_name.clone());
+      let mut collected = HashSet::new();
+      let mut node_type_args = Vec::with_capacity(nodes.len());
+      for node in nodes.iter() {
+        let instantiated = if options.substitute_type_params {
+          instantiate(node, &base_type_args)
+        } else {
+          HashMap::new()
+        };
+        if let Some(class_def) = &node.class_def {
+          for property in &class_def.properties {
+            if !names.contains(&property.name) {
+              collected.insert(property.name.clone());
+              let mut property = ClassPropertyDef {
+                inherited_from: inherited_from.clone(),
+                ..property.clone()
+              };
+              substitute_member_type_params(
+                &mut [],
+                property.ts_type.as_mut(),
+                &mut [],
+                &instantiated,
+              );
+              inherited.class_properties.push(property);
+            }
+          }
+          for method in &class_def.methods {
+            if !names.contains(&method.name) {
+              collected.insert(method.name.clone());
+              let mut method = ClassMethodDef {
+                inherited_from: inherited_from.clone(),
+                ..method.clone()
+              };
+              let function_def = &mut method.function_def;
+              substitute_member_type_params(
+                &mut function_def.params,
+                function_def.return_type.as_mut(),
+                &mut function_def.type_params,
+                &instantiated,
+              );
+              inherited.class_methods.push(method);
+            }
+          }
+        }
+        if let Some(interface_def) = &node.interface_def {
+          for property in &interface_def.properties {
+            if !names.contains(&property.name) {
+              collected.insert(property.name.clone());
+              let mut property = InterfacePropertyDef {
+                inherited_from: inherited_from.clone(),
+                ..property.clone()
+              };
+              substitute_member_type_params(
+                &mut property.params,
+                property.ts_type.as_mut(),
+                &mut property.type_params,
+                &instantiated,
+              );
+              inherited.interface_properties.push(property);
+            }
+          }
+          for method in &interface_def.methods {
+            if !names.contains(&method.name) {
+              collected.insert(method.name.clone());
+              let mut method = InterfaceMethodDef {
+                inherited_from: inherited_from.clone(),
+                ..method.clone()
+              };
+              substitute_member_type_params(
+                &mut method.params,
+                method.return_type.as_mut(),
+                &mut method.type_params,
+                &instantiated,
+              );
+              inherited.interface_methods.push(method);
+            }
+          }
+        }
+        node_type_args.push(instantiated);
+      }
+      names.extend(collected);
+      for (node, instantiated) in nodes.iter().zip(node_type_args) {
+        let namespace = qualified_name.rsplit_once('.').map(|(ns, _)| ns);
+        let node_bases = self.resolve_extends(namespace, node, options);
+        self.collect_inherited_members(
+          node_bases,
+          &instantiated,
+          options,
+          names,
+          visited,
+          inherited,
+        );
+      }
+    }
+  }
+
+  /// Resolves the names of bases to the qualified names of doc nodes,
+  /// looking in the enclosing namespaces first.
+  fn resolve_bases(
+    &self,
+    namespace: Option<&str>,
+    names: &[String],
+  ) -> Vec<(String, &[&'a DocNode])> {
+    let mut bases = Vec::new();
+    for name in names {
+      let mut scope = namespace;
+      loop {
+        let qualified_name = qualify(scope, name);
+        if let Some(nodes) = self.index.get(&qualified_name) {
+          bases.push((qualified_name, nodes.as_slice()));
+          break;
+        }
+        match scope {
+          Some(s) => scope = s.rsplit_once('.').map(|(parent, _)| parent),
+          None => break,
+        }
+      }
+    }
+    bases
+  }
+
+  fn inherited_js_doc(
+    &self,
+    bases: &[(String, &[&'a DocNode])],
+    visited: &mut HashSet<String>,
+  ) -> Option<JsDoc> {
+    for (qualified_name, nodes) in bases {
+      if !visited.insert(qualified_name.clone()) {
+        continue;
+      }
+      for node in nodes.iter() {
+        let namespace = qualified_name.rsplit_once('.').map(|(ns, _)| ns);
+        let node_bases = self.resolve_bases(namespace, &base_names(node));
+        let effective =
+          combine(&node.js_doc, || self.inherited_js_doc(&node_bases, visited));
+        if effective.doc.is_some() {
+          return Some(effective);
+        }
+      }
+    }
+    None
+  }
+
+  fn inherited_member_js_doc(
+    &self,
+    bases: &[(String, &[&'a DocNode])],
+    name: &str,
+    visited: &mut HashSet<String>,
+  ) -> Option<JsDoc> {
+    for (qualified_name, nodes) in bases {
+      if !visited.insert(qualified_name.clone()) {
+        continue;
+      }
+      for node in nodes.iter() {
+        let namespace = qualified_name.rsplit_once('.').map(|(ns, _)| ns);
+        let node_bases = self.resolve_bases(namespace, &base_names(node));
+        let own = member_js_docs(node)
+          .into_iter()
+          .find(|(member_name, _)| *member_name == name)
+          .map(|(_, js_doc)| js_doc.clone())
+          .unwrap_or_default();
+        let effective = combine(&own, || {
+          self.inherited_member_js_doc(&node_bases, name, visited)
+        });
+        if effective.doc.is_some() {
+          return Some(effective);
+        }
+      }
+    }
+    None
+  }
+}
@@ -0,0 +1,246 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::interface::InterfaceDef;
+use crate::node::DocNode;
+use crate::node::ReferencedType;
+use crate::parser::referenced_imports;
+use crate::ts_type::LiteralCallSignatureDef;
+use crate::ts_type::LiteralIndexSignatureDef;
+use crate::ts_type::LiteralMethodDef;
+use crate::ts_type::LiteralPropertyDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeLiteralDef;
+use crate::ts_type::TsTypeRefDef;
+
+/// Which external types to inline with [`inline_external_types`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineOptions {
+  /// The names of the types to inline, or `None` to inline all of them.
+  pub type_names: Option<Vec<String>>,
+  /// How many levels of inlined types are themselves inlined. A depth of `0`
+  /// inlines nothing.
+  pub max_depth: usize,
+}
+
+impl Default for InlineOptions {
+  fn default() -> Self {
+    Self {
+      type_names: None,
+      max_depth: 1,
+    }
+  }
+}
+
+/// Replaces the type references in the doc nodes which refer to one of
+/// `referenced_types`, as found in the `definitions` and the
+/// `referenced_types` of a [`crate::node::ModuleDoc`], with the definition of
+/// that type, returning the number of type references which were replaced.
+///
+/// The type references are resolved through the imports among the doc nodes,
+/// so a local declaration is never replaced, even when its name is the one
+/// of a referenced type, and the types in the definition of a referenced type
+/// are in turn resolved through the imports of its module.
+///
+/// Type aliases are replaced by their type and interfaces by a type literal
+/// of their members. Generic types are only inlined when the reference has
+/// no type arguments, as the type params are not substituted.
+pub fn inline_external_types(
+  doc_nodes: &mut [DocNode],
+  referenced_types: &[ReferencedType],
+  options: &InlineOptions,
+) -> usize {
+  let imports = referenced_imports(doc_nodes, doc_nodes)
+    .into_iter()
+    .collect::<BTreeMap<_, _>>();
+  let scope = Scope {
+    imports: &imports,
+    src: None,
+  };
+  let mut count = 0;
+  inline_doc_nodes(doc_nodes, &scope, referenced_types, options, &mut count);
+  count
+}
+
+/// The names which the type references of a module refer to referenced
+/// types with.
+struct Scope<'a> {
+  /// The referenced types imported by the module, by the name they are
+  /// referred to with, as their `src` and `name`.
+  imports: &'a BTreeMap<String, (String, String)>,
+  /// The module whose private types are referred to by their local name, for
+  /// the definitions of referenced types.
+  src: Option<&'a str>,
+}
+
+fn inline_doc_nodes(
+  doc_nodes: &mut [DocNode],
+  scope: &Scope,
+  referenced_types: &[ReferencedType],
+  options: &InlineOptions,
+  count: &mut usize,
+) {
+  for doc_node in doc_nodes {
+    doc_node.for_each_ts_type_mut(&mut |ts_type| {
+      inline_ts_type(ts_type, 0, scope, referenced_types, options, count)
+    });
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      inline_doc_nodes(
+        &mut namespace_def.elements,
+        scope,
+        referenced_types,
+        options,
+        count,
+      );
+    }
+  }
+}
+
+fn inline_ts_type(
+  ts_type: &mut TsTypeDef,
+  depth: usize,
+  scope: &Scope,
+  referenced_types: &[ReferencedType],
+  options: &InlineOptions,
+  count: &mut usize,
+) {
+  if depth >= options.max_depth {
+    return;
+  }
+  let resolved = ts_type.type_ref.as_ref().and_then(|type_ref| {
+    resolve_type_ref(type_ref, scope, referenced_types, options)
+  });
+  if let Some((inlined, referenced_type)) = resolved {
+    *ts_type = inlined;
+    *count += 1;
+    // the types of the definition refer to the ones of its module
+    let scope = Scope {
+      imports: &referenced_type.imports,
+      src: Some(referenced_type.src.as_str()),
+    };
+    ts_type.for_each_child_mut(&mut |child| {
+      inline_ts_type(child, depth + 1, &scope, referenced_types, options, count)
+    });
+  } else {
+    ts_type.for_each_child_mut(&mut |child| {
+      inline_ts_type(child, depth, scope, referenced_types, options, count)
+    });
+  }
+}
+
+fn resolve_type_ref<'a>(
+  type_ref: &TsTypeRefDef,
+  scope: &Scope,
+  referenced_types: &'a [ReferencedType],
+  options: &InlineOptions,
+) -> Option<(TsTypeDef, &'a ReferencedType)> {
+  let (src, name) = match scope.imports.get(&type_ref.type_name) {
+    Some((src, name)) => (src.as_str(), name.as_str()),
+    None => (scope.src?, type_ref.type_name.as_str()),
+  };
+  if let Some(type_names) = &options.type_names {
+    if !type_names.iter().any(|n| n == name) {
+      return None;
+    }
+  }
+  let has_type_args = type_ref
+    .type_params
+    .as_ref()
+    .map(|type_params| !type_params.is_empty())
+    .unwrap_or(false);
+  referenced_types
+    .iter()
+    .filter(|referenced_type| {
+      referenced_type.src == src && referenced_type.name == name
+    })
+    .find_map(|referenced_type| {
+      let doc_node = &referenced_type.doc_node;
+      if let Some(type_alias_def) = &doc_node.type_alias_def {
+        if !has_type_args || type_alias_def.type_params.is_empty() {
+          return Some((type_alias_def.ts_type.clone(), referenced_type));
+        }
+      }
+      if let Some(interface_def) = &doc_node.interface_def {
+        if !has_type_args || interface_def.type_params.is_empty() {
+          return Some((interface_to_ts_type(interface_def), referenced_type));
+        }
+      }
+      None
+    })
+}
+
+fn interface_to_ts_type(interface_def: &InterfaceDef) -> TsTypeDef {
+  let type_literal = TsTypeLiteralDef {
+    methods: interface_def
+      .methods
+      .iter()
+      .map(|method| LiteralMethodDef {
+        name: method.name.clone(),
+        js_doc: method.js_doc.clone(),
+        kind: method.kind,
+        params: method.params.clone(),
+        computed: method.computed,
+        well_known_symbol: method.well_known_symbol,
+        optional: method.optional,
+        return_type: method.return_type.clone(),
+        type_params: method.type_params.clone(),
+      })
+      .collect(),
+    properties: interface_def
+      .properties
+      .iter()
+      .map(|property| LiteralPropertyDef {
+        name: property.name.clone(),
+        js_doc: property.js_doc.clone(),
+        params: property.params.clone(),
+        readonly: property.readonly,
+        computed: property.computed,
+        well_known_symbol: property.well_known_symbol,
+        optional: property.optional,
+        ts_type: property.ts_type.clone(),
+        type_params: property.type_params.clone(),
+      })
+      .collect(),
+    call_signatures: interface_def
+      .call_signatures
+      .iter()
+      .map(|call_signature| LiteralCallSignatureDef {
+        params: call_signature.params.clone(),
+        ts_type: call_signature.ts_type.clone(),
+        type_params: call_signature.type_params.clone(),
+      })
+      .collect(),
+    index_signatures: interface_def
+      .index_signatures
+      .iter()
+      .map(|index_signature| LiteralIndexSignatureDef {
+        js_doc: index_signature.js_doc.clone(),
+        readonly: index_signature.readonly,
+        params: index_signature.params.clone(),
+        ts_type: index_signature.ts_type.clone(),
+      })
+      .collect(),
+  };
+  let literal = TsTypeDef {
+    kind: Some(TsTypeDefKind::TypeLiteral),
+    type_literal: Some(type_literal),
+    ..Default::default()
+  };
+  if interface_def.extends.is_empty() {
+    literal
+  } else {
+    let mut types = interface_def.extends.clone();
+    types.push(literal);
+    TsTypeDef {
+      kind: Some(TsTypeDefKind::Intersection),
+      intersection: Some(types),
+      ..Default::default()
+    }
+  }
+}
@@ -5,13 +5,18 @@ use deno_ast::SourceRangedForSpanned;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::events::EventDef;
 use crate::function::FunctionDef;
 use crate::js_doc::JsDoc;
+use crate::members::MemberGroup;
 use crate::node::DeclarationKind;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::get_location;
 use crate::swc_util::is_false;
+use crate::swc_util::is_well_known_symbol;
 use crate::swc_util::js_doc_for_range;
+use crate::swc_util::trailing_line_comment_for_range;
+use crate::swc_util::well_known_symbol_name;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
@@ -38,12 +43,36 @@ cfg_if! {
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceMethodDef {
   pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  /// The group the member is ordered in, when members are ordered with
+  /// [`crate::DocParserOptions::member_order`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub group: Option<MemberGroup>,
+  /// The base class or interface the member is inherited from, when
+  /// inherited members are copied with
+  /// [`crate::DocParserOptions::inherited_members`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub inherited_from: Option<String>,
+  /// Whether the method returns the object it is called on, like the
+  /// methods of a builder, when requested with
+  /// [`crate::DocParserOptions::chainable_methods`].
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub chainable: bool,
   pub kind: deno_ast::swc::ast::MethodKind,
   pub location: Location,
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
-  #[serde(skip_serializing_if = "is_false")]
+  /// The documentation which applies to the member, including inherited
+  /// documentation, when requested with
+  /// [`crate::DocParserOptions::effective_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub effective_js_doc: Option<JsDoc>,
+  #[serde(skip_serializing_if = "is_false", default)]
   pub computed: bool,
+  /// Whether the key is a well-known symbol, like `Symbol.iterator`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
   pub optional: bool,
   pub params: Vec<ParamDef>,
   pub return_type: Option<TsTypeDef>,
@@ -75,11 +104,18 @@ impl Display for InterfaceMethodDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(
       f,
-      "{}{}({})",
+      "{}{}",
       display_computed(self.computed, &self.name),
       display_optional(self.optional),
-      SliceDisplayer::new(&self.params, ", ", false),
     )?;
+    if !self.type_params.is_empty() {
+      write!(
+        f,
+        "<{}>",
+        SliceDisplayer::new(&self.type_params, ", ", false)
+      )?;
+    }
+    write!(f, "({})", SliceDisplayer::new(&self.params, ", ", false))?;
     if let Some(return_type) = &self.return_type {
       write!(f, ": {}", return_type)?;
     }
@@ -91,13 +127,32 @@ impl Display for InterfaceMethodDef {
 #[serde(rename_all = "camelCase")]
 pub struct InterfacePropertyDef {
   pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
+  /// The group the member is ordered in, when members are ordered with
+  /// [`crate::DocParserOptions::member_order`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub group: Option<MemberGroup>,
+  /// The base class or interface the member is inherited from, when
+  /// inherited members are copied with
+  /// [`crate::DocParserOptions::inherited_members`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub inherited_from: Option<String>,
   pub location: Location,
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  /// The documentation which applies to the member, including inherited
+  /// documentation, when requested with
+  /// [`crate::DocParserOptions::effective_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub effective_js_doc: Option<JsDoc>,
   pub params: Vec<ParamDef>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub readonly: bool,
   pub computed: bool,
+  /// Whether the key is a well-known symbol, like `Symbol.iterator`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
   pub optional: bool,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
@@ -138,6 +193,8 @@ impl Display for InterfacePropertyDef {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceIndexSignatureDef {
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
   pub readonly: bool,
   pub params: Vec<ParamDef>,
   pub ts_type: Option<TsTypeDef>,
@@ -163,13 +220,31 @@ impl Display for InterfaceIndexSignatureDef {
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceCallSignatureDef {
   pub location: Location,
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
   pub params: Vec<ParamDef>,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
 }
 
+#[cfg(feature = "rust")]
+impl Display for InterfaceCallSignatureDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if !self.type_params.is_empty() {
+      write!(
+        f,
+        "<{}>",
+        SliceDisplayer::new(&self.type_params, ", ", false)
+      )?;
+    }
+    write!(f, "({})", SliceDisplayer::new(&self.params, ", ", false))?;
+    if let Some(ts_type) = &self.ts_type {
+      write!(f, ": {}", ts_type)?;
+    }
+    Ok(())
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceDef {
@@ -179,6 +254,10 @@ pub struct InterfaceDef {
   pub call_signatures: Vec<InterfaceCallSignatureDef>,
   pub index_signatures: Vec<InterfaceIndexSignatureDef>,
   pub type_params: Vec<TsTypeParamDef>,
+  /// The events of the interface, from the overloads of its listener methods,
+  /// when requested with [`crate::DocParserOptions::events`].
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub events: Vec<EventDef>,
 }
 
 pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
@@ -188,6 +267,9 @@ pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
   match expr {
     Ident(ident) => ident.sym.to_string(),
     Member(member_expr) => {
+      if let Some(name) = well_known_symbol_name(expr) {
+        return name;
+      }
       let left = expr_to_name(&member_expr.obj);
       let right = match &member_expr.prop {
         MemberProp::Ident(ident) => format!(".{}", ident.sym),
@@ -260,10 +342,17 @@ pub fn get_doc_for_ts_interface_decl(
 
           let method_def = InterfaceMethodDef {
             name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
             kind: deno_ast::swc::ast::MethodKind::Method,
             js_doc: method_js_doc,
+            effective_js_doc: None,
             location: get_location(parsed_source, ts_method_sig.start()),
             computed: ts_method_sig.computed,
+            well_known_symbol: ts_method_sig.computed
+              && is_well_known_symbol(&ts_method_sig.key),
             optional: ts_method_sig.optional,
             params,
             return_type: maybe_return_type,
@@ -283,10 +372,17 @@ pub fn get_doc_for_ts_interface_decl(
 
           let method_def = InterfaceMethodDef {
             name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
             kind: deno_ast::swc::ast::MethodKind::Getter,
             js_doc: method_js_doc,
+            effective_js_doc: None,
             location: get_location(parsed_source, ts_getter_sig.start()),
             computed: ts_getter_sig.computed,
+            well_known_symbol: ts_getter_sig.computed
+              && is_well_known_symbol(&ts_getter_sig.key),
             optional: ts_getter_sig.optional,
             params: vec![],
             return_type: maybe_return_type,
@@ -307,10 +403,17 @@ pub fn get_doc_for_ts_interface_decl(
 
           let method_def = InterfaceMethodDef {
             name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
             kind: deno_ast::swc::ast::MethodKind::Setter,
             js_doc: method_js_doc,
+            effective_js_doc: None,
             location: get_location(parsed_source, ts_setter_sig.start()),
             computed: ts_setter_sig.computed,
+            well_known_symbol: ts_setter_sig.computed
+              && is_well_known_symbol(&ts_setter_sig.key),
             optional: ts_setter_sig.optional,
             params,
             return_type: None,
@@ -341,12 +444,18 @@ pub fn get_doc_for_ts_interface_decl(
 
           let prop_def = InterfacePropertyDef {
             name,
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
             js_doc: prop_js_doc,
+            effective_js_doc: None,
             location: get_location(parsed_source, ts_prop_sig.start()),
             params,
             ts_type,
             readonly: ts_prop_sig.readonly,
             computed: ts_prop_sig.computed,
+            well_known_symbol: ts_prop_sig.computed
+              && is_well_known_symbol(&ts_prop_sig.key),
             optional: ts_prop_sig.optional,
             type_params,
           };
@@ -381,24 +490,29 @@ pub fn get_doc_for_ts_interface_decl(
         }
       }
       TsIndexSignature(ts_index_sig) => {
-        let mut params = vec![];
-        for param in &ts_index_sig.params {
-          // todo(kitsonk): investigate why `None` is provided here
-          let param_def = ts_fn_param_to_param_def(None, param);
-          params.push(param_def);
-        }
+        if let Some(js_doc) =
+          js_doc_for_range(parsed_source, &ts_index_sig.range())
+        {
+          let mut params = vec![];
+          for param in &ts_index_sig.params {
+            // todo(kitsonk): investigate why `None` is provided here
+            let param_def = ts_fn_param_to_param_def(None, param);
+            params.push(param_def);
+          }
+
+          let ts_type = ts_index_sig
+            .type_ann
+            .as_ref()
+            .map(|rt| (&*rt.type_ann).into());
 
-        let ts_type = ts_index_sig
-          .type_ann
-          .as_ref()
-          .map(|rt| (&*rt.type_ann).into());
-
-        let index_sig_def = InterfaceIndexSignatureDef {
-          readonly: ts_index_sig.readonly,
-          params,
-          ts_type,
-        };
-        index_signatures.push(index_sig_def);
+          let index_sig_def = InterfaceIndexSignatureDef {
+            js_doc,
+            readonly: ts_index_sig.readonly,
+            params,
+            ts_type,
+          };
+          index_signatures.push(index_sig_def);
+        }
       }
       TsConstructSignatureDecl(ts_construct_sig) => {
         if let Some(construct_js_doc) =
@@ -423,10 +537,16 @@ pub fn get_doc_for_ts_interface_decl(
 
           let construct_sig_def = InterfaceMethodDef {
             name: "new".to_string(),
+            qualified_name: None,
+            group: None,
+            inherited_from: None,
+            chainable: false,
             kind: deno_ast::swc::ast::MethodKind::Method,
             js_doc: construct_js_doc,
+            effective_js_doc: None,
             location: get_location(parsed_source, ts_construct_sig.start()),
             computed: false,
+            well_known_symbol: false,
             optional: false,
             params,
             return_type: maybe_return_type,
@@ -456,7 +576,41 @@ pub fn get_doc_for_ts_interface_decl(
     call_signatures,
     index_signatures,
     type_params,
+    events: Vec::new(),
   };
 
   (interface_name, interface_def)
 }
+
+/// Documents the properties and methods of the interface which have no JSDoc
+/// with the `//` comment which trails them on their line, if any.
+pub fn set_trailing_comment_docs(
+  parsed_source: &ParsedSource,
+  interface_decl: &deno_ast::swc::ast::TsInterfaceDecl,
+  interface_def: &mut InterfaceDef,
+) {
+  for type_element in &interface_decl.body.body {
+    let range = type_element.range();
+    let location = get_location(parsed_source, range.start);
+    let js_doc = interface_def
+      .properties
+      .iter_mut()
+      .map(|prop| (&prop.location, &mut prop.js_doc))
+      .chain(
+        interface_def
+          .methods
+          .iter_mut()
+          .map(|method| (&method.location, &mut method.js_doc)),
+      )
+      .find(|(member_location, _)| **member_location == location)
+      .map(|(_, js_doc)| js_doc);
+    let Some(js_doc) = js_doc.filter(|js_doc| js_doc.is_empty()) else {
+      continue;
+    };
+    if let Some(comment) =
+      trailing_line_comment_for_range(parsed_source, &range)
+    {
+      js_doc.doc = Some(comment);
+    }
+  }
+}
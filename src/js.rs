@@ -2,7 +2,14 @@
 // https://github.com/rustwasm/wasm-bindgen/issues/2774
 #![allow(clippy::unused_unit)]
 
+use crate::diff::diff_doc_nodes;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::parser::DocDiagnosticKind;
 use crate::parser::DocParser;
+use crate::parser::DocParserOptions;
+use crate::score::score_modules;
+use crate::score::ScoreWeights;
 
 use anyhow::anyhow;
 use deno_graph::source::CacheSetting;
@@ -18,6 +25,9 @@ use deno_graph::ModuleGraph;
 use deno_graph::ModuleSpecifier;
 use import_map::ImportMap;
 use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
@@ -31,13 +41,20 @@ macro_rules! console_warn {
   ($($t:tt)*) => (warn(&format_args!($($t)*).to_string()))
 }
 
+type ModuleHeaders = HashMap<ModuleSpecifier, HashMap<String, String>>;
+
 struct JsLoader {
   load: js_sys::Function,
+  /// The headers of the loaded modules, which are not kept by the graph.
+  headers: Rc<RefCell<ModuleHeaders>>,
 }
 
 impl JsLoader {
   pub fn new(load: js_sys::Function) -> Self {
-    Self { load }
+    Self {
+      load,
+      headers: Default::default(),
+    }
   }
 }
 
@@ -53,14 +70,27 @@ impl Loader for JsLoader {
     let arg1 = JsValue::from(is_dynamic);
     let arg2 = JsValue::from(cache_setting.as_js_str());
     let result = self.load.call3(&this, &arg0, &arg1, &arg2);
+    let headers = self.headers.clone();
     let f = async move {
       let response = match result {
         Ok(result) => JsFuture::from(js_sys::Promise::resolve(&result)).await,
         Err(err) => Err(err),
       };
-      response
-        .map(|value| serde_wasm_bindgen::from_value(value).unwrap())
-        .map_err(|_| anyhow!("load rejected or errored"))
+      let value = response.map_err(|_| anyhow!("load rejected or errored"))?;
+      let response: Option<LoadResponse> =
+        serde_wasm_bindgen::from_value(value)
+          .map_err(|err| anyhow!("Invalid load response: {}", err))?;
+      if let Some(LoadResponse::Module {
+        specifier,
+        maybe_headers: Some(module_headers),
+        ..
+      }) = &response
+      {
+        headers
+          .borrow_mut()
+          .insert(specifier.clone(), module_headers.clone());
+      }
+      Ok(response)
     };
     Box::pin(f)
   }
@@ -131,6 +161,8 @@ pub async fn doc(
   maybe_resolve: Option<js_sys::Function>,
   maybe_import_map: Option<String>,
   print_import_map_diagnostics: bool,
+  maybe_kinds: JsValue,
+  maybe_on_load_error: Option<js_sys::Function>,
 ) -> anyhow::Result<JsValue, JsValue> {
   console_error_panic_hook::set_once();
   inner_doc(
@@ -140,6 +172,8 @@ pub async fn doc(
     maybe_resolve,
     maybe_import_map,
     print_import_map_diagnostics,
+    maybe_kinds,
+    maybe_on_load_error,
   )
   .await
   .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
@@ -152,8 +186,190 @@ async fn inner_doc(
   maybe_resolve: Option<js_sys::Function>,
   maybe_import_map: Option<String>,
   print_import_map_diagnostics: bool,
+  maybe_kinds: JsValue,
+  maybe_on_load_error: Option<js_sys::Function>,
 ) -> Result<JsValue, anyhow::Error> {
   let root_specifier = ModuleSpecifier::parse(&root_specifier)?;
+  let maybe_kinds: Option<Vec<DocNodeKind>> =
+    serde_wasm_bindgen::from_value(maybe_kinds)
+      .map_err(|err| anyhow!("Invalid kinds: {}", err))?;
+  let (graph, analyzer, module_headers) = build_graph(
+    &root_specifier,
+    load,
+    maybe_resolve,
+    maybe_import_map,
+    print_import_map_diagnostics,
+  )
+  .await?;
+  let mut entries = {
+    trace_span!(INFO, "parse", root = %root_specifier);
+    // with a load error callback, the modules which fail to load or parse are
+    // reported as diagnostics and skipped instead of failing
+    let doc_parser = DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        private: include_all,
+        import_diagnostics: maybe_on_load_error.is_some(),
+        recover_parse_errors: maybe_on_load_error.is_some(),
+        module_headers,
+        ..Default::default()
+      },
+    )?;
+    let entries = doc_parser.parse_with_reexports(&root_specifier)?;
+    if let Some(on_load_error) = &maybe_on_load_error {
+      report_load_errors(&doc_parser, on_load_error)?;
+    }
+    entries
+  };
+  if let Some(kinds) = maybe_kinds {
+    retain_kinds(&mut entries, &kinds);
+  }
+  trace_span!(INFO, "serialize");
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  Ok(entries.serialize(&serializer).unwrap())
+}
+
+/// A module which could not be loaded or parsed, passed to the `onLoadError`
+/// callback of `doc()`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LoadError {
+  /// The specifier as written in the import, or the specifier of the module
+  /// which could not be parsed.
+  specifier: String,
+  /// The module which imports the specifier, unless it is a parse error.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  referrer: Option<String>,
+  line: usize,
+  col: usize,
+  message: String,
+}
+
+/// Calls the callback for each module of the graph which could not be loaded
+/// or parsed, in the order of their location.
+fn report_load_errors(
+  doc_parser: &DocParser,
+  on_load_error: &js_sys::Function,
+) -> Result<(), anyhow::Error> {
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  for diagnostic in doc_parser.diagnostics() {
+    let location = diagnostic.location;
+    let load_error = match diagnostic.kind {
+      DocDiagnosticKind::UnresolvedImport { specifier, message } => LoadError {
+        specifier,
+        referrer: Some(location.filename),
+        line: location.line,
+        col: location.col,
+        message,
+      },
+      DocDiagnosticKind::ParseError { message, .. } => LoadError {
+        specifier: location.filename,
+        referrer: None,
+        line: location.line,
+        col: location.col,
+        message,
+      },
+      _ => continue,
+    };
+    on_load_error
+      .call1(
+        &JsValue::null(),
+        &load_error.serialize(&serializer).unwrap(),
+      )
+      .map_err(|_| anyhow!("JavaScript onLoadError() function threw."))?;
+  }
+  Ok(())
+}
+
+/// Computes the documentation score of a module and its reexports, so that
+/// only the [`ScoreReport`] is transferred instead of the doc nodes.
+#[wasm_bindgen]
+pub async fn score(
+  root_specifier: String,
+  load: js_sys::Function,
+  maybe_resolve: Option<js_sys::Function>,
+  maybe_import_map: Option<String>,
+  print_import_map_diagnostics: bool,
+  maybe_weights: JsValue,
+) -> anyhow::Result<JsValue, JsValue> {
+  console_error_panic_hook::set_once();
+  inner_score(
+    root_specifier,
+    load,
+    maybe_resolve,
+    maybe_import_map,
+    print_import_map_diagnostics,
+    maybe_weights,
+  )
+  .await
+  .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+async fn inner_score(
+  root_specifier: String,
+  load: js_sys::Function,
+  maybe_resolve: Option<js_sys::Function>,
+  maybe_import_map: Option<String>,
+  print_import_map_diagnostics: bool,
+  maybe_weights: JsValue,
+) -> Result<JsValue, anyhow::Error> {
+  let root_specifier = ModuleSpecifier::parse(&root_specifier)?;
+  let maybe_weights: Option<ScoreWeights> =
+    serde_wasm_bindgen::from_value(maybe_weights)
+      .map_err(|err| anyhow!("Invalid weights: {}", err))?;
+  let (graph, analyzer, _) = build_graph(
+    &root_specifier,
+    load,
+    maybe_resolve,
+    maybe_import_map,
+    print_import_map_diagnostics,
+  )
+  .await?;
+  let entries = {
+    trace_span!(INFO, "parse", root = %root_specifier);
+    DocParser::new(&graph, false, analyzer.as_capturing_parser())?
+      .parse_with_reexports(&root_specifier)?
+  };
+  let report = score_modules(
+    &[(root_specifier.to_string(), entries)],
+    &maybe_weights.unwrap_or_default(),
+  );
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  Ok(report.serialize(&serializer).unwrap())
+}
+
+/// Compares two versions of an API, given as the doc nodes output by
+/// `doc()`, classifying the changes by their impact on the version.
+#[wasm_bindgen]
+pub fn diff(old: JsValue, new: JsValue) -> anyhow::Result<JsValue, JsValue> {
+  console_error_panic_hook::set_once();
+  inner_diff(old, new)
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+fn inner_diff(old: JsValue, new: JsValue) -> Result<JsValue, anyhow::Error> {
+  let old: Vec<DocNode> = serde_wasm_bindgen::from_value(old)
+    .map_err(|err| anyhow!("Invalid old doc nodes: {}", err))?;
+  let new: Vec<DocNode> = serde_wasm_bindgen::from_value(new)
+    .map_err(|err| anyhow!("Invalid new doc nodes: {}", err))?;
+  let api_diff = diff_doc_nodes(&old, &new);
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  Ok(api_diff.serialize(&serializer).unwrap())
+}
+
+async fn build_graph(
+  root_specifier: &ModuleSpecifier,
+  load: js_sys::Function,
+  maybe_resolve: Option<js_sys::Function>,
+  maybe_import_map: Option<String>,
+  print_import_map_diagnostics: bool,
+) -> Result<(ModuleGraph, CapturingModuleAnalyzer, ModuleHeaders), anyhow::Error>
+{
   let mut loader = JsLoader::new(load);
   let maybe_resolver: Option<Box<dyn Resolver>> = if let Some(import_map) =
     maybe_import_map
@@ -189,8 +405,8 @@ async fn inner_doc(
   };
   let analyzer = CapturingModuleAnalyzer::default();
   let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
-  graph
-    .build(
+  trace_instrument!(
+    graph.build(
       vec![root_specifier.clone()],
       &mut loader,
       BuildOptions {
@@ -198,12 +414,23 @@ async fn inner_doc(
         resolver: maybe_resolver.as_ref().map(|r| r.as_ref()),
         ..Default::default()
       },
-    )
-    .await;
-  let entries =
-    DocParser::new(&graph, include_all, analyzer.as_capturing_parser())?
-      .parse_with_reexports(&root_specifier)?;
-  let serializer =
-    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
-  Ok(entries.serialize(&serializer).unwrap())
+    ),
+    INFO,
+    "build_graph",
+    root = %root_specifier
+  )
+  .await;
+  let module_headers = loader.headers.borrow().clone();
+  Ok((graph, analyzer, module_headers))
+}
+
+/// Keeps the doc nodes of the kinds, and the elements of the kinds of the
+/// namespaces which are kept.
+fn retain_kinds(doc_nodes: &mut Vec<DocNode>, kinds: &[DocNodeKind]) {
+  doc_nodes.retain(|doc_node| kinds.contains(&doc_node.kind));
+  for doc_node in doc_nodes {
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      retain_kinds(&mut namespace_def.elements, kinds);
+    }
+  }
 }
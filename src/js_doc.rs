@@ -4,32 +4,271 @@ use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::node::Location;
+
 lazy_static! {
-  static ref JS_DOC_TAG_MAYBE_DOC_RE: Regex = Regex::new(r"(?s)^\s*@(category|deprecated|example|tags)(?:\s+(.+))?").unwrap();
-  static ref JS_DOC_TAG_NAMED_RE: Regex = Regex::new(r"(?s)^\s*@(callback|template)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
-  static ref JS_DOC_TAG_NAMED_TYPED_RE: Regex = Regex::new(r"(?s)^\s*@(prop(?:erty)?|typedef)\s+\{([^}]+)\}\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
-  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r"^\s*@(constructor|class|ignore|module|public|private|protected|readonly)").unwrap();
+  static ref JS_DOC_INLINE_LINK_RE: Regex = Regex::new(r"\{@link(code|plain)?\s+([^\s|}]+)(?:\s*\|\s*([^}]*)|\s+([^}]*))?\}").unwrap();
+  static ref JS_DOC_TAG_MAYBE_DOC_RE: Regex = Regex::new(r"(?s)^\s*@(category|deprecated|example|group|tags)(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_EXAMPLE_CAPTION_RE: Regex = Regex::new(r"(?s)^<caption>(.*?)</caption>").unwrap();
+  static ref JS_DOC_TAG_NAMED_RE: Regex = Regex::new(r"(?s)^\s*@(callback)\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_NAMED_TYPED_RE: Regex = Regex::new(r"(?s)^\s*@(typedef)\s+\{([^}]+)\}\s+([a-zA-Z_$]\S*)(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_NAMESPACE_RE: Regex = Regex::new(r"^\s*@namespace(?:\s+(?:\{[^}]+\}\s+)?([a-zA-Z_$][\w$.]*))?").unwrap();
+  static ref JS_DOC_TAG_ONLY_RE: Regex = Regex::new(r"^\s*@(alpha|beta|constructor|class|experimental|ignore|inheritDoc|inheritdoc|internal|module|packageDocumentation|public|private|protected|readonly)").unwrap();
   static ref JS_DOC_TAG_PARAM_RE: Regex = Regex::new(
     r"(?s)^\s*@(?:param|arg(?:ument)?)(?:\s+\{(?P<type>[^}]+)\})?\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$]\S*?)(?:\s*=\s*(?P<default>[^]]+))?\])|(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
   )
   .unwrap();
+  static ref JS_DOC_TAG_PROPERTY_RE: Regex = Regex::new(
+    r"(?s)^\s*@prop(?:erty)?\s+\{(?P<type>[^}]+)\}\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$][^\s=\]]*)(?:\s*=\s*(?P<default>[^\]]+))?\])|(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
+  )
+  .unwrap();
   static ref JS_DOC_TAG_RE: Regex = Regex::new(r"(?s)^\s*@(\S+)").unwrap();
   static ref JS_DOC_TAG_RETURN_RE: Regex = Regex::new(r"(?s)^\s*@returns?(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_SEE_RE: Regex = Regex::new(r"(?s)^\s*@see\s+(?:\{@link(?:code|plain)?\s+([^\s|}]+)[^}]*\}|(\S+))(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_SINCE_RE: Regex = Regex::new(r"(?s)^\s*@since\s+(\S+)(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_TEMPLATE_RE: Regex = Regex::new(
+    r"(?s)^\s*@template(?:\s+\{(?P<constraint>[^}]+)\})?\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$][\w$]*)(?:\s*=\s*(?P<default>[^\]]+))?\])|(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
+  )
+  .unwrap();
   static ref JS_DOC_TAG_TYPED_RE: Regex = Regex::new(r"(?s)^\s*@(enum|extends|augments|this|type|default)\s+\{([^}]+)\}(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_YIELDS_RE: Regex = Regex::new(r"(?s)^\s*@yields?(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub struct JsDoc {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub doc: Option<String>,
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
   pub tags: Vec<JsDocTag>,
+  /// The documentation split into text and inline links, when requested
+  /// with [`crate::DocParserOptions::inline_links`].
+  #[serde(
+    rename = "docSegments",
+    skip_serializing_if = "Option::is_none",
+    default
+  )]
+  pub doc_segments: Option<Vec<DocSegment>>,
+  /// The comment the documentation was parsed from, when requested with
+  /// [`crate::DocParserOptions::raw_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub raw: Option<RawJsDoc>,
+}
+
+/// A JSDoc comment as it is written in the source.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RawJsDoc {
+  /// The text of the comment, from its `/**` to its `*/`.
+  pub text: String,
+  /// Where the comment starts.
+  pub location: Location,
+  /// Where the comment ends, after its `*/`.
+  pub end: Location,
+}
+
+/// A part of documentation text.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DocSegment {
+  Text {
+    text: String,
+  },
+  /// An inline `{@link target}`, `{@link target | label}` or
+  /// `{@link target label}` tag, or the same with `@linkcode` or
+  /// `@linkplain`.
+  Link {
+    target: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "LinkStyle::is_link", default)]
+    style: LinkStyle,
+  },
+}
+
+/// How an inline link is displayed, which depends on its tag.
+#[derive(
+  Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkStyle {
+  /// `{@link}`, which is displayed as the renderer sees fit.
+  #[default]
+  Link,
+  /// `{@linkcode}`, which is displayed in a monospace font.
+  Code,
+  /// `{@linkplain}`, which is displayed as plain text.
+  Plain,
+}
+
+impl LinkStyle {
+  fn is_link(&self) -> bool {
+    *self == LinkStyle::Link
+  }
+}
+
+/// Splits documentation text into text segments and the inline link tags
+/// between them.
+pub fn parse_doc_segments(doc: &str) -> Vec<DocSegment> {
+  let mut segments = Vec::new();
+  let mut last_end = 0;
+  for caps in JS_DOC_INLINE_LINK_RE.captures_iter(doc) {
+    let link = caps.get(0).unwrap();
+    if link.start() > last_end {
+      segments.push(DocSegment::Text {
+        text: doc[last_end..link.start()].to_string(),
+      });
+    }
+    let style = match caps.get(1).map(|m| m.as_str()) {
+      Some("code") => LinkStyle::Code,
+      Some("plain") => LinkStyle::Plain,
+      _ => LinkStyle::Link,
+    };
+    let label = caps
+      .get(3)
+      .or_else(|| caps.get(4))
+      .map(|m| m.as_str().trim().to_string())
+      .filter(|label| !label.is_empty());
+    segments.push(DocSegment::Link {
+      target: caps[2].to_string(),
+      label,
+      style,
+    });
+    last_end = link.end();
+  }
+  if last_end < doc.len() {
+    segments.push(DocSegment::Text {
+      text: doc[last_end..].to_string(),
+    });
+  }
+  segments
+}
+
+/// A `@param` tag with the tags of the properties of the param, which are
+/// named by a path from the param, like `@param options.timeout` or
+/// `@param users[].name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamTag {
+  /// The last segment of the name of the tag.
+  pub name: String,
+  pub type_ref: Option<String>,
+  pub optional: bool,
+  pub default: Option<String>,
+  pub doc: Option<String>,
+  pub properties: Vec<ParamTag>,
+}
+
+/// Adds a param tag to the properties of the tag at `path`, which is
+/// dropped if there is no such tag.
+fn insert_param_tag(
+  param_tags: &mut Vec<ParamTag>,
+  path: &[&str],
+  param_tag: ParamTag,
+) {
+  let Some((parent_name, path)) = path.split_first() else {
+    param_tags.push(param_tag);
+    return;
+  };
+  if let Some(parent) = param_tags.iter_mut().find(|p| p.name == *parent_name) {
+    insert_param_tag(&mut parent.properties, path, param_tag);
+  }
 }
 
 impl JsDoc {
   pub fn is_empty(&self) -> bool {
     self.doc.is_none() && self.tags.is_empty()
   }
+
+  /// Whether this is the doc of a module, tagged with `@module` or
+  /// `@packageDocumentation`.
+  pub fn is_module_doc(&self) -> bool {
+    self.tags.iter().any(|tag| {
+      matches!(tag, JsDocTag::Module | JsDocTag::PackageDocumentation)
+    })
+  }
+
+  /// The `@param` tags, with the tags of properties nested in the tags of
+  /// their params. A property is given after its param.
+  pub fn param_tags(&self) -> Vec<ParamTag> {
+    let mut param_tags = Vec::new();
+    for tag in &self.tags {
+      let JsDocTag::Param {
+        name,
+        type_ref,
+        optional,
+        default,
+        doc,
+      } = tag
+      else {
+        continue;
+      };
+      let mut path = name
+        .split('.')
+        .map(|segment| segment.trim_end_matches("[]"))
+        .collect::<Vec<_>>();
+      let name = path.pop().unwrap();
+      let param_tag = ParamTag {
+        name: name.to_string(),
+        type_ref: type_ref.clone(),
+        optional: *optional,
+        default: default.clone(),
+        doc: doc.clone(),
+        properties: Vec::new(),
+      };
+      insert_param_tag(&mut param_tags, &path, param_tag);
+    }
+    param_tags
+  }
+
+  /// Sets the `doc_segments` from the documentation text.
+  pub(crate) fn set_doc_segments(&mut self) {
+    self.doc_segments = self.doc.as_deref().map(parse_doc_segments);
+  }
+
+  /// Whether there is a `@deprecated` tag, with or without a message.
+  pub fn is_deprecated(&self) -> bool {
+    self
+      .tags
+      .iter()
+      .any(|tag| matches!(tag, JsDocTag::Deprecated { .. }))
+  }
+
+  /// The message of the first `@deprecated` tag which has one.
+  pub fn deprecation_message(&self) -> Option<&str> {
+    self.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Deprecated { doc } => doc.as_deref(),
+      _ => None,
+    })
+  }
+
+  /// The version given to the first `@since` tag.
+  pub fn since(&self) -> Option<&str> {
+    self.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Since { version, .. } => Some(version.as_str()),
+      _ => None,
+    })
+  }
+
+  /// The distinct names given to `@category` and `@group` tags, in order.
+  pub fn categories(&self) -> Vec<&str> {
+    let mut categories = Vec::new();
+    for tag in &self.tags {
+      if let JsDocTag::Category { doc: Some(doc) } = tag {
+        let category = doc.trim();
+        if !category.is_empty() && !categories.contains(&category) {
+          categories.push(category);
+        }
+      }
+    }
+    categories
+  }
+
+  /// The `@template` tag which documents the type param `name`, to be
+  /// correlated with the `typeParams` of the documented node.
+  pub fn template(&self, name: &str) -> Option<&JsDocTag> {
+    self.tags.iter().find(|tag| match tag {
+      JsDocTag::Template { name: tag_name, .. } => tag_name == name,
+      _ => false,
+    })
+  }
 }
 
 impl From<String> for JsDoc {
@@ -71,20 +310,29 @@ impl From<String> for JsDoc {
     } else {
       Some(doc_lines.join("\n"))
     };
-    Self { doc, tags }
+    Self {
+      doc,
+      tags,
+      doc_segments: None,
+      raw: None,
+    }
   }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 pub enum JsDocTag {
+  /// `@alpha`, the TSDoc release tag of an early preview
+  Alpha,
+  /// `@beta`, the TSDoc release tag of a preview
+  Beta,
   /// `@callback Predicate comment`
   Callback {
     name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
-  /// `@category comment`
+  /// `@category comment` or `@group comment`
   Category {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
@@ -109,7 +357,12 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
+  /// `@example <caption>caption</caption> code`, or `@example caption`
+  /// followed by fenced code blocks
   Example {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    caption: Option<String>,
+    /// The body of the example, including its code fences.
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
@@ -120,10 +373,25 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
+  /// `@experimental`
+  Experimental,
   /// `@ignore`
   Ignore,
+  /// `@inheritDoc` or `@inheritdoc`
+  InheritDoc,
+  /// `@internal`
+  Internal,
   /// `@module`
   Module,
+  /// `@packageDocumentation`, the TSDoc tag of the doc of the entrypoint of
+  /// a package, which is taken like `@module`
+  PackageDocumentation,
+  /// `@namespace` or `@namespace name`, for an object which groups values
+  /// described by `@property` tags
+  Namespace {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    name: Option<String>,
+  },
   /// `@param`, `@arg` or `argument`, in format of `@param {type} name comment`
   /// or `@param {type} [name=default] comment`
   /// or `@param {type} [name] comment`
@@ -131,7 +399,7 @@ pub enum JsDocTag {
     name: String,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     type_ref: Option<String>,
-    #[serde(skip_serializing_if = "core::ops::Not::not")]
+    #[serde(skip_serializing_if = "core::ops::Not::not", default)]
     optional: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     default: Option<String>,
@@ -142,11 +410,17 @@ pub enum JsDocTag {
   Public,
   /// `@private`
   Private,
-  /// `@property {type} name comment` or `@prop {type} name comment`
+  /// `@property {type} name comment` or `@prop {type} name comment`,
+  /// where the name can be `[name]` or `[name=default]` for an optional
+  /// property, and a path like `options.timeout` for a nested property
   Property {
     name: String,
     #[serde(rename = "type")]
     type_ref: String,
+    #[serde(skip_serializing_if = "core::ops::Not::not", default)]
+    optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    default: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
@@ -161,13 +435,31 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
+  /// `@see target comment`, where the target is a symbol name, like
+  /// `Deno.Conn.closeWrite`, or a URL, optionally in a `{@link}`
+  See {
+    target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+  },
+  /// `@since version comment`, the version in which the API was added
+  Since {
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+  },
   /// `@tags allow-read, allow-write`
   Tags {
     tags: Vec<String>,
   },
-  /// `@template T comment`
+  /// `@template T comment`, `@template {constraint} T comment`
+  /// or `@template [T=default] comment`
   Template {
     name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    constraint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    default: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
@@ -194,34 +486,92 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none")]
     doc: Option<String>,
   },
+  /// `@yields {type} comment` or `@yield {type} comment`, for the values
+  /// yielded by a generator
+  Yields {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    type_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+  },
   Unsupported {
     value: String,
   },
 }
 
+impl JsDocTag {
+  /// The comment of the tag, if it has one, except for `@category`, whose
+  /// comment is the name of the category.
+  pub(crate) fn doc_mut(&mut self) -> Option<&mut String> {
+    match self {
+      Self::Callback { doc, .. }
+      | Self::Default { doc, .. }
+      | Self::Deprecated { doc }
+      | Self::Enum { doc, .. }
+      | Self::Example { doc, .. }
+      | Self::Extends { doc, .. }
+      | Self::Param { doc, .. }
+      | Self::Property { doc, .. }
+      | Self::Return { doc, .. }
+      | Self::See { doc, .. }
+      | Self::Since { doc, .. }
+      | Self::Template { doc, .. }
+      | Self::This { doc, .. }
+      | Self::TypeDef { doc, .. }
+      | Self::TypeRef { doc, .. }
+      | Self::Yields { doc, .. } => doc.as_mut(),
+      _ => None,
+    }
+  }
+}
+
 impl From<String> for JsDocTag {
   fn from(value: String) -> Self {
     if let Some(caps) = JS_DOC_TAG_ONLY_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       match kind {
+        "alpha" => Self::Alpha,
+        "beta" => Self::Beta,
         "constructor" | "class" => Self::Constructor,
+        "experimental" => Self::Experimental,
         "ignore" => Self::Ignore,
+        "inheritDoc" | "inheritdoc" => Self::InheritDoc,
+        "internal" => Self::Internal,
         "module" => Self::Module,
+        "packageDocumentation" => Self::PackageDocumentation,
         "public" => Self::Public,
         "private" => Self::Private,
         "protected" => Self::Protected,
         "readonly" => Self::ReadOnly,
         _ => unreachable!("kind unexpected: {}", kind),
       }
+    } else if let Some(caps) = JS_DOC_TAG_NAMESPACE_RE.captures(&value) {
+      let name = caps.get(1).map(|m| m.as_str().to_string());
+      Self::Namespace { name }
     } else if let Some(caps) = JS_DOC_TAG_NAMED_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       let name = caps.get(2).unwrap().as_str().to_string();
       let doc = caps.get(3).map(|m| m.as_str().to_string());
       match kind {
         "callback" => Self::Callback { name, doc },
-        "template" => Self::Template { name, doc },
         _ => unreachable!("kind unexpected: {}", kind),
       }
+    } else if let Some(caps) = JS_DOC_TAG_TEMPLATE_RE.captures(&value) {
+      let name = caps
+        .name("name")
+        .or_else(|| caps.name("nameWithDefault"))
+        .unwrap()
+        .as_str()
+        .to_string();
+      let constraint = caps.name("constraint").map(|m| m.as_str().to_string());
+      let default = caps.name("default").map(|m| m.as_str().trim().to_string());
+      let doc = caps.name("doc").map(|m| m.as_str().to_string());
+      Self::Template {
+        name,
+        constraint,
+        default,
+        doc,
+      }
     } else if let Some(caps) = JS_DOC_TAG_TYPED_RE.captures(&value) {
       let kind = caps.get(1).unwrap().as_str();
       let type_ref = caps.get(2).unwrap().as_str().to_string();
@@ -243,11 +593,6 @@ impl From<String> for JsDocTag {
       let name = caps.get(3).unwrap().as_str().to_string();
       let doc = caps.get(4).map(|m| m.as_str().to_string());
       match kind {
-        "prop" | "property" => Self::Property {
-          name,
-          type_ref,
-          doc,
-        },
         "typedef" => Self::TypeDef {
           name,
           type_ref,
@@ -259,9 +604,9 @@ impl From<String> for JsDocTag {
       let kind = caps.get(1).unwrap().as_str();
       let doc = caps.get(2).map(|m| m.as_str().to_string());
       match kind {
-        "category" => Self::Category { doc },
+        "category" | "group" => Self::Category { doc },
         "deprecated" => Self::Deprecated { doc },
-        "example" => Self::Example { doc },
+        "example" => parse_example(doc),
         "tags" => Self::Tags {
           tags: doc
             .map(|s| s.split(',').map(|i| i.trim().to_string()).collect())
@@ -287,20 +632,151 @@ impl From<String> for JsDocTag {
         default,
         doc,
       }
+    } else if let Some(caps) = JS_DOC_TAG_PROPERTY_RE.captures(&value) {
+      let name_with_maybe_default = caps.name("nameWithDefault");
+      let name = caps
+        .name("name")
+        .or(name_with_maybe_default)
+        .unwrap()
+        .as_str()
+        .to_string();
+      let type_ref = caps.name("type").unwrap().as_str().to_string();
+      let default = caps.name("default").map(|m| m.as_str().trim().to_string());
+      let doc = caps.name("doc").map(|m| m.as_str().to_string());
+      Self::Property {
+        name,
+        type_ref,
+        optional: name_with_maybe_default.is_some(),
+        default,
+        doc,
+      }
+    } else if let Some(caps) = JS_DOC_TAG_SEE_RE.captures(&value) {
+      let target = caps.get(1).or_else(|| caps.get(2)).unwrap();
+      let doc = caps.get(3).map(|m| m.as_str().to_string());
+      Self::See {
+        target: target.as_str().to_string(),
+        doc,
+      }
+    } else if let Some(caps) = JS_DOC_TAG_SINCE_RE.captures(&value) {
+      let version = caps.get(1).unwrap().as_str().to_string();
+      let doc = caps.get(2).map(|m| m.as_str().to_string());
+      Self::Since { version, doc }
     } else if let Some(caps) = JS_DOC_TAG_RETURN_RE.captures(&value) {
       let type_ref = caps.get(1).map(|m| m.as_str().to_string());
       let doc = caps.get(2).map(|m| m.as_str().to_string());
       Self::Return { type_ref, doc }
+    } else if let Some(caps) = JS_DOC_TAG_YIELDS_RE.captures(&value) {
+      let type_ref = caps.get(1).map(|m| m.as_str().to_string());
+      let doc = caps.get(2).map(|m| m.as_str().to_string());
+      Self::Yields { type_ref, doc }
     } else {
       Self::Unsupported { value }
     }
   }
 }
 
+/// Separates the caption of an example from its body: either the text of a
+/// leading `<caption>` element, or the text before the first code fence.
+fn parse_example(doc: Option<String>) -> JsDocTag {
+  let Some(doc) = doc else {
+    return JsDocTag::Example {
+      caption: None,
+      doc: None,
+    };
+  };
+  let (caption, body) =
+    if let Some(caps) = JS_DOC_EXAMPLE_CAPTION_RE.captures(&doc) {
+      let caption = caps.get(1).unwrap().as_str().trim().to_string();
+      (
+        Some(caption),
+        doc[caps.get(0).unwrap().end()..].trim_start(),
+      )
+    } else {
+      let fence = doc
+        .lines()
+        .scan(0, |offset, line| {
+          let start = *offset;
+          *offset += line.len() + 1;
+          Some((start, line))
+        })
+        .find(|(_, line)| {
+          let line = line.trim_start();
+          line.starts_with("```") || line.starts_with("~~~")
+        });
+      match fence {
+        Some((start, _)) if !doc[..start].trim().is_empty() => {
+          (Some(doc[..start].trim().to_string()), &doc[start..])
+        }
+        _ => (None, doc.as_str()),
+      }
+    };
+  JsDocTag::Example {
+    caption: caption.filter(|caption| !caption.is_empty()),
+    doc: Some(body.to_string()).filter(|body| !body.trim().is_empty()),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_js_doc_deprecated() {
+    let js_doc =
+      JsDoc::from("Docs.\n\n@deprecated Use `b` instead.".to_string());
+    assert!(js_doc.is_deprecated());
+    assert_eq!(js_doc.deprecation_message(), Some("Use `b` instead."));
+    let js_doc = JsDoc::from("@deprecated".to_string());
+    assert!(js_doc.is_deprecated());
+    assert_eq!(js_doc.deprecation_message(), None);
+    let js_doc = JsDoc::from("Docs.".to_string());
+    assert!(!js_doc.is_deprecated());
+  }
+
+  #[test]
+  fn test_js_doc_see() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@see Deno.Conn.closeWrite for details\n@see {@link Deno.Listener | the listener}\n@see https://deno.land/"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [
+          {
+            "kind": "see",
+            "target": "Deno.Conn.closeWrite",
+            "doc": "for details"
+          },
+          { "kind": "see", "target": "Deno.Listener" },
+          { "kind": "see", "target": "https://deno.land/" }
+        ]
+      }),
+    );
+  }
+
+  #[test]
+  fn test_js_doc_since() {
+    let js_doc = JsDoc::from(
+      "@since 1.2.0 with the `signal` option\n@since 1.0.0".to_string(),
+    );
+    assert_eq!(
+      serde_json::to_value(&js_doc).unwrap(),
+      json!({
+        "tags": [
+          {
+            "kind": "since",
+            "version": "1.2.0",
+            "doc": "with the `signal` option"
+          },
+          { "kind": "since", "version": "1.0.0" }
+        ]
+      }),
+    );
+    assert_eq!(js_doc.since(), Some("1.2.0"));
+    assert_eq!(JsDoc::default().since(), None);
+  }
+
   #[test]
   fn test_js_doc_tag_only() {
     assert_eq!(
@@ -312,10 +788,22 @@ mod tests {
       serde_json::to_value(JsDoc::from("@class more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "constructor" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@experimental".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "experimental" } ] }),
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@ignore more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "ignore" } ] }),
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@inheritDoc".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "inheritdoc" } ] }),
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@internal more".to_string())).unwrap(),
+      json!({ "tags": [ { "kind": "internal" } ] }),
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@module more".to_string())).unwrap(),
       json!({ "tags": [ { "kind": "module" } ] }),
@@ -367,7 +855,8 @@ if (true) {
         "tags": [
           {
             "kind": "example",
-            "doc": "something like this\n\nexplain\n\n```ts\nif (true) {\n  console.log(\"hello\");\n}\n```\n"
+            "caption": "something like this\n\nexplain",
+            "doc": "```ts\nif (true) {\n  console.log(\"hello\");\n}\n```\n"
           },
           {
             "kind": "param",
@@ -417,6 +906,106 @@ if (true) {
     );
   }
 
+  #[test]
+  fn test_parse_doc_segments() {
+    assert_eq!(
+      parse_doc_segments(
+        "See {@link Foo}, {@link Bar.baz | the baz} and {@link https://deno.land Deno}."
+      ),
+      vec![
+        DocSegment::Text {
+          text: "See ".to_string()
+        },
+        DocSegment::Link {
+          target: "Foo".to_string(),
+          label: None,
+          style: LinkStyle::Link,
+        },
+        DocSegment::Text {
+          text: ", ".to_string()
+        },
+        DocSegment::Link {
+          target: "Bar.baz".to_string(),
+          label: Some("the baz".to_string()),
+          style: LinkStyle::Link,
+        },
+        DocSegment::Text {
+          text: " and ".to_string()
+        },
+        DocSegment::Link {
+          target: "https://deno.land".to_string(),
+          label: Some("Deno".to_string()),
+          style: LinkStyle::Link,
+        },
+        DocSegment::Text {
+          text: ".".to_string()
+        },
+      ]
+    );
+    assert_eq!(parse_doc_segments(""), vec![]);
+    assert_eq!(
+      parse_doc_segments("{@linkcode Foo.bar} or {@linkplain Baz | baz}"),
+      vec![
+        DocSegment::Link {
+          target: "Foo.bar".to_string(),
+          label: None,
+          style: LinkStyle::Code,
+        },
+        DocSegment::Text {
+          text: " or ".to_string()
+        },
+        DocSegment::Link {
+          target: "Baz".to_string(),
+          label: Some("baz".to_string()),
+          style: LinkStyle::Plain,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_template() {
+    let js_doc = JsDoc::from(
+      "@template {string} K the key\n@template [V=unknown] the value\n@template {Record<K, V>} [M = Map<K, V>]"
+        .to_string(),
+    );
+    assert_eq!(
+      serde_json::to_value(&js_doc).unwrap(),
+      json!({
+        "tags": [
+          {
+            "kind": "template",
+            "name": "K",
+            "constraint": "string",
+            "doc": "the key",
+          },
+          {
+            "kind": "template",
+            "name": "V",
+            "default": "unknown",
+            "doc": "the value",
+          },
+          {
+            "kind": "template",
+            "name": "M",
+            "constraint": "Record<K, V>",
+            "default": "Map<K, V>",
+          }
+        ]
+      })
+    );
+    assert_eq!(
+      js_doc.template("V"),
+      Some(&JsDocTag::Template {
+        name: "V".to_string(),
+        constraint: None,
+        default: Some("unknown".to_string()),
+        doc: Some("the value".to_string()),
+      })
+    );
+    assert_eq!(js_doc.template("T"), None);
+  }
+
   #[test]
   fn test_js_doc_tag_typed() {
     assert_eq!(
@@ -529,6 +1118,29 @@ if (true) {
         }]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@property {number} [a.b=1] more doc\n@prop {string} [c] doc"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "property",
+          "name": "a.b",
+          "type": "number",
+          "optional": true,
+          "default": "1",
+          "doc": "more doc"
+        }, {
+          "kind": "property",
+          "name": "c",
+          "type": "string",
+          "optional": true,
+          "doc": "doc"
+        }]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from(
         "@typedef {object} Interface more doc\n\nnew paragraph".to_string()
@@ -567,6 +1179,15 @@ if (true) {
         }]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@group Hooks".to_string())).unwrap(),
+      json!({
+        "tags": [{
+          "kind": "category",
+          "doc": "Hooks",
+        }]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from("@deprecated".to_string())).unwrap(),
       json!({
@@ -607,6 +1228,44 @@ if (true) {
         }]
       })
     );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@example <caption>Reading a file</caption>\nconst a = 1;".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "example",
+          "caption": "Reading a file",
+          "doc": "const a = 1;"
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@example Reading a file\n```ts\nconst a = 1;\n```".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "example",
+          "caption": "Reading a file",
+          "doc": "```ts\nconst a = 1;\n```"
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@example\n```ts\nconst a = 1;\n```".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "example",
+          "doc": "```ts\nconst a = 1;\n```"
+        }]
+      })
+    );
     assert_eq!(
       serde_json::to_value(JsDoc::from(
         "@tags allow-read, allow-write".to_string()
@@ -717,6 +1376,88 @@ if (true) {
     );
   }
 
+  #[test]
+  fn test_js_doc_param_tags() {
+    let js_doc = JsDoc::from(
+      "@param {Object} options The options.\n@param {number} [options.timeout=1000] The timeout.\n@param {string[]} [options.names]\n@param {Object[]} users\n@param {string} users[].name The name.\n@param {string} other.name"
+        .to_string(),
+    );
+    let param_tag = |name: &str, type_ref: &str, doc: Option<&str>| ParamTag {
+      name: name.to_string(),
+      type_ref: Some(type_ref.to_string()),
+      optional: false,
+      default: None,
+      doc: doc.map(|doc| doc.to_string()),
+      properties: Vec::new(),
+    };
+    assert_eq!(
+      js_doc.param_tags(),
+      vec![
+        ParamTag {
+          properties: vec![
+            ParamTag {
+              default: Some("1000".to_string()),
+              ..param_tag("timeout", "number", Some("The timeout."))
+            },
+            ParamTag {
+              optional: true,
+              ..param_tag("names", "string[]", None)
+            },
+          ],
+          ..param_tag("options", "Object", Some("The options."))
+        },
+        ParamTag {
+          properties: vec![param_tag("name", "string", Some("The name."))],
+          ..param_tag("users", "Object[]", None)
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_release() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@alpha\n@beta".to_string())).unwrap(),
+      json!({
+        "tags": [{
+          "kind": "alpha",
+        }, {
+          "kind": "beta",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_yields() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@returns {Generator<string>} the generator\n@yields {string} each line"
+          .to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "return",
+          "type": "Generator<string>",
+          "doc": "the generator",
+        }, {
+          "kind": "yields",
+          "type": "string",
+          "doc": "each line",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@yield".to_string())).unwrap(),
+      json!({
+        "tags": [{
+          "kind": "yields",
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_tag_returns() {
     assert_eq!(
@@ -905,6 +1646,8 @@ multi-line
       serde_json::to_value(JsDocTag::Property {
         name: "prop".to_string(),
         type_ref: "string".to_string(),
+        optional: false,
+        default: None,
         doc: None,
       })
       .unwrap(),
@@ -937,6 +1680,8 @@ multi-line
     assert_eq!(
       serde_json::to_value(JsDocTag::Template {
         name: "T".to_string(),
+        constraint: None,
+        default: None,
         doc: None,
       })
       .unwrap(),
@@ -12,37 +12,205 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde_json;
 
+/// Enters a `tracing` span at the given level until the end of the enclosing
+/// block, when the `tracing` feature is enabled.
+macro_rules! trace_span {
+  ($level:ident, $($args:tt)*) => {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::span!(tracing::Level::$level, $($args)*).entered();
+  };
+}
+
+/// Instruments a future with a `tracing` span at the given level, when the
+/// `tracing` feature is enabled. Unlike [`trace_span`], the span is only
+/// entered while the future is polled, so it can be used across `.await`.
+macro_rules! trace_instrument {
+  ($future:expr, $level:ident, $($args:tt)*) => {{
+    #[cfg(feature = "tracing")]
+    let future = tracing::Instrument::instrument(
+      $future,
+      tracing::span!(tracing::Level::$level, $($args)*),
+    );
+    #[cfg(not(feature = "tracing"))]
+    let future = $future;
+    future
+  }};
+}
+
+mod anchor;
+mod builder;
+mod canonical;
+mod category;
+mod chunk;
 mod class;
+mod closure_type;
 mod colors;
 mod decorators;
+mod diff;
 mod display;
+mod docsearch;
 mod r#enum;
+mod events;
 mod function;
+mod index;
+mod inherit;
+mod inline;
 mod interface;
 mod js_doc;
+mod limits;
+mod manifest;
+mod members;
+mod metrics;
+mod module_filter;
 mod node;
+mod overlay;
+mod overload;
 mod params;
 mod parser;
+mod position;
+mod score;
+mod see;
 mod swc_util;
 mod ts_type;
 mod ts_type_param;
 mod type_alias;
+mod typedef;
 mod variable;
 
+pub use anchor::slug;
+pub use anchor::AnchorGenerator;
+pub use builder::merge_doc_nodes;
+pub use builder::DocNodeBuildError;
+pub use builder::DocNodeBuilder;
+pub use canonical::CanonicalPath;
+pub use canonical::CanonicalPaths;
+pub use category::group_nodes_by_category;
+pub use category::CategoryGroup;
+pub use chunk::serialize_chunks;
+pub use chunk::ChunkBy;
+pub use chunk::ChunkManifest;
+pub use chunk::ChunkManifestEntry;
+pub use chunk::ChunkedOutput;
+pub use class::ClassDef;
+pub use diff::diff_doc_nodes;
+pub use diff::render_doc_node_diff;
+pub use diff::ApiChange;
+pub use diff::ApiChangeKind;
+pub use diff::ApiDiff;
+pub use diff::SemverImpact;
+pub use docsearch::docsearch_records;
+pub use docsearch::DocSearchHierarchy;
+pub use docsearch::DocSearchRecord;
+pub use events::EventDef;
+pub use function::FunctionDef;
+pub use index::DocNodeIndex;
+pub use index::IndexEntry;
+pub use index::IndexedNode;
+pub use inherit::InheritedMembers;
+pub use inline::inline_external_types;
+pub use inline::InlineOptions;
+pub use interface::InterfaceDef;
+pub use js_doc::parse_doc_segments;
+pub use js_doc::DocSegment;
+pub use js_doc::JsDoc;
+pub use js_doc::JsDocTag;
+pub use js_doc::LinkStyle;
+pub use js_doc::ParamTag;
+pub use js_doc::RawJsDoc;
+pub use limits::truncate_doc_nodes;
+pub use limits::OutputLimits;
+pub use limits::TruncationSummary;
+pub use limits::TRUNCATION_MARKER;
+pub use manifest::package_roots;
+pub use manifest::PackageRoot;
+pub use members::MemberGroup;
+pub use members::MemberOrder;
+pub use metrics::api_metrics;
+pub use metrics::ApiMetrics;
+pub use metrics::ExternalTypeReference;
+pub use module_filter::ModuleFilter;
+pub use node::DeclarationKind;
 pub use node::DocNode;
 pub use node::DocNodeKind;
+pub use node::DynamicImport;
+pub use node::LeadingComment;
+pub use node::LeadingCommentKind;
+pub use node::Location;
+pub use node::ModuleDoc;
+pub use node::ModuleDocDef;
+pub use node::NamespaceDef;
+pub use node::ReferencedType;
+pub use node::ReleaseTag;
+pub use node::Stability;
+pub use overlay::apply_overlay;
+pub use overlay::DocOverlay;
+pub use overlay::OverlayEntry;
+pub use overload::select_overload;
+pub use overload::ArgumentKind;
+pub use overload::OverloadHint;
+pub use position::ColumnEncoding;
+pub use position::PositionEncoding;
+pub use r#enum::EnumDef;
+pub use score::score_module;
+pub use score::score_modules;
+pub use score::ModuleScore;
+pub use score::ScoreReport;
+pub use score::ScoreWeights;
+pub use type_alias::TypeAliasDef;
+pub use variable::VariableDef;
 
 use node::ImportDef;
-use node::Location;
 use node::ReexportKind;
 use params::ParamDef;
 
 cfg_if! {
   if #[cfg(feature = "rust")] {
+    mod backend;
+    mod messages;
+    mod outline;
     mod printer;
+    mod source;
+    mod tags;
+    mod text;
+    mod tokens;
+    pub use backend::Artifact;
+    pub use backend::BackendOptions;
+    pub use backend::BackendRegistry;
+    pub use backend::CtagsBackend;
+    pub use backend::JsonBackend;
+    pub use backend::OutputBackend;
+    pub use backend::SemanticTokensBackend;
+    pub use backend::TerminalBackend;
+    pub use backend::TextBackend;
+    pub use messages::MessageCatalog;
+    pub use outline::document_symbols;
+    pub use outline::DocumentSymbol;
+    pub use outline::Position;
+    pub use outline::Range;
+    pub use outline::SymbolKind;
+    pub use parser::DefaultExportName;
+    pub use parser::DocDiagnostic;
+    pub use parser::DocDiagnosticKind;
     pub use parser::DocError;
     pub use parser::DocParser;
+    pub use parser::DocParserMetrics;
+    pub use parser::DocParserOptions;
+    pub use parser::GraphStats;
+    pub use parser::ModuleIgnoreScope;
+    pub use parser::ModuleInfo;
+    pub use parser::ModuleParseMetrics;
+    pub use parser::StrictMode;
+    pub use parser::SymbolAlias;
+    pub use parser::VisibilityFilter;
     pub use printer::DocPrinter;
+    pub use source::source_text;
+    pub use source::SourceTextProvider;
+    pub use tags::to_ctags;
+    pub use text::to_plain_text;
+    pub use tokens::semantic_signatures;
+    pub use tokens::SemanticSignature;
+    pub use tokens::SemanticToken;
+    pub use tokens::SemanticTokenKind;
   }
 }
 
@@ -50,9 +218,17 @@ cfg_if! {
   if #[cfg(feature = "wasm")] {
     mod js;
     pub use js::doc;
+  } else if #[cfg(feature = "napi")] {
+    mod native;
+    pub use native::doc;
   }
 }
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 #[cfg(test)]
 mod tests;
 
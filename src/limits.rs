@@ -0,0 +1,400 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::js_doc::JsDoc;
+use crate::node::DocNode;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeLiteralDef;
+
+/// The marker appended to documentation and used as the `repr` of types
+/// which were cut short because of an [`OutputLimits`].
+pub const TRUNCATION_MARKER: &str = "…";
+
+/// Bounds on the size of the documentation output. A limit of `None` means
+/// unbounded.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputLimits {
+  /// The maximum number of doc nodes, including the elements of namespaces.
+  pub max_nodes: Option<usize>,
+  /// The maximum nesting of type literals. Type literals nested deeper than
+  /// this are replaced by an empty type literal with a `repr` of
+  /// [`TRUNCATION_MARKER`].
+  pub max_type_literal_depth: Option<usize>,
+  /// The maximum length, in characters, of any JSDoc description.
+  pub max_doc_length: Option<usize>,
+  /// The maximum length, in characters, of the `repr` of any type, like a
+  /// long string literal. The structure of the type is kept, so the full
+  /// representation is still available from [`TsTypeDef::full_repr`].
+  pub max_repr_length: Option<usize>,
+}
+
+/// What was removed from the output when applying an [`OutputLimits`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TruncationSummary {
+  pub omitted_nodes: usize,
+  pub truncated_docs: usize,
+  pub truncated_type_literals: usize,
+  pub truncated_reprs: usize,
+  /// The number of characters removed from the `repr` of types.
+  pub truncated_repr_chars: usize,
+}
+
+impl TruncationSummary {
+  pub fn is_empty(&self) -> bool {
+    self.omitted_nodes == 0
+      && self.truncated_docs == 0
+      && self.truncated_type_literals == 0
+      && self.truncated_reprs == 0
+  }
+}
+
+/// Truncates the doc nodes in place so they fit within `limits`, returning a
+/// summary of what was truncated.
+pub fn truncate_doc_nodes(
+  doc_nodes: &mut Vec<DocNode>,
+  limits: &OutputLimits,
+) -> TruncationSummary {
+  let mut summary = TruncationSummary::default();
+  if let Some(max_nodes) = limits.max_nodes {
+    let mut remaining = max_nodes;
+    truncate_node_count(doc_nodes, &mut remaining, &mut summary);
+  }
+  for doc_node in doc_nodes.iter_mut() {
+    truncate_doc_node(doc_node, limits, &mut summary);
+  }
+  summary
+}
+
+fn count_nodes(doc_node: &DocNode) -> usize {
+  1 + doc_node
+    .namespace_def
+    .as_ref()
+    .map(|ns| ns.elements.iter().map(count_nodes).sum())
+    .unwrap_or(0)
+}
+
+fn truncate_node_count(
+  doc_nodes: &mut Vec<DocNode>,
+  remaining: &mut usize,
+  summary: &mut TruncationSummary,
+) {
+  let mut kept = 0;
+  for doc_node in doc_nodes.iter_mut() {
+    if *remaining == 0 {
+      break;
+    }
+    *remaining -= 1;
+    kept += 1;
+    if let Some(ns) = &mut doc_node.namespace_def {
+      truncate_node_count(&mut ns.elements, remaining, summary);
+    }
+  }
+  for doc_node in doc_nodes.drain(kept..) {
+    summary.omitted_nodes += count_nodes(&doc_node);
+  }
+}
+
+fn truncate_doc_node(
+  doc_node: &mut DocNode,
+  limits: &OutputLimits,
+  summary: &mut TruncationSummary,
+) {
+  doc_node.for_each_ts_type_mut(&mut |ts_type| {
+    truncate_ts_type(ts_type, 0, limits, summary)
+  });
+
+  if let Some(max_doc_length) = limits.max_doc_length {
+    doc_node.for_each_nested_js_doc_mut(&mut |js_doc| {
+      truncate_js_doc(js_doc, max_doc_length, summary)
+    });
+    doc_node.for_each_param_mut(&mut |param| {
+      param.for_each_doc_mut(&mut |doc| {
+        if truncate_doc(doc, max_doc_length) {
+          summary.truncated_docs += 1;
+        }
+      })
+    });
+  }
+
+  if let Some(namespace_def) = &mut doc_node.namespace_def {
+    for element in &mut namespace_def.elements {
+      truncate_doc_node(element, limits, summary);
+    }
+  }
+}
+
+/// Truncates the documentation and the comments of the tags of a JSDoc. The
+/// doc segments of a truncated JSDoc are recomputed from its documentation,
+/// and its raw comment, which still holds the full text, is dropped.
+fn truncate_js_doc(
+  js_doc: &mut JsDoc,
+  max_doc_length: usize,
+  summary: &mut TruncationSummary,
+) {
+  let mut truncated = 0;
+  if let Some(doc) = &mut js_doc.doc {
+    if truncate_doc(doc, max_doc_length) {
+      truncated += 1;
+    }
+  }
+  for tag in &mut js_doc.tags {
+    if let Some(doc) = tag.doc_mut() {
+      if truncate_doc(doc, max_doc_length) {
+        truncated += 1;
+      }
+    }
+  }
+  if truncated > 0 {
+    if js_doc.doc_segments.is_some() {
+      js_doc.set_doc_segments();
+    }
+    js_doc.raw = None;
+    summary.truncated_docs += truncated;
+  }
+}
+
+/// Cuts the text short after `max_doc_length` characters, returning whether
+/// it was longer.
+fn truncate_doc(doc: &mut String, max_doc_length: usize) -> bool {
+  let Some((index, _)) = doc.char_indices().nth(max_doc_length) else {
+    return false;
+  };
+  doc.truncate(index);
+  doc.push_str(TRUNCATION_MARKER);
+  true
+}
+
+fn truncate_ts_type(
+  ts_type: &mut TsTypeDef,
+  depth: usize,
+  limits: &OutputLimits,
+  summary: &mut TruncationSummary,
+) {
+  if limits.max_type_literal_depth.is_none() && limits.max_repr_length.is_none()
+  {
+    return;
+  }
+  let depth = if ts_type.kind == Some(TsTypeDefKind::TypeLiteral) {
+    depth + 1
+  } else {
+    depth
+  };
+  if let Some(max_depth) = limits.max_type_literal_depth {
+    if depth > max_depth {
+      *ts_type = TsTypeDef {
+        repr: TRUNCATION_MARKER.to_string(),
+        kind: Some(TsTypeDefKind::TypeLiteral),
+        type_literal: Some(TsTypeLiteralDef::default()),
+        ..Default::default()
+      };
+      summary.truncated_type_literals += 1;
+      return;
+    }
+  }
+  if let Some(max_repr_length) = limits.max_repr_length {
+    let repr = &mut ts_type.repr;
+    if let Some((index, _)) = repr.char_indices().nth(max_repr_length) {
+      summary.truncated_repr_chars += repr[index..].chars().count();
+      summary.truncated_reprs += 1;
+      repr.truncate(index);
+      repr.push_str(TRUNCATION_MARKER);
+    }
+  }
+  ts_type.for_each_child_mut(&mut |child| {
+    truncate_ts_type(child, depth, limits, summary)
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::class::ClassDef;
+  use crate::js_doc::JsDocTag;
+  use crate::js_doc::RawJsDoc;
+  use crate::node::Location;
+  use crate::node::NamespaceDef;
+  use crate::ts_type_param::TsTypeParamDef;
+  use crate::variable::VariableDef;
+
+  fn node(name: &str) -> DocNode {
+    DocNode {
+      name: name.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_truncate_max_nodes() {
+    let mut ns = node("ns");
+    ns.namespace_def = Some(NamespaceDef {
+      elements: vec![node("a"), node("b")],
+      ambient_module: false,
+    });
+    let mut doc_nodes = vec![node("first"), ns, node("last")];
+    let summary = truncate_doc_nodes(
+      &mut doc_nodes,
+      &OutputLimits {
+        max_nodes: Some(3),
+        ..Default::default()
+      },
+    );
+    assert_eq!(doc_nodes.len(), 2);
+    let elements = &doc_nodes[1].namespace_def.as_ref().unwrap().elements;
+    assert_eq!(elements.len(), 1);
+    assert_eq!(elements[0].name, "a");
+    assert_eq!(
+      summary,
+      TruncationSummary {
+        omitted_nodes: 2,
+        ..Default::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_truncate_doc_length() {
+    let mut doc_node = node("a");
+    doc_node.js_doc = JsDoc {
+      doc: Some("héllo world".to_string()),
+      tags: vec![],
+      doc_segments: None,
+      raw: None,
+    };
+    let mut doc_nodes = vec![doc_node];
+    let summary = truncate_doc_nodes(
+      &mut doc_nodes,
+      &OutputLimits {
+        max_doc_length: Some(5),
+        ..Default::default()
+      },
+    );
+    assert_eq!(doc_nodes[0].js_doc.doc.as_deref(), Some("héllo…"));
+    assert_eq!(summary.truncated_docs, 1);
+    assert!(!summary.is_empty());
+  }
+
+  #[test]
+  fn test_truncate_doc_length_of_segments_and_tags() {
+    let mut doc_node = node("a");
+    let location = Location {
+      filename: "file:///mod.ts".to_string(),
+      line: 1,
+      col: 0,
+      requested_filename: None,
+    };
+    doc_node.js_doc = JsDoc {
+      doc: Some("See {@link Foo} for more.".to_string()),
+      tags: vec![JsDocTag::Deprecated {
+        doc: Some("Use {@link Bar} instead.".to_string()),
+      }],
+      doc_segments: None,
+      raw: Some(RawJsDoc {
+        text: "/** See {@link Foo} for more. */".to_string(),
+        location: location.clone(),
+        end: location,
+      }),
+    };
+    doc_node.js_doc.set_doc_segments();
+    let mut doc_nodes = vec![doc_node];
+    let summary = truncate_doc_nodes(
+      &mut doc_nodes,
+      &OutputLimits {
+        max_doc_length: Some(15),
+        ..Default::default()
+      },
+    );
+    let js_doc = serde_json::to_value(&doc_nodes[0].js_doc).unwrap();
+    assert_eq!(
+      js_doc,
+      serde_json::json!({
+        "doc": "See {@link Foo}…",
+        "tags": [{ "kind": "deprecated", "doc": "Use {@link Bar}…" }],
+        "docSegments": [
+          { "kind": "text", "text": "See " },
+          { "kind": "link", "target": "Foo" },
+          { "kind": "text", "text": "…" }
+        ]
+      })
+    );
+    assert_eq!(summary.truncated_docs, 2);
+  }
+
+  #[test]
+  fn test_truncate_repr_length() {
+    let mut doc_node = node("a");
+    doc_node.variable_def = Some(VariableDef {
+      ts_type: Some(TsTypeDef {
+        kind: Some(TsTypeDefKind::Union),
+        union: Some(vec![
+          TsTypeDef::string_value("abcdefgh".to_string()),
+          TsTypeDef::string_value("abc".to_string()),
+        ]),
+        ..Default::default()
+      }),
+      kind: deno_ast::swc::ast::VarDeclKind::Const,
+    });
+    let mut doc_nodes = vec![doc_node];
+    let summary = truncate_doc_nodes(
+      &mut doc_nodes,
+      &OutputLimits {
+        max_repr_length: Some(3),
+        ..Default::default()
+      },
+    );
+    let ts_type = doc_nodes[0]
+      .variable_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+      .as_ref()
+      .unwrap();
+    let union = ts_type.union.as_ref().unwrap();
+    assert_eq!(union[0].repr, "abc…");
+    assert_eq!(union[1].repr, "abc");
+    assert_eq!(ts_type.full_repr(), r#""abcdefgh" | "abc""#);
+    assert_eq!(summary.truncated_reprs, 1);
+    assert_eq!(summary.truncated_repr_chars, 5);
+  }
+
+  #[test]
+  fn test_truncate_repr_length_of_type_params_and_implements() {
+    let mut doc_node = node("A");
+    doc_node.class_def = Some(ClassDef {
+      is_abstract: false,
+      constructors: vec![],
+      properties: vec![],
+      index_signatures: vec![],
+      methods: vec![],
+      extends: None,
+      implements: vec![TsTypeDef::string_value("implemented".to_string())],
+      type_params: vec![TsTypeParamDef {
+        name: "T".to_string(),
+        constraint: Some(TsTypeDef::string_value("constraint".to_string())),
+        default: None,
+      }],
+      super_type_params: vec![],
+      decorators: vec![],
+      events: vec![],
+    });
+    let mut doc_nodes = vec![doc_node];
+    let summary = truncate_doc_nodes(
+      &mut doc_nodes,
+      &OutputLimits {
+        max_repr_length: Some(4),
+        ..Default::default()
+      },
+    );
+    let class_def = doc_nodes[0].class_def.as_ref().unwrap();
+    assert_eq!(class_def.implements[0].repr, "impl…");
+    assert_eq!(
+      class_def.type_params[0].constraint.as_ref().unwrap().repr,
+      "cons…"
+    );
+    assert_eq!(summary.truncated_reprs, 2);
+  }
+}
@@ -0,0 +1,72 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use anyhow::anyhow;
+use deno_graph::ModuleSpecifier;
+use serde_json::Value;
+
+/// The conditions of a conditional export of a `package.json` which are
+/// used, in order of preference.
+const EXPORT_CONDITIONS: &[&str] = &["types", "deno", "import", "default"];
+
+/// An entrypoint of a package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageRoot {
+  /// The public name of the entrypoint, like `.` or `./path`.
+  pub name: String,
+  pub specifier: ModuleSpecifier,
+}
+
+/// Reads the roots of a package from the `exports` field of its manifest,
+/// either a `deno.json` or a `package.json`, in the order they are declared.
+///
+/// The field can be a single path, which is exported as `.`, or an object of
+/// names to paths. For conditional exports, the first of the `types`, `deno`,
+/// `import` and `default` conditions is used. Paths are resolved relative to
+/// `manifest_specifier`. Subpath patterns, which contain a `*`, are skipped.
+pub fn package_roots(
+  manifest_specifier: &ModuleSpecifier,
+  manifest: &str,
+) -> Result<Vec<PackageRoot>, anyhow::Error> {
+  let manifest: Value = serde_json::from_str(manifest)?;
+  let Some(exports) = manifest.get("exports") else {
+    return Err(anyhow!(
+      "The manifest \"{}\" has no \"exports\" field.",
+      manifest_specifier
+    ));
+  };
+  let entries = match exports {
+    Value::Object(map) if map.keys().all(|key| key.starts_with('.')) => map
+      .iter()
+      .map(|(name, target)| (name.clone(), target))
+      .collect::<Vec<_>>(),
+    // a single path, or conditions for the root export
+    _ => vec![(".".to_string(), exports)],
+  };
+
+  let mut roots = Vec::new();
+  for (name, target) in entries {
+    if name.contains('*') {
+      continue;
+    }
+    let Some(path) = export_target_path(target) else {
+      continue;
+    };
+    roots.push(PackageRoot {
+      name,
+      specifier: manifest_specifier.join(path)?,
+    });
+  }
+  Ok(roots)
+}
+
+fn export_target_path(target: &Value) -> Option<&str> {
+  match target {
+    Value::String(path) => Some(path),
+    Value::Object(conditions) => EXPORT_CONDITIONS
+      .iter()
+      .find_map(|condition| conditions.get(*condition))
+      .and_then(export_target_path),
+    Value::Array(targets) => targets.iter().find_map(export_target_path),
+    _ => None,
+  }
+}
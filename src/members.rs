@@ -0,0 +1,112 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::cmp::Ordering;
+
+use deno_ast::swc::ast::MethodKind;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::node::DocNode;
+
+/// How the members of classes and interfaces are ordered within their
+/// [`MemberGroup`], with [`crate::DocParserOptions::member_order`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MemberOrder {
+  /// In the order they are declared in.
+  #[default]
+  Source,
+  /// By name, ignoring case, and then in the order they are declared in.
+  Alphabetical,
+}
+
+/// The group of a member of a class or interface, in the order the groups
+/// are presented in.
+#[derive(
+  Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum MemberGroup {
+  /// Constructors of classes, and construct signatures of interfaces.
+  Constructor,
+  StaticProperty,
+  /// Static getters and setters.
+  StaticAccessor,
+  StaticMethod,
+  Property,
+  /// Getters and setters.
+  Accessor,
+  Method,
+}
+
+/// Sorts the properties and methods of classes and interfaces by group, and
+/// within a group by `order`, and sets the group of every member.
+/// Constructors are kept in the order they are declared in.
+pub(crate) fn order_members(doc_nodes: &mut [DocNode], order: MemberOrder) {
+  for doc_node in doc_nodes {
+    if let Some(class_def) = &mut doc_node.class_def {
+      for constructor in &mut class_def.constructors {
+        constructor.group = Some(MemberGroup::Constructor);
+      }
+      for property in &mut class_def.properties {
+        property.group = Some(if property.is_static {
+          MemberGroup::StaticProperty
+        } else {
+          MemberGroup::Property
+        });
+      }
+      for method in &mut class_def.methods {
+        let is_accessor = method.kind != MethodKind::Method;
+        method.group = Some(match (method.is_static, is_accessor) {
+          (true, true) => MemberGroup::StaticAccessor,
+          (true, false) => MemberGroup::StaticMethod,
+          (false, true) => MemberGroup::Accessor,
+          (false, false) => MemberGroup::Method,
+        });
+      }
+      class_def
+        .properties
+        .sort_by(|a, b| compare(order, (a.group, &a.name), (b.group, &b.name)));
+      class_def
+        .methods
+        .sort_by(|a, b| compare(order, (a.group, &a.name), (b.group, &b.name)));
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for property in &mut interface_def.properties {
+        property.group = Some(MemberGroup::Property);
+      }
+      for method in &mut interface_def.methods {
+        // construct signatures are the methods named `new`
+        method.group = Some(match method.kind {
+          MethodKind::Method if method.name == "new" && !method.computed => {
+            MemberGroup::Constructor
+          }
+          MethodKind::Method => MemberGroup::Method,
+          MethodKind::Getter | MethodKind::Setter => MemberGroup::Accessor,
+        });
+      }
+      interface_def
+        .properties
+        .sort_by(|a, b| compare(order, (a.group, &a.name), (b.group, &b.name)));
+      interface_def
+        .methods
+        .sort_by(|a, b| compare(order, (a.group, &a.name), (b.group, &b.name)));
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      order_members(&mut namespace_def.elements, order);
+    }
+  }
+}
+
+fn compare(
+  order: MemberOrder,
+  (a_group, a_name): (Option<MemberGroup>, &str),
+  (b_group, b_name): (Option<MemberGroup>, &str),
+) -> Ordering {
+  a_group.cmp(&b_group).then_with(|| match order {
+    MemberOrder::Source => Ordering::Equal,
+    MemberOrder::Alphabetical => a_name
+      .to_lowercase()
+      .cmp(&b_name.to_lowercase())
+      .then_with(|| a_name.cmp(b_name)),
+  })
+}
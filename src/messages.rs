@@ -0,0 +1,58 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The labels the printer emits around the documentation, which can be
+/// replaced to localize the output. Missing entries keep their English
+/// defaults when read from JSON with [`MessageCatalog::from_json`]:
+///
+/// ```json
+/// {
+///   "definedIn": "Definiert in",
+///   "tagLabels": { "deprecated": "veraltet", "example": "Beispiel" }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MessageCatalog {
+  /// Precedes the location of a node, as in `Defined in file:///mod.ts:1:0`.
+  pub defined_in: String,
+  /// Follows the signature of a method which returns the object it is called
+  /// on, as in `add(): this (chainable)`.
+  pub chainable: String,
+  /// Precedes the name of an event of a class or interface, as in
+  /// `event "data": Uint8Array`.
+  pub event: String,
+  /// Labels displayed instead of the names of JSDoc tags, keyed by the tag
+  /// name without the `@`.
+  pub tag_labels: BTreeMap<String, String>,
+}
+
+impl Default for MessageCatalog {
+  fn default() -> Self {
+    Self {
+      defined_in: "Defined in".to_string(),
+      chainable: "chainable".to_string(),
+      event: "event".to_string(),
+      tag_labels: BTreeMap::new(),
+    }
+  }
+}
+
+impl MessageCatalog {
+  pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+    serde_json::from_str(json)
+  }
+
+  /// The label of a JSDoc tag, which is the tag name unless it is replaced.
+  pub fn tag_label<'a>(&'a self, tag_name: &'a str) -> &'a str {
+    self
+      .tag_labels
+      .get(tag_name)
+      .map(|label| label.as_str())
+      .unwrap_or(tag_name)
+  }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::ts_type::TsTypeDef;
+
+/// How often the public API refers to types from another module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalTypeReference {
+  pub specifier: String,
+  pub count: usize,
+}
+
+/// Counts describing the size of the public API of a set of doc nodes.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiMetrics {
+  pub functions: usize,
+  pub variables: usize,
+  pub classes: usize,
+  pub enums: usize,
+  pub interfaces: usize,
+  pub type_aliases: usize,
+  pub namespaces: usize,
+  /// Properties, methods, constructors and call and index signatures of
+  /// classes and interfaces, and members of enums.
+  pub members: usize,
+  /// Overload signatures of functions and methods, not counting the
+  /// implementation signature.
+  pub overloads: usize,
+  /// Symbols and members tagged with `@deprecated`.
+  pub deprecated: usize,
+  /// References to types imported from other modules, sorted by specifier.
+  /// Types referred to through an import are keyed by the resolved specifier
+  /// of the import, while import types are keyed by their specifier as
+  /// written.
+  pub external_type_references: Vec<ExternalTypeReference>,
+}
+
+/// Computes metrics of the public API described by the doc nodes of a
+/// module. Private nodes, imports and module docs are not counted.
+pub fn api_metrics(doc_nodes: &[DocNode]) -> ApiMetrics {
+  let imports = doc_nodes
+    .iter()
+    .filter_map(|node| {
+      node
+        .import_def
+        .as_ref()
+        .map(|import_def| (node.name.as_str(), import_def.src.as_str()))
+    })
+    .collect::<HashMap<_, _>>();
+  let mut metrics = ApiMetrics::default();
+  let mut references = BTreeMap::new();
+  collect_metrics(&mut metrics, &mut references, &imports, doc_nodes);
+  metrics.external_type_references = references
+    .into_iter()
+    .map(|(specifier, count)| ExternalTypeReference { specifier, count })
+    .collect();
+  metrics
+}
+
+fn collect_metrics(
+  metrics: &mut ApiMetrics,
+  references: &mut BTreeMap<String, usize>,
+  imports: &HashMap<&str, &str>,
+  doc_nodes: &[DocNode],
+) {
+  for (i, doc_node) in doc_nodes.iter().enumerate() {
+    if matches!(
+      doc_node.kind,
+      DocNodeKind::ModuleDoc | DocNodeKind::Import | DocNodeKind::UmdNamespace
+    ) || doc_node.declaration_kind == DeclarationKind::Private
+    {
+      continue;
+    }
+    if doc_node.js_doc.is_deprecated() {
+      metrics.deprecated += 1;
+    }
+
+    // overloads and merged declarations are counted once
+    let is_first = !doc_nodes[..i]
+      .iter()
+      .any(|n| n.kind == doc_node.kind && n.name == doc_node.name);
+    if is_first {
+      match doc_node.kind {
+        DocNodeKind::Function => metrics.functions += 1,
+        DocNodeKind::Variable => metrics.variables += 1,
+        DocNodeKind::Class => metrics.classes += 1,
+        DocNodeKind::Enum => metrics.enums += 1,
+        DocNodeKind::Interface => metrics.interfaces += 1,
+        DocNodeKind::TypeAlias => metrics.type_aliases += 1,
+        DocNodeKind::Namespace => metrics.namespaces += 1,
+        DocNodeKind::ModuleDoc
+        | DocNodeKind::Import
+        | DocNodeKind::UmdNamespace => unreachable!(),
+      }
+    }
+
+    if let Some(function_def) = &doc_node.function_def {
+      let has_overloads = doc_nodes
+        .iter()
+        .filter(|n| n.kind == DocNodeKind::Function && n.name == doc_node.name)
+        .count()
+        > 1;
+      if has_overloads && !function_def.has_body {
+        metrics.overloads += 1;
+      }
+    }
+    if let Some(class_def) = &doc_node.class_def {
+      metrics.members += class_def.constructors.len()
+        + class_def.properties.len()
+        + class_def.index_signatures.len();
+      let mut methods = HashMap::new();
+      for method in &class_def.methods {
+        *methods
+          .entry((method.name.as_str(), method.is_static))
+          .or_insert(0) += 1;
+      }
+      metrics.members += methods.len();
+      metrics.overloads += class_def
+        .methods
+        .iter()
+        .filter(|method| {
+          !method.function_def.has_body
+            && methods[&(method.name.as_str(), method.is_static)] > 1
+        })
+        .count();
+      let members_js_doc = class_def
+        .constructors
+        .iter()
+        .map(|c| &c.js_doc)
+        .chain(class_def.properties.iter().map(|p| &p.js_doc))
+        .chain(class_def.methods.iter().map(|m| &m.js_doc));
+      metrics.deprecated +=
+        members_js_doc.filter(|d| d.is_deprecated()).count();
+    }
+    if let Some(interface_def) = &doc_node.interface_def {
+      metrics.members += interface_def.methods.len()
+        + interface_def.properties.len()
+        + interface_def.call_signatures.len()
+        + interface_def.index_signatures.len();
+      let members_js_doc = interface_def
+        .methods
+        .iter()
+        .map(|m| &m.js_doc)
+        .chain(interface_def.properties.iter().map(|p| &p.js_doc))
+        .chain(interface_def.call_signatures.iter().map(|c| &c.js_doc));
+      metrics.deprecated +=
+        members_js_doc.filter(|d| d.is_deprecated()).count();
+    }
+    if let Some(enum_def) = &doc_node.enum_def {
+      metrics.members += enum_def.members.len();
+      metrics.deprecated += enum_def
+        .members
+        .iter()
+        .filter(|m| m.js_doc.is_deprecated())
+        .count();
+    }
+
+    doc_node.for_each_ts_type(&mut |ts_type| {
+      collect_references(references, imports, ts_type)
+    });
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_metrics(metrics, references, imports, &namespace_def.elements);
+    }
+  }
+}
+
+fn collect_references(
+  references: &mut BTreeMap<String, usize>,
+  imports: &HashMap<&str, &str>,
+  ts_type: &TsTypeDef,
+) {
+  let specifier = if let Some(import_type) = &ts_type.import_type {
+    Some(import_type.specifier.as_str())
+  } else if let Some(type_ref) = &ts_type.type_ref {
+    let name = type_ref.type_name.split('.').next().unwrap();
+    imports.get(name).copied()
+  } else {
+    None
+  };
+  if let Some(specifier) = specifier {
+    *references.entry(specifier.to_string()).or_default() += 1;
+  }
+  ts_type.for_each_child(&mut |child| {
+    collect_references(references, imports, child)
+  });
+}
@@ -0,0 +1,106 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_graph::ModuleSpecifier;
+
+/// Which modules are documented, by glob patterns matched against their
+/// specifiers, like `file:///**/vendor/**` or `https://deno.land/x/*/mod.ts`.
+/// In a pattern, `*` matches any characters but `/`, `**` matches any
+/// characters, and `?` matches a single character but `/`.
+#[derive(Debug, Default, Clone)]
+pub struct ModuleFilter {
+  /// When not empty, only the modules which match one of the patterns are
+  /// documented.
+  pub include: Vec<String>,
+  /// The modules which match one of the patterns are left out, including
+  /// their exports which are reexported by documented modules.
+  pub exclude: Vec<String>,
+}
+
+impl ModuleFilter {
+  /// Whether the module is documented.
+  pub fn includes(&self, specifier: &ModuleSpecifier) -> bool {
+    let specifier = specifier.as_str().chars().collect::<Vec<_>>();
+    let matches = |pattern: &String| {
+      glob_match(&pattern.chars().collect::<Vec<_>>(), &specifier)
+    };
+    (self.include.is_empty() || self.include.iter().any(matches))
+      && !self.exclude.iter().any(matches)
+  }
+}
+
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+  match pattern.split_first() {
+    None => text.is_empty(),
+    Some(('*', ['*', rest @ ..])) => {
+      // `**/` also matches no directory at all
+      (rest.first() == Some(&'/') && glob_match(&rest[1..], text))
+        || (0..=text.len()).any(|i| glob_match(rest, &text[i..]))
+    }
+    Some(('*', rest)) => {
+      for i in 0..=text.len() {
+        if glob_match(rest, &text[i..]) {
+          return true;
+        }
+        if text.get(i) == Some(&'/') {
+          break;
+        }
+      }
+      false
+    }
+    Some(('?', rest)) => match text.split_first() {
+      Some((c, text)) => *c != '/' && glob_match(rest, text),
+      None => false,
+    },
+    Some((c, rest)) => match text.split_first() {
+      Some((t, text)) => c == t && glob_match(rest, text),
+      None => false,
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn filter(include: &[&str], exclude: &[&str]) -> ModuleFilter {
+    ModuleFilter {
+      include: include.iter().map(|p| p.to_string()).collect(),
+      exclude: exclude.iter().map(|p| p.to_string()).collect(),
+    }
+  }
+
+  fn specifier(specifier: &str) -> ModuleSpecifier {
+    ModuleSpecifier::parse(specifier).unwrap()
+  }
+
+  #[test]
+  fn test_module_filter() {
+    let all = ModuleFilter::default();
+    assert!(all.includes(&specifier("file:///mod.ts")));
+
+    let filter = filter(&["file:///**"], &["**/vendor/**", "**/_*.ts"]);
+    assert!(filter.includes(&specifier("file:///mod.ts")));
+    assert!(filter.includes(&specifier("file:///lib/util.ts")));
+    assert!(!filter.includes(&specifier("file:///vendor/dep.ts")));
+    assert!(!filter.includes(&specifier("file:///lib/vendor/a/dep.ts")));
+    assert!(!filter.includes(&specifier("file:///lib/_internal.ts")));
+    assert!(!filter.includes(&specifier("https://deno.land/x/mod.ts")));
+  }
+
+  #[test]
+  fn test_glob_match() {
+    let matches = |pattern: &str, text: &str| {
+      glob_match(
+        &pattern.chars().collect::<Vec<_>>(),
+        &text.chars().collect::<Vec<_>>(),
+      )
+    };
+    assert!(matches("file:///*.ts", "file:///mod.ts"));
+    assert!(!matches("file:///*.ts", "file:///lib/mod.ts"));
+    assert!(matches("file:///**/*.ts", "file:///mod.ts"));
+    assert!(matches("file:///**/*.ts", "file:///lib/a/mod.ts"));
+    assert!(matches("file:///mod.?s", "file:///mod.js"));
+    assert!(!matches("file:///mod?ts", "file:///mod/ts"));
+    assert!(!matches("file:///mod.ts", "file:///mod.tsx"));
+  }
+}
@@ -0,0 +1,173 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+//! Node-API bindings, exposing the same `doc()` function as the Web Assembly
+//! build to Node.js natively. Each call runs on its own thread, so several
+//! graphs can be documented in parallel.
+
+use crate::parser::DocParser;
+
+use anyhow::anyhow;
+use deno_graph::source::CacheSetting;
+use deno_graph::source::LoadFuture;
+use deno_graph::source::LoadResponse;
+use deno_graph::source::Loader;
+use deno_graph::source::ResolveError;
+use deno_graph::source::Resolver;
+use deno_graph::BuildOptions;
+use deno_graph::CapturingModuleAnalyzer;
+use deno_graph::GraphKind;
+use deno_graph::ModuleGraph;
+use deno_graph::ModuleSpecifier;
+use import_map::ImportMap;
+use napi::bindgen_prelude::Promise;
+use napi::threadsafe_function::ErrorStrategy;
+use napi::threadsafe_function::ThreadSafeCallContext;
+use napi::threadsafe_function::ThreadsafeFunction;
+use napi::Env;
+use napi::JsFunction;
+use napi::JsObject;
+use napi::JsUnknown;
+use napi_derive::napi;
+
+type LoadFunction =
+  ThreadsafeFunction<(String, bool, String), ErrorStrategy::Fatal>;
+
+struct NativeLoader {
+  load: LoadFunction,
+}
+
+impl Loader for NativeLoader {
+  fn load(
+    &mut self,
+    specifier: &ModuleSpecifier,
+    is_dynamic: bool,
+    cache_setting: CacheSetting,
+  ) -> LoadFuture {
+    let load = self.load.clone();
+    let args = (
+      specifier.to_string(),
+      is_dynamic,
+      cache_setting.as_js_str().to_string(),
+    );
+    let f = async move {
+      let response: Promise<serde_json::Value> = load
+        .call_async(args)
+        .await
+        .map_err(|_| anyhow!("load rejected or errored"))?;
+      let value = response
+        .await
+        .map_err(|_| anyhow!("load rejected or errored"))?;
+      Ok(serde_json::from_value(value)?)
+    };
+    Box::pin(f)
+  }
+}
+
+#[derive(Debug)]
+struct ImportMapResolver(ImportMap);
+
+impl Resolver for ImportMapResolver {
+  fn resolve(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+    _mode: deno_graph::source::ResolutionMode,
+  ) -> Result<ModuleSpecifier, ResolveError> {
+    self
+      .0
+      .resolve(specifier, referrer)
+      .map_err(|err| ResolveError::Other(err.into()))
+  }
+}
+
+/// Documents the module at `root_specifier`, resolving with an array of doc
+/// nodes. Unlike the Web Assembly build, a custom resolve function is not
+/// supported, as it could not be called synchronously from the documenting
+/// thread.
+#[napi(
+  ts_args_type = "rootSpecifier: string, includeAll: boolean, load: (specifier: string, isDynamic: boolean, cacheSetting: string) => Promise<LoadResponse | undefined>, maybeImportMap?: string",
+  ts_return_type = "Promise<DocNode[]>"
+)]
+pub fn doc(
+  env: Env,
+  root_specifier: String,
+  include_all: bool,
+  load: JsFunction,
+  maybe_import_map: Option<String>,
+) -> napi::Result<JsObject> {
+  let load: LoadFunction = load.create_threadsafe_function(
+    0,
+    |ctx: ThreadSafeCallContext<(String, bool, String)>| {
+      let (specifier, is_dynamic, cache_setting) = ctx.value;
+      Ok(vec![
+        ctx.env.create_string(&specifier)?.into_unknown(),
+        ctx.env.get_boolean(is_dynamic)?.into_unknown(),
+        ctx.env.create_string(&cache_setting)?.into_unknown(),
+      ])
+    },
+  )?;
+  let (deferred, promise) = env.create_deferred::<JsUnknown, _>()?;
+  std::thread::spawn(move || {
+    let result = futures::executor::block_on(inner_doc(
+      root_specifier,
+      include_all,
+      load,
+      maybe_import_map,
+    ));
+    match result {
+      Ok(value) => deferred.resolve(move |env| env.to_js_value(&value)),
+      Err(err) => deferred.reject(napi::Error::from_reason(err.to_string())),
+    }
+  });
+  Ok(promise)
+}
+
+async fn inner_doc(
+  root_specifier: String,
+  include_all: bool,
+  load: LoadFunction,
+  maybe_import_map: Option<String>,
+) -> Result<serde_json::Value, anyhow::Error> {
+  let root_specifier = ModuleSpecifier::parse(&root_specifier)?;
+  let mut loader = NativeLoader { load };
+  let maybe_resolver = if let Some(import_map) = maybe_import_map {
+    let import_map_specifier = ModuleSpecifier::parse(&import_map)?;
+    if let Some(LoadResponse::Module {
+      content, specifier, ..
+    }) = loader
+      .load(&import_map_specifier, false, CacheSetting::Use)
+      .await?
+    {
+      let result = import_map::parse_from_json(&specifier, content.as_ref())?;
+      Some(ImportMapResolver(result.import_map))
+    } else {
+      None
+    }
+  } else {
+    None
+  };
+  let analyzer = CapturingModuleAnalyzer::default();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  trace_instrument!(
+    graph.build(
+      vec![root_specifier.clone()],
+      &mut loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        resolver: maybe_resolver.as_ref().map(|r| r as &dyn Resolver),
+        ..Default::default()
+      },
+    ),
+    INFO,
+    "build_graph",
+    root = %root_specifier
+  )
+  .await;
+  let entries = {
+    trace_span!(INFO, "parse", root = %root_specifier);
+    DocParser::new(&graph, include_all, analyzer.as_capturing_parser())?
+      .parse_with_reexports(&root_specifier)?
+  };
+  trace_span!(INFO, "serialize");
+  Ok(serde_json::to_value(entries)?)
+}
@@ -1,16 +1,38 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::decorators::DecoratorDef;
+use crate::function::FunctionDef;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::params::document_params;
+use crate::params::ParamDef;
+use crate::swc_util::is_false;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type_param::TsTypeParamDef;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct NamespaceDef {
   pub elements: Vec<DocNode>,
+  /// Whether this is an ambient module declared with a module name or a
+  /// wildcard pattern, like `declare module "*.css" { }`, which is kept as
+  /// the name of the node.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub ambient_module: bool,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[derive(
+  Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Clone,
+)]
 #[serde(rename_all = "camelCase")]
 pub enum DocNodeKind {
   ModuleDoc,
@@ -22,9 +44,11 @@ pub enum DocNodeKind {
   TypeAlias,
   Namespace,
   Import,
+  UmdNamespace,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
 pub struct Location {
   pub filename: String,
   /// The 1-indexed display line.
@@ -32,6 +56,10 @@ pub struct Location {
   pub line: usize,
   /// The 0-indexed display column.
   pub col: usize,
+  /// The specifier the module was originally requested as, when it was
+  /// redirected to `filename`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub requested_filename: Option<String>,
 }
 
 impl Ord for Location {
@@ -77,13 +105,102 @@ pub struct Reexport {
 pub struct ModuleDoc {
   pub definitions: Vec<DocNode>,
   pub reexports: Vec<Reexport>,
+  /// Types declared in other modules which are referenced by the
+  /// definitions, but not exported by this module.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub referenced_types: Vec<ReferencedType>,
+  /// The modules which are only imported dynamically, with `import()`.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub dynamic_imports: Vec<DynamicImport>,
+}
+
+/// A type declared in another module which is referenced by the definitions
+/// of a module, as given by the import it is referred to through.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferencedType {
+  /// The resolved specifier of the module the type is imported from, or for
+  /// a type which is private to that module, of the module declaring it.
+  pub src: String,
+  /// The name of the export of the module, or for a type which is private to
+  /// it, the local name of its declaration.
+  pub name: String,
+  /// The other referenced types which the type refers to through the imports
+  /// of its module, by the name it refers to them with, as their `src` and
+  /// `name`.
+  #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+  pub imports: BTreeMap<String, (String, String)>,
+  pub doc_node: DocNode,
+}
+
+/// A module imported with a statically analyzable `import()` expression.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicImport {
+  /// The resolved specifier of the module.
+  pub src: String,
+  /// The exports of the module.
+  pub definitions: Vec<DocNode>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportDef {
+  /// The specifier of the imported module, after any redirects.
   pub src: String,
   pub imported: Option<String>,
+  /// The specifier the module was requested as, when it was redirected to
+  /// `src`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub requested_src: Option<String>,
+  /// Whether this is a namespace import, like `import * as ns`, which can
+  /// be expanded into the exports of the imported module with
+  /// [`crate::DocParser::expand_namespace_import`].
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub namespace: bool,
+  /// Whether this is a CommonJS import, like `import fs = require("fs")`,
+  /// where `src` is the specifier as written when it can't be resolved.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub require: bool,
+}
+
+/// How stable the API of a node is, as given by its tags.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Stability {
+  /// Tagged with `@internal`: not meant to be used outside of the package.
+  Internal,
+  /// Tagged with `@experimental`: may change without a major release.
+  Experimental,
+}
+
+/// The TSDoc release tag of a node, from the least to the most released.
+#[derive(
+  Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ReleaseTag {
+  /// `@internal`
+  Internal,
+  /// `@alpha`
+  Alpha,
+  /// `@beta`
+  Beta,
+  /// `@public`
+  Public,
+}
+
+impl ReleaseTag {
+  /// The release tag the JSDoc is tagged with, if any.
+  pub fn from_js_doc(js_doc: &JsDoc) -> Option<Self> {
+    js_doc.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Internal => Some(Self::Internal),
+      JsDocTag::Alpha => Some(Self::Alpha),
+      JsDocTag::Beta => Some(Self::Beta),
+      JsDocTag::Public => Some(Self::Public),
+      _ => None,
+    })
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
@@ -94,15 +211,108 @@ pub enum DeclarationKind {
   Export,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LeadingCommentKind {
+  /// A `// ...` comment.
+  Line,
+  /// A `/* ... */` comment, which includes JSDoc comments.
+  Block,
+}
+
+/// A comment preceding the declaration of a node, as written, when requested
+/// with [`crate::DocParserOptions::leading_comments`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeadingComment {
+  pub kind: LeadingCommentKind,
+  /// The text between the delimiters of the comment, so for a JSDoc comment
+  /// it starts with the second `*` of `/**`.
+  pub text: String,
+  /// Where the comment starts.
+  pub location: Location,
+  /// Where the comment ends, after its delimiter.
+  pub end: Location,
+}
+
+/// Metadata of a module given by the headers it was served with, when
+/// provided with [`crate::DocParserOptions::module_headers`].
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleDocDef {
+  /// The warning of the registry which hosts the module, from the
+  /// `x-deno-warning` header.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub warning: Option<String>,
+  /// The `x-doc-*` headers, by their name without the prefix, like `owner`
+  /// for `x-doc-owner`.
+  #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+  pub metadata: BTreeMap<String, String>,
+}
+
+impl ModuleDocDef {
+  /// Gets the metadata from the headers of a module, or `None` when there is
+  /// none. Header names are matched case-insensitively.
+  pub fn from_headers(headers: &HashMap<String, String>) -> Option<Self> {
+    let mut module_doc_def = ModuleDocDef::default();
+    for (name, value) in headers {
+      let name = name.to_lowercase();
+      if name == "x-deno-warning" {
+        module_doc_def.warning = Some(value.clone());
+      } else if let Some(key) = name.strip_prefix("x-doc-") {
+        module_doc_def
+          .metadata
+          .insert(key.to_string(), value.clone());
+      }
+    }
+    if module_doc_def == ModuleDocDef::default() {
+      None
+    } else {
+      Some(module_doc_def)
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DocNode {
   pub kind: DocNodeKind,
   pub name: String,
+  /// The dotted path of the node, for example `Deno.Conn`, when requested
+  /// with [`crate::DocParserOptions::qualified_names`].
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub qualified_name: Option<String>,
   pub location: Location,
   pub declaration_kind: DeclarationKind,
-  #[serde(skip_serializing_if = "JsDoc::is_empty")]
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  /// The documentation which applies to the node, combining its own
+  /// documentation with inherited documentation, when requested with
+  /// [`crate::DocParserOptions::effective_js_doc`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub effective_js_doc: Option<JsDoc>,
+  /// The stability of the node, when requested with
+  /// [`crate::DocParserOptions::stability`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub stability: Option<Stability>,
+  /// The TSDoc release tag of the node, or else of its namespace, when
+  /// requested with [`crate::DocParserOptions::release_tags`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub release_tag: Option<ReleaseTag>,
+  /// The modules imported by the module of the node which its signature
+  /// refers to, when requested with
+  /// [`crate::DocParserOptions::dependencies`].
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub dependencies: Vec<String>,
+  /// The comments preceding the declaration of the node, when requested with
+  /// [`crate::DocParserOptions::leading_comments`].
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub leading_comments: Vec<LeadingComment>,
+  /// Whether the declaration of the node is marked `@ignore`, as such nodes
+  /// are kept when requested with
+  /// [`crate::DocParserOptions::hidden_ignored`].
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub hidden: bool,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub function_def: Option<super::function::FunctionDef>,
@@ -127,6 +337,9 @@ pub struct DocNode {
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub import_def: Option<ImportDef>,
+
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub module_doc_def: Option<ModuleDocDef>,
 }
 
 impl Default for DocNode {
@@ -134,13 +347,21 @@ impl Default for DocNode {
     Self {
       kind: DocNodeKind::ModuleDoc,
       name: "".to_string(),
+      qualified_name: None,
       declaration_kind: DeclarationKind::Private,
       location: Location {
         filename: "".to_string(),
         line: 0,
         col: 0,
+        requested_filename: None,
       },
       js_doc: JsDoc::default(),
+      effective_js_doc: None,
+      stability: None,
+      release_tag: None,
+      dependencies: Vec::new(),
+      leading_comments: Vec::new(),
+      hidden: false,
       function_def: None,
       variable_def: None,
       enum_def: None,
@@ -149,6 +370,7 @@ impl Default for DocNode {
       namespace_def: None,
       interface_def: None,
       import_def: None,
+      module_doc_def: None,
     }
   }
 }
@@ -307,4 +529,711 @@ impl DocNode {
       ..Default::default()
     }
   }
+
+  /// The global name of a UMD module, declared with
+  /// `export as namespace Name`.
+  pub fn umd_namespace(
+    name: String,
+    location: Location,
+    js_doc: JsDoc,
+  ) -> Self {
+    Self {
+      kind: DocNodeKind::UmdNamespace,
+      name,
+      location,
+      declaration_kind: DeclarationKind::Export,
+      js_doc,
+      ..Default::default()
+    }
+  }
+
+  /// Calls `f` with the JSDoc of this node and of its members, including
+  /// their effective JSDoc, but not with those of the elements of a
+  /// namespace.
+  pub(crate) fn for_each_js_doc_mut(&mut self, f: &mut dyn FnMut(&mut JsDoc)) {
+    f(&mut self.js_doc);
+    self.effective_js_doc.iter_mut().for_each(&mut *f);
+    if let Some(class_def) = &mut self.class_def {
+      for constructor in &mut class_def.constructors {
+        f(&mut constructor.js_doc);
+      }
+      for property in &mut class_def.properties {
+        f(&mut property.js_doc);
+        property.effective_js_doc.iter_mut().for_each(&mut *f);
+      }
+      for method in &mut class_def.methods {
+        f(&mut method.js_doc);
+        method.effective_js_doc.iter_mut().for_each(&mut *f);
+      }
+      for index_signature in &mut class_def.index_signatures {
+        f(&mut index_signature.js_doc);
+      }
+    }
+    if let Some(interface_def) = &mut self.interface_def {
+      for property in &mut interface_def.properties {
+        f(&mut property.js_doc);
+        property.effective_js_doc.iter_mut().for_each(&mut *f);
+      }
+      for method in &mut interface_def.methods {
+        f(&mut method.js_doc);
+        method.effective_js_doc.iter_mut().for_each(&mut *f);
+      }
+      for call_signature in &mut interface_def.call_signatures {
+        f(&mut call_signature.js_doc);
+      }
+      for index_signature in &mut interface_def.index_signatures {
+        f(&mut index_signature.js_doc);
+      }
+    }
+    if let Some(enum_def) = &mut self.enum_def {
+      for member in &mut enum_def.members {
+        f(&mut member.js_doc);
+      }
+    }
+  }
+
+  /// Calls `f` with the JSDoc of this node and of its members, like
+  /// [`DocNode::for_each_js_doc_mut`], and also with the JSDoc of the members
+  /// of the type literals in its types.
+  pub(crate) fn for_each_nested_js_doc_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut JsDoc),
+  ) {
+    fn type_literals(ts_type: &mut TsTypeDef, f: &mut dyn FnMut(&mut JsDoc)) {
+      if let Some(type_literal) = &mut ts_type.type_literal {
+        for method in &mut type_literal.methods {
+          f(&mut method.js_doc);
+        }
+        for property in &mut type_literal.properties {
+          f(&mut property.js_doc);
+        }
+        for index_signature in &mut type_literal.index_signatures {
+          f(&mut index_signature.js_doc);
+        }
+      }
+      ts_type.for_each_child_mut(&mut |child| type_literals(child, &mut *f));
+    }
+
+    self.for_each_js_doc_mut(f);
+    self.for_each_ts_type_mut(&mut |ts_type| type_literals(ts_type, &mut *f));
+  }
+
+  /// Calls `f` with the params of the functions, methods, constructors and
+  /// signatures of this node, but not with those of the elements of a
+  /// namespace.
+  pub(crate) fn for_each_param_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut ParamDef),
+  ) {
+    if let Some(function_def) = &mut self.function_def {
+      function_def.params.iter_mut().for_each(&mut *f);
+    }
+    if let Some(class_def) = &mut self.class_def {
+      for constructor in &mut class_def.constructors {
+        for param in &mut constructor.params {
+          f(&mut param.param);
+        }
+      }
+      for index_signature in &mut class_def.index_signatures {
+        index_signature.params.iter_mut().for_each(&mut *f);
+      }
+      for method in &mut class_def.methods {
+        method.function_def.params.iter_mut().for_each(&mut *f);
+      }
+    }
+    if let Some(interface_def) = &mut self.interface_def {
+      for method in &mut interface_def.methods {
+        method.params.iter_mut().for_each(&mut *f);
+      }
+      for property in &mut interface_def.properties {
+        property.params.iter_mut().for_each(&mut *f);
+      }
+      for call_signature in &mut interface_def.call_signatures {
+        call_signature.params.iter_mut().for_each(&mut *f);
+      }
+      for index_signature in &mut interface_def.index_signatures {
+        index_signature.params.iter_mut().for_each(&mut *f);
+      }
+    }
+  }
+
+  /// Calls `f` with each type annotation of this node and its members, but
+  /// not with the types nested in those, nor with the types of the elements
+  /// of a namespace.
+  pub(crate) fn for_each_ts_type_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut TsTypeDef),
+  ) {
+    fn type_params(
+      type_params: &mut [TsTypeParamDef],
+      f: &mut dyn FnMut(&mut TsTypeDef),
+    ) {
+      for type_param in type_params {
+        type_param.for_each_ts_type_mut(f);
+      }
+    }
+
+    fn params(params: &mut [ParamDef], f: &mut dyn FnMut(&mut TsTypeDef)) {
+      for param in params {
+        param.for_each_ts_type_mut(f);
+      }
+    }
+
+    fn function(
+      function_def: &mut FunctionDef,
+      f: &mut dyn FnMut(&mut TsTypeDef),
+    ) {
+      params(&mut function_def.params, f);
+      if let Some(return_type) = &mut function_def.return_type {
+        f(return_type);
+      }
+      type_params(&mut function_def.type_params, f);
+    }
+
+    if let Some(function_def) = &mut self.function_def {
+      function(function_def, f);
+    }
+    if let Some(variable_def) = &mut self.variable_def {
+      if let Some(ts_type) = &mut variable_def.ts_type {
+        f(ts_type);
+      }
+    }
+    if let Some(enum_def) = &mut self.enum_def {
+      for member in &mut enum_def.members {
+        if let Some(init) = &mut member.init {
+          f(init);
+        }
+      }
+    }
+    if let Some(class_def) = &mut self.class_def {
+      class_def.implements.iter_mut().for_each(&mut *f);
+      class_def.super_type_params.iter_mut().for_each(&mut *f);
+      type_params(&mut class_def.type_params, f);
+      for constructor in &mut class_def.constructors {
+        for param in &mut constructor.params {
+          param.param.for_each_ts_type_mut(f);
+        }
+      }
+      for property in &mut class_def.properties {
+        if let Some(ts_type) = &mut property.ts_type {
+          f(ts_type);
+        }
+      }
+      for index_signature in &mut class_def.index_signatures {
+        params(&mut index_signature.params, f);
+        if let Some(ts_type) = &mut index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+      for method in &mut class_def.methods {
+        function(&mut method.function_def, f);
+      }
+    }
+    if let Some(type_alias_def) = &mut self.type_alias_def {
+      f(&mut type_alias_def.ts_type);
+      type_params(&mut type_alias_def.type_params, f);
+    }
+    if let Some(interface_def) = &mut self.interface_def {
+      interface_def.extends.iter_mut().for_each(&mut *f);
+      type_params(&mut interface_def.type_params, f);
+      for method in &mut interface_def.methods {
+        params(&mut method.params, f);
+        if let Some(return_type) = &mut method.return_type {
+          f(return_type);
+        }
+        type_params(&mut method.type_params, f);
+      }
+      for property in &mut interface_def.properties {
+        params(&mut property.params, f);
+        if let Some(ts_type) = &mut property.ts_type {
+          f(ts_type);
+        }
+        type_params(&mut property.type_params, f);
+      }
+      for call_signature in &mut interface_def.call_signatures {
+        params(&mut call_signature.params, f);
+        if let Some(ts_type) = &mut call_signature.ts_type {
+          f(ts_type);
+        }
+        type_params(&mut call_signature.type_params, f);
+      }
+      for index_signature in &mut interface_def.index_signatures {
+        params(&mut index_signature.params, f);
+        if let Some(ts_type) = &mut index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+    }
+  }
+
+  /// Like [`Self::for_each_ts_type_mut`], but with shared references.
+  pub(crate) fn for_each_ts_type(&self, f: &mut dyn FnMut(&TsTypeDef)) {
+    fn type_params(
+      type_params: &[TsTypeParamDef],
+      f: &mut dyn FnMut(&TsTypeDef),
+    ) {
+      for type_param in type_params {
+        type_param.for_each_ts_type(f);
+      }
+    }
+
+    fn params(params: &[ParamDef], f: &mut dyn FnMut(&TsTypeDef)) {
+      for param in params {
+        param.for_each_ts_type(f);
+      }
+    }
+
+    fn function(function_def: &FunctionDef, f: &mut dyn FnMut(&TsTypeDef)) {
+      params(&function_def.params, f);
+      if let Some(return_type) = &function_def.return_type {
+        f(return_type);
+      }
+      type_params(&function_def.type_params, f);
+    }
+
+    if let Some(function_def) = &self.function_def {
+      function(function_def, f);
+    }
+    if let Some(variable_def) = &self.variable_def {
+      if let Some(ts_type) = &variable_def.ts_type {
+        f(ts_type);
+      }
+    }
+    if let Some(enum_def) = &self.enum_def {
+      for member in &enum_def.members {
+        if let Some(init) = &member.init {
+          f(init);
+        }
+      }
+    }
+    if let Some(class_def) = &self.class_def {
+      class_def.implements.iter().for_each(&mut *f);
+      class_def.super_type_params.iter().for_each(&mut *f);
+      type_params(&class_def.type_params, f);
+      for constructor in &class_def.constructors {
+        for param in &constructor.params {
+          param.param.for_each_ts_type(f);
+        }
+      }
+      for property in &class_def.properties {
+        if let Some(ts_type) = &property.ts_type {
+          f(ts_type);
+        }
+      }
+      for index_signature in &class_def.index_signatures {
+        params(&index_signature.params, f);
+        if let Some(ts_type) = &index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+      for method in &class_def.methods {
+        function(&method.function_def, f);
+      }
+    }
+    if let Some(type_alias_def) = &self.type_alias_def {
+      f(&type_alias_def.ts_type);
+      type_params(&type_alias_def.type_params, f);
+    }
+    if let Some(interface_def) = &self.interface_def {
+      interface_def.extends.iter().for_each(&mut *f);
+      type_params(&interface_def.type_params, f);
+      for method in &interface_def.methods {
+        params(&method.params, f);
+        if let Some(return_type) = &method.return_type {
+          f(return_type);
+        }
+        type_params(&method.type_params, f);
+      }
+      for property in &interface_def.properties {
+        params(&property.params, f);
+        if let Some(ts_type) = &property.ts_type {
+          f(ts_type);
+        }
+        type_params(&property.type_params, f);
+      }
+      for call_signature in &interface_def.call_signatures {
+        params(&call_signature.params, f);
+        if let Some(ts_type) = &call_signature.ts_type {
+          f(ts_type);
+        }
+        type_params(&call_signature.type_params, f);
+      }
+      for index_signature in &interface_def.index_signatures {
+        params(&index_signature.params, f);
+        if let Some(ts_type) = &index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+    }
+  }
+
+  /// Calls `f` with the location of this node, of its members and their
+  /// decorators, and of the elements of a namespace.
+  pub(crate) fn for_each_location_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut Location),
+  ) {
+    fn decorators(
+      decorators: &mut [DecoratorDef],
+      f: &mut dyn FnMut(&mut Location),
+    ) {
+      for decorator in decorators {
+        f(&mut decorator.location);
+      }
+    }
+
+    fn params(params: &mut [ParamDef], f: &mut dyn FnMut(&mut Location)) {
+      for param in params {
+        decorators(param.decorators_mut(), f);
+      }
+    }
+
+    f(&mut self.location);
+    for comment in &mut self.leading_comments {
+      f(&mut comment.location);
+      f(&mut comment.end);
+    }
+    if let Some(function_def) = &mut self.function_def {
+      decorators(&mut function_def.decorators, f);
+      params(&mut function_def.params, f);
+    }
+    if let Some(enum_def) = &mut self.enum_def {
+      for member in &mut enum_def.members {
+        f(&mut member.location);
+      }
+    }
+    if let Some(class_def) = &mut self.class_def {
+      decorators(&mut class_def.decorators, f);
+      for constructor in &mut class_def.constructors {
+        f(&mut constructor.location);
+        for param in &mut constructor.params {
+          decorators(param.param.decorators_mut(), f);
+        }
+      }
+      for property in &mut class_def.properties {
+        f(&mut property.location);
+        decorators(&mut property.decorators, f);
+      }
+      for method in &mut class_def.methods {
+        f(&mut method.location);
+        decorators(&mut method.function_def.decorators, f);
+        params(&mut method.function_def.params, f);
+      }
+    }
+    if let Some(interface_def) = &mut self.interface_def {
+      for method in &mut interface_def.methods {
+        f(&mut method.location);
+      }
+      for property in &mut interface_def.properties {
+        f(&mut property.location);
+      }
+      for call_signature in &mut interface_def.call_signatures {
+        f(&mut call_signature.location);
+      }
+    }
+    self.for_each_nested_js_doc_mut(&mut |js_doc| {
+      if let Some(raw) = &mut js_doc.raw {
+        f(&mut raw.location);
+        f(&mut raw.end);
+      }
+    });
+    if let Some(namespace_def) = &mut self.namespace_def {
+      for element in &mut namespace_def.elements {
+        element.for_each_location_mut(f);
+      }
+    }
+  }
+}
+
+/// Sets the `qualified_name` of the doc nodes, the elements of namespaces and
+/// the members of classes, interfaces and enums.
+pub(crate) fn set_qualified_names(
+  doc_nodes: &mut [DocNode],
+  namespace: Option<&str>,
+) {
+  for doc_node in doc_nodes {
+    let qualified_name = match namespace {
+      Some(namespace) => format!("{}.{}", namespace, doc_node.name),
+      None => doc_node.name.clone(),
+    };
+    let member_name = |name: &str| Some(format!("{}.{}", qualified_name, name));
+    if let Some(class_def) = &mut doc_node.class_def {
+      for constructor in &mut class_def.constructors {
+        constructor.qualified_name = member_name(&constructor.name);
+      }
+      for property in &mut class_def.properties {
+        property.qualified_name = member_name(&property.name);
+      }
+      for method in &mut class_def.methods {
+        method.qualified_name = member_name(&method.name);
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for property in &mut interface_def.properties {
+        property.qualified_name = member_name(&property.name);
+      }
+      for method in &mut interface_def.methods {
+        method.qualified_name = member_name(&method.name);
+      }
+    }
+    if let Some(enum_def) = &mut doc_node.enum_def {
+      for member in &mut enum_def.members {
+        member.qualified_name = member_name(&member.name);
+      }
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_qualified_names(&mut namespace_def.elements, Some(&qualified_name));
+    }
+    doc_node.qualified_name = Some(qualified_name);
+  }
+}
+
+/// Sets the `stability` of the doc nodes and the elements of namespaces which
+/// are tagged with `@internal` or `@experimental`, preferring `@internal`.
+pub(crate) fn set_stability(doc_nodes: &mut [DocNode]) {
+  for doc_node in doc_nodes {
+    let tags = &doc_node.js_doc.tags;
+    doc_node.stability = if tags.contains(&JsDocTag::Internal) {
+      Some(Stability::Internal)
+    } else if tags.contains(&JsDocTag::Experimental) {
+      Some(Stability::Experimental)
+    } else {
+      None
+    };
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_stability(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Sets the `release_tag` of the doc nodes and the elements of namespaces,
+/// where untagged elements inherit the release tag of their namespace, and
+/// removes the nodes released below the minimum. Untagged nodes are
+/// considered public.
+pub(crate) fn set_release_tags(
+  doc_nodes: &mut Vec<DocNode>,
+  parent: Option<ReleaseTag>,
+  min_release_tag: Option<ReleaseTag>,
+) {
+  for doc_node in doc_nodes.iter_mut() {
+    doc_node.release_tag = ReleaseTag::from_js_doc(&doc_node.js_doc).or(parent);
+  }
+  if let Some(min_release_tag) = min_release_tag {
+    doc_nodes.retain(|doc_node| {
+      doc_node.release_tag.unwrap_or(ReleaseTag::Public) >= min_release_tag
+    });
+  }
+  for doc_node in doc_nodes {
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_release_tags(
+        &mut namespace_def.elements,
+        doc_node.release_tag,
+        min_release_tag,
+      );
+    }
+  }
+}
+
+/// Sets the `doc` of the params of functions, methods, constructors and call
+/// signatures from their `@param` tags, including the elements of
+/// namespaces. The effective documentation is used when it is set.
+pub(crate) fn set_param_docs(doc_nodes: &mut [DocNode]) {
+  for doc_node in doc_nodes {
+    let js_doc = doc_node
+      .effective_js_doc
+      .as_ref()
+      .unwrap_or(&doc_node.js_doc);
+    if let Some(function_def) = &mut doc_node.function_def {
+      document_params(&mut function_def.params, js_doc);
+    }
+    if let Some(class_def) = &mut doc_node.class_def {
+      for constructor in &mut class_def.constructors {
+        document_params(
+          constructor.params.iter_mut().map(|param| &mut param.param),
+          &constructor.js_doc,
+        );
+      }
+      for method in &mut class_def.methods {
+        document_params(
+          &mut method.function_def.params,
+          method.effective_js_doc.as_ref().unwrap_or(&method.js_doc),
+        );
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for method in &mut interface_def.methods {
+        document_params(
+          &mut method.params,
+          method.effective_js_doc.as_ref().unwrap_or(&method.js_doc),
+        );
+      }
+      for call_signature in &mut interface_def.call_signatures {
+        document_params(&mut call_signature.params, &call_signature.js_doc);
+      }
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_param_docs(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Sets the `doc_segments` of the JSDoc of the doc nodes, their members and
+/// the elements of namespaces.
+pub(crate) fn set_doc_segments(doc_nodes: &mut [DocNode]) {
+  for doc_node in doc_nodes {
+    doc_node.for_each_js_doc_mut(&mut |js_doc| js_doc.set_doc_segments());
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_doc_segments(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Sets `chainable` on the methods of the classes and interfaces of the doc
+/// nodes and the elements of namespaces which return `this` or the class or
+/// interface itself, like the methods of a builder.
+pub(crate) fn set_chainable_methods(doc_nodes: &mut [DocNode]) {
+  fn returns_self(return_type: Option<&TsTypeDef>, name: &str) -> bool {
+    let Some(return_type) = return_type else {
+      return false;
+    };
+    match return_type.kind {
+      Some(TsTypeDefKind::This) => true,
+      Some(TsTypeDefKind::TypeRef) => return_type
+        .type_ref
+        .as_ref()
+        .is_some_and(|type_ref| type_ref.type_name == name),
+      _ => false,
+    }
+  }
+
+  for doc_node in doc_nodes {
+    if let Some(class_def) = &mut doc_node.class_def {
+      for method in &mut class_def.methods {
+        method.chainable = !method.is_static
+          && returns_self(
+            method.function_def.return_type.as_ref(),
+            &doc_node.name,
+          );
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for method in &mut interface_def.methods {
+        method.chainable =
+          returns_self(method.return_type.as_ref(), &doc_node.name);
+      }
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_chainable_methods(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Gives the overloads of functions, methods and constructors of the doc
+/// nodes and the elements of namespaces which are not documented the
+/// documentation of their implementation, or else of their first documented
+/// overload.
+pub(crate) fn share_overload_js_docs(doc_nodes: &mut [DocNode]) {
+  fill_overload_js_docs(
+    doc_nodes
+      .iter_mut()
+      .filter_map(|doc_node| {
+        let has_body = doc_node.function_def.as_ref()?.has_body;
+        Some((doc_node.name.clone(), has_body, &mut doc_node.js_doc))
+      })
+      .collect(),
+  );
+
+  for doc_node in doc_nodes {
+    if let Some(class_def) = &mut doc_node.class_def {
+      fill_overload_js_docs(
+        class_def
+          .constructors
+          .iter_mut()
+          .map(|c| ((), c.has_body, &mut c.js_doc))
+          .collect(),
+      );
+      fill_overload_js_docs(
+        class_def
+          .methods
+          .iter_mut()
+          .map(|m| {
+            let key = (m.name.clone(), m.is_static);
+            (key, m.function_def.has_body, &mut m.js_doc)
+          })
+          .collect(),
+      );
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      fill_overload_js_docs(
+        interface_def
+          .methods
+          .iter_mut()
+          .map(|m| (m.name.clone(), false, &mut m.js_doc))
+          .collect(),
+      );
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      share_overload_js_docs(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Sets the documentation of the overloads which have none, given with their
+/// key and whether they have a body, to the one of the overloads with the
+/// same key which have a body, or else to the first one which has one.
+fn fill_overload_js_docs<K: Eq + Hash + Clone>(
+  overloads: Vec<(K, bool, &mut JsDoc)>,
+) {
+  let mut js_docs = HashMap::new();
+  for (key, has_body, js_doc) in &overloads {
+    if !js_doc.is_empty() && (*has_body || !js_docs.contains_key(key)) {
+      js_docs.insert(key.clone(), JsDoc::clone(js_doc));
+    }
+  }
+  for (key, _, js_doc) in overloads {
+    if js_doc.is_empty() {
+      if let Some(fallback) = js_docs.get(&key) {
+        *js_doc = fallback.clone();
+      }
+    }
+  }
+}
+
+/// Removes the implementation signatures of overloaded functions, methods
+/// and constructors, which are the declarations with a body when there are
+/// declarations without one, from the doc nodes and the elements of
+/// namespaces.
+pub(crate) fn remove_overload_implementations(doc_nodes: &mut Vec<DocNode>) {
+  let overloaded = doc_nodes
+    .iter()
+    .filter(
+      |doc_node| matches!(&doc_node.function_def, Some(def) if !def.has_body),
+    )
+    .map(|doc_node| doc_node.name.clone())
+    .collect::<HashSet<_>>();
+  doc_nodes.retain(|doc_node| {
+    !matches!(&doc_node.function_def, Some(def) if def.has_body)
+      || !overloaded.contains(&doc_node.name)
+  });
+
+  for doc_node in doc_nodes {
+    if let Some(class_def) = &mut doc_node.class_def {
+      if class_def.constructors.iter().any(|c| !c.has_body) {
+        class_def.constructors.retain(|c| !c.has_body);
+      }
+      let overloaded = class_def
+        .methods
+        .iter()
+        .filter(|m| !m.function_def.has_body)
+        .map(|m| (m.name.clone(), m.is_static))
+        .collect::<HashSet<_>>();
+      class_def.methods.retain(|m| {
+        !m.function_def.has_body
+          || !overloaded.contains(&(m.name.clone(), m.is_static))
+      });
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      remove_overload_implementations(&mut namespace_def.elements);
+    }
+  }
 }
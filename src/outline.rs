@@ -0,0 +1,270 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::ParsedSource;
+use deno_ast::SourcePos;
+use deno_ast::SourceRange;
+use deno_graph::ModuleSpecifier;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+use crate::source::location_to_pos;
+use crate::source::source_range;
+use crate::source::SourceTextProvider;
+
+/// The kind of a [`DocumentSymbol`], serialized as the number of the
+/// language server protocol `SymbolKind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+  Namespace = 3,
+  Class = 5,
+  Method = 6,
+  Property = 7,
+  Constructor = 9,
+  Enum = 10,
+  Interface = 11,
+  Function = 12,
+  Variable = 13,
+  EnumMember = 22,
+  /// Used for type aliases, which have no kind of their own.
+  TypeParameter = 26,
+}
+
+impl Serialize for SymbolKind {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(*self as u8)
+  }
+}
+
+/// A 0-indexed line and a column in UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+  pub line: usize,
+  pub character: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Range {
+  pub start: Position,
+  pub end: Position,
+}
+
+/// A symbol of the outline of a module, shaped like the language server
+/// protocol `DocumentSymbol`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentSymbol {
+  pub name: String,
+  pub kind: SymbolKind,
+  /// The whole declaration, including its `export` keyword.
+  pub range: Range,
+  /// The name of the declaration.
+  pub selection_range: Range,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub children: Vec<DocumentSymbol>,
+}
+
+/// Converts the doc nodes of a module into a hierarchical outline of its
+/// symbols, with the members of classes, interfaces and enums, and the
+/// elements of namespaces, as children. Symbols are in source order.
+///
+/// The locations of the doc nodes must use the default position encoding.
+/// When the source of a symbol is not available, its ranges are empty and
+/// at its location.
+pub fn document_symbols(
+  provider: &dyn SourceTextProvider,
+  doc_nodes: &[DocNode],
+) -> Vec<DocumentSymbol> {
+  let mut outline = Outline {
+    provider,
+    parsed_source: None,
+  };
+  outline.symbols(doc_nodes)
+}
+
+struct Outline<'a> {
+  provider: &'a dyn SourceTextProvider,
+  parsed_source: Option<(String, Option<ParsedSource>)>,
+}
+
+impl Outline<'_> {
+  fn symbols(&mut self, doc_nodes: &[DocNode]) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for doc_node in doc_nodes {
+      let kind = match doc_node.kind {
+        DocNodeKind::Class => SymbolKind::Class,
+        DocNodeKind::Enum => SymbolKind::Enum,
+        DocNodeKind::Function => SymbolKind::Function,
+        DocNodeKind::Interface => SymbolKind::Interface,
+        DocNodeKind::Namespace => SymbolKind::Namespace,
+        DocNodeKind::TypeAlias => SymbolKind::TypeParameter,
+        DocNodeKind::Variable => SymbolKind::Variable,
+        DocNodeKind::ModuleDoc
+        | DocNodeKind::Import
+        | DocNodeKind::UmdNamespace => continue,
+      };
+
+      let mut children = Vec::new();
+      if let Some(class_def) = &doc_node.class_def {
+        for constructor in &class_def.constructors {
+          children.push(self.symbol(
+            "constructor",
+            SymbolKind::Constructor,
+            &constructor.location,
+          ));
+        }
+        for property in &class_def.properties {
+          children.push(self.symbol(
+            &property.name,
+            SymbolKind::Property,
+            &property.location,
+          ));
+        }
+        for method in &class_def.methods {
+          children.push(self.symbol(
+            &method.name,
+            SymbolKind::Method,
+            &method.location,
+          ));
+        }
+      }
+      if let Some(interface_def) = &doc_node.interface_def {
+        for property in &interface_def.properties {
+          children.push(self.symbol(
+            &property.name,
+            SymbolKind::Property,
+            &property.location,
+          ));
+        }
+        for method in &interface_def.methods {
+          children.push(self.symbol(
+            &method.name,
+            SymbolKind::Method,
+            &method.location,
+          ));
+        }
+      }
+      if let Some(enum_def) = &doc_node.enum_def {
+        for member in &enum_def.members {
+          children.push(self.symbol(
+            &member.name,
+            SymbolKind::EnumMember,
+            &member.location,
+          ));
+        }
+      }
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        children = self.symbols(&namespace_def.elements);
+      }
+      children.sort_by_key(|symbol| symbol.range.start.sort_key());
+
+      let mut symbol = self.symbol(&doc_node.name, kind, &doc_node.location);
+      symbol.children = children;
+      symbols.push(symbol);
+    }
+    symbols.sort_by_key(|symbol| symbol.range.start.sort_key());
+    symbols
+  }
+
+  fn symbol(
+    &mut self,
+    name: &str,
+    kind: SymbolKind,
+    location: &Location,
+  ) -> DocumentSymbol {
+    let empty = Range {
+      start: Position {
+        line: location.line.saturating_sub(1),
+        character: location.col,
+      },
+      end: Position {
+        line: location.line.saturating_sub(1),
+        character: location.col,
+      },
+    };
+    let (range, selection_range) = self
+      .parsed_source(&location.filename)
+      .and_then(|parsed_source| {
+        let range = source_range(parsed_source, location).or_else(|| {
+          // declarations which are not statements, like variables, only
+          // have a location
+          let pos = location_to_pos(parsed_source.text_info(), location)?;
+          Some(SourceRange::new(pos, pos + name.len()))
+        })?;
+        let selection_range = name_range(parsed_source, range, name);
+        Some((
+          to_range(parsed_source, range),
+          to_range(parsed_source, selection_range),
+        ))
+      })
+      .unwrap_or((empty, empty));
+    DocumentSymbol {
+      name: name.to_string(),
+      kind,
+      range,
+      selection_range,
+      children: Vec::new(),
+    }
+  }
+
+  /// The parsed source of a module, cached for the last module looked up as
+  /// the symbols of a module are usually in the same file.
+  fn parsed_source(&mut self, filename: &str) -> Option<&ParsedSource> {
+    if self.parsed_source.as_ref().map(|(f, _)| f.as_str()) != Some(filename) {
+      let parsed_source = ModuleSpecifier::parse(filename)
+        .ok()
+        .and_then(|specifier| self.provider.get_parsed_source(&specifier));
+      self.parsed_source = Some((filename.to_string(), parsed_source));
+    }
+    self.parsed_source.as_ref().and_then(|(_, p)| p.as_ref())
+  }
+}
+
+impl Position {
+  fn sort_key(&self) -> (usize, usize) {
+    (self.line, self.character)
+  }
+}
+
+/// The range of the first occurrence of `name` as a word in `range`, or the
+/// start of `range` if there is none.
+fn name_range(
+  parsed_source: &ParsedSource,
+  range: SourceRange,
+  name: &str,
+) -> SourceRange {
+  let text = range.text_fast(parsed_source.text_info());
+  let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+  let offset = text.match_indices(name).map(|(i, _)| i).find(|i| {
+    let before = text[..*i].chars().next_back();
+    let after = text[i + name.len()..].chars().next();
+    !before.map(is_word_char).unwrap_or(false)
+      && !after.map(is_word_char).unwrap_or(false)
+  });
+  match offset {
+    Some(offset) => {
+      SourceRange::new(range.start + offset, range.start + offset + name.len())
+    }
+    None => SourceRange::new(range.start, range.start),
+  }
+}
+
+fn to_range(parsed_source: &ParsedSource, range: SourceRange) -> Range {
+  Range {
+    start: to_position(parsed_source, range.start),
+    end: to_position(parsed_source, range.end),
+  }
+}
+
+fn to_position(parsed_source: &ParsedSource, pos: SourcePos) -> Position {
+  let text_info = parsed_source.text_info();
+  let line = text_info.line_index(pos);
+  let line_start = text_info.line_start(line);
+  let character = SourceRange::new(line_start, pos)
+    .text_fast(text_info)
+    .encode_utf16()
+    .count();
+  Position { line, character }
+}
@@ -0,0 +1,136 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::swc_util::is_false;
+
+/// The documentation to merge over a node or member.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverlayEntry {
+  /// Replaces the documentation text.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub doc: Option<String>,
+  /// Tags in JSDoc syntax, like `@deprecated Use bar instead.`, which are
+  /// added to the tags of the node.
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
+  pub tags: Vec<String>,
+  /// Remove the existing tags before adding `tags`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub replace_tags: bool,
+}
+
+/// Documentation for modules which can not be edited, like third party
+/// dependencies, keyed by the qualified names of nodes and members, like
+/// `Deno.Conn.closeWrite`. Read from JSON with [`DocOverlay::from_json`]:
+///
+/// ```json
+/// {
+///   "Deno.Conn.closeWrite": {
+///     "doc": "Closes the write side of the connection.",
+///     "tags": ["@since 1.0.0"]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DocOverlay {
+  pub entries: BTreeMap<String, OverlayEntry>,
+}
+
+impl DocOverlay {
+  pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+    serde_json::from_str(json)
+  }
+}
+
+/// Merges the documentation of an overlay over the doc nodes, as a
+/// post-processing step of parsing, returning the qualified names of the
+/// overlay which matched no node or member. All the overloads of a function
+/// or method are changed.
+pub fn apply_overlay(
+  doc_nodes: &mut [DocNode],
+  overlay: &DocOverlay,
+) -> Vec<String> {
+  let mut matched = HashSet::new();
+  apply_overlay_inner(doc_nodes, None, overlay, &mut matched);
+  overlay
+    .entries
+    .keys()
+    .filter(|name| !matched.contains(name.as_str()))
+    .cloned()
+    .collect()
+}
+
+fn apply_overlay_inner<'a>(
+  doc_nodes: &mut [DocNode],
+  namespace: Option<&str>,
+  overlay: &'a DocOverlay,
+  matched: &mut HashSet<&'a str>,
+) {
+  for doc_node in doc_nodes {
+    let qualified_name = match namespace {
+      Some(namespace) => format!("{}.{}", namespace, doc_node.name),
+      None => doc_node.name.clone(),
+    };
+    let mut apply = |name: &str, js_doc: &mut JsDoc| {
+      if let Some((name, entry)) = overlay.entries.get_key_value(name) {
+        merge_entry(js_doc, entry);
+        matched.insert(name.as_str());
+      }
+    };
+    apply(&qualified_name, &mut doc_node.js_doc);
+    let member_name = |name: &str| format!("{}.{}", qualified_name, name);
+    if let Some(class_def) = &mut doc_node.class_def {
+      for constructor in &mut class_def.constructors {
+        apply(&member_name(&constructor.name), &mut constructor.js_doc);
+      }
+      for property in &mut class_def.properties {
+        apply(&member_name(&property.name), &mut property.js_doc);
+      }
+      for method in &mut class_def.methods {
+        apply(&member_name(&method.name), &mut method.js_doc);
+      }
+    }
+    if let Some(interface_def) = &mut doc_node.interface_def {
+      for property in &mut interface_def.properties {
+        apply(&member_name(&property.name), &mut property.js_doc);
+      }
+      for method in &mut interface_def.methods {
+        apply(&member_name(&method.name), &mut method.js_doc);
+      }
+    }
+    if let Some(enum_def) = &mut doc_node.enum_def {
+      for member in &mut enum_def.members {
+        apply(&member_name(&member.name), &mut member.js_doc);
+      }
+    }
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      apply_overlay_inner(
+        &mut namespace_def.elements,
+        Some(&qualified_name),
+        overlay,
+        matched,
+      );
+    }
+  }
+}
+
+fn merge_entry(js_doc: &mut JsDoc, entry: &OverlayEntry) {
+  if let Some(doc) = &entry.doc {
+    js_doc.doc = Some(doc.clone());
+  }
+  if entry.replace_tags {
+    js_doc.tags.clear();
+  }
+  js_doc
+    .tags
+    .extend(entry.tags.iter().map(|tag| JsDocTag::from(tag.clone())));
+}
@@ -0,0 +1,222 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::function::FunctionDef;
+use crate::node::DocNode;
+use crate::ts_type::LiteralDefKind;
+use crate::ts_type::TsTypeDef;
+
+/// The kind of an argument at a call site, as far as it is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArgumentKind {
+  String,
+  Number,
+  Boolean,
+  BigInt,
+  Function,
+  Array,
+  Object,
+  Null,
+  Undefined,
+}
+
+/// What is known about a call, used to pick the overload to display first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverloadHint {
+  /// The number of arguments, if known.
+  pub arity: Option<usize>,
+  /// The kinds of the arguments by position, `None` where unknown.
+  pub argument_kinds: Vec<Option<ArgumentKind>>,
+}
+
+/// How well a type accepts an argument kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Fit {
+  Mismatch,
+  /// The type may accept the argument, like `any`, a type parameter or a
+  /// type reference which can not be resolved.
+  Possible,
+  Exact,
+}
+
+/// Selects the overload of a function to display first, the way editors
+/// pick the primary signature of a hover or signature help.
+///
+/// The doc nodes are the declarations of a single function, in source order;
+/// nodes which are not functions are ignored. The implementation signature
+/// is only selected when there are no other signatures. Overloads which
+/// accept the number of arguments of the hint are preferred, then those
+/// whose params best match the argument kinds, and then the ones declared
+/// first, so with an empty hint the first overload is selected.
+pub fn select_overload<'a>(
+  doc_nodes: &'a [DocNode],
+  hint: &OverloadHint,
+) -> Option<&'a DocNode> {
+  let overloads = doc_nodes
+    .iter()
+    .filter_map(|doc_node| {
+      doc_node.function_def.as_ref().map(|def| (doc_node, def))
+    })
+    .collect::<Vec<_>>();
+  let function_defs = overloads.iter().map(|(_, def)| *def).collect::<Vec<_>>();
+  select_function_def(&function_defs, hint).map(|i| overloads[i].0)
+}
+
+/// The index of the overload to display first, see [`select_overload`].
+pub(crate) fn select_function_def(
+  overloads: &[&FunctionDef],
+  hint: &OverloadHint,
+) -> Option<usize> {
+  let has_signatures = overloads.iter().any(|def| !def.has_body);
+  let mut selected: Option<(usize, (bool, i64))> = None;
+  for (i, function_def) in overloads.iter().enumerate() {
+    if has_signatures && function_def.has_body {
+      continue;
+    }
+    let score = score(function_def, hint);
+    if selected.map(|(_, best)| score > best).unwrap_or(true) {
+      selected = Some((i, score));
+    }
+  }
+  selected.map(|(i, _)| i)
+}
+
+/// Whether the overload accepts the arity of the hint, and how well its
+/// params match the argument kinds.
+fn score(function_def: &FunctionDef, hint: &OverloadHint) -> (bool, i64) {
+  let params = &function_def.params;
+  let required = params.iter().filter(|param| !param.is_optional()).count();
+  let max = if params.iter().any(|param| param.is_rest()) {
+    usize::MAX
+  } else {
+    params.len()
+  };
+  let arity = hint.arity.unwrap_or(hint.argument_kinds.len());
+  let accepts_arity = (required..=max).contains(&arity);
+
+  let mut score = 0;
+  for (i, kind) in hint.argument_kinds.iter().enumerate() {
+    let Some(kind) = kind else {
+      continue;
+    };
+    let fit = match param_type(function_def, i) {
+      Some(ts_type) => fit(ts_type, *kind, function_def),
+      None if i < max => Fit::Possible,
+      None => Fit::Mismatch,
+    };
+    score += match fit {
+      Fit::Exact => 2,
+      Fit::Possible => 1,
+      Fit::Mismatch => -2,
+    };
+  }
+  (accepts_arity, score)
+}
+
+/// The type of the argument at `index`, which is the element type of a rest
+/// param for the arguments it collects.
+fn param_type(function_def: &FunctionDef, index: usize) -> Option<&TsTypeDef> {
+  for (i, param) in function_def.params.iter().enumerate() {
+    if param.is_rest() {
+      let ts_type = param.ts_type()?;
+      return Some(ts_type.array.as_deref().unwrap_or(ts_type));
+    }
+    if i == index {
+      return param.ts_type();
+    }
+  }
+  None
+}
+
+fn fit(ts_type: &TsTypeDef, kind: ArgumentKind, def: &FunctionDef) -> Fit {
+  if let Some(keyword) = &ts_type.keyword {
+    let exact = match keyword.as_str() {
+      "any" | "unknown" => return Fit::Possible,
+      "string" => kind == ArgumentKind::String,
+      "number" => kind == ArgumentKind::Number,
+      "boolean" => kind == ArgumentKind::Boolean,
+      "bigint" => kind == ArgumentKind::BigInt,
+      "null" => kind == ArgumentKind::Null,
+      "undefined" | "void" => kind == ArgumentKind::Undefined,
+      "object" => matches!(
+        kind,
+        ArgumentKind::Object | ArgumentKind::Array | ArgumentKind::Function
+      ),
+      _ => false,
+    };
+    return if exact { Fit::Exact } else { Fit::Mismatch };
+  }
+  if let Some(literal) = &ts_type.literal {
+    let literal_kind = match literal.kind {
+      LiteralDefKind::String | LiteralDefKind::Template => ArgumentKind::String,
+      LiteralDefKind::Number => ArgumentKind::Number,
+      LiteralDefKind::Boolean => ArgumentKind::Boolean,
+      LiteralDefKind::BigInt => ArgumentKind::BigInt,
+    };
+    return if literal_kind == kind {
+      Fit::Exact
+    } else {
+      Fit::Mismatch
+    };
+  }
+  if let Some(types) = ts_type.union.as_ref().or(ts_type.intersection.as_ref())
+  {
+    return types
+      .iter()
+      .map(|ts_type| fit(ts_type, kind, def))
+      .max()
+      .unwrap_or(Fit::Possible);
+  }
+  if let Some(inner) = ts_type
+    .parenthesized
+    .as_deref()
+    .or(ts_type.optional.as_deref())
+  {
+    return fit(inner, kind, def);
+  }
+  if ts_type.array.is_some() || ts_type.tuple.is_some() {
+    return exact_if(kind == ArgumentKind::Array);
+  }
+  if ts_type.fn_or_constructor.is_some() {
+    return exact_if(kind == ArgumentKind::Function);
+  }
+  if let Some(type_literal) = &ts_type.type_literal {
+    return exact_if(
+      kind == ArgumentKind::Object
+        || (kind == ArgumentKind::Function
+          && !type_literal.call_signatures.is_empty()),
+    );
+  }
+  if let Some(type_ref) = &ts_type.type_ref {
+    if def
+      .type_params
+      .iter()
+      .any(|type_param| type_param.name == type_ref.type_name)
+    {
+      return Fit::Possible;
+    }
+    return match type_ref.type_name.as_str() {
+      "String" => exact_if(kind == ArgumentKind::String),
+      "Number" => exact_if(kind == ArgumentKind::Number),
+      "Boolean" => exact_if(kind == ArgumentKind::Boolean),
+      "BigInt" => exact_if(kind == ArgumentKind::BigInt),
+      "Array" | "ReadonlyArray" => exact_if(kind == ArgumentKind::Array),
+      "Function" => exact_if(kind == ArgumentKind::Function),
+      "Record" | "Object" => exact_if(kind == ArgumentKind::Object),
+      _ => Fit::Possible,
+    };
+  }
+  Fit::Possible
+}
+
+fn exact_if(exact: bool) -> Fit {
+  if exact {
+    Fit::Exact
+  } else {
+    Fit::Mismatch
+  }
+}
@@ -4,6 +4,9 @@ use crate::decorators::decorators_to_defs;
 use crate::decorators::DecoratorDef;
 use crate::display::display_optional;
 use crate::display::SliceDisplayer;
+use crate::js_doc::JsDoc;
+use crate::js_doc::ParamTag;
+use crate::swc_util::well_known_symbol_name;
 use crate::ts_type::ts_type_ann_to_def;
 use crate::ts_type::TsTypeDef;
 
@@ -48,9 +51,211 @@ enum ParamPatternDef {
 pub struct ParamDef {
   #[serde(flatten)]
   pattern: ParamPatternDef,
-  #[serde(skip_serializing_if = "Vec::is_empty")]
+  #[serde(skip_serializing_if = "Vec::is_empty", default)]
   decorators: Vec<DecoratorDef>,
   ts_type: Option<TsTypeDef>,
+  /// The documentation of the `@param` tag of the param, when requested with
+  /// [`crate::DocParserOptions::param_docs`].
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  doc: Option<String>,
+}
+
+impl ParamDef {
+  /// The name of the param, when it is bound to a single identifier.
+  pub(crate) fn name(&self) -> Option<&str> {
+    match &self.pattern {
+      ParamPatternDef::Identifier { name, .. } => Some(name.as_str()),
+      ParamPatternDef::Assign { left, .. } => left.name(),
+      ParamPatternDef::Rest { arg } => arg.name(),
+      ParamPatternDef::Array { .. } | ParamPatternDef::Object { .. } => None,
+    }
+  }
+
+  /// Whether an argument may be omitted for this param, as it is optional,
+  /// has a default value or is a rest param.
+  pub(crate) fn is_optional(&self) -> bool {
+    match &self.pattern {
+      ParamPatternDef::Array { optional, .. }
+      | ParamPatternDef::Identifier { optional, .. }
+      | ParamPatternDef::Object { optional, .. } => *optional,
+      ParamPatternDef::Assign { .. } | ParamPatternDef::Rest { .. } => true,
+    }
+  }
+
+  pub(crate) fn is_rest(&self) -> bool {
+    matches!(self.pattern, ParamPatternDef::Rest { .. })
+  }
+
+  pub(crate) fn ts_type(&self) -> Option<&TsTypeDef> {
+    match &self.pattern {
+      ParamPatternDef::Assign { left, .. } => {
+        self.ts_type.as_ref().or_else(|| left.ts_type())
+      }
+      ParamPatternDef::Rest { arg } => {
+        self.ts_type.as_ref().or_else(|| arg.ts_type())
+      }
+      _ => self.ts_type.as_ref(),
+    }
+  }
+
+  pub fn doc(&self) -> Option<&str> {
+    self.doc.as_deref()
+  }
+
+  /// Sets the `doc` of the param, and of the properties it destructures,
+  /// from its `@param` tag.
+  fn document(&mut self, tag: Option<&ParamTag>) {
+    self.doc = tag.and_then(|tag| tag.doc.clone());
+    self.document_properties(tag.map(|tag| &tag.properties[..]).unwrap_or(&[]));
+  }
+
+  fn document_properties(&mut self, properties: &[ParamTag]) {
+    let find = |key: &str| properties.iter().find(|tag| tag.name == key);
+    match &mut self.pattern {
+      ParamPatternDef::Object { props, .. } => {
+        for prop in props {
+          match prop {
+            ObjectPatPropDef::Assign { key, doc, .. } => {
+              *doc = find(key.as_str()).and_then(|tag| tag.doc.clone());
+            }
+            ObjectPatPropDef::KeyValue { key, value } => {
+              value.document(find(key.as_str()));
+            }
+            ObjectPatPropDef::Rest { .. } => {}
+          }
+        }
+      }
+      ParamPatternDef::Assign { left, .. } => {
+        left.document_properties(properties)
+      }
+      ParamPatternDef::Array { .. }
+      | ParamPatternDef::Identifier { .. }
+      | ParamPatternDef::Rest { .. } => {}
+    }
+  }
+
+  /// Calls `f` with the documentation of this param and of the properties
+  /// it destructures.
+  pub(crate) fn for_each_doc_mut(&mut self, f: &mut dyn FnMut(&mut String)) {
+    if let Some(doc) = &mut self.doc {
+      f(doc);
+    }
+    match &mut self.pattern {
+      ParamPatternDef::Object { props, .. } => {
+        for prop in props {
+          match prop {
+            ObjectPatPropDef::Assign { doc, .. } => {
+              if let Some(doc) = doc {
+                f(doc);
+              }
+            }
+            ObjectPatPropDef::KeyValue { value, .. } => {
+              value.for_each_doc_mut(f)
+            }
+            ObjectPatPropDef::Rest { .. } => {}
+          }
+        }
+      }
+      ParamPatternDef::Assign { left, .. } => left.for_each_doc_mut(f),
+      ParamPatternDef::Array { .. }
+      | ParamPatternDef::Identifier { .. }
+      | ParamPatternDef::Rest { .. } => {}
+    }
+  }
+
+  pub(crate) fn decorators_mut(&mut self) -> &mut [DecoratorDef] {
+    &mut self.decorators
+  }
+
+  /// Calls `f` with the type annotation of this param as well as the type
+  /// annotations of any params nested in its binding pattern.
+  pub(crate) fn for_each_ts_type_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut TsTypeDef),
+  ) {
+    if let Some(ts_type) = &mut self.ts_type {
+      f(ts_type);
+    }
+    match &mut self.pattern {
+      ParamPatternDef::Array { elements, .. } => {
+        for element in elements.iter_mut().flatten() {
+          element.for_each_ts_type_mut(f);
+        }
+      }
+      ParamPatternDef::Assign { left, .. } => left.for_each_ts_type_mut(f),
+      ParamPatternDef::Identifier { .. } => {}
+      ParamPatternDef::Object { props, .. } => {
+        for prop in props {
+          match prop {
+            ObjectPatPropDef::Assign { .. } => {}
+            ObjectPatPropDef::KeyValue { value, .. } => {
+              value.for_each_ts_type_mut(f)
+            }
+            ObjectPatPropDef::Rest { arg } => arg.for_each_ts_type_mut(f),
+          }
+        }
+      }
+      ParamPatternDef::Rest { arg } => arg.for_each_ts_type_mut(f),
+    }
+  }
+
+  /// Like [`Self::for_each_ts_type_mut`], but with shared references.
+  pub(crate) fn for_each_ts_type(&self, f: &mut dyn FnMut(&TsTypeDef)) {
+    if let Some(ts_type) = &self.ts_type {
+      f(ts_type);
+    }
+    match &self.pattern {
+      ParamPatternDef::Array { elements, .. } => {
+        for element in elements.iter().flatten() {
+          element.for_each_ts_type(f);
+        }
+      }
+      ParamPatternDef::Assign { left, .. } => left.for_each_ts_type(f),
+      ParamPatternDef::Identifier { .. } => {}
+      ParamPatternDef::Object { props, .. } => {
+        for prop in props {
+          match prop {
+            ObjectPatPropDef::Assign { .. } => {}
+            ObjectPatPropDef::KeyValue { value, .. } => {
+              value.for_each_ts_type(f)
+            }
+            ObjectPatPropDef::Rest { arg } => arg.for_each_ts_type(f),
+          }
+        }
+      }
+      ParamPatternDef::Rest { arg } => arg.for_each_ts_type(f),
+    }
+  }
+}
+
+/// Sets the `doc` of params from the `@param` tags of the JSDoc of their
+/// function. A param bound to a single identifier is documented by the tag
+/// with its name, and a destructured param by the tag at its position, as in
+/// `@param options` for `{ a, b }: Options`. The properties destructured by
+/// a param are documented by the tags of the properties of its tag, as in
+/// `@param options.a`.
+pub(crate) fn document_params<'a>(
+  params: impl IntoIterator<Item = &'a mut ParamDef>,
+  js_doc: &JsDoc,
+) {
+  let tags = js_doc.param_tags();
+  if tags.is_empty() {
+    return;
+  }
+  let mut params = params.into_iter().collect::<Vec<_>>();
+  let names = params
+    .iter()
+    .filter_map(|param| param.name().map(|name| name.to_string()))
+    .collect::<Vec<_>>();
+  for (i, param) in params.iter_mut().enumerate() {
+    let tag = match param.name() {
+      Some(name) => tags.iter().find(|tag| tag.name == name),
+      None => tags
+        .get(i)
+        .filter(|tag| !names.iter().any(|name| *name == tag.name)),
+    };
+    param.document(tag);
+  }
 }
 
 impl Display for ParamDef {
@@ -122,9 +327,21 @@ impl Display for ParamDef {
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
 pub enum ObjectPatPropDef {
-  Assign { key: String, value: Option<String> },
-  KeyValue { key: String, value: Box<ParamDef> },
-  Rest { arg: Box<ParamDef> },
+  Assign {
+    key: String,
+    value: Option<String>,
+    /// The documentation of the `@param` tag of the property, when
+    /// requested with [`crate::DocParserOptions::param_docs`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<String>,
+  },
+  KeyValue {
+    key: String,
+    value: Box<ParamDef>,
+  },
+  Rest {
+    arg: Box<ParamDef>,
+  },
 }
 
 impl Display for ObjectPatPropDef {
@@ -156,6 +373,7 @@ pub fn ident_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -171,6 +389,7 @@ fn rest_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -182,6 +401,7 @@ fn object_pat_prop_to_def(
     ObjectPatProp::Assign(assign) => ObjectPatPropDef::Assign {
       key: assign.key.sym.to_string(),
       value: assign.value.as_ref().map(|_| "[UNSUPPORTED]".to_string()),
+      doc: None,
     },
     ObjectPatProp::KeyValue(keyvalue) => ObjectPatPropDef::KeyValue {
       key: prop_name_to_string(parsed_source, &keyvalue.key),
@@ -211,6 +431,7 @@ fn object_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -232,6 +453,7 @@ fn array_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type,
+    doc: None,
   }
 }
 
@@ -246,6 +468,7 @@ pub fn assign_pat_to_param_def(
     },
     decorators: Vec::new(),
     ts_type: None,
+    doc: None,
   }
 }
 
@@ -302,8 +525,26 @@ pub fn prop_name_to_string(
     PropName::Str(str_) => str_.value.to_string(),
     PropName::Num(num) => num.value.to_string(),
     PropName::BigInt(num) => num.value.to_string(),
-    PropName::Computed(comp_prop_name) => parsed_source
-      .map(|s| comp_prop_name.text_fast(s.text_info()).to_string())
-      .unwrap_or_else(|| "<UNAVAILABLE>".to_string()),
+    PropName::Computed(comp_prop_name) => {
+      if let Some(name) = well_known_symbol_name(&comp_prop_name.expr) {
+        return format!("[{}]", name);
+      }
+      parsed_source
+        .map(|s| comp_prop_name.text_fast(s.text_info()).to_string())
+        .unwrap_or_else(|| "<UNAVAILABLE>".to_string())
+    }
+  }
+}
+
+/// The canonical name of the well-known symbol a computed property name
+/// refers to, like `Symbol.iterator`.
+pub fn prop_name_well_known_symbol(
+  prop_name: &deno_ast::swc::ast::PropName,
+) -> Option<String> {
+  match prop_name {
+    deno_ast::swc::ast::PropName::Computed(comp_prop_name) => {
+      well_known_symbol_name(&comp_prop_name.expr)
+    }
+    _ => None,
   }
 }
@@ -1,20 +1,53 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::class::set_super_type_params_from_js_doc;
+use crate::events::set_events;
+use crate::inherit::set_effective_js_docs;
+use crate::inherit::set_inherited_members;
+use crate::inherit::InheritedMembers;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::manifest::PackageRoot;
+use crate::members::order_members;
+use crate::members::MemberOrder;
+use crate::module_filter::ModuleFilter;
 use crate::node;
+use crate::node::remove_overload_implementations;
+use crate::node::set_chainable_methods;
+use crate::node::set_doc_segments;
+use crate::node::set_param_docs;
+use crate::node::set_qualified_names;
+use crate::node::set_release_tags;
+use crate::node::set_stability;
+use crate::node::share_overload_js_docs;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
+use crate::node::DynamicImport;
 use crate::node::ModuleDoc;
+use crate::node::ModuleDocDef;
 use crate::node::NamespaceDef;
+use crate::node::ReferencedType;
+use crate::node::ReleaseTag;
+use crate::position::encode_location;
+use crate::position::PositionEncoding;
+use crate::see::unresolved_see_targets;
+#[cfg(feature = "rust")]
+use crate::source::SourceTextProvider;
 use crate::swc_util::get_location;
 use crate::swc_util::get_text_info_location;
+use crate::swc_util::is_false;
 use crate::swc_util::js_doc_for_range;
+use crate::swc_util::js_doc_for_range_with_ignored;
+use crate::swc_util::leading_comments_for_pos;
 use crate::swc_util::module_export_name_value;
 use crate::swc_util::module_js_doc_for_source;
+use crate::swc_util::module_js_doc_name;
 use crate::ts_type::LiteralPropertyDef;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type::TsTypeDefKind;
 use crate::ts_type::TsTypeLiteralDef;
+use crate::typedef::get_doc_nodes_for_typedefs;
+use crate::variable::js_doc_type_for_var_declarator;
 use crate::variable::VariableDef;
 use crate::DocNodeKind;
 use crate::ImportDef;
@@ -28,42 +61,86 @@ use deno_ast::swc::ast::ExportDecl;
 use deno_ast::swc::ast::ExportDefaultDecl;
 use deno_ast::swc::ast::ExportDefaultExpr;
 use deno_ast::swc::ast::ExportSpecifier;
+use deno_ast::swc::ast::Expr;
 use deno_ast::swc::ast::FnDecl;
 use deno_ast::swc::ast::Ident;
 use deno_ast::swc::ast::ImportSpecifier;
 use deno_ast::swc::ast::ModuleDecl;
 use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Pat;
+use deno_ast::swc::ast::Stmt;
 use deno_ast::swc::ast::TsEnumDecl;
+use deno_ast::swc::ast::TsImportEqualsDecl;
 use deno_ast::swc::ast::TsInterfaceDecl;
 use deno_ast::swc::ast::TsModuleDecl;
 use deno_ast::swc::ast::TsModuleName;
+use deno_ast::swc::ast::TsModuleRef;
+use deno_ast::swc::ast::TsNamespaceBody;
 use deno_ast::swc::ast::TsTypeAliasDecl;
 use deno_ast::swc::ast::VarDecl;
 use deno_ast::swc::ast::VarDeclKind;
 use deno_ast::swc::ast::VarDeclarator;
+use deno_ast::LineAndColumnIndex;
+use deno_ast::MediaType;
 use deno_ast::ParsedSource;
+use deno_ast::SourcePos;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use deno_graph::type_tracer::EsmModuleSymbol;
 use deno_graph::type_tracer::ExportDeclRef;
 use deno_graph::type_tracer::ModuleSymbolRef;
 use deno_graph::type_tracer::Symbol;
+use deno_graph::type_tracer::SymbolId;
 use deno_graph::type_tracer::SymbolNodeRef;
 use deno_graph::CapturingModuleParser;
 use deno_graph::Module;
+use deno_graph::ModuleError;
 use deno_graph::ModuleGraph;
 use deno_graph::ModuleSpecifier;
+use deno_graph::Resolution;
+
+use serde::Serialize;
 
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub enum DocDiagnosticKind {
   PrivateTypeRef,
+  /// The target of a `@see` tag names a symbol which is not documented.
+  UnresolvedSeeTarget(String),
+  /// An import of a module of the graph could not be resolved or the
+  /// imported module could not be loaded, so its declarations are missing.
+  UnresolvedImport {
+    specifier: String,
+    message: String,
+  },
+  /// A module has a syntax error and was left out of the documentation.
+  ParseError {
+    media_type: MediaType,
+    message: String,
+  },
+}
+
+impl DocDiagnosticKind {
+  /// The code of the kind of diagnostic, like `unresolved-see-target`, by
+  /// which [`StrictMode::Codes`] selects diagnostics.
+  pub fn code(&self) -> &'static str {
+    match self {
+      DocDiagnosticKind::PrivateTypeRef => "private-type-ref",
+      DocDiagnosticKind::UnresolvedSeeTarget(_) => "unresolved-see-target",
+      DocDiagnosticKind::UnresolvedImport { .. } => "unresolved-import",
+      DocDiagnosticKind::ParseError { .. } => "parse-error",
+    }
+  }
 }
 
 impl std::fmt::Display for DocDiagnosticKind {
@@ -72,6 +149,22 @@ impl std::fmt::Display for DocDiagnosticKind {
       DocDiagnosticKind::PrivateTypeRef => {
         f.write_str("Type is not exported, but referenced by an exported type.")
       }
+      DocDiagnosticKind::UnresolvedSeeTarget(target) => {
+        write!(f, "The @see target \"{}\" could not be resolved.", target)
+      }
+      DocDiagnosticKind::UnresolvedImport { specifier, message } => {
+        write!(f, "The import \"{}\" failed: {}", specifier, message)
+      }
+      DocDiagnosticKind::ParseError {
+        media_type,
+        message,
+      } => {
+        write!(
+          f,
+          "The {} module could not be parsed: {}",
+          media_type, message
+        )
+      }
     }
   }
 }
@@ -88,6 +181,8 @@ pub enum DocError {
   #[allow(dead_code)]
   Io(std::io::Error),
   Parse(deno_ast::Diagnostic),
+  /// Diagnostics selected by the [`StrictMode`] of the parser were found.
+  Strict(Vec<DocDiagnostic>),
 }
 
 impl Error for DocError {}
@@ -98,6 +193,25 @@ impl fmt::Display for DocError {
       Self::Resolve(s) => s.to_string(),
       Self::Io(err) => err.to_string(),
       Self::Parse(err) => err.to_string(),
+      Self::Strict(diagnostics) => {
+        let mut m = format!(
+          "Strict mode failed with {} diagnostic{}:",
+          diagnostics.len(),
+          if diagnostics.len() == 1 { "" } else { "s" }
+        );
+        for diagnostic in diagnostics {
+          let location = &diagnostic.location;
+          m.push_str(&format!(
+            "\n  {}:{}:{} [{}] {}",
+            location.filename,
+            location.line,
+            location.col + 1,
+            diagnostic.kind.code(),
+            diagnostic.kind
+          ));
+        }
+        m
+      }
     };
     f.pad(&m)
   }
@@ -121,11 +235,311 @@ struct Import {
   kind: ImportKind,
 }
 
+/// The metrics of parsing a single module.
+#[derive(Debug, Clone)]
+pub struct ModuleParseMetrics {
+  pub specifier: ModuleSpecifier,
+  /// The time it took to extract the doc nodes of the module. Always zero
+  /// on `wasm32` targets, which lack a clock.
+  pub duration: Duration,
+  /// The number of doc nodes extracted, including namespace elements.
+  pub node_count: usize,
+}
+
+/// Metrics of the parses done by a [`DocParser`], for logging and monitoring
+/// the performance of documentation pipelines.
+#[derive(Debug, Clone, Default)]
+pub struct DocParserMetrics {
+  /// Every module parse, in the order they finished.
+  pub modules: Vec<ModuleParseMetrics>,
+  pub total_duration: Duration,
+  pub node_count: usize,
+  pub diagnostic_count: usize,
+  /// The number of module parses which reused the doc nodes of a previous
+  /// parse of the module instead of extracting them again.
+  pub cache_hits: usize,
+}
+
+/// A summary of the modules doc nodes are declared in, like for showing how
+/// many modules documentation was generated from. Returned by
+/// [`DocParser::parse_with_reexports_and_stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphStats {
+  pub module_count: usize,
+  /// The modules fetched over `http:` or `https:`.
+  pub remote_count: usize,
+  pub local_count: usize,
+  /// The size of the sources of the modules, in bytes.
+  pub total_bytes: usize,
+  /// The number of modules of each media type, like `TypeScript`.
+  pub media_types: BTreeMap<String, usize>,
+}
+
+/// A symbol exported under another name than the one it is declared with,
+/// through `export { foo as bar }` or a chain of such reexports.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolAlias {
+  /// The module exporting the symbol as `name`.
+  pub specifier: ModuleSpecifier,
+  pub name: String,
+  /// The module declaring the symbol as `original_name`.
+  pub original_specifier: ModuleSpecifier,
+  pub original_name: String,
+}
+
+impl fmt::Display for SymbolAlias {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} -> {}#{}",
+      self.name, self.original_specifier, self.original_name
+    )
+  }
+}
+
+/// A module of the graph, with its module documentation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleInfo {
+  pub specifier: ModuleSpecifier,
+  pub media_type: MediaType,
+  /// The JSDoc of the module, from a leading comment with a `@module` tag.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub module_doc: Option<JsDoc>,
+  /// Whether the module can't be documented, like a CSS or WebAssembly
+  /// module imported for its side effects, and is only listed so that the
+  /// modules of the graph are complete.
+  #[serde(skip_serializing_if = "is_false")]
+  pub asset: bool,
+}
+
+/// Which declarations are documented, each toggled independently.
+#[derive(Debug, Clone)]
+pub struct VisibilityFilter {
+  /// Include non-exported declarations of modules.
+  pub non_exported: bool,
+  /// Include declarations and members tagged with `@internal`.
+  pub internal: bool,
+  /// Include the `#private` members of classes.
+  pub private_members: bool,
+  /// Include declarations from remote (`http:` and `https:`) modules.
+  pub remote: bool,
+}
+
+/// How the doc node of an `export default` value expression, like
+/// `export default 42`, is named. It is documented as a variable with the
+/// type inferred from the expression.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultExportName {
+  /// Named `default`.
+  #[default]
+  Default,
+  /// Named after its module: the name given to the `@module` tag of the
+  /// module doc, like `@module config`, or else an identifier derived from
+  /// the file name, like `myConfig` for `my-config.ts`, or from the name of
+  /// the directory of `mod.ts` and `index.ts` files. The name is kept when
+  /// the value is reexported as `default`.
+  Module,
+}
+
+/// Where a module whose module doc is tagged with `@ignore` is left out of
+/// the documentation, each toggled independently.
+#[derive(Debug, Clone)]
+pub struct ModuleIgnoreScope {
+  /// Ignore the module when it is documented directly, as the module being
+  /// parsed or as the root whose reexports are resolved. Its reexports are
+  /// still resolved.
+  pub direct: bool,
+  /// Ignore the module when it is reexported as a namespace, with
+  /// `export * as ns`, leaving the namespace out of the documentation of the
+  /// reexporting module.
+  pub namespace_reexports: bool,
+}
+
+impl Default for ModuleIgnoreScope {
+  fn default() -> Self {
+    Self {
+      direct: true,
+      namespace_reexports: true,
+    }
+  }
+}
+
+impl Default for VisibilityFilter {
+  fn default() -> Self {
+    Self {
+      non_exported: false,
+      internal: true,
+      private_members: false,
+      remote: true,
+    }
+  }
+}
+
+/// Which diagnostics fail a parse in strict mode, so a documentation build
+/// can enforce having none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrictMode {
+  /// Every diagnostic.
+  All,
+  /// The diagnostics with one of the [`DocDiagnosticKind::code`]s, like
+  /// `unresolved-see-target`.
+  Codes(Vec<String>),
+}
+
+impl StrictMode {
+  fn fails_on(&self, diagnostic: &DocDiagnostic) -> bool {
+    match self {
+      StrictMode::All => true,
+      StrictMode::Codes(codes) => {
+        codes.iter().any(|code| code == diagnostic.kind.code())
+      }
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct DocParserOptions {
+  /// Include private (non-exported) nodes in the output.
+  pub private: bool,
+  /// A finer grained filter of the declarations to document. When set,
+  /// `private` is ignored in favor of its `non_exported` setting.
+  pub visibility: Option<VisibilityFilter>,
+  /// Document ambient globals: all declarations of script-mode declaration
+  /// files and the contents of `declare global { }` blocks, output with a
+  /// declaration kind of `declare`.
+  pub document_globals: bool,
+  /// Collect the types imported from other modules which are referenced by
+  /// the definitions of a module, and in turn the types those reference,
+  /// into the `referenced_types` of the [`ModuleDoc`], or for the parses with
+  /// reexports, into [`DocParser::referenced_types`].
+  pub referenced_types: bool,
+  /// Document the exports of the modules which a module imports with
+  /// `import()` into the `dynamic_imports` of the [`ModuleDoc`].
+  pub dynamic_imports: bool,
+  /// How the lines and columns of locations are counted.
+  pub position_encoding: PositionEncoding,
+  /// Set the `qualified_name` of every node and member, for example
+  /// `Deno.Conn.closeWrite`.
+  pub qualified_names: bool,
+  /// When `private` is off, only extract the exports of a module and the
+  /// private declarations and imports which they reference, found by
+  /// walking the types of the exports, instead of every declaration which
+  /// the type tracer found to be public from the roots of the graph.
+  pub reachable_only: bool,
+  /// Set the `effective_js_doc` of nodes and of class and interface members,
+  /// which combines their own documentation with documentation inherited
+  /// from their bases.
+  pub effective_js_doc: bool,
+  /// Copy the methods and properties which classes and interfaces inherit
+  /// from the documented classes and interfaces they extend into them, with
+  /// `inherited_from` set, for a flattened view of their API.
+  pub inherited_members: Option<InheritedMembers>,
+  /// Where modules tagged with `@ignore` are left out.
+  pub module_ignore: ModuleIgnoreScope,
+  /// How `export default` value expressions are named.
+  pub default_export_name: DefaultExportName,
+  /// Order the properties and methods of classes and interfaces by their
+  /// [`crate::MemberGroup`], which is set on every member, and within a
+  /// group as given.
+  pub member_order: Option<MemberOrder>,
+  /// Set `chainable` on the methods of classes and interfaces which return
+  /// `this` or the class or interface itself, so the fluent API of a builder
+  /// can be told apart, and label them in the printer.
+  pub chainable_methods: bool,
+  /// Set the `events` of classes and interfaces from the overloads of their
+  /// listener methods, like `on(event: "data", listener: ...)` and
+  /// `addEventListener`, as a table of event names and payload types.
+  pub events: bool,
+  /// Leave out the implementation signatures of overloaded functions,
+  /// methods and constructors, which have a body, keeping only the overload
+  /// declarations.
+  pub hide_overload_implementations: bool,
+  /// Give the overloads of functions, methods and constructors which are not
+  /// documented the documentation of the implementation, or else of the
+  /// first documented overload, as authors often document a function once.
+  pub overload_js_doc_fallback: bool,
+  /// Report the imports of the modules of the graph which could not be
+  /// resolved or loaded as diagnostics, located at the import.
+  pub import_diagnostics: bool,
+  /// Skip the modules which have a syntax error, reporting each as a
+  /// diagnostic, and document the rest of the graph, instead of failing.
+  pub recover_parse_errors: bool,
+  /// Fail [`DocParser::parse_module`] and [`DocParser::parse_with_reexports`]
+  /// with [`DocError::Strict`], which reports all of them, when they leave
+  /// any of the selected diagnostics, including those of previous parses.
+  pub strict: Option<StrictMode>,
+  /// Set the `stability` of nodes tagged with `@internal` or
+  /// `@experimental`. Nodes tagged with `@internal` can instead be left out
+  /// with [`VisibilityFilter::internal`].
+  pub stability: bool,
+  /// Set the `release_tag` of nodes tagged with the TSDoc release tags
+  /// `@public`, `@beta`, `@alpha` or `@internal`, which elements of
+  /// namespaces inherit. Nodes tagged with `@internal` are only kept with
+  /// [`VisibilityFilter::internal`].
+  pub release_tags: bool,
+  /// With `release_tags`, leave out the nodes released below this level,
+  /// where untagged nodes are considered public. For example, `Beta` keeps
+  /// the public and beta nodes.
+  pub min_release_tag: Option<ReleaseTag>,
+  /// Set the `doc` of the params of functions, methods, constructors and
+  /// call signatures from the `@param` tags of their JSDoc.
+  pub param_docs: bool,
+  /// Set the `doc_segments` of JSDoc, which split the documentation into
+  /// text and inline `{@link}` tags.
+  pub inline_links: bool,
+  /// Which modules are documented. The exports of excluded modules are also
+  /// left out of the modules which reexport them.
+  pub module_filter: ModuleFilter,
+  /// Document the enum members and the interface properties and methods
+  /// which have no JSDoc with a `//` comment which trails them on their
+  /// line, like `a: string; // The a.`.
+  pub trailing_comments: bool,
+  /// The headers each module was served with, by its specifier after
+  /// redirects, as they are not kept by the graph. An `x-deno-warning`
+  /// header and `x-doc-*` headers are set as the `module_doc_def` of the
+  /// module doc node, which is added to modules without a module doc.
+  pub module_headers: HashMap<ModuleSpecifier, HashMap<String, String>>,
+  /// Set the `dependencies` of nodes: the modules imported by their module
+  /// which their signatures refer to, as resolved.
+  pub dependencies: bool,
+  /// Set the `leading_comments` of nodes of declarations: all the comments
+  /// preceding them as written, for tools implementing comment conventions
+  /// which are not parsed as JSDoc.
+  pub leading_comments: bool,
+  /// Keep the nodes of declarations marked `@ignore`, with `hidden` set,
+  /// instead of leaving them out, so that consumers can decide whether to
+  /// show them. The printer still leaves them out.
+  pub hidden_ignored: bool,
+  /// Keep the `raw` comment the JSDoc was parsed from, as written and with
+  /// its location, for tools which re-render or lint the comments.
+  pub raw_js_doc: bool,
+}
+
 pub struct DocParser<'a> {
   graph: &'a ModuleGraph,
-  private: bool,
+  options: DocParserOptions,
+  /// The visibility filter of the options, or else the one given by their
+  /// `private`.
+  visibility: VisibilityFilter,
   root_symbol: deno_graph::type_tracer::RootSymbol,
   private_types_in_public: RefCell<HashSet<Location>>,
+  unresolved_see_targets: RefCell<HashSet<(Location, String)>>,
+  parse_errors: RefCell<Vec<DocDiagnostic>>,
+  module_metrics: RefCell<Vec<ModuleParseMetrics>>,
+  symbol_aliases: RefCell<HashSet<SymbolAlias>>,
+  /// The modules doc nodes were taken from by the parses with reexports.
+  reexported_modules: RefCell<BTreeSet<ModuleSpecifier>>,
+  /// The types referenced by the doc nodes of the parses with reexports.
+  referenced_types: RefCell<Vec<ReferencedType>>,
+  /// The module docs extracted by the previous parses, by the specifier of
+  /// the module and whether it was parsed as the target of a namespace
+  /// reexport, which are reused when a module is parsed again.
+  module_docs: RefCell<HashMap<(ModuleSpecifier, bool), ModuleDoc>>,
+  /// The number of parses which reused one of `module_docs`.
+  cache_hits: Cell<usize>,
 }
 
 impl<'a> DocParser<'a> {
@@ -133,6 +547,21 @@ impl<'a> DocParser<'a> {
     graph: &'a ModuleGraph,
     private: bool,
     parser: CapturingModuleParser,
+  ) -> Result<Self, anyhow::Error> {
+    Self::new_with_options(
+      graph,
+      parser,
+      DocParserOptions {
+        private,
+        ..Default::default()
+      },
+    )
+  }
+
+  pub fn new_with_options(
+    graph: &'a ModuleGraph,
+    parser: CapturingModuleParser,
+    mut options: DocParserOptions,
   ) -> Result<Self, anyhow::Error> {
     struct NullTypeTraceHandler;
 
@@ -151,44 +580,393 @@ impl<'a> DocParser<'a> {
       &NullTypeTraceHandler,
     )?;
 
+    let visibility = options.visibility.take().unwrap_or(VisibilityFilter {
+      non_exported: options.private,
+      ..Default::default()
+    });
+    options.private = visibility.non_exported;
+    options.reachable_only &= !options.private;
+
     Ok(DocParser {
       graph,
-      private,
+      options,
+      visibility,
       root_symbol,
       private_types_in_public: Default::default(),
+      unresolved_see_targets: Default::default(),
+      parse_errors: Default::default(),
+      module_metrics: Default::default(),
+      symbol_aliases: Default::default(),
+      reexported_modules: Default::default(),
+      referenced_types: Default::default(),
+      module_docs: Default::default(),
+      cache_hits: Default::default(),
     })
   }
 
   /// Gets diagnostics found during any of the previous parses.
   pub fn diagnostics(&self) -> Vec<DocDiagnostic> {
     let private_types_in_public = self.private_types_in_public.borrow();
-    let mut diagnostics = Vec::with_capacity(private_types_in_public.len());
+    let unresolved_see_targets = self.unresolved_see_targets.borrow();
+    let mut diagnostics = Vec::with_capacity(
+      private_types_in_public.len() + unresolved_see_targets.len(),
+    );
     for location in private_types_in_public.iter() {
+      let mut location = location.clone();
+      self.encode_location(&mut location);
       diagnostics.push(DocDiagnostic {
-        location: location.clone(),
+        location,
         kind: DocDiagnosticKind::PrivateTypeRef,
       });
     }
-    diagnostics.sort_by(|a, b| a.location.cmp(&b.location));
+    for (location, target) in unresolved_see_targets.iter() {
+      let mut location = location.clone();
+      self.encode_location(&mut location);
+      diagnostics.push(DocDiagnostic {
+        location,
+        kind: DocDiagnosticKind::UnresolvedSeeTarget(target.clone()),
+      });
+    }
+    if self.options.import_diagnostics {
+      diagnostics.extend(self.get_import_diagnostics());
+    }
+    diagnostics.extend(self.parse_errors.borrow().iter().cloned());
+    diagnostics.sort_by(|a, b| {
+      a.location
+        .cmp(&b.location)
+        .then_with(|| a.kind.to_string().cmp(&b.kind.to_string()))
+    });
+    diagnostics
+  }
+
+  /// Gets the symbols renamed by exports during any of the previous parses,
+  /// sorted by the exporting module and name.
+  pub fn symbol_aliases(&self) -> Vec<SymbolAlias> {
+    let mut aliases = self
+      .symbol_aliases
+      .borrow()
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>();
+    aliases.sort();
+    aliases
+  }
+
+  /// Records the export of the doc node of a definition under a new name.
+  fn record_symbol_alias(
+    &self,
+    specifier: &ModuleSpecifier,
+    name: &str,
+    original_specifier: &ModuleSpecifier,
+    original_name: &str,
+  ) {
+    if name == original_name {
+      return;
+    }
+    self.symbol_aliases.borrow_mut().insert(SymbolAlias {
+      specifier: specifier.clone(),
+      name: name.to_string(),
+      original_specifier: original_specifier.clone(),
+      original_name: original_name.to_string(),
+    });
+  }
+
+  /// Gets the types declared in other modules which are referenced by the
+  /// doc nodes of the previous parses with reexports, but not documented by
+  /// them, when the `referenced_types` option is set.
+  pub fn referenced_types(&self) -> Vec<ReferencedType> {
+    self.referenced_types.borrow().clone()
+  }
+
+  /// Gets the metrics of all of the previous parses.
+  pub fn metrics(&self) -> DocParserMetrics {
+    let modules = self.module_metrics.borrow().clone();
+    DocParserMetrics {
+      total_duration: modules.iter().map(|module| module.duration).sum(),
+      node_count: modules.iter().map(|module| module.node_count).sum(),
+      diagnostic_count: self.diagnostics().len(),
+      cache_hits: self.cache_hits.get(),
+      modules,
+    }
+  }
+
+  /// Gets a diagnostic for every import of an ES module of the graph which
+  /// could not be resolved, or whose module could not be loaded.
+  fn get_import_diagnostics(&self) -> Vec<DocDiagnostic> {
+    let mut seen = HashSet::new();
+    let mut diagnostics = Vec::new();
+    for module in self.graph.modules() {
+      let Module::Esm(module) = module else {
+        continue;
+      };
+      for (specifier, dep) in &module.dependencies {
+        for resolution in [&dep.maybe_code, &dep.maybe_type] {
+          let (range, message) = match resolution {
+            Resolution::Ok(resolved) => {
+              let resolved_specifier = self.graph.resolve(&resolved.specifier);
+              match self.graph.try_get(&resolved_specifier) {
+                Err(err) => (&resolved.range, err.to_string()),
+                Ok(_) => continue,
+              }
+            }
+            Resolution::Err(err) => (err.range(), err.to_string()),
+            Resolution::None => continue,
+          };
+          let mut location = self.get_range_location(range);
+          if !seen.insert((location.clone(), specifier.clone())) {
+            continue;
+          }
+          self.encode_location(&mut location);
+          diagnostics.push(DocDiagnostic {
+            location,
+            kind: DocDiagnosticKind::UnresolvedImport {
+              specifier: specifier.clone(),
+              message,
+            },
+          });
+        }
+      }
+    }
     diagnostics
   }
 
+  /// Converts the start of a range of the graph to a location.
+  fn get_range_location(&self, range: &deno_graph::Range) -> Location {
+    match self.root_symbol.get_module_from_specifier(&range.specifier) {
+      Some(module_symbol) => {
+        let text_info = module_symbol.text_info();
+        let pos = text_info.loc_to_source_pos(LineAndColumnIndex {
+          line_index: range.start.line,
+          column_index: range.start.character,
+        });
+        get_text_info_location(range.specifier.as_str(), text_info, pos)
+      }
+      None => Location {
+        filename: range.specifier.to_string(),
+        line: range.start.line + 1,
+        col: range.start.character,
+        requested_filename: None,
+      },
+    }
+  }
+
+  /// Lists the JavaScript, TypeScript and JSON modules of the graph with
+  /// their module documentation, as well as the assets, sorted by specifier.
+  pub fn modules(&self) -> Vec<ModuleInfo> {
+    let mut modules = Vec::new();
+    for module in self.graph.modules() {
+      if !self.options.module_filter.includes(module.specifier()) {
+        continue;
+      }
+      match module {
+        Module::Esm(module) => {
+          let module_doc = self
+            .get_module_symbol(&module.specifier)
+            .ok()
+            .and_then(|module_symbol| module_symbol.esm())
+            .and_then(|module_symbol| {
+              module_js_doc_for_source(module_symbol.source())
+            })
+            .flatten()
+            .map(|(js_doc, _)| js_doc);
+          modules.push(ModuleInfo {
+            specifier: module.specifier.clone(),
+            media_type: module.media_type,
+            module_doc,
+            asset: false,
+          });
+        }
+        Module::Json(module) => modules.push(ModuleInfo {
+          specifier: module.specifier.clone(),
+          media_type: MediaType::Json,
+          module_doc: None,
+          asset: false,
+        }),
+        Module::External(module) => modules.push(ModuleInfo {
+          specifier: module.specifier.clone(),
+          media_type: MediaType::from_specifier(&module.specifier),
+          module_doc: None,
+          asset: true,
+        }),
+        _ => {}
+      }
+    }
+    for (specifier, result) in self.graph.specifiers() {
+      if let Err(ModuleError::UnsupportedMediaType(_, media_type, ..)) = result
+      {
+        if self.options.module_filter.includes(specifier) {
+          modules.push(ModuleInfo {
+            specifier: specifier.clone(),
+            media_type: *media_type,
+            module_doc: None,
+            asset: true,
+          });
+        }
+      }
+    }
+    modules.sort_by(|a, b| a.specifier.cmp(&b.specifier));
+    modules
+  }
+
   /// Parses a module into a list of exported items,
   /// as well as a list of reexported items which need to be fetched from other modules.
   pub fn parse_module(
     &self,
     specifier: &ModuleSpecifier,
   ) -> Result<ModuleDoc, DocError> {
-    let module_symbol = self.get_module_symbol(specifier)?;
-    let definitions = self.get_doc_nodes_for_module_symbol(module_symbol)?;
-    let reexports = self.get_reexports_for_module(module_symbol);
-    let module_doc = ModuleDoc {
-      definitions,
-      reexports,
+    if self.skip_unparsable_module(specifier)
+      || self.is_asset_module(specifier)
+      || !self.options.module_filter.includes(specifier)
+    {
+      return Ok(ModuleDoc {
+        definitions: Vec::new(),
+        reexports: Vec::new(),
+        referenced_types: Vec::new(),
+        dynamic_imports: Vec::new(),
+      });
+    }
+    let mut module_doc = self.parse_module_inner(specifier, false)?;
+    if self.options.dynamic_imports {
+      module_doc.dynamic_imports = self.get_dynamic_imports(specifier)?;
+    }
+    self.finish_doc_nodes(&mut module_doc.definitions);
+    module_doc.referenced_types =
+      self.finish_referenced_types(module_doc.referenced_types);
+    for dynamic_import in &mut module_doc.dynamic_imports {
+      self.finish_doc_nodes(&mut dynamic_import.definitions);
+    }
+    self.check_strict()?;
+    Ok(module_doc)
+  }
+
+  /// Parses a module, either directly or as the target of a namespace
+  /// reexport, which decides whether its `@ignore` tag applies.
+  fn parse_module_inner(
+    &self,
+    specifier: &ModuleSpecifier,
+    namespace_reexport: bool,
+  ) -> Result<ModuleDoc, DocError> {
+    trace_span!(DEBUG, "parse_module", specifier = %specifier);
+    #[cfg(not(target_arch = "wasm32"))]
+    let start = std::time::Instant::now();
+    let key = (specifier.clone(), namespace_reexport);
+    let cached = self.module_docs.borrow().get(&key).cloned();
+    let module_doc = if let Some(module_doc) = cached {
+      self.cache_hits.set(self.cache_hits.get() + 1);
+      module_doc
+    } else {
+      let module_symbol = self.get_module_symbol(specifier)?;
+      let definitions = self
+        .get_doc_nodes_for_module_symbol(module_symbol, namespace_reexport)?;
+      let reexports = self.get_reexports_for_module(module_symbol);
+      let referenced_types = if self.options.referenced_types {
+        self.get_referenced_types(module_symbol, &definitions)?
+      } else {
+        Vec::new()
+      };
+      let module_doc = ModuleDoc {
+        definitions,
+        reexports,
+        referenced_types,
+        dynamic_imports: Vec::new(),
+      };
+      self
+        .module_docs
+        .borrow_mut()
+        .insert(key, module_doc.clone());
+      module_doc
     };
+    #[cfg(not(target_arch = "wasm32"))]
+    let duration = start.elapsed();
+    #[cfg(target_arch = "wasm32")]
+    let duration = Duration::ZERO;
+    self.module_metrics.borrow_mut().push(ModuleParseMetrics {
+      specifier: specifier.clone(),
+      duration,
+      node_count: count_doc_nodes(&module_doc.definitions),
+    });
     Ok(module_doc)
   }
 
+  /// Whether the module doc of a module is tagged with `@ignore`.
+  fn is_module_ignored(&self, specifier: &ModuleSpecifier) -> bool {
+    self
+      .get_module_symbol(specifier)
+      .ok()
+      .and_then(|module_symbol| module_symbol.esm())
+      .map(|module_symbol| {
+        matches!(module_js_doc_for_source(module_symbol.source()), Some(None))
+      })
+      .unwrap_or(false)
+  }
+
+  /// Whether a module tagged with `@ignore` is left out in the context it
+  /// is documented in.
+  fn ignores_module(&self, namespace_reexport: bool) -> bool {
+    if namespace_reexport {
+      self.options.module_ignore.namespace_reexports
+    } else {
+      self.options.module_ignore.direct
+    }
+  }
+
+  /// Returns `true` for a dependency which can't be documented, so that it
+  /// is skipped instead of failing the parse: an asset module and, when
+  /// reporting import diagnostics, a module which could not be loaded, as
+  /// its import is already reported as unresolved.
+  fn skip_unloadable_module(&self, specifier: &ModuleSpecifier) -> bool {
+    self.is_asset_module(specifier)
+      || (self.options.import_diagnostics
+        && !matches!(
+          self.graph.try_get(specifier),
+          Ok(Some(_)) | Err(ModuleError::ParseErr(..))
+        ))
+  }
+
+  /// Whether the module is an asset which can't be documented, like a CSS or
+  /// WebAssembly module imported for its side effects, or an external
+  /// module.
+  fn is_asset_module(&self, specifier: &ModuleSpecifier) -> bool {
+    matches!(
+      self.graph.try_get(specifier),
+      Ok(Some(Module::External(_)))
+        | Err(ModuleError::UnsupportedMediaType(..))
+    )
+  }
+
+  /// When recovering from parse errors, records a diagnostic for a module
+  /// of the graph which has a syntax error and returns `true`, so that it is
+  /// skipped.
+  fn skip_unparsable_module(&self, specifier: &ModuleSpecifier) -> bool {
+    if !self.options.recover_parse_errors {
+      return false;
+    }
+    let Err(ModuleError::ParseErr(specifier, diagnostic)) =
+      self.graph.try_get(specifier)
+    else {
+      return false;
+    };
+    let mut parse_errors = self.parse_errors.borrow_mut();
+    if !parse_errors
+      .iter()
+      .any(|d| d.location.filename == specifier.as_str())
+    {
+      parse_errors.push(DocDiagnostic {
+        location: Location {
+          filename: specifier.to_string(),
+          line: diagnostic.display_position.line_number,
+          col: diagnostic.display_position.column_number - 1,
+          requested_filename: None,
+        },
+        kind: DocDiagnosticKind::ParseError {
+          media_type: MediaType::from_specifier(specifier),
+          message: diagnostic.message().to_string(),
+        },
+      });
+    }
+    true
+  }
+
   fn get_module_symbol(
     &self,
     specifier: &ModuleSpecifier,
@@ -216,17 +994,320 @@ impl<'a> DocParser<'a> {
     &self,
     specifier: &ModuleSpecifier,
   ) -> Result<Vec<DocNode>, DocError> {
-    self.parse_with_reexports_inner(specifier, HashSet::new())
+    let referenced_types_start = self.referenced_types.borrow().len();
+    let mut doc_nodes =
+      self.parse_with_reexports_inner(specifier, HashSet::new(), false)?;
+    let mut referenced_types = self
+      .referenced_types
+      .borrow_mut()
+      .split_off(referenced_types_start);
+    // leave out the types which are documented, as they are reexported
+    let mut locations = HashSet::new();
+    collect_locations(&doc_nodes, &mut locations);
+    referenced_types.retain(|referenced_type| {
+      locations.insert(referenced_type.doc_node.location.clone())
+    });
+    self.finish_doc_nodes(&mut doc_nodes);
+    let referenced_types = self.finish_referenced_types(referenced_types);
+    self.referenced_types.borrow_mut().extend(referenced_types);
+    self.check_strict()?;
+    Ok(doc_nodes)
+  }
+
+  /// Parses a module and resolves its reexports, like
+  /// [`DocParser::parse_with_reexports`], also returning a summary of the
+  /// modules the doc nodes were taken from.
+  pub fn parse_with_reexports_and_stats(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Result<(Vec<DocNode>, GraphStats), DocError> {
+    self.reexported_modules.borrow_mut().clear();
+    let doc_nodes = self.parse_with_reexports(specifier)?;
+    let modules = self.reexported_modules.take();
+    Ok((doc_nodes, self.get_graph_stats(&modules)))
+  }
+
+  fn get_graph_stats(&self, modules: &BTreeSet<ModuleSpecifier>) -> GraphStats {
+    let mut stats = GraphStats::default();
+    for specifier in modules {
+      let (media_type, bytes) = match self.graph.get(specifier) {
+        Some(Module::Esm(module)) => (module.media_type, module.source.len()),
+        Some(Module::Json(module)) => (MediaType::Json, module.source.len()),
+        _ => continue,
+      };
+      stats.module_count += 1;
+      if is_remote(specifier.as_str()) {
+        stats.remote_count += 1;
+      } else {
+        stats.local_count += 1;
+      }
+      stats.total_bytes += bytes;
+      *stats.media_types.entry(media_type.to_string()).or_default() += 1;
+    }
+    stats
+  }
+
+  /// In strict mode, fails with the diagnostics selected by it.
+  fn check_strict(&self) -> Result<(), DocError> {
+    let Some(strict) = &self.options.strict else {
+      return Ok(());
+    };
+    let diagnostics = self
+      .diagnostics()
+      .into_iter()
+      .filter(|diagnostic| strict.fails_on(diagnostic))
+      .collect::<Vec<_>>();
+    if diagnostics.is_empty() {
+      Ok(())
+    } else {
+      Err(DocError::Strict(diagnostics))
+    }
+  }
+
+  /// Parses every root of a package, resolving reexports, for example those
+  /// read from its manifest with [`crate::package_roots`]. The roots have to
+  /// be roots of the graph.
+  pub fn parse_package_roots(
+    &self,
+    roots: &[PackageRoot],
+  ) -> Result<Vec<(PackageRoot, Vec<DocNode>)>, DocError> {
+    roots
+      .iter()
+      .map(|root| {
+        let doc_nodes = self.parse_with_reexports(&root.specifier)?;
+        Ok((root.clone(), doc_nodes))
+      })
+      .collect()
+  }
+
+  /// Expands the doc node of a namespace import, like
+  /// `import * as ns from "./dep.ts"`, into a namespace of the exports of the
+  /// imported module, resolving its reexports, so types which refer to `ns`
+  /// can be rendered with its members. The module doc of the imported module
+  /// is used unless the import is documented. Returns `None` for other doc
+  /// nodes.
+  pub fn expand_namespace_import(
+    &self,
+    doc_node: &DocNode,
+  ) -> Result<Option<DocNode>, DocError> {
+    let Some(import_def) = doc_node.import_def.as_ref().filter(|i| i.namespace)
+    else {
+      return Ok(None);
+    };
+    let specifier = ModuleSpecifier::parse(&import_def.src)
+      .map_err(|err| DocError::Resolve(err.to_string()))?;
+    let doc_nodes = self.parse_with_reexports(&specifier)?;
+    let mut js_doc = JsDoc::default();
+    for doc_node in &doc_nodes {
+      if matches!(doc_node.kind, DocNodeKind::ModuleDoc) {
+        js_doc = doc_node.js_doc.clone();
+      }
+    }
+    merge_js_doc(&mut js_doc, &doc_node.js_doc);
+    let ns_def = NamespaceDef {
+      elements: doc_nodes
+        .into_iter()
+        .filter(|dn| !matches!(dn.kind, DocNodeKind::ModuleDoc))
+        .collect(),
+      ambient_module: false,
+    };
+    Ok(Some(DocNode::namespace(
+      doc_node.name.clone(),
+      doc_node.location.clone(),
+      doc_node.declaration_kind,
+      js_doc,
+      ns_def,
+    )))
+  }
+
+  /// Applies the output options of this parser to the doc nodes returned by
+  /// it.
+  fn finish_doc_nodes(&self, doc_nodes: &mut Vec<DocNode>) {
+    if self.options.overload_js_doc_fallback {
+      share_overload_js_docs(doc_nodes);
+    }
+    // before filtering, so documentation can be inherited from hidden bases
+    if self.options.effective_js_doc {
+      set_effective_js_docs(doc_nodes);
+    }
+    if let Some(inherited_members) = &self.options.inherited_members {
+      set_inherited_members(doc_nodes, inherited_members);
+    }
+    self.apply_visibility(doc_nodes);
+    if self.options.hide_overload_implementations {
+      remove_overload_implementations(doc_nodes);
+    }
+    self
+      .unresolved_see_targets
+      .borrow_mut()
+      .extend(unresolved_see_targets(doc_nodes));
+    if let Some(member_order) = self.options.member_order {
+      order_members(doc_nodes, member_order);
+    }
+    if self.options.chainable_methods {
+      set_chainable_methods(doc_nodes);
+    }
+    if self.options.events {
+      set_events(doc_nodes);
+    }
+    if self.options.stability {
+      set_stability(doc_nodes);
+    }
+    if self.options.release_tags {
+      set_release_tags(doc_nodes, None, self.options.min_release_tag);
+    }
+    if self.options.param_docs {
+      set_param_docs(doc_nodes);
+    }
+    if self.options.inline_links {
+      set_doc_segments(doc_nodes);
+    }
+    if !self.options.raw_js_doc {
+      clear_raw_js_docs(doc_nodes);
+    }
+    self.record_redirects(doc_nodes);
+    self.encode_locations(doc_nodes);
+    if self.options.qualified_names {
+      set_qualified_names(doc_nodes, None);
+    }
+  }
+
+  /// Applies the output options of this parser to the doc nodes of the
+  /// referenced types, leaving out the types whose doc node is filtered out.
+  fn finish_referenced_types(
+    &self,
+    referenced_types: Vec<ReferencedType>,
+  ) -> Vec<ReferencedType> {
+    referenced_types
+      .into_iter()
+      .filter_map(|referenced_type| {
+        let mut doc_nodes = vec![referenced_type.doc_node];
+        self.finish_doc_nodes(&mut doc_nodes);
+        Some(ReferencedType {
+          doc_node: doc_nodes.pop()?,
+          ..referenced_type
+        })
+      })
+      .collect()
+  }
+
+  /// Removes the nodes and members excluded by the visibility filter.
+  fn apply_visibility(&self, doc_nodes: &mut Vec<DocNode>) {
+    let visibility = &self.visibility;
+    let is_visible = |js_doc: &JsDoc| {
+      visibility.internal || !js_doc.tags.contains(&JsDocTag::Internal)
+    };
+    let is_member_visible = |js_doc: &JsDoc, name: &str| {
+      is_visible(js_doc)
+        && (visibility.private_members || !name.starts_with('#'))
+    };
+    doc_nodes.retain(|doc_node| {
+      is_visible(&doc_node.js_doc)
+        && (visibility.remote || !is_remote(&doc_node.location.filename))
+    });
+    for doc_node in doc_nodes {
+      if let Some(class_def) = &mut doc_node.class_def {
+        class_def
+          .constructors
+          .retain(|member| is_visible(&member.js_doc));
+        class_def
+          .properties
+          .retain(|member| is_member_visible(&member.js_doc, &member.name));
+        class_def
+          .methods
+          .retain(|member| is_member_visible(&member.js_doc, &member.name));
+      }
+      if let Some(interface_def) = &mut doc_node.interface_def {
+        interface_def
+          .properties
+          .retain(|member| is_visible(&member.js_doc));
+        interface_def
+          .methods
+          .retain(|member| is_visible(&member.js_doc));
+        interface_def
+          .call_signatures
+          .retain(|member| is_visible(&member.js_doc));
+      }
+      if let Some(enum_def) = &mut doc_node.enum_def {
+        enum_def.members.retain(|member| is_visible(&member.js_doc));
+      }
+      if let Some(namespace_def) = &mut doc_node.namespace_def {
+        self.apply_visibility(&mut namespace_def.elements);
+      }
+    }
+  }
+
+  /// Records the specifier each location's module was originally requested
+  /// as, when it was redirected. Where several specifiers were redirected to
+  /// the same module, the first of them is used.
+  fn record_redirects(&self, doc_nodes: &mut [DocNode]) {
+    if self.graph.redirects.is_empty() {
+      return;
+    }
+    let mut requested = HashMap::new();
+    for from in self.graph.redirects.keys() {
+      // only the start of a chain of redirects was requested
+      if self.graph.redirects.values().any(|to| to == from) {
+        continue;
+      }
+      requested
+        .entry(self.graph.resolve(from).to_string())
+        .or_insert_with(|| from.to_string());
+    }
+    for doc_node in doc_nodes {
+      doc_node.for_each_location_mut(&mut |location| {
+        location.requested_filename =
+          requested.get(&location.filename).cloned();
+      });
+    }
+  }
+
+  /// Converts the locations of the doc nodes to the position encoding of
+  /// this parser.
+  fn encode_locations(&self, doc_nodes: &mut [DocNode]) {
+    if self.options.position_encoding.is_default() {
+      return;
+    }
+    for doc_node in doc_nodes {
+      doc_node
+        .for_each_location_mut(&mut |location| self.encode_location(location));
+    }
+  }
+
+  fn encode_location(&self, location: &mut Location) {
+    if self.options.position_encoding.is_default() {
+      return;
+    }
+    let Ok(specifier) = ModuleSpecifier::parse(&location.filename) else {
+      return;
+    };
+    if let Some(module_symbol) =
+      self.root_symbol.get_module_from_specifier(&specifier)
+    {
+      encode_location(
+        location,
+        module_symbol.text_info(),
+        self.options.position_encoding,
+      );
+    }
   }
 
   fn parse_with_reexports_inner(
     &self,
     specifier: &ModuleSpecifier,
     mut visited: HashSet<ModuleSpecifier>,
+    namespace_reexport: bool,
   ) -> Result<Vec<DocNode>, DocError> {
     if !visited.insert(specifier.clone()) {
       return Ok(Vec::new()); // circular
     }
+    trace_span!(DEBUG, "parse_with_reexports", specifier = %specifier);
+    if self.skip_unparsable_module(specifier)
+      || self.is_asset_module(specifier)
+      || !self.options.module_filter.includes(specifier)
+    {
+      return Ok(Vec::new());
+    }
     let module = self
       .graph
       .try_get(specifier)
@@ -244,6 +1325,11 @@ impl<'a> DocParser<'a> {
         .and_then(|d| d.dependency.ok())
         .map(|r| &r.specifier)
     }) {
+      if self.skip_unparsable_module(specifier)
+        || self.skip_unloadable_module(specifier)
+      {
+        return Ok(Vec::new());
+      }
       self
         .graph
         .try_get(specifier)
@@ -259,17 +1345,34 @@ impl<'a> DocParser<'a> {
     };
 
     match module {
-      Module::Json(module) => Ok(
-        parse_json_module_doc_node(&module.specifier, &module.source)
-          .map(|n| vec![n])
-          .unwrap_or_default(),
-      ),
+      Module::Json(module) => {
+        self
+          .reexported_modules
+          .borrow_mut()
+          .insert(module.specifier.clone());
+        Ok(
+          parse_json_module_doc_node(&module.specifier, &module.source)
+            .map(|n| vec![n])
+            .unwrap_or_default(),
+        )
+      }
       Module::Esm(module) => {
-        let module_doc = self.parse_module(&module.specifier)?;
+        self
+          .reexported_modules
+          .borrow_mut()
+          .insert(module.specifier.clone());
+        let module_doc =
+          self.parse_module_inner(&module.specifier, namespace_reexport)?;
         let mut flattened_docs = Vec::new();
         let module_symbol = self.get_module_symbol(&module.specifier)?;
         let exports = module_symbol.exports(self.graph, &self.root_symbol);
         for (export_name, (export_module, export_symbol_id)) in exports {
+          let maybe_reexport_js_doc = module_symbol
+            .esm()
+            .and_then(|m| reexport_js_doc(m.source(), &export_name));
+          if matches!(maybe_reexport_js_doc, Some(None)) {
+            continue; // the export statement is tagged with `@ignore`
+          }
           let export_symbol = export_module.symbol(export_symbol_id).unwrap();
           let definitions = self.root_symbol.go_to_definitions(
             self.graph,
@@ -286,8 +1389,22 @@ impl<'a> DocParser<'a> {
                   &file_dep.specifier,
                   first_def.module.specifier(),
                 )?;
-                let doc_nodes = self
-                  .parse_with_reexports_inner(&specifier, visited.clone())?;
+                if self.options.module_ignore.namespace_reexports
+                  && self.is_module_ignored(&specifier)
+                {
+                  continue;
+                }
+                if self.skip_unparsable_module(&specifier)
+                  || self.skip_unloadable_module(&specifier)
+                  || !self.options.module_filter.includes(&specifier)
+                {
+                  continue;
+                }
+                let doc_nodes = self.parse_with_reexports_inner(
+                  &specifier,
+                  visited.clone(),
+                  true,
+                )?;
                 // hoist any module doc to be the exported namespaces module doc
                 let mut js_doc = JsDoc::default();
                 for doc_node in &doc_nodes {
@@ -295,12 +1412,16 @@ impl<'a> DocParser<'a> {
                     js_doc = doc_node.js_doc.clone();
                   }
                 }
+                if let Some(Some(reexport_js_doc)) = &maybe_reexport_js_doc {
+                  merge_js_doc(&mut js_doc, reexport_js_doc);
+                }
                 let ns_def = NamespaceDef {
                   elements: doc_nodes
                     .iter()
                     .filter(|dn| !matches!(dn.kind, DocNodeKind::ModuleDoc))
                     .cloned()
                     .collect(),
+                  ambient_module: false,
                 };
                 let ns_doc_node = DocNode::namespace(
                   export_name,
@@ -314,6 +1435,13 @@ impl<'a> DocParser<'a> {
               DefinitionKind::Definition => {
                 if first_def.module.specifier() != module_symbol.specifier() {
                   for definition in definitions {
+                    if !self
+                      .options
+                      .module_filter
+                      .includes(definition.module.specifier())
+                    {
+                      continue;
+                    }
                     let decl = definition.symbol_decl;
                     let maybe_doc = self.doc_for_maybe_node(
                       definition.module,
@@ -321,8 +1449,60 @@ impl<'a> DocParser<'a> {
                       decl.maybe_node(),
                     );
                     if let Some(mut doc_node) = maybe_doc {
-                      doc_node.name = export_name.clone();
+                      self
+                        .reexported_modules
+                        .borrow_mut()
+                        .insert(definition.module.specifier().clone());
+                      if let Some(esm_module) = definition
+                        .module
+                        .esm()
+                        .filter(|_| self.options.dependencies)
+                      {
+                        let imports =
+                          self.get_doc_nodes_for_module_imports(esm_module)?;
+                        set_dependencies(
+                          std::slice::from_mut(&mut doc_node),
+                          &imports,
+                        );
+                      }
+                      if let Some(esm_module) = definition
+                        .module
+                        .esm()
+                        .filter(|_| self.options.referenced_types)
+                      {
+                        let imports =
+                          self.get_doc_nodes_for_module_imports(esm_module)?;
+                        let (mut referenced_types, referenced) = self
+                          .get_module_references(
+                            esm_module,
+                            std::slice::from_ref(&doc_node),
+                            &imports,
+                          );
+                        referenced_types
+                          .extend(self.get_imported_types(referenced)?);
+                        self
+                          .referenced_types
+                          .borrow_mut()
+                          .extend(referenced_types);
+                      }
+                      let original_name = doc_node.name.clone();
+                      set_export_name(
+                        &mut doc_node,
+                        &export_name,
+                        decl.maybe_node(),
+                      );
+                      self.record_symbol_alias(
+                        module_symbol.specifier(),
+                        &doc_node.name,
+                        definition.module.specifier(),
+                        &original_name,
+                      );
                       doc_node.declaration_kind = DeclarationKind::Export;
+                      if let Some(Some(reexport_js_doc)) =
+                        &maybe_reexport_js_doc
+                      {
+                        merge_js_doc(&mut doc_node.js_doc, reexport_js_doc);
+                      }
 
                       flattened_docs.push(doc_node);
                     }
@@ -334,24 +1514,369 @@ impl<'a> DocParser<'a> {
         }
 
         flattened_docs.extend(module_doc.definitions);
+        self
+          .referenced_types
+          .borrow_mut()
+          .extend(module_doc.referenced_types);
+        for doc_node in
+          self.get_import_equals_reexports(module_symbol, &visited)?
+        {
+          if !flattened_docs.iter().any(|dn| {
+            dn.name == doc_node.name && dn.kind != DocNodeKind::Import
+          }) {
+            flattened_docs.push(doc_node);
+          }
+        }
+        for doc_node in self.get_json_star_reexports(module_symbol) {
+          if !flattened_docs.iter().any(|dn| dn.name == doc_node.name) {
+            flattened_docs.push(doc_node);
+          }
+        }
         Ok(flattened_docs)
       }
       Module::Npm(_) | Module::Node(_) | Module::External(_) => Ok(vec![]),
     }
   }
 
-  fn get_doc_nodes_for_module_imports(
+  /// Gets the doc nodes of the modules which a module reexports through a
+  /// CommonJS import: a namespace for `export import lib = require("./lib")`,
+  /// and the exports of the imported module for
+  /// `import lib = require("./lib"); export = lib;`, which documents them as
+  /// the exports of the module.
+  fn get_import_equals_reexports(
     &self,
-    module_symbol: &EsmModuleSymbol,
+    module_symbol: ModuleSymbolRef,
+    visited: &HashSet<ModuleSpecifier>,
   ) -> Result<Vec<DocNode>, DocError> {
+    let Some(module_symbol) = module_symbol.esm() else {
+      return Ok(Vec::new());
+    };
     let parsed_source = module_symbol.source();
-    let referrer = module_symbol.specifier();
+    let assigned_name = export_assignment_name(parsed_source);
+    let mut doc_nodes = Vec::new();
+    for item in &parsed_source.module().body {
+      let ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import_equals)) =
+        item
+      else {
+        continue;
+      };
+      let TsModuleRef::TsExternalModuleRef(module_ref) =
+        &import_equals.module_ref
+      else {
+        continue;
+      };
+      let is_assigned =
+        assigned_name.as_deref() == Some(&*import_equals.id.sym);
+      if !import_equals.is_export && !is_assigned {
+        continue;
+      }
+      let Ok(specifier) = self
+        .resolve_dependency(&module_ref.expr.value, module_symbol.specifier())
+      else {
+        continue;
+      };
+      if self.skip_unparsable_module(&specifier)
+        || self.skip_unloadable_module(&specifier)
+        || !self.options.module_filter.includes(&specifier)
+      {
+        continue;
+      }
+      let Some(js_doc) =
+        self.js_doc_for_range(parsed_source, &import_equals.range())
+      else {
+        continue; // the import is tagged with `@ignore`
+      };
+      let elements = self
+        .parse_with_reexports_inner(&specifier, visited.clone(), !is_assigned)?
+        .into_iter()
+        .filter(|dn| !matches!(dn.kind, DocNodeKind::ModuleDoc))
+        .collect::<Vec<_>>();
+      if is_assigned {
+        doc_nodes.extend(elements);
+      } else {
+        doc_nodes.push(DocNode::namespace(
+          import_equals.id.sym.to_string(),
+          get_location(parsed_source, import_equals.start()),
+          DeclarationKind::Export,
+          js_doc,
+          NamespaceDef {
+            elements,
+            ambient_module: false,
+          },
+        ));
+      }
+    }
+    Ok(doc_nodes)
+  }
+
+  /// Gets a doc node for every top-level property of the JSON modules which
+  /// a module reexports with `export * from "./data.json"`, typed like the
+  /// default export of a JSON module.
+  fn get_json_star_reexports(
+    &self,
+    module_symbol: ModuleSymbolRef,
+  ) -> Vec<DocNode> {
+    let Some(module_symbol) = module_symbol.esm() else {
+      return Vec::new();
+    };
+    let mut doc_nodes = Vec::new();
+    for item in &module_symbol.source().module().body {
+      let ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export_all)) = item
+      else {
+        continue;
+      };
+      let Ok(specifier) = self
+        .resolve_dependency(&export_all.src.value, module_symbol.specifier())
+      else {
+        continue;
+      };
+      if let Ok(Some(Module::Json(module))) = self.graph.try_get(&specifier) {
+        self
+          .reexported_modules
+          .borrow_mut()
+          .insert(module.specifier.clone());
+        doc_nodes.extend(parse_json_module_export_doc_nodes(
+          &module.specifier,
+          &module.source,
+        ));
+      }
+    }
+    doc_nodes
+  }
+
+  /// Gets the types imported from other modules which are referenced by
+  /// `doc_nodes`, following the imports of those types' modules in turn.
+  /// Types which are exported by `module_symbol` are skipped, as they are
+  /// documented as part of it.
+  fn get_referenced_types(
+    &self,
+    module_symbol: ModuleSymbolRef,
+    doc_nodes: &[DocNode],
+  ) -> Result<Vec<ReferencedType>, DocError> {
+    let exports = module_symbol.exports(self.graph, &self.root_symbol);
+    let imports = referenced_imports(doc_nodes, doc_nodes)
+      .into_iter()
+      .filter(|(type_name, _)| {
+        !exports.contains_key(type_name.split('.').next().unwrap())
+      })
+      .map(|(_, import)| import)
+      .collect();
+    self.get_imported_types(imports)
+  }
+
+  /// Gets the exports of other modules given by their specifier and name as
+  /// referenced types, along with the private declarations of those modules
+  /// which they refer to, following the imports of those in turn.
+  fn get_imported_types(
+    &self,
+    mut pending: Vec<(String, String)>,
+  ) -> Result<Vec<ReferencedType>, DocError> {
+    let mut visited = HashSet::new();
+    let mut locations = HashSet::new();
+    let mut imported_types = Vec::new();
+    while let Some((src, name)) = pending.pop() {
+      if !visited.insert((src.clone(), name.clone())) {
+        continue;
+      }
+      let Ok(specifier) = ModuleSpecifier::parse(&src) else {
+        continue;
+      };
+      let Ok(module_symbol) = self.get_module_symbol(&specifier) else {
+        continue;
+      };
+      let Some(esm_module_symbol) = module_symbol.esm() else {
+        continue;
+      };
+      let doc_nodes = self.get_doc_nodes_for_export(module_symbol, &name);
+      let imports = self.get_doc_nodes_for_module_imports(esm_module_symbol)?;
+      let (private_types, referenced) =
+        self.get_module_references(esm_module_symbol, &doc_nodes, &imports);
+      pending.extend(referenced);
+      let exported_types = doc_nodes.into_iter().map(|doc_node| {
+        referenced_type(src.clone(), name.clone(), doc_node, &imports)
+      });
+      imported_types.extend(exported_types.chain(private_types).filter(
+        |referenced_type| {
+          locations.insert(referenced_type.doc_node.location.clone())
+        },
+      ));
+    }
+    Ok(imported_types)
+  }
+
+  /// Gets the private declarations of a module which `doc_nodes` refer to as
+  /// referenced types, along with the exports of other modules which those
+  /// and `doc_nodes` refer to through `imports`, the imports of the module.
+  fn get_module_references(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    doc_nodes: &[DocNode],
+    imports: &[DocNode],
+  ) -> (Vec<ReferencedType>, Vec<(String, String)>) {
+    let src = module_symbol.specifier().to_string();
+    let private_types = self
+      .get_reachable_private_doc_nodes(
+        module_symbol,
+        &self.get_exported_symbol_ids(module_symbol),
+        doc_nodes,
+      )
+      .into_iter()
+      .map(|doc_node| {
+        referenced_type(src.clone(), doc_node.name.clone(), doc_node, imports)
+      })
+      .collect::<Vec<_>>();
+    let referenced = referenced_imports(doc_nodes, imports)
+      .into_iter()
+      .map(|(_, import)| import)
+      .chain(
+        private_types
+          .iter()
+          .flat_map(|private_type| private_type.imports.values().cloned()),
+      )
+      .collect();
+    (private_types, referenced)
+  }
+
+  /// The symbols of the module which are exported, either directly or as the
+  /// definitions of its exports.
+  fn get_exported_symbol_ids(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+  ) -> HashSet<SymbolId> {
+    let mut symbol_ids = HashSet::new();
+    let exports = module_symbol.exports(self.graph, &self.root_symbol);
+    for (export_module, export_symbol_id) in exports.values() {
+      symbol_ids.insert(*export_symbol_id);
+      let export_symbol = export_module.symbol(*export_symbol_id).unwrap();
+      let definitions = self.root_symbol.go_to_definitions(
+        self.graph,
+        *export_module,
+        export_symbol,
+      );
+      for definition in definitions {
+        if definition.module.specifier() == module_symbol.specifier() {
+          symbol_ids.insert(definition.symbol.symbol_id());
+        }
+      }
+    }
+    symbol_ids
+  }
+
+  /// Gets the exports of the modules which are only imported dynamically by
+  /// a module, in the order of the imports.
+  fn get_dynamic_imports(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Result<Vec<DynamicImport>, DocError> {
+    let Some(module) = self.graph.get(specifier).and_then(|m| m.esm()) else {
+      return Ok(Vec::new());
+    };
+    let static_imports = module
+      .dependencies
+      .values()
+      .filter(|dep| !dep.is_dynamic)
+      .filter_map(|dep| dep.get_type().or_else(|| dep.get_code()))
+      .collect::<HashSet<_>>();
+    let mut dynamic_imports = Vec::new();
+    let mut seen = HashSet::new();
+    for dep in module.dependencies.values() {
+      if !dep.is_dynamic {
+        continue;
+      }
+      let Some(dep_specifier) = dep.get_type().or_else(|| dep.get_code())
+      else {
+        continue;
+      };
+      if dep_specifier == specifier
+        || static_imports.contains(dep_specifier)
+        || !seen.insert(dep_specifier)
+      {
+        continue;
+      }
+      let resolved = self.graph.resolve(dep_specifier);
+      if !matches!(self.graph.try_get(&resolved), Ok(Some(_))) {
+        continue; // not loaded, or failed to load
+      }
+      dynamic_imports.push(DynamicImport {
+        src: resolved.to_string(),
+        definitions: self.parse_with_reexports_inner(
+          &resolved,
+          HashSet::new(),
+          false,
+        )?,
+      });
+    }
+    Ok(dynamic_imports)
+  }
+
+  /// Gets the doc nodes of the definitions of an export of a module.
+  fn get_doc_nodes_for_export(
+    &self,
+    module_symbol: ModuleSymbolRef,
+    export_name: &str,
+  ) -> Vec<DocNode> {
+    let exports = module_symbol.exports(self.graph, &self.root_symbol);
+    let Some((export_module, export_symbol_id)) = exports.get(export_name)
+    else {
+      return Vec::new();
+    };
+    let export_symbol = export_module.symbol(*export_symbol_id).unwrap();
+    let definitions = self.root_symbol.go_to_definitions(
+      self.graph,
+      *export_module,
+      export_symbol,
+    );
+    let mut doc_nodes = Vec::new();
+    for definition in definitions {
+      use deno_graph::type_tracer::DefinitionKind;
+      if !matches!(definition.kind, DefinitionKind::Definition) {
+        continue;
+      }
+      let maybe_doc = self.doc_for_maybe_node(
+        definition.module,
+        definition.symbol,
+        definition.symbol_decl.maybe_node(),
+      );
+      if let Some(mut doc_node) = maybe_doc {
+        let original_name = doc_node.name.clone();
+        set_export_name(
+          &mut doc_node,
+          export_name,
+          definition.symbol_decl.maybe_node(),
+        );
+        self.record_symbol_alias(
+          module_symbol.specifier(),
+          &doc_node.name,
+          definition.module.specifier(),
+          &original_name,
+        );
+        doc_node.declaration_kind = DeclarationKind::Export;
+        doc_nodes.push(doc_node);
+      }
+    }
+    doc_nodes
+  }
+
+  fn get_doc_nodes_for_module_imports(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+  ) -> Result<Vec<DocNode>, DocError> {
+    let parsed_source = module_symbol.source();
+    let referrer = module_symbol.specifier();
     let mut imports = vec![];
 
     for node in &parsed_source.module().body {
-      if let deno_ast::swc::ast::ModuleItem::ModuleDecl(ModuleDecl::Import(
-        import_decl,
-      )) = node
+      if let ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import_equals)) =
+        node
+      {
+        if let Some(doc_node) =
+          self.get_doc_for_import_equals(module_symbol, import_equals)
+        {
+          imports.push(doc_node);
+        }
+      } else if let deno_ast::swc::ast::ModuleItem::ModuleDecl(
+        ModuleDecl::Import(import_decl),
+      ) = node
       {
         if let Some(js_doc) =
           js_doc_for_range(parsed_source, &import_decl.range())
@@ -360,6 +1885,7 @@ impl<'a> DocParser<'a> {
           for specifier in &import_decl.specifiers {
             use deno_ast::swc::ast::ImportSpecifier::*;
 
+            let namespace = matches!(specifier, Namespace(_));
             let (name, maybe_imported_name, src) = match specifier {
               Named(named_specifier) => (
                 named_specifier.local.sym.to_string(),
@@ -383,9 +1909,16 @@ impl<'a> DocParser<'a> {
             };
 
             let resolved_specifier = self.resolve_dependency(&src, referrer)?;
+            let final_specifier = self.graph.resolve(&resolved_specifier);
             let import_def = ImportDef {
-              src: resolved_specifier.to_string(),
+              src: final_specifier.to_string(),
               imported: maybe_imported_name,
+              requested_src: self
+                .requested_dependency(&src, referrer)
+                .filter(|requested| *requested != final_specifier)
+                .map(|requested| requested.to_string()),
+              namespace,
+              require: false,
             };
 
             let doc_node = DocNode::import(
@@ -404,6 +1937,67 @@ impl<'a> DocParser<'a> {
     Ok(imports)
   }
 
+  /// Gets an import doc node for a CommonJS import, like
+  /// `import fs = require("fs")`, which is a namespace import of the module
+  /// when it can be resolved, or else keeps the specifier as written.
+  fn get_doc_for_import_equals(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    import_equals: &TsImportEqualsDecl,
+  ) -> Option<DocNode> {
+    let TsModuleRef::TsExternalModuleRef(module_ref) =
+      &import_equals.module_ref
+    else {
+      return None;
+    };
+    let parsed_source = module_symbol.source();
+    let referrer = module_symbol.specifier();
+    let js_doc = js_doc_for_range(parsed_source, &import_equals.range())?;
+    let src = module_ref.expr.value.to_string();
+    let import_def = match self.resolve_dependency(&src, referrer) {
+      Ok(resolved_specifier) => {
+        let final_specifier = self.graph.resolve(&resolved_specifier);
+        ImportDef {
+          src: final_specifier.to_string(),
+          imported: None,
+          requested_src: self
+            .requested_dependency(&src, referrer)
+            .filter(|requested| *requested != final_specifier)
+            .map(|requested| requested.to_string()),
+          namespace: true,
+          require: true,
+        }
+      }
+      Err(_) => ImportDef {
+        src,
+        imported: None,
+        requested_src: None,
+        namespace: false,
+        require: true,
+      },
+    };
+    Some(DocNode::import(
+      import_equals.id.sym.to_string(),
+      get_location(parsed_source, import_equals.start()),
+      js_doc,
+      import_def,
+    ))
+  }
+
+  /// Gets the documentation of a declaration, or `None` when it is marked
+  /// `@ignore` and ignored declarations are not kept as hidden.
+  fn js_doc_for_range(
+    &self,
+    parsed_source: &ParsedSource,
+    range: &SourceRange,
+  ) -> Option<JsDoc> {
+    if self.options.hidden_ignored {
+      Some(js_doc_for_range_with_ignored(parsed_source, range))
+    } else {
+      js_doc_for_range(parsed_source, range)
+    }
+  }
+
   fn get_doc_for_var_declarator_ident(
     &self,
     module_symbol: &EsmModuleSymbol,
@@ -417,7 +2011,7 @@ impl<'a> DocParser<'a> {
     } else {
       Cow::Borrowed(full_range)
     };
-    let js_doc = js_doc_for_range(module_symbol.source(), &full_range)?;
+    let js_doc = self.js_doc_for_range(module_symbol.source(), &full_range)?;
     // todo(dsherret): it's not ideal to call this function over
     // and over for the same var declarator when there are a lot
     // of idents
@@ -428,7 +2022,14 @@ impl<'a> DocParser<'a> {
     )
     .into_iter()
     .find(|(name, _, _)| name.as_str() == &*ident.sym)
-    .map(|(name, var_def, _)| {
+    .map(|(name, mut var_def, _)| {
+      if let Some(ts_type) = js_doc_type_for_var_declarator(
+        module_symbol.source(),
+        var_declarator,
+        &js_doc,
+      ) {
+        var_def.ts_type = Some(ts_type);
+      }
       let location = get_location(module_symbol.source(), ident.start());
       DocNode::variable(
         name,
@@ -452,10 +2053,11 @@ impl<'a> DocParser<'a> {
       }
       _ => Cow::Borrowed(full_range),
     };
-    let js_doc = js_doc_for_range(parsed_source, &jsdoc_range)?;
+    let js_doc = self.js_doc_for_range(parsed_source, &jsdoc_range)?;
     // declared classes cannot have decorators, so we ignore that return
-    let (name, class_def, _) =
+    let (name, mut class_def, _) =
       super::class::get_doc_for_class_decl(parsed_source, class_decl);
+    set_super_type_params_from_js_doc(parsed_source, &mut class_def, &js_doc);
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::class(
       name,
@@ -472,7 +2074,7 @@ impl<'a> DocParser<'a> {
     fn_decl: &FnDecl,
     full_range: &SourceRange,
   ) -> Option<DocNode> {
-    let js_doc = js_doc_for_range(parsed_source, full_range)?;
+    let js_doc = self.js_doc_for_range(parsed_source, full_range)?;
     let (name, function_def) =
       super::function::get_doc_for_fn_decl(parsed_source, fn_decl);
     let location = get_location(parsed_source, full_range.start);
@@ -491,11 +2093,19 @@ impl<'a> DocParser<'a> {
     ts_interface_decl: &TsInterfaceDecl,
     full_range: &SourceRange,
   ) -> Option<DocNode> {
-    let js_doc = js_doc_for_range(parsed_source, full_range)?;
-    let (name, interface_def) = super::interface::get_doc_for_ts_interface_decl(
-      parsed_source,
-      ts_interface_decl,
-    );
+    let js_doc = self.js_doc_for_range(parsed_source, full_range)?;
+    let (name, mut interface_def) =
+      super::interface::get_doc_for_ts_interface_decl(
+        parsed_source,
+        ts_interface_decl,
+      );
+    if self.options.trailing_comments {
+      super::interface::set_trailing_comment_docs(
+        parsed_source,
+        ts_interface_decl,
+        &mut interface_def,
+      );
+    }
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::interface(
       name,
@@ -512,7 +2122,7 @@ impl<'a> DocParser<'a> {
     ts_type_alias: &TsTypeAliasDecl,
     full_range: &SourceRange,
   ) -> Option<DocNode> {
-    let js_doc = js_doc_for_range(parsed_source, full_range)?;
+    let js_doc = self.js_doc_for_range(parsed_source, full_range)?;
     let (name, type_alias_def) =
       super::type_alias::get_doc_for_ts_type_alias_decl(
         parsed_source,
@@ -534,9 +2144,16 @@ impl<'a> DocParser<'a> {
     ts_enum: &TsEnumDecl,
     full_range: &SourceRange,
   ) -> Option<DocNode> {
-    let js_doc = js_doc_for_range(parsed_source, full_range)?;
-    let (name, enum_def) =
+    let js_doc = self.js_doc_for_range(parsed_source, full_range)?;
+    let (name, mut enum_def) =
       super::r#enum::get_doc_for_ts_enum_decl(parsed_source, ts_enum);
+    if self.options.trailing_comments {
+      super::r#enum::set_trailing_comment_docs(
+        parsed_source,
+        ts_enum,
+        &mut enum_def,
+      );
+    }
     let location = get_location(parsed_source, full_range.start);
     Some(DocNode::r#enum(
       name,
@@ -617,12 +2234,12 @@ impl<'a> DocParser<'a> {
       }
       let child_symbol = module_symbol.symbol(child_id).unwrap();
       let is_public = child_symbol.is_public();
-      if is_public || is_ambient || self.private {
+      if is_public || is_ambient || self.options.private {
         for decl in child_symbol.decls() {
           if let Some(node) = decl.maybe_node() {
             let is_declared =
               is_ambient && self.get_declare_for_symbol_node(node);
-            if is_public || is_declared || self.private {
+            if is_public || is_declared || self.options.private {
               if let Some(mut doc_node) = self.get_doc_for_symbol_node_ref(
                 module_symbol,
                 child_symbol,
@@ -647,14 +2264,17 @@ impl<'a> DocParser<'a> {
       }
     }
 
-    let js_doc = js_doc_for_range(module_symbol.source(), full_range)?;
+    let js_doc = self.js_doc_for_range(module_symbol.source(), full_range)?;
     let location = get_location(module_symbol.source(), full_range.start);
     Some(DocNode::namespace(
       namespace_name,
       location,
       DeclarationKind::Declare,
       js_doc,
-      NamespaceDef { elements },
+      NamespaceDef {
+        elements,
+        ambient_module: false,
+      },
     ))
   }
 
@@ -663,19 +2283,25 @@ impl<'a> DocParser<'a> {
     parsed_source: &ParsedSource,
     export_default_decl: &ExportDefaultDecl,
   ) -> Option<DocNode> {
-    let js_doc = js_doc_for_range(parsed_source, &export_default_decl.range())?;
+    let js_doc =
+      self.js_doc_for_range(parsed_source, &export_default_decl.range())?;
     let location = get_location(parsed_source, export_default_decl.start());
     let name = "default".to_string();
 
     let doc_node = match &export_default_decl.decl {
       DefaultDecl::Class(class_expr) => {
-        let (class_def, decorator_js_doc) =
+        let (mut class_def, decorator_js_doc) =
           crate::class::class_to_class_def(parsed_source, &class_expr.class);
         let js_doc = if js_doc.is_empty() {
           decorator_js_doc
         } else {
           js_doc
         };
+        set_super_type_params_from_js_doc(
+          parsed_source,
+          &mut class_def,
+          &js_doc,
+        );
         DocNode::class(
           name,
           location,
@@ -721,11 +2347,17 @@ impl<'a> DocParser<'a> {
     parsed_source: &ParsedSource,
     export_expr: &ExportDefaultExpr,
   ) -> Option<DocNode> {
-    if let Some(js_doc) = js_doc_for_range(parsed_source, &export_expr.range())
+    if let Some(js_doc) =
+      self.js_doc_for_range(parsed_source, &export_expr.range())
     {
       let location = get_location(parsed_source, export_expr.start());
+      let name = match self.options.default_export_name {
+        DefaultExportName::Default => String::from("default"),
+        DefaultExportName::Module => module_js_doc_name(parsed_source)
+          .unwrap_or_else(|| module_identifier(parsed_source.specifier())),
+      };
       Some(DocNode::variable(
-        String::from("default"),
+        name,
         location,
         DeclarationKind::Export,
         js_doc,
@@ -784,6 +2416,22 @@ impl<'a> DocParser<'a> {
 
           imports.insert(name, import);
         }
+      } else if let ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(
+        import_equals,
+      )) = node
+      {
+        if let TsModuleRef::TsExternalModuleRef(module_ref) =
+          &import_equals.module_ref
+        {
+          let name = import_equals.id.sym.to_string();
+          imports.insert(
+            name.clone(),
+            Import {
+              kind: ImportKind::Namespace(name),
+              src: module_ref.expr.value.to_string(),
+            },
+          );
+        }
       }
     }
 
@@ -803,7 +2451,7 @@ impl<'a> DocParser<'a> {
 
     let mut reexports: Vec<node::Reexport> = vec![];
 
-    if self.private {
+    if self.options.private {
       reexports.extend(imports.values().cloned().map(|import| node::Reexport {
         src: import.src,
         kind: match import.kind {
@@ -865,7 +2513,7 @@ impl<'a> DocParser<'a> {
                     {
                       // If it has the same name as the original import and private values are exported,
                       // don't export this again and document the same value twice.
-                      if self.private && specifier.exported.is_none() {
+                      if self.options.private && specifier.exported.is_none() {
                         return None;
                       }
 
@@ -905,6 +2553,32 @@ impl<'a> DocParser<'a> {
             };
             vec![reexport]
           }
+          ModuleDecl::TsImportEquals(import_equals)
+            if import_equals.is_export =>
+          {
+            imports
+              .get(&*import_equals.id.sym)
+              .map(|import| node::Reexport {
+                kind: ReexportKind::Namespace(import_equals.id.sym.to_string()),
+                src: import.src.clone(),
+              })
+              .into_iter()
+              .collect()
+          }
+          // `import lib = require("./lib"); export = lib;`
+          ModuleDecl::TsExportAssignment(export_assignment) => {
+            export_assignment
+              .expr
+              .as_ident()
+              .and_then(|ident| imports.get(&*ident.sym))
+              .filter(|import| matches!(import.kind, ImportKind::Namespace(_)))
+              .map(|import| node::Reexport {
+                kind: ReexportKind::All,
+                src: import.src.clone(),
+              })
+              .into_iter()
+              .collect()
+          }
           _ => vec![],
         };
 
@@ -918,13 +2592,30 @@ impl<'a> DocParser<'a> {
   fn get_doc_nodes_for_module_symbol(
     &self,
     module_symbol: ModuleSymbolRef,
+    namespace_reexport: bool,
   ) -> Result<Vec<DocNode>, DocError> {
     let Some(module_symbol) = module_symbol.esm() else {
       return Ok(Vec::new());
     };
-    let mut definitions =
-      self.get_doc_nodes_for_module_symbol_body(module_symbol);
-    definitions.extend(self.get_doc_nodes_for_module_imports(module_symbol)?);
+    let mut definitions = self
+      .get_doc_nodes_for_module_symbol_body(module_symbol, namespace_reexport);
+    let imports = self.get_doc_nodes_for_module_imports(module_symbol)?;
+    if self.options.dependencies {
+      set_dependencies(&mut definitions, &imports);
+    }
+    if self.options.reachable_only {
+      let mut names = HashSet::new();
+      for doc_node in &definitions {
+        collect_referenced_names(doc_node, &mut names);
+      }
+      definitions.extend(
+        imports
+          .into_iter()
+          .filter(|import| names.contains(&import.name)),
+      );
+    } else {
+      definitions.extend(imports);
+    }
 
     Ok(definitions)
   }
@@ -932,6 +2623,7 @@ impl<'a> DocParser<'a> {
   fn get_doc_nodes_for_module_symbol_body(
     &self,
     module_symbol: &EsmModuleSymbol,
+    namespace_reexport: bool,
   ) -> Vec<DocNode> {
     let mut doc_nodes = Vec::new();
     let parsed_source = module_symbol.source();
@@ -941,10 +2633,27 @@ impl<'a> DocParser<'a> {
         let doc_node =
           DocNode::module_doc(get_location(parsed_source, range.start), js_doc);
         doc_nodes.push(doc_node);
-      } else {
+      } else if self.ignores_module(namespace_reexport) {
         return vec![];
       }
     }
+    if let Some(module_doc_def) = self
+      .options
+      .module_headers
+      .get(module_symbol.specifier())
+      .and_then(ModuleDocDef::from_headers)
+    {
+      if doc_nodes.is_empty() {
+        let location = Location {
+          filename: parsed_source.specifier().to_string(),
+          line: 1,
+          col: 0,
+          requested_filename: None,
+        };
+        doc_nodes.push(DocNode::module_doc(location, JsDoc::default()));
+      }
+      doc_nodes[0].module_doc_def = Some(module_doc_def);
+    }
 
     let mut handled_symbols = HashSet::new();
     let exports = module_symbol.exports(self.graph, &self.root_symbol);
@@ -967,7 +2676,18 @@ impl<'a> DocParser<'a> {
           definition.symbol_decl.maybe_node(),
         );
         if let Some(mut doc_node) = maybe_doc {
-          doc_node.name = export_name.clone();
+          let original_name = doc_node.name.clone();
+          set_export_name(
+            &mut doc_node,
+            export_name,
+            definition.symbol_decl.maybe_node(),
+          );
+          self.record_symbol_alias(
+            module_symbol.specifier(),
+            &doc_node.name,
+            definition.module.specifier(),
+            &original_name,
+          );
           doc_node.declaration_kind = DeclarationKind::Export;
 
           doc_nodes.push(doc_node);
@@ -975,19 +2695,56 @@ impl<'a> DocParser<'a> {
       }
     }
 
+    for doc_node in get_doc_nodes_for_typedefs(parsed_source) {
+      if !doc_nodes.iter().any(|n| n.name == doc_node.name) {
+        doc_nodes.push(doc_node);
+      }
+    }
+
+    for (symbol_id, doc_node) in
+      self.get_doc_nodes_for_export_assignment(module_symbol)
+    {
+      handled_symbols.insert(symbol_id);
+      doc_nodes.push(doc_node);
+    }
+    doc_nodes.extend(self.get_doc_nodes_for_umd_namespaces(parsed_source));
+
     let is_ambient = exports.is_empty() && !module_has_import(module_symbol);
+    if self.options.reachable_only && !is_ambient {
+      let private_doc_nodes = self.get_reachable_private_doc_nodes(
+        module_symbol,
+        &handled_symbols,
+        &doc_nodes,
+      );
+      self.private_types_in_public.borrow_mut().extend(
+        private_doc_nodes
+          .iter()
+          .map(|doc_node| doc_node.location.clone()),
+      );
+      doc_nodes.extend(private_doc_nodes);
+      if self.options.document_globals {
+        doc_nodes.extend(self.get_doc_nodes_for_global_blocks(module_symbol));
+      }
+      doc_nodes.extend(self.get_doc_nodes_for_ambient_modules(module_symbol));
+      return doc_nodes;
+    }
+    // in script-mode declaration files everything is implicitly declared
+    let is_ambient_declaration_file = is_ambient
+      && self.options.document_globals
+      && is_declaration_file(parsed_source);
     for child_id in module_symbol.child_decls() {
       if !handled_symbols.insert(child_id) {
         continue; // already handled
       }
       let child_symbol = module_symbol.symbol(child_id).unwrap();
       let is_public = child_symbol.is_public();
-      if is_public || is_ambient || self.private {
+      if is_public || is_ambient || self.options.private {
         for decl in child_symbol.decls() {
           if let Some(node) = decl.maybe_node() {
-            let is_declared =
-              is_ambient && self.get_declare_for_symbol_node(node);
-            if is_public || is_declared || self.private {
+            let is_declared = is_ambient
+              && (is_ambient_declaration_file
+                || self.get_declare_for_symbol_node(node));
+            if is_public || is_declared || self.options.private {
               if let Some(mut doc_node) = self.get_doc_for_symbol_node_ref(
                 module_symbol,
                 child_symbol,
@@ -1012,9 +2769,392 @@ impl<'a> DocParser<'a> {
       }
     }
 
+    if self.options.document_globals {
+      doc_nodes.extend(self.get_doc_nodes_for_global_blocks(module_symbol));
+    }
+    doc_nodes.extend(self.get_doc_nodes_for_ambient_modules(module_symbol));
+
     doc_nodes
   }
 
+  /// Documents the global names declared with `export as namespace Name`,
+  /// under which UMD declaration files expose the module to scripts.
+  fn get_doc_nodes_for_umd_namespaces(
+    &self,
+    parsed_source: &ParsedSource,
+  ) -> Vec<DocNode> {
+    let mut doc_nodes = Vec::new();
+    for item in &parsed_source.module().body {
+      let ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(decl)) = item
+      else {
+        continue;
+      };
+      let Some(js_doc) = self.js_doc_for_range(parsed_source, &decl.range())
+      else {
+        continue;
+      };
+      doc_nodes.push(DocNode::umd_namespace(
+        decl.id.sym.to_string(),
+        get_location(parsed_source, decl.start()),
+        js_doc,
+      ));
+    }
+    doc_nodes
+  }
+
+  /// Documents the declarations assigned with `export = Name` as the exports
+  /// of the module, as legacy declaration files of npm packages do.
+  fn get_doc_nodes_for_export_assignment(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+  ) -> Vec<(SymbolId, DocNode)> {
+    let Some(name) = export_assignment_name(module_symbol.source()) else {
+      return Vec::new();
+    };
+    if let Some(doc_node) =
+      self.get_namespace_for_export_assignment(module_symbol, &name)
+    {
+      return vec![doc_node];
+    }
+    let mut decls = self.get_doc_nodes_for_module_decls(module_symbol, &name);
+    for (_, doc_node) in &mut decls {
+      doc_node.declaration_kind = DeclarationKind::Export;
+    }
+    decls
+  }
+
+  /// Documents the variable of an `export = Name` assignment as a namespace
+  /// of the members of its type, when it is declared with the type of a
+  /// namespace or interface of the module, as older declaration files do
+  /// with `declare const MyLib: MyLibNamespace; export = MyLib;`.
+  fn get_namespace_for_export_assignment(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    name: &str,
+  ) -> Option<(SymbolId, DocNode)> {
+    let mut decls = self.get_doc_nodes_for_module_decls(module_symbol, name);
+    let index = decls
+      .iter()
+      .position(|(_, doc_node)| doc_node.kind == DocNodeKind::Variable)?;
+    let (symbol_id, variable) = decls.remove(index);
+    let ts_type = variable.variable_def.as_ref()?.ts_type.as_ref()?;
+    let type_name = match ts_type.kind {
+      Some(TsTypeDefKind::TypeRef) => &ts_type.type_ref.as_ref()?.type_name,
+      Some(TsTypeDefKind::TypeQuery) => ts_type.type_query.as_ref()?,
+      _ => return None,
+    };
+    let mut path = type_name.split('.');
+    let mut candidates = self
+      .get_doc_nodes_for_module_decls(module_symbol, path.next()?)
+      .into_iter()
+      .map(|(_, doc_node)| doc_node)
+      .collect::<Vec<_>>();
+    for name in path {
+      candidates = candidates
+        .into_iter()
+        .filter_map(|doc_node| doc_node.namespace_def)
+        .flat_map(|namespace_def| namespace_def.elements)
+        .filter(|doc_node| doc_node.name == name)
+        .collect();
+    }
+
+    let mut elements = Vec::new();
+    for candidate in candidates {
+      if let Some(namespace_def) = candidate.namespace_def {
+        elements.extend(namespace_def.elements);
+      } else if let Some(interface_def) = candidate.interface_def {
+        elements.extend(interface_def.methods.into_iter().map(DocNode::from));
+        elements
+          .extend(interface_def.properties.into_iter().map(DocNode::from));
+      }
+    }
+    if elements.is_empty() {
+      return None;
+    }
+    // the types of a namespace merged with the variable
+    for (_, doc_node) in decls {
+      if let Some(namespace_def) = doc_node.namespace_def {
+        elements.extend(namespace_def.elements);
+      }
+    }
+    for element in &mut elements {
+      element.declaration_kind = DeclarationKind::Export;
+    }
+    let doc_node = DocNode::namespace(
+      variable.name,
+      variable.location,
+      DeclarationKind::Export,
+      variable.js_doc,
+      NamespaceDef {
+        elements,
+        ambient_module: false,
+      },
+    );
+    Some((symbol_id, doc_node))
+  }
+
+  /// Gets the doc nodes for the declarations of the module with the name.
+  fn get_doc_nodes_for_module_decls(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    name: &str,
+  ) -> Vec<(SymbolId, DocNode)> {
+    let mut doc_nodes = Vec::new();
+    for child_id in module_symbol.child_decls() {
+      let child_symbol = module_symbol.symbol(child_id).unwrap();
+      for decl in child_symbol.decls() {
+        let Some(node) = decl.maybe_node() else {
+          continue;
+        };
+        if symbol_node_name(node).as_deref() != Some(name) {
+          continue;
+        }
+        if let Some(doc_node) =
+          self.get_doc_for_symbol_node_ref(module_symbol, child_symbol, node)
+        {
+          doc_nodes.push((child_id, doc_node));
+        }
+      }
+    }
+    doc_nodes
+  }
+
+  /// Gets the private declarations of the module which are referenced by
+  /// the doc nodes, and in turn by those declarations, skipping the symbols
+  /// which are already documented.
+  fn get_reachable_private_doc_nodes(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    handled_symbols: &HashSet<SymbolId>,
+    doc_nodes: &[DocNode],
+  ) -> Vec<DocNode> {
+    let mut candidates = Vec::new();
+    for child_id in module_symbol.child_decls() {
+      if handled_symbols.contains(&child_id) {
+        continue;
+      }
+      let child_symbol = module_symbol.symbol(child_id).unwrap();
+      for decl in child_symbol.decls() {
+        if let Some(node) = decl.maybe_node() {
+          if let Some(name) = symbol_node_name(node) {
+            candidates.push((name, child_symbol, node));
+          }
+        }
+      }
+    }
+    // walk the declarations in source order, so the order of the doc nodes
+    // does not depend on the order the names are referenced in
+    candidates.sort_by_key(|(_, _, node)| symbol_node_comments_pos(*node));
+
+    let mut referenced = HashSet::new();
+    for doc_node in doc_nodes {
+      collect_referenced_names(doc_node, &mut referenced);
+    }
+    let mut private_doc_nodes = Vec::new();
+    loop {
+      let (reachable, rest): (Vec<_>, Vec<_>) = candidates
+        .into_iter()
+        .partition(|(name, _, _)| referenced.contains(name));
+      candidates = rest;
+      if reachable.is_empty() {
+        break;
+      }
+      for (_, child_symbol, node) in reachable {
+        if let Some(mut doc_node) =
+          self.get_doc_for_symbol_node_ref(module_symbol, child_symbol, node)
+        {
+          doc_node.declaration_kind = DeclarationKind::Private;
+          collect_referenced_names(&doc_node, &mut referenced);
+          private_doc_nodes.push(doc_node);
+        }
+      }
+    }
+    private_doc_nodes
+  }
+
+  /// Gets the doc nodes for the declarations in `declare global { }` blocks
+  /// of the module.
+  fn get_doc_nodes_for_global_blocks(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+  ) -> Vec<DocNode> {
+    let mut doc_nodes = Vec::new();
+    for item in &module_symbol.source().module().body {
+      let ts_module = match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) => ts_module,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+          decl: Decl::TsModule(ts_module),
+          ..
+        })) => ts_module,
+        _ => continue,
+      };
+      if !ts_module.global {
+        continue;
+      }
+      let Some(TsNamespaceBody::TsModuleBlock(block)) = &ts_module.body else {
+        continue;
+      };
+      for item in &block.body {
+        let decl = match item {
+          ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+          ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+            &export_decl.decl
+          }
+          _ => continue,
+        };
+        doc_nodes.extend(self.get_docs_for_global_decl(module_symbol, decl));
+      }
+    }
+    doc_nodes
+  }
+
+  /// Gets the doc nodes for the ambient modules which the module declares
+  /// with a wildcard pattern, like `declare module "*.css" { }`, as
+  /// namespaces named after the pattern.
+  fn get_doc_nodes_for_ambient_modules(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+  ) -> Vec<DocNode> {
+    let parsed_source = module_symbol.source();
+    let mut doc_nodes = Vec::new();
+    for item in &parsed_source.module().body {
+      let ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) = item else {
+        continue;
+      };
+      let TsModuleName::Str(module_name) = &ts_module.id else {
+        continue;
+      };
+      if !module_name.value.contains('*') {
+        continue;
+      }
+      let Some(js_doc) =
+        self.js_doc_for_range(parsed_source, &ts_module.range())
+      else {
+        continue;
+      };
+      let mut elements = Vec::new();
+      if let Some(TsNamespaceBody::TsModuleBlock(block)) = &ts_module.body {
+        let mut default_exprs = Vec::new();
+        for item in &block.body {
+          let (decl, declaration_kind) = match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => {
+              (decl, DeclarationKind::Declare)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+              (&export_decl.decl, DeclarationKind::Export)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+              export_default_decl,
+            )) => {
+              elements.extend(self.get_doc_for_export_default_decl(
+                parsed_source,
+                export_default_decl,
+              ));
+              continue;
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+              export_expr,
+            )) => {
+              default_exprs.push(export_expr);
+              continue;
+            }
+            _ => continue,
+          };
+          for mut doc_node in self.get_docs_for_global_decl(module_symbol, decl)
+          {
+            doc_node.declaration_kind = declaration_kind;
+            elements.push(doc_node);
+          }
+        }
+        // a default export of a declaration of the block, like
+        // `export default classes;`, is documented as that declaration
+        for export_expr in default_exprs {
+          let declared = match &*export_expr.expr {
+            Expr::Ident(ident) => elements
+              .iter()
+              .filter(|doc_node| *doc_node.name == *ident.sym)
+              .cloned()
+              .collect::<Vec<_>>(),
+            _ => Vec::new(),
+          };
+          if declared.is_empty() {
+            if let Some(mut doc_node) =
+              self.get_doc_for_export_default_expr(parsed_source, export_expr)
+            {
+              doc_node.name = "default".to_string();
+              elements.push(doc_node);
+            }
+          }
+          for mut doc_node in declared {
+            doc_node.name = "default".to_string();
+            doc_node.declaration_kind = DeclarationKind::Export;
+            elements.push(doc_node);
+          }
+        }
+      }
+      doc_nodes.push(DocNode::namespace(
+        module_name.value.to_string(),
+        get_location(parsed_source, ts_module.start()),
+        DeclarationKind::Declare,
+        js_doc,
+        NamespaceDef {
+          elements,
+          ambient_module: true,
+        },
+      ));
+    }
+    doc_nodes
+  }
+
+  fn get_docs_for_global_decl(
+    &self,
+    module_symbol: &EsmModuleSymbol,
+    decl: &Decl,
+  ) -> Vec<DocNode> {
+    let parsed_source = module_symbol.source();
+    let maybe_doc_node = match decl {
+      Decl::Class(n) => {
+        self.get_doc_for_class_decl(parsed_source, n, &n.class.range())
+      }
+      Decl::Fn(n) => {
+        self.get_doc_for_fn_decl(parsed_source, n, &n.function.range())
+      }
+      Decl::TsEnum(n) => self.get_doc_for_enum(parsed_source, n, &n.range()),
+      Decl::TsInterface(n) => {
+        self.get_doc_for_interface_decl(parsed_source, n, &n.range())
+      }
+      Decl::TsTypeAlias(n) => {
+        self.get_docs_for_type_alias(parsed_source, n, &n.range())
+      }
+      Decl::TsModule(n) => match &n.id {
+        TsModuleName::Ident(ident) => module_symbol
+          .symbol_from_swc(&ident.to_id())
+          .and_then(|symbol| {
+            self.get_doc_for_ts_namespace(module_symbol, symbol, n, &n.range())
+          }),
+        TsModuleName::Str(_) => None,
+      },
+      Decl::Var(var_decl) => {
+        return var_decl
+          .decls
+          .iter()
+          .filter_map(|var_declarator| match &var_declarator.name {
+            Pat::Ident(ident) => self.get_doc_for_var_declarator_ident(
+              module_symbol,
+              var_decl,
+              var_declarator,
+              &ident.id,
+              &var_decl.range(),
+            ),
+            _ => None,
+          })
+          .collect();
+      }
+      Decl::Using(_) => None,
+    };
+    maybe_doc_node.into_iter().collect()
+  }
+
   fn doc_for_maybe_node(
     &self,
     module_symbol: ModuleSymbolRef,
@@ -1043,7 +3183,7 @@ impl<'a> DocParser<'a> {
     node: SymbolNodeRef<'_>,
   ) -> Option<DocNode> {
     let parsed_source = module_symbol.source();
-    match node {
+    let mut doc_node = match node {
       SymbolNodeRef::ClassDecl(n) => {
         self.get_doc_for_class_decl(parsed_source, n, &n.class.range())
       }
@@ -1109,7 +3249,13 @@ impl<'a> DocParser<'a> {
             &export_decl.range(),
           ),
       },
+    }?;
+    doc_node.hidden = doc_node.js_doc.tags.contains(&JsDocTag::Ignore);
+    if self.options.leading_comments {
+      doc_node.leading_comments =
+        leading_comments_for_pos(parsed_source, symbol_node_comments_pos(node));
     }
+    Some(doc_node)
   }
 
   fn get_declare_for_symbol_node(&self, node: SymbolNodeRef) -> bool {
@@ -1140,6 +3286,22 @@ impl<'a> DocParser<'a> {
     }
   }
 
+  /// Gets the specifier a dependency of `referrer` was requested as, before
+  /// any redirects were followed.
+  fn requested_dependency(
+    &self,
+    specifier: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<&ModuleSpecifier> {
+    let module = self.graph.get(referrer)?.esm()?;
+    let dep = module.dependencies.get(specifier)?;
+    dep.get_type().or_else(|| dep.get_code())
+  }
+
+  /// Resolves a dependency of `referrer` to the module its types come from,
+  /// so a declaration file given by a `// @deno-types` directive or an
+  /// `X-TypeScript-Types` header is documented instead of the implementation,
+  /// as the Deno CLI does.
   fn resolve_dependency(
     &self,
     specifier: &str,
@@ -1157,6 +3319,18 @@ impl<'a> DocParser<'a> {
   }
 }
 
+#[cfg(feature = "rust")]
+impl SourceTextProvider for DocParser<'_> {
+  fn get_parsed_source(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Option<ParsedSource> {
+    let module_symbol =
+      self.root_symbol.get_module_from_specifier(specifier)?;
+    module_symbol.esm().map(|m| m.source().clone())
+  }
+}
+
 fn parse_json_module_doc_node(
   specifier: &ModuleSpecifier,
   source: &str,
@@ -1169,6 +3343,7 @@ fn parse_json_module_doc_node(
         filename: specifier.to_string(),
         col: 0,
         line: 1,
+        requested_filename: None,
       },
       declaration_kind: DeclarationKind::Export,
       variable_def: Some(VariableDef {
@@ -1183,6 +3358,36 @@ fn parse_json_module_doc_node(
   }
 }
 
+/// Documents the top-level properties of a JSON module as exported
+/// variables, for when it is reexported with `export * from`.
+fn parse_json_module_export_doc_nodes(
+  specifier: &ModuleSpecifier,
+  source: &str,
+) -> Vec<DocNode> {
+  let Ok(serde_json::Value::Object(obj)) = serde_json::from_str(source) else {
+    return Vec::new();
+  };
+  obj
+    .iter()
+    .map(|(key, value)| DocNode {
+      kind: DocNodeKind::Variable,
+      name: key.to_string(),
+      location: Location {
+        filename: specifier.to_string(),
+        col: 0,
+        line: 1,
+        requested_filename: None,
+      },
+      declaration_kind: DeclarationKind::Export,
+      variable_def: Some(VariableDef {
+        kind: VarDeclKind::Var,
+        ts_type: Some(parse_json_module_type(value)),
+      }),
+      ..Default::default()
+    })
+    .collect()
+}
+
 fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
   match value {
     serde_json::Value::Null => TsTypeDef::keyword("null"),
@@ -1208,10 +3413,12 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
           .iter()
           .map(|(key, value)| LiteralPropertyDef {
             name: key.to_string(),
+            js_doc: JsDoc::default(),
             ts_type: Some(parse_json_module_type(value)),
             params: Vec::new(),
             readonly: false,
             computed: false,
+            well_known_symbol: false,
             optional: false,
             type_params: Vec::new(),
           })
@@ -1223,6 +3430,371 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
   }
 }
 
+/// Gets the JSDoc of the named export statement which exports `export_name`,
+/// like `export { a as b } from "./a.ts";` or `export * as ns from "./a.ts";`.
+/// Returns `Some(None)` when the statement is tagged with `@ignore`.
+fn reexport_js_doc(
+  parsed_source: &ParsedSource,
+  export_name: &str,
+) -> Option<Option<JsDoc>> {
+  for item in &parsed_source.module().body {
+    let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named_export)) = item
+    else {
+      continue;
+    };
+    let exports_name = named_export.specifiers.iter().any(|specifier| {
+      let name = match specifier {
+        ExportSpecifier::Namespace(ns_export) => {
+          module_export_name_value(&ns_export.name)
+        }
+        ExportSpecifier::Default(specifier) => {
+          specifier.exported.sym.to_string()
+        }
+        ExportSpecifier::Named(named) => module_export_name_value(
+          named.exported.as_ref().unwrap_or(&named.orig),
+        ),
+      };
+      name == export_name
+    });
+    if exports_name {
+      return match js_doc_for_range(parsed_source, &named_export.range()) {
+        Some(js_doc) if js_doc.is_empty() || js_doc.is_module_doc() => None,
+        maybe_js_doc => Some(maybe_js_doc),
+      };
+    }
+  }
+  None
+}
+
+/// Names the doc node of a definition after the name it is exported as,
+/// except for `export default` value expressions exported as `default`,
+/// which keep the name given by [`DefaultExportName`].
+fn set_export_name(
+  doc_node: &mut DocNode,
+  export_name: &str,
+  node: Option<SymbolNodeRef>,
+) {
+  let is_default_expr =
+    matches!(node, Some(SymbolNodeRef::ExportDefaultExprLit(..)));
+  if !is_default_expr || export_name != "default" {
+    doc_node.name = export_name.to_string();
+  }
+}
+
+/// An identifier derived from the file name of a module, in camel case, like
+/// `myConfig` for `my-config.ts`. The name of the directory is used for
+/// `mod` and `index` files.
+fn module_identifier(specifier: &str) -> String {
+  let path = specifier.split(['?', '#']).next().unwrap_or_default();
+  let mut segments = path.rsplit('/').filter(|segment| !segment.is_empty());
+  let mut stem = segments.next().unwrap_or_default();
+  stem = stem.split('.').next().unwrap_or_default();
+  if matches!(stem, "mod" | "index") {
+    if let Some(dir) = segments.next().filter(|dir| !dir.contains(':')) {
+      stem = dir;
+    }
+  }
+  let mut name = String::new();
+  for (i, word) in stem
+    .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '$')
+    .filter(|word| !word.is_empty())
+    .enumerate()
+  {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+      if i == 0 {
+        name.extend(first.to_lowercase());
+      } else {
+        name.extend(first.to_uppercase());
+      }
+      name.push_str(chars.as_str());
+    }
+  }
+  if name.is_empty() {
+    String::from("default")
+  } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+    format!("_{}", name)
+  } else {
+    name
+  }
+}
+
+/// Merges the JSDoc of an export statement onto the JSDoc of the exported
+/// node: its description replaces the one of the node, and its tags are
+/// added to those of the node.
+fn merge_js_doc(js_doc: &mut JsDoc, reexport_js_doc: &JsDoc) {
+  if reexport_js_doc.doc.is_some() {
+    js_doc.doc = reexport_js_doc.doc.clone();
+  }
+  for tag in &reexport_js_doc.tags {
+    if !js_doc.tags.contains(tag) {
+      js_doc.tags.push(tag.clone());
+    }
+  }
+}
+
+fn count_doc_nodes(doc_nodes: &[DocNode]) -> usize {
+  doc_nodes
+    .iter()
+    .map(|doc_node| {
+      1 + doc_node
+        .namespace_def
+        .as_ref()
+        .map(|namespace_def| count_doc_nodes(&namespace_def.elements))
+        .unwrap_or(0)
+    })
+    .sum()
+}
+
+/// Makes the referenced type of a doc node declared in or exported by the
+/// module `src`, with the imports among `imports` which it refers to.
+fn referenced_type(
+  src: String,
+  name: String,
+  doc_node: DocNode,
+  imports: &[DocNode],
+) -> ReferencedType {
+  let imports = referenced_imports(std::slice::from_ref(&doc_node), imports)
+    .into_iter()
+    .collect();
+  ReferencedType {
+    src,
+    name,
+    imports,
+    doc_node,
+  }
+}
+
+/// Collects the locations of the doc nodes and the elements of namespaces.
+fn collect_locations(doc_nodes: &[DocNode], locations: &mut HashSet<Location>) {
+  for doc_node in doc_nodes {
+    locations.insert(doc_node.location.clone());
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_locations(&namespace_def.elements, locations);
+    }
+  }
+}
+
+/// Gets the imports among `imports` referred to by type references in the
+/// doc nodes, as the type name they are referred to with, like `ns.A` for a
+/// namespace import, along with the resolved specifier and the name of the
+/// export of the imported module.
+pub(crate) fn referenced_imports(
+  doc_nodes: &[DocNode],
+  imports: &[DocNode],
+) -> Vec<(String, (String, String))> {
+  fn collect(
+    ts_type: &TsTypeDef,
+    imports: &HashMap<&str, &ImportDef>,
+    referenced: &mut Vec<(String, (String, String))>,
+  ) {
+    if let Some(type_ref) = &ts_type.type_ref {
+      let mut parts = type_ref.type_name.split('.');
+      let local_name = parts.next().unwrap();
+      if let Some(import_def) = imports.get(local_name) {
+        let export_name = match (&import_def.imported, parts.next()) {
+          (Some(imported), None) => Some(imported.as_str()),
+          // namespace imports refer to the export after the namespace
+          (None, Some(name)) if parts.next().is_none() => Some(name),
+          _ => None,
+        };
+        if let Some(export_name) = export_name {
+          referenced.push((
+            type_ref.type_name.clone(),
+            (import_def.src.clone(), export_name.to_string()),
+          ));
+        }
+      }
+    }
+    ts_type.for_each_child(&mut |child| collect(child, imports, referenced));
+  }
+
+  fn collect_doc_nodes(
+    doc_nodes: &[DocNode],
+    imports: &HashMap<&str, &ImportDef>,
+    referenced: &mut Vec<(String, (String, String))>,
+  ) {
+    for doc_node in doc_nodes {
+      if doc_node.kind == DocNodeKind::Import {
+        continue;
+      }
+      doc_node
+        .for_each_ts_type(&mut |ts_type| collect(ts_type, imports, referenced));
+      if let Some(namespace_def) = &doc_node.namespace_def {
+        collect_doc_nodes(&namespace_def.elements, imports, referenced);
+      }
+    }
+  }
+
+  let imports = imports
+    .iter()
+    .filter_map(|doc_node| {
+      doc_node
+        .import_def
+        .as_ref()
+        .map(|import_def| (doc_node.name.as_str(), import_def))
+    })
+    .collect::<HashMap<_, _>>();
+  let mut referenced = Vec::new();
+  collect_doc_nodes(doc_nodes, &imports, &mut referenced);
+  referenced
+}
+
+/// The position the comments of the declaration of a symbol precede, which is
+/// the one its JSDoc is looked up at.
+fn symbol_node_comments_pos(node: SymbolNodeRef) -> SourcePos {
+  fn class_pos(class_decl: &ClassDecl, start: SourcePos) -> SourcePos {
+    match class_decl.class.decorators.first() {
+      Some(decorator) if decorator.start() < start => decorator.start(),
+      _ => start,
+    }
+  }
+
+  fn var_pos(
+    var_declarator: &VarDeclarator,
+    ident: &Ident,
+    start: SourcePos,
+  ) -> SourcePos {
+    if ident.start() != var_declarator.start() {
+      ident.start()
+    } else {
+      start
+    }
+  }
+
+  match node {
+    SymbolNodeRef::ClassDecl(n) => class_pos(n, n.class.start()),
+    SymbolNodeRef::ExportDefaultDecl(n) => n.start(),
+    SymbolNodeRef::ExportDefaultExprLit(n, _) => n.start(),
+    SymbolNodeRef::FnDecl(n) => n.function.start(),
+    SymbolNodeRef::TsEnum(n) => n.start(),
+    SymbolNodeRef::TsInterface(n) => n.start(),
+    SymbolNodeRef::TsNamespace(n) => n.start(),
+    SymbolNodeRef::TsTypeAlias(n) => n.start(),
+    SymbolNodeRef::Var(parent_decl, n, ident) => {
+      var_pos(n, ident, parent_decl.start())
+    }
+    SymbolNodeRef::ExportDecl(export_decl, inner) => match inner {
+      ExportDeclRef::Class(n) => class_pos(n, export_decl.start()),
+      ExportDeclRef::Var(_, n, ident) => var_pos(n, ident, export_decl.start()),
+      _ => export_decl.start(),
+    },
+  }
+}
+
+/// Removes the raw comments the JSDoc of the doc nodes, their members and the
+/// elements of namespaces was parsed from.
+fn clear_raw_js_docs(doc_nodes: &mut [DocNode]) {
+  for doc_node in doc_nodes {
+    doc_node.for_each_nested_js_doc_mut(&mut |js_doc| js_doc.raw = None);
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      clear_raw_js_docs(&mut namespace_def.elements);
+    }
+  }
+}
+
+/// Sets the `dependencies` of the doc nodes and the elements of namespaces to
+/// the modules of the imports which they refer to.
+fn set_dependencies(doc_nodes: &mut [DocNode], imports: &[DocNode]) {
+  for doc_node in doc_nodes {
+    let mut names = HashSet::new();
+    collect_referenced_names(doc_node, &mut names);
+    let mut dependencies = imports
+      .iter()
+      .filter(|import| names.contains(&import.name))
+      .filter_map(|import| Some(import.import_def.as_ref()?.src.clone()))
+      .collect::<Vec<_>>();
+    dependencies.sort();
+    dependencies.dedup();
+    doc_node.dependencies = dependencies;
+    if let Some(namespace_def) = &mut doc_node.namespace_def {
+      set_dependencies(&mut namespace_def.elements, imports);
+    }
+  }
+}
+
+/// The local names of the declarations which the types of a doc node refer
+/// to, like `A` for `A.B<C>`, which also refers to `C`.
+fn collect_referenced_names(doc_node: &DocNode, names: &mut HashSet<String>) {
+  fn collect(ts_type: &TsTypeDef, names: &mut HashSet<String>) {
+    let maybe_name = match (&ts_type.type_ref, &ts_type.type_query) {
+      (Some(type_ref), _) => Some(type_ref.type_name.as_str()),
+      (None, Some(type_query)) => Some(type_query.as_str()),
+      (None, None) => None,
+    };
+    if let Some(name) = maybe_name {
+      names.insert(name.split('.').next().unwrap().to_string());
+    }
+    ts_type.for_each_child(&mut |child| collect(child, names));
+  }
+
+  if let Some(extends) = doc_node
+    .class_def
+    .as_ref()
+    .and_then(|class_def| class_def.extends.as_ref())
+  {
+    names.insert(extends.split('.').next().unwrap().to_string());
+  }
+  doc_node.for_each_ts_type(&mut |ts_type| collect(ts_type, names));
+  if let Some(namespace_def) = &doc_node.namespace_def {
+    for element in &namespace_def.elements {
+      collect_referenced_names(element, names);
+    }
+  }
+}
+
+/// The local name a declaration is bound to.
+fn symbol_node_name(node: SymbolNodeRef) -> Option<String> {
+  fn ts_module_name(name: &TsModuleName) -> Option<String> {
+    match name {
+      TsModuleName::Ident(ident) => Some(ident.sym.to_string()),
+      TsModuleName::Str(_) => None,
+    }
+  }
+
+  match node {
+    SymbolNodeRef::ClassDecl(n) => Some(n.ident.sym.to_string()),
+    SymbolNodeRef::ExportDecl(_, inner) => match inner {
+      ExportDeclRef::Class(n) => Some(n.ident.sym.to_string()),
+      ExportDeclRef::Fn(n) => Some(n.ident.sym.to_string()),
+      ExportDeclRef::TsEnum(n) => Some(n.id.sym.to_string()),
+      ExportDeclRef::TsModule(n) => ts_module_name(&n.id),
+      ExportDeclRef::TsInterface(n) => Some(n.id.sym.to_string()),
+      ExportDeclRef::TsTypeAlias(n) => Some(n.id.sym.to_string()),
+      ExportDeclRef::Var(_, _, ident) => Some(ident.sym.to_string()),
+    },
+    SymbolNodeRef::ExportDefaultDecl(_) => None,
+    SymbolNodeRef::ExportDefaultExprLit(_, _) => None,
+    SymbolNodeRef::FnDecl(n) => Some(n.ident.sym.to_string()),
+    SymbolNodeRef::TsEnum(n) => Some(n.id.sym.to_string()),
+    SymbolNodeRef::TsInterface(n) => Some(n.id.sym.to_string()),
+    SymbolNodeRef::TsNamespace(n) => ts_module_name(&n.id),
+    SymbolNodeRef::TsTypeAlias(n) => Some(n.id.sym.to_string()),
+    SymbolNodeRef::Var(_, _, ident) => Some(ident.sym.to_string()),
+  }
+}
+
+/// The name assigned with `export = Name` in the module, if any.
+fn export_assignment_name(parsed_source: &ParsedSource) -> Option<String> {
+  parsed_source
+    .module()
+    .body
+    .iter()
+    .find_map(|item| match item {
+      ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(assignment)) => {
+        match &*assignment.expr {
+          Expr::Ident(ident) => Some(ident.sym.to_string()),
+          _ => None,
+        }
+      }
+      _ => None,
+    })
+}
+
+fn is_remote(filename: &str) -> bool {
+  filename.starts_with("http:") || filename.starts_with("https:")
+}
+
 fn module_has_import(module_symbol: &EsmModuleSymbol) -> bool {
   module_symbol.source().module().body.iter().any(|m| {
     matches!(
@@ -1234,6 +3806,13 @@ fn module_has_import(module_symbol: &EsmModuleSymbol) -> bool {
   })
 }
 
+fn is_declaration_file(parsed_source: &ParsedSource) -> bool {
+  matches!(
+    parsed_source.media_type(),
+    MediaType::Dts | MediaType::Dmts | MediaType::Dcts
+  )
+}
+
 fn definition_location(
   definition: &deno_graph::type_tracer::Definition,
 ) -> Location {
@@ -0,0 +1,75 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::SourceTextInfo;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::node::Location;
+
+/// The indent width used when computing display columns.
+const DISPLAY_INDENT_WIDTH: usize = 4;
+
+/// The unit of the columns of [`Location`]s.
+#[derive(
+  Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnEncoding {
+  /// Characters, with a tab counted as 4 columns.
+  #[default]
+  Display,
+  /// UTF-8 code units, i.e. bytes.
+  Utf8,
+  /// UTF-16 code units, as used by the language server protocol.
+  Utf16,
+}
+
+/// How lines and columns of [`Location`]s are counted. Columns are always
+/// 0-indexed.
+#[derive(
+  Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionEncoding {
+  pub columns: ColumnEncoding,
+  /// Whether lines are 0-indexed instead of 1-indexed.
+  pub zero_based_lines: bool,
+}
+
+impl PositionEncoding {
+  pub fn is_default(&self) -> bool {
+    *self == Self::default()
+  }
+}
+
+/// Converts a location with a 1-indexed line and a display column, as
+/// created by the parser, to the given encoding.
+pub(crate) fn encode_location(
+  location: &mut Location,
+  text_info: &SourceTextInfo,
+  encoding: PositionEncoding,
+) {
+  let line_index = location.line.saturating_sub(1);
+  if encoding.columns != ColumnEncoding::Display
+    && line_index < text_info.lines_count()
+  {
+    let line_text = text_info.line_text(line_index);
+    let mut display_col = 0;
+    let mut col = 0;
+    for c in line_text.chars() {
+      if display_col >= location.col {
+        break;
+      }
+      display_col += if c == '\t' { DISPLAY_INDENT_WIDTH } else { 1 };
+      col += match encoding.columns {
+        ColumnEncoding::Display => unreachable!(),
+        ColumnEncoding::Utf8 => c.len_utf8(),
+        ColumnEncoding::Utf16 => c.len_utf16(),
+      };
+    }
+    location.col = col;
+  }
+  if encoding.zero_based_lines {
+    location.line = line_index;
+  }
+}
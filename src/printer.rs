@@ -16,8 +16,10 @@ use crate::display::display_async;
 use crate::display::display_generator;
 use crate::display::Indent;
 use crate::display::SliceDisplayer;
+use crate::events::EventDef;
 use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
+use crate::messages::MessageCatalog;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
 use crate::node::DocNodeKind;
@@ -30,6 +32,7 @@ pub struct DocPrinter<'a> {
   doc_nodes: &'a [DocNode],
   use_color: bool,
   private: bool,
+  messages: MessageCatalog,
 }
 
 impl<'a> DocPrinter<'a> {
@@ -42,13 +45,51 @@ impl<'a> DocPrinter<'a> {
       doc_nodes,
       use_color,
       private,
+      messages: MessageCatalog::default(),
     }
   }
 
+  /// Replaces the labels of the output, to localize it.
+  pub fn with_messages(mut self, messages: MessageCatalog) -> Self {
+    self.messages = messages;
+    self
+  }
+
   pub fn format(&self, w: &mut Formatter<'_>) -> FmtResult {
     self.format_(w, self.doc_nodes, 0)
   }
 
+  /// Formats the given nodes instead of the ones of the printer, nested by
+  /// `indent` levels, so that callers composing their own layout can print
+  /// any subset of the nodes, like the elements of a single namespace.
+  pub fn format_nodes(
+    &self,
+    w: &mut Formatter<'_>,
+    doc_nodes: &[DocNode],
+    indent: i64,
+  ) -> FmtResult {
+    self.format_(w, doc_nodes, indent)
+  }
+
+  /// Prints the given nodes like [`DocPrinter::format_nodes`].
+  pub fn print_nodes(&self, doc_nodes: &[DocNode], indent: i64) -> String {
+    NodesDisplayer {
+      printer: self,
+      doc_nodes,
+      indent,
+    }
+    .to_string()
+  }
+
+  /// Prints the elements of a namespace node in full, indented as they are
+  /// nested under it, or nothing for other nodes.
+  pub fn print_namespace_elements(&self, node: &DocNode) -> String {
+    match &node.namespace_def {
+      Some(namespace_def) => self.print_nodes(&namespace_def.elements, 1),
+      None => String::new(),
+    }
+  }
+
   fn format_(
     &self,
     w: &mut Formatter<'_>,
@@ -59,7 +100,11 @@ impl<'a> DocPrinter<'a> {
       colors::enable_color();
     }
 
-    let mut sorted = Vec::from(doc_nodes);
+    let mut sorted = doc_nodes
+      .iter()
+      .filter(|node| !node.hidden)
+      .cloned()
+      .collect::<Vec<_>>();
     sorted.sort_unstable_by(|a, b| {
       let kind_cmp = self.kind_order(&a.kind).cmp(&self.kind_order(&b.kind));
       if kind_cmp == core::cmp::Ordering::Equal {
@@ -89,10 +134,14 @@ impl<'a> DocPrinter<'a> {
       {
         write!(
           w,
-          "{}",
+          "{}{}",
+          Indent(indent),
           colors::italic_gray(&format!(
-            "Defined in {}:{}:{}\n\n",
-            node.location.filename, node.location.line, node.location.col
+            "{} {}:{}:{}\n\n",
+            self.messages.defined_in,
+            node.location.filename,
+            node.location.line,
+            node.location.col
           ))
         )?;
       }
@@ -103,10 +152,10 @@ impl<'a> DocPrinter<'a> {
       writeln!(w)?;
 
       match node.kind {
-        DocNodeKind::Class => self.format_class(w, node)?,
-        DocNodeKind::Enum => self.format_enum(w, node)?,
-        DocNodeKind::Interface => self.format_interface(w, node)?,
-        DocNodeKind::Namespace => self.format_namespace(w, node)?,
+        DocNodeKind::Class => self.format_class(w, node, indent)?,
+        DocNodeKind::Enum => self.format_enum(w, node, indent)?,
+        DocNodeKind::Interface => self.format_interface(w, node, indent)?,
+        DocNodeKind::Namespace => self.format_namespace(w, node, indent)?,
         _ => {}
       }
     }
@@ -129,6 +178,7 @@ impl<'a> DocPrinter<'a> {
       DocNodeKind::TypeAlias => 6,
       DocNodeKind::Namespace => 7,
       DocNodeKind::Import => 8,
+      DocNodeKind::UmdNamespace => 9,
     }
   }
 
@@ -157,6 +207,9 @@ impl<'a> DocPrinter<'a> {
         self.format_namespace_signature(w, node, indent)
       }
       DocNodeKind::Import => Ok(()),
+      DocNodeKind::UmdNamespace => {
+        self.format_umd_namespace_signature(w, node, indent)
+      }
     }
   }
 
@@ -203,35 +256,66 @@ impl<'a> DocPrinter<'a> {
     indent: i64,
   ) -> FmtResult {
     match tag {
+      JsDocTag::Alpha => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("alpha"))
+        )
+      }
+      JsDocTag::Beta => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("beta"))
+        )
+      }
       JsDocTag::Callback { name, doc } => {
         writeln!(
           w,
           "{}@{} {}",
           Indent(indent),
-          colors::magenta("callback"),
+          colors::magenta(self.messages.tag_label("callback")),
           colors::bold(name)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Category { doc } => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("category"))?;
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("category"))
+        )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Constructor => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("constructor"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("constructor"))
+        )
       }
       JsDocTag::Default { value, doc } => {
         writeln!(
           w,
           "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("default"),
+          colors::magenta(self.messages.tag_label("default")),
           colors::italic_cyan(value)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Deprecated { doc } => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("deprecated"))?;
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("deprecated"))
+        )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Enum { type_ref, doc } => {
@@ -239,13 +323,22 @@ impl<'a> DocPrinter<'a> {
           w,
           "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("enum"),
+          colors::magenta(self.messages.tag_label("enum")),
           colors::italic_cyan(type_ref)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
-      JsDocTag::Example { doc } => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("example"))?;
+      JsDocTag::Example { caption, doc } => {
+        write!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("example"))
+        )?;
+        if let Some(caption) = caption {
+          write!(w, " {}", caption)?;
+        }
+        writeln!(w)?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Extends { type_ref, doc } => {
@@ -253,16 +346,70 @@ impl<'a> DocPrinter<'a> {
           w,
           "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("extends"),
+          colors::magenta(self.messages.tag_label("extends")),
           colors::italic_cyan(type_ref)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Experimental => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("experimental"))
+        )
+      }
       JsDocTag::Ignore => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("ignore"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("ignore"))
+        )
+      }
+      JsDocTag::InheritDoc => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("inheritDoc"))
+        )
+      }
+      JsDocTag::Internal => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("internal"))
+        )
       }
       JsDocTag::Module => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("module"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("module"))
+        )
+      }
+      JsDocTag::PackageDocumentation => {
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("packageDocumentation"))
+        )
+      }
+      JsDocTag::Namespace { name } => {
+        write!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("namespace"))
+        )?;
+        if let Some(name) = name {
+          write!(w, " {}", colors::bold(name))?;
+        }
+        writeln!(w)
       }
       JsDocTag::Param {
         name,
@@ -271,7 +418,12 @@ impl<'a> DocPrinter<'a> {
         default,
         doc,
       } => {
-        write!(w, "{}@{}", Indent(indent), colors::magenta("param"))?;
+        write!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("param"))
+        )?;
         if let Some(type_ref) = type_ref {
           write!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
         }
@@ -284,34 +436,66 @@ impl<'a> DocPrinter<'a> {
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Public => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("public"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("public"))
+        )
       }
       JsDocTag::Private => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("private"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("private"))
+        )
       }
       JsDocTag::Property {
         name,
         type_ref,
+        optional,
+        default,
         doc,
       } => {
-        writeln!(
+        write!(
           w,
-          "{}@{} {{{}}} {}",
+          "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("property"),
-          colors::italic_cyan(type_ref),
-          colors::bold(name)
+          colors::magenta(self.messages.tag_label("property")),
+          colors::italic_cyan(type_ref)
         )?;
+        if let Some(default) = default {
+          write!(w, " [{}]", colors::italic_cyan(default))?;
+        } else if *optional {
+          write!(w, " [?]")?;
+        }
+        writeln!(w, " {}", colors::bold(name))?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::Protected => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("protected"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("protected"))
+        )
       }
       JsDocTag::ReadOnly => {
-        writeln!(w, "{}@{}", Indent(indent), colors::magenta("readonly"))
+        writeln!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("readonly"))
+        )
       }
       JsDocTag::Return { type_ref, doc } => {
-        write!(w, "{}@{}", Indent(indent), colors::magenta("return"))?;
+        write!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("return"))
+        )?;
         if let Some(type_ref) = type_ref {
           writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
         } else {
@@ -319,23 +503,54 @@ impl<'a> DocPrinter<'a> {
         }
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::See { target, doc } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("see")),
+          target
+        )?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
+      JsDocTag::Since { version, doc } => {
+        writeln!(
+          w,
+          "{}@{} {}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("since")),
+          version
+        )?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
       JsDocTag::Tags { tags } => {
         writeln!(
           w,
           "{}@{} {}",
           Indent(indent),
-          colors::magenta("tags"),
+          colors::magenta(self.messages.tag_label("tags")),
           tags.join(", "),
         )
       }
-      JsDocTag::Template { name, doc } => {
-        writeln!(
+      JsDocTag::Template {
+        name,
+        constraint,
+        default,
+        doc,
+      } => {
+        write!(
           w,
-          "{}@{} {}",
+          "{}@{}",
           Indent(indent),
-          colors::magenta("template"),
-          colors::bold(name)
+          colors::magenta(self.messages.tag_label("template"))
         )?;
+        if let Some(constraint) = constraint {
+          write!(w, " {{{}}}", colors::italic_cyan(constraint))?;
+        }
+        if let Some(default) = default {
+          write!(w, " [{}]", colors::italic_cyan(default))?;
+        }
+        writeln!(w, " {}", colors::bold(name))?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
       JsDocTag::This { type_ref, doc } => {
@@ -343,7 +558,7 @@ impl<'a> DocPrinter<'a> {
           w,
           "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("this"),
+          colors::magenta(self.messages.tag_label("this")),
           colors::italic_cyan(type_ref)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
@@ -357,7 +572,7 @@ impl<'a> DocPrinter<'a> {
           w,
           "{}@{} {{{}}} {}",
           Indent(indent),
-          colors::magenta("typedef"),
+          colors::magenta(self.messages.tag_label("typedef")),
           colors::italic_cyan(type_ref),
           colors::bold(name)
         )?;
@@ -368,24 +583,43 @@ impl<'a> DocPrinter<'a> {
           w,
           "{}@{} {{{}}}",
           Indent(indent),
-          colors::magenta("typeref"),
+          colors::magenta(self.messages.tag_label("typeref")),
           colors::italic_cyan(type_ref)
         )?;
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Yields { type_ref, doc } => {
+        write!(
+          w,
+          "{}@{}",
+          Indent(indent),
+          colors::magenta(self.messages.tag_label("yields"))
+        )?;
+        if let Some(type_ref) = type_ref {
+          writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
+        } else {
+          writeln!(w)?;
+        }
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
       JsDocTag::Unsupported { value } => {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta(value))
       }
     }
   }
 
-  fn format_class(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
+  fn format_class(
+    &self,
+    w: &mut Formatter<'_>,
+    node: &DocNode,
+    indent: i64,
+  ) -> FmtResult {
     let class_def = node.class_def.as_ref().unwrap();
     let has_overloads = class_def.constructors.len() > 1;
     for node in &class_def.constructors {
       if !has_overloads || !node.has_body {
-        writeln!(w, "{}{}", Indent(1), node,)?;
-        self.format_jsdoc(w, &node.js_doc, 2)?;
+        writeln!(w, "{}{}", Indent(indent + 1), node,)?;
+        self.format_jsdoc(w, &node.js_doc, indent + 2)?;
       }
     }
     for node in class_def.properties.iter().filter(|node| {
@@ -396,13 +630,14 @@ impl<'a> DocPrinter<'a> {
           != deno_ast::swc::ast::Accessibility::Private
     }) {
       for d in &node.decorators {
-        writeln!(w, "{}{}", Indent(1), d)?;
+        writeln!(w, "{}{}", Indent(indent + 1), d)?;
       }
-      writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      writeln!(w, "{}{}", Indent(indent + 1), node,)?;
+      self.format_jsdoc(w, &node.js_doc, indent + 2)?;
     }
     for index_sign_def in &class_def.index_signatures {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+      writeln!(w, "{}{}", Indent(indent + 1), index_sign_def)?;
+      self.format_jsdoc(w, &index_sign_def.js_doc, indent + 2)?;
     }
     for node in class_def.methods.iter().filter(|node| {
       self.private
@@ -419,20 +654,68 @@ impl<'a> DocPrinter<'a> {
         > 1;
       if !has_overloads || !node.function_def.has_body {
         for d in &node.function_def.decorators {
-          writeln!(w, "{}{}", Indent(1), d)?;
+          writeln!(w, "{}{}", Indent(indent + 1), d)?;
         }
-        writeln!(w, "{}{}", Indent(1), node,)?;
-        self.format_jsdoc(w, &node.js_doc, 2)?;
+        write!(w, "{}{}", Indent(indent + 1), node)?;
+        self.format_chainable(w, node.chainable)?;
+        self.format_jsdoc(w, &node.js_doc, indent + 2)?;
       }
     }
+    self.format_events(w, &class_def.events, indent)?;
     writeln!(w)
   }
 
-  fn format_enum(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
+  /// Ends the line of the signature of a method, labeling it when it is
+  /// chainable.
+  fn format_chainable(
+    &self,
+    w: &mut Formatter<'_>,
+    chainable: bool,
+  ) -> FmtResult {
+    if chainable {
+      writeln!(
+        w,
+        " {}",
+        colors::gray(&format!("({})", self.messages.chainable))
+      )
+    } else {
+      writeln!(w)
+    }
+  }
+
+  /// Writes the events of a class or interface, with their payload types.
+  fn format_events(
+    &self,
+    w: &mut Formatter<'_>,
+    events: &[EventDef],
+    indent: i64,
+  ) -> FmtResult {
+    for event in events {
+      write!(
+        w,
+        "{}{} {}",
+        Indent(indent + 1),
+        colors::magenta(&self.messages.event),
+        colors::green(format!("\"{}\"", event.name))
+      )?;
+      if let Some(payload) = &event.payload {
+        write!(w, ": {}", payload)?;
+      }
+      writeln!(w)?;
+    }
+    Ok(())
+  }
+
+  fn format_enum(
+    &self,
+    w: &mut Formatter<'_>,
+    node: &DocNode,
+    indent: i64,
+  ) -> FmtResult {
     let enum_def = node.enum_def.as_ref().unwrap();
     for member in &enum_def.members {
-      writeln!(w, "{}{}", Indent(1), colors::bold(&member.name))?;
-      self.format_jsdoc(w, &member.js_doc, 2)?;
+      writeln!(w, "{}{}", Indent(indent + 1), colors::bold(&member.name))?;
+      self.format_jsdoc(w, &member.js_doc, indent + 2)?;
     }
     writeln!(w)
   }
@@ -441,20 +724,28 @@ impl<'a> DocPrinter<'a> {
     &self,
     w: &mut Formatter<'_>,
     node: &DocNode,
+    indent: i64,
   ) -> FmtResult {
     let interface_def = node.interface_def.as_ref().unwrap();
 
+    for call_signature_def in &interface_def.call_signatures {
+      writeln!(w, "{}{}", Indent(indent + 1), call_signature_def)?;
+      self.format_jsdoc(w, &call_signature_def.js_doc, indent + 2)?;
+    }
     for property_def in &interface_def.properties {
-      writeln!(w, "{}{}", Indent(1), property_def)?;
-      self.format_jsdoc(w, &property_def.js_doc, 2)?;
+      writeln!(w, "{}{}", Indent(indent + 1), property_def)?;
+      self.format_jsdoc(w, &property_def.js_doc, indent + 2)?;
     }
     for method_def in &interface_def.methods {
-      writeln!(w, "{}{}", Indent(1), method_def)?;
-      self.format_jsdoc(w, &method_def.js_doc, 2)?;
+      write!(w, "{}{}", Indent(indent + 1), method_def)?;
+      self.format_chainable(w, method_def.chainable)?;
+      self.format_jsdoc(w, &method_def.js_doc, indent + 2)?;
     }
     for index_sign_def in &interface_def.index_signatures {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+      writeln!(w, "{}{}", Indent(indent + 1), index_sign_def)?;
+      self.format_jsdoc(w, &index_sign_def.js_doc, indent + 2)?;
     }
+    self.format_events(w, &interface_def.events, indent)?;
     writeln!(w)
   }
 
@@ -462,9 +753,10 @@ impl<'a> DocPrinter<'a> {
     &self,
     w: &mut Formatter<'_>,
     node: &DocNode,
+    indent: i64,
   ) -> FmtResult {
     let elements = &node.namespace_def.as_ref().unwrap().elements;
-    for node in elements {
+    for node in elements.iter().filter(|node| !node.hidden) {
       let has_overloads = if node.kind == DocNodeKind::Function {
         elements
           .iter()
@@ -474,8 +766,8 @@ impl<'a> DocPrinter<'a> {
       } else {
         false
       };
-      self.format_signature(w, node, 1, has_overloads)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
+      self.format_signature(w, node, indent + 1, has_overloads)?;
+      self.format_jsdoc(w, &node.js_doc, indent + 2)?;
     }
     writeln!(w)
   }
@@ -675,6 +967,21 @@ impl<'a> DocPrinter<'a> {
     )
   }
 
+  fn format_umd_namespace_signature(
+    &self,
+    w: &mut Formatter<'_>,
+    node: &DocNode,
+    indent: i64,
+  ) -> FmtResult {
+    writeln!(
+      w,
+      "{}{} {}",
+      Indent(indent),
+      colors::magenta("export as namespace"),
+      colors::bold(&node.name)
+    )
+  }
+
   fn format_variable_signature(
     &self,
     w: &mut Formatter<'_>,
@@ -707,6 +1014,35 @@ impl<'a> Display for DocPrinter<'a> {
   }
 }
 
+struct NodesDisplayer<'a> {
+  printer: &'a DocPrinter<'a>,
+  doc_nodes: &'a [DocNode],
+  indent: i64,
+}
+
+impl<'a> Display for NodesDisplayer<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    self.printer.format_nodes(f, self.doc_nodes, self.indent)
+  }
+}
+
+/// Displays the signature of a single doc node, without its documentation.
+pub(crate) struct SignatureDisplayer<'a> {
+  pub node: &'a DocNode,
+  pub has_overloads: bool,
+}
+
+impl<'a> Display for SignatureDisplayer<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    DocPrinter::new(&[], false, true).format_signature(
+      f,
+      self.node,
+      0,
+      self.has_overloads,
+    )
+  }
+}
+
 fn fmt_visibility(decl_kind: DeclarationKind) -> impl std::fmt::Display {
   colors::italic_gray(if decl_kind == DeclarationKind::Private {
     "private "
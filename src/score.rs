@@ -0,0 +1,227 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::ParamDef;
+
+/// The relative weight of each factor of the documentation score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScoreWeights {
+  pub module_doc: f64,
+  pub symbol_docs: f64,
+  pub param_docs: f64,
+  pub examples: f64,
+}
+
+impl Default for ScoreWeights {
+  fn default() -> Self {
+    Self {
+      module_doc: 1.0,
+      symbol_docs: 2.0,
+      param_docs: 1.0,
+      examples: 1.0,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleScore {
+  pub specifier: String,
+  pub has_module_doc: bool,
+  pub has_examples: bool,
+  pub documented_symbols: usize,
+  pub total_symbols: usize,
+  pub documented_params: usize,
+  pub total_params: usize,
+  /// The weighted score, between `0.0` and `1.0`.
+  pub score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreReport {
+  pub modules: Vec<ModuleScore>,
+  /// The mean of the module scores, between `0.0` and `1.0`.
+  pub score: f64,
+}
+
+/// Computes the documentation score of each module, from the module's
+/// specifier and the doc nodes parsed for it, along with an overall score.
+pub fn score_modules(
+  modules: &[(String, Vec<DocNode>)],
+  weights: &ScoreWeights,
+) -> ScoreReport {
+  let modules = modules
+    .iter()
+    .map(|(specifier, doc_nodes)| score_module(specifier, doc_nodes, weights))
+    .collect::<Vec<_>>();
+  let score = if modules.is_empty() {
+    0.0
+  } else {
+    modules.iter().map(|m| m.score).sum::<f64>() / modules.len() as f64
+  };
+  ScoreReport { modules, score }
+}
+
+/// Computes the documentation score of a single module.
+pub fn score_module(
+  specifier: &str,
+  doc_nodes: &[DocNode],
+  weights: &ScoreWeights,
+) -> ModuleScore {
+  let module_doc = doc_nodes
+    .iter()
+    .find(|node| node.kind == DocNodeKind::ModuleDoc)
+    .map(|node| &node.js_doc);
+  let has_module_doc = module_doc.map(has_doc).unwrap_or(false);
+  let mut counts = Counts {
+    has_examples: module_doc.map(has_example).unwrap_or(false),
+    ..Default::default()
+  };
+  count_nodes(&mut counts, "", doc_nodes);
+
+  let symbols_ratio =
+    ratio(counts.documented_symbols.len(), counts.symbols.len());
+  let params_ratio = ratio(counts.documented_params, counts.total_params);
+  let total_weight = weights.module_doc
+    + weights.symbol_docs
+    + weights.param_docs
+    + weights.examples;
+  let score = if total_weight > 0.0 {
+    (weights.module_doc * bool_ratio(has_module_doc)
+      + weights.symbol_docs * symbols_ratio
+      + weights.param_docs * params_ratio
+      + weights.examples * bool_ratio(counts.has_examples))
+      / total_weight
+  } else {
+    0.0
+  };
+
+  ModuleScore {
+    specifier: specifier.to_string(),
+    has_module_doc,
+    has_examples: counts.has_examples,
+    documented_symbols: counts.documented_symbols.len(),
+    total_symbols: counts.symbols.len(),
+    documented_params: counts.documented_params,
+    total_params: counts.total_params,
+    score,
+  }
+}
+
+#[derive(Default)]
+struct Counts {
+  has_examples: bool,
+  /// Qualified names of the symbols, so overloads and merged declarations
+  /// are only counted once.
+  symbols: HashSet<String>,
+  documented_symbols: HashSet<String>,
+  documented_params: usize,
+  total_params: usize,
+}
+
+fn count_nodes(counts: &mut Counts, prefix: &str, doc_nodes: &[DocNode]) {
+  for doc_node in doc_nodes {
+    if matches!(
+      doc_node.kind,
+      DocNodeKind::ModuleDoc | DocNodeKind::Import | DocNodeKind::UmdNamespace
+    ) || doc_node.declaration_kind == DeclarationKind::Private
+    {
+      continue;
+    }
+    let name = format!("{}{}", prefix, doc_node.name);
+    counts.symbols.insert(name.clone());
+    if has_doc(&doc_node.js_doc) {
+      counts.documented_symbols.insert(name.clone());
+    }
+    counts.has_examples |= has_example(&doc_node.js_doc);
+
+    if let Some(function_def) = &doc_node.function_def {
+      // only the signatures visible to users have their params counted
+      if !function_def.has_body
+        || doc_nodes
+          .iter()
+          .filter(|n| {
+            n.kind == DocNodeKind::Function && n.name == doc_node.name
+          })
+          .count()
+          == 1
+      {
+        count_params(counts, &function_def.params, &doc_node.js_doc);
+      }
+    }
+    if let Some(class_def) = &doc_node.class_def {
+      for method in &class_def.methods {
+        count_params(counts, &method.function_def.params, &method.js_doc);
+      }
+    }
+    if let Some(interface_def) = &doc_node.interface_def {
+      for method in &interface_def.methods {
+        count_params(counts, &method.params, &method.js_doc);
+      }
+    }
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      count_nodes(counts, &format!("{}.", name), &namespace_def.elements);
+    }
+  }
+}
+
+fn count_params(counts: &mut Counts, params: &[ParamDef], js_doc: &JsDoc) {
+  for param in params {
+    counts.total_params += 1;
+    let Some(name) = param.name() else {
+      continue;
+    };
+    let is_documented = js_doc.tags.iter().any(|tag| {
+      matches!(
+        tag,
+        JsDocTag::Param { name: tag_name, doc: Some(doc), .. }
+          if tag_name == name && !doc.trim().is_empty()
+      )
+    });
+    if is_documented {
+      counts.documented_params += 1;
+    }
+  }
+}
+
+fn has_doc(js_doc: &JsDoc) -> bool {
+  js_doc
+    .doc
+    .as_ref()
+    .map(|doc| !doc.trim().is_empty())
+    .unwrap_or(false)
+}
+
+fn has_example(js_doc: &JsDoc) -> bool {
+  js_doc
+    .tags
+    .iter()
+    .any(|tag| matches!(tag, JsDocTag::Example { .. }))
+}
+
+fn ratio(count: usize, total: usize) -> f64 {
+  if total == 0 {
+    1.0
+  } else {
+    count as f64 / total as f64
+  }
+}
+
+fn bool_ratio(value: bool) -> f64 {
+  if value {
+    1.0
+  } else {
+    0.0
+  }
+}
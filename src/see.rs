@@ -0,0 +1,164 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashSet;
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::Location;
+
+/// Finds the targets of `@see` tags which name a symbol that is not among
+/// the doc nodes, along with the location of the node or member which has
+/// the tag.
+///
+/// A target like `Conn.closeWrite` is looked up relative to the member or
+/// node which has the tag, then relative to its enclosing namespaces and
+/// finally from the root. Instance members can also be referred to as
+/// `Conn#closeWrite`. Targets which are URLs or paths are not checked.
+pub(crate) fn unresolved_see_targets(
+  doc_nodes: &[DocNode],
+) -> Vec<(Location, String)> {
+  let mut names = HashSet::new();
+  collect_names(&mut names, None, doc_nodes);
+  let mut unresolved = Vec::new();
+  check_doc_nodes(&names, &mut unresolved, None, doc_nodes);
+  unresolved
+}
+
+fn qualify(scope: Option<&str>, name: &str) -> String {
+  match scope {
+    Some(scope) => format!("{}.{}", scope, name),
+    None => name.to_string(),
+  }
+}
+
+fn collect_names(
+  names: &mut HashSet<String>,
+  namespace: Option<&str>,
+  doc_nodes: &[DocNode],
+) {
+  for doc_node in doc_nodes {
+    let qualified_name = qualify(namespace, &doc_node.name);
+    for_each_member(doc_node, &mut |name, _, _| {
+      names.insert(qualify(Some(&qualified_name), name));
+    });
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_names(names, Some(&qualified_name), &namespace_def.elements);
+    }
+    names.insert(qualified_name);
+  }
+}
+
+/// Calls `f` with the name, documentation and location of every member of a
+/// class, interface or enum.
+fn for_each_member<'a>(
+  doc_node: &'a DocNode,
+  f: &mut impl FnMut(&'a str, &'a JsDoc, &'a Location),
+) {
+  if let Some(class_def) = &doc_node.class_def {
+    for constructor in &class_def.constructors {
+      f(
+        &constructor.name,
+        &constructor.js_doc,
+        &constructor.location,
+      );
+    }
+    for property in &class_def.properties {
+      f(&property.name, &property.js_doc, &property.location);
+    }
+    for method in &class_def.methods {
+      f(&method.name, &method.js_doc, &method.location);
+    }
+  }
+  if let Some(interface_def) = &doc_node.interface_def {
+    for property in &interface_def.properties {
+      f(&property.name, &property.js_doc, &property.location);
+    }
+    for method in &interface_def.methods {
+      f(&method.name, &method.js_doc, &method.location);
+    }
+  }
+  if let Some(enum_def) = &doc_node.enum_def {
+    for member in &enum_def.members {
+      f(&member.name, &member.js_doc, &member.location);
+    }
+  }
+}
+
+fn check_doc_nodes(
+  names: &HashSet<String>,
+  unresolved: &mut Vec<(Location, String)>,
+  namespace: Option<&str>,
+  doc_nodes: &[DocNode],
+) {
+  for doc_node in doc_nodes {
+    let qualified_name = qualify(namespace, &doc_node.name);
+    check_js_doc(
+      names,
+      unresolved,
+      namespace,
+      &doc_node.js_doc,
+      &doc_node.location,
+    );
+    for_each_member(doc_node, &mut |_, js_doc, location| {
+      check_js_doc(names, unresolved, Some(&qualified_name), js_doc, location)
+    });
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      check_doc_nodes(
+        names,
+        unresolved,
+        Some(&qualified_name),
+        &namespace_def.elements,
+      );
+    }
+  }
+}
+
+fn check_js_doc(
+  names: &HashSet<String>,
+  unresolved: &mut Vec<(Location, String)>,
+  scope: Option<&str>,
+  js_doc: &JsDoc,
+  location: &Location,
+) {
+  for tag in &js_doc.tags {
+    let JsDocTag::See { target, .. } = tag else {
+      continue;
+    };
+    if is_link(target) || resolves(names, scope, target) {
+      continue;
+    }
+    let unresolved_target = (location.clone(), target.clone());
+    if !unresolved.contains(&unresolved_target) {
+      unresolved.push(unresolved_target);
+    }
+  }
+}
+
+fn is_link(target: &str) -> bool {
+  target.contains("://")
+    || target.starts_with('.')
+    || target.starts_with('/')
+    || target.starts_with('#')
+}
+
+fn resolves(
+  names: &HashSet<String>,
+  scope: Option<&str>,
+  target: &str,
+) -> bool {
+  let name = target
+    .trim_end_matches("()")
+    .replace(".prototype.", ".")
+    .replace('#', ".");
+  let mut scope = scope;
+  loop {
+    if names.contains(&qualify(scope, &name)) {
+      return true;
+    }
+    match scope {
+      Some(s) => scope = s.rsplit_once('.').map(|(parent, _)| parent),
+      None => return false,
+    }
+  }
+}
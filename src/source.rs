@@ -0,0 +1,130 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::ast::ClassMember;
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::ModuleDecl;
+use deno_ast::swc::ast::ModuleItem;
+use deno_ast::swc::ast::Stmt;
+use deno_ast::swc::ast::TsNamespaceBody;
+use deno_ast::ParsedSource;
+use deno_ast::SourcePos;
+use deno_ast::SourceRange;
+use deno_ast::SourceRanged;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::SourceTextInfo;
+use deno_graph::ModuleSpecifier;
+
+use crate::node::Location;
+
+/// Provides the sources of modules, so the source text of doc nodes can be
+/// fetched on demand instead of being embedded in the output.
+pub trait SourceTextProvider {
+  fn get_parsed_source(
+    &self,
+    specifier: &ModuleSpecifier,
+  ) -> Option<ParsedSource>;
+}
+
+/// Gets the source text of the declaration or member found at a location,
+/// such as the `location` of a doc node or of a class member. The location
+/// must use the default position encoding.
+pub fn source_text(
+  provider: &dyn SourceTextProvider,
+  location: &Location,
+) -> Option<String> {
+  let specifier = ModuleSpecifier::parse(&location.filename).ok()?;
+  let parsed_source = provider.get_parsed_source(&specifier)?;
+  let range = source_range(&parsed_source, location)?;
+  Some(range.text_fast(parsed_source.text_info()).to_string())
+}
+
+/// Gets the range of the declaration or member found at a location, as for
+/// [`source_text`].
+pub(crate) fn source_range(
+  parsed_source: &ParsedSource,
+  location: &Location,
+) -> Option<SourceRange> {
+  let pos = location_to_pos(parsed_source.text_info(), location)?;
+  let mut ranges = Vec::new();
+  collect_module_item_ranges(&mut ranges, &parsed_source.module().body);
+  // the outermost range wins, so that an exported declaration includes its
+  // `export` keyword
+  ranges.into_iter().find(|range| range.start == pos)
+}
+
+pub(crate) fn location_to_pos(
+  text_info: &SourceTextInfo,
+  location: &Location,
+) -> Option<SourcePos> {
+  let line_index = location.line.checked_sub(1)?;
+  if line_index >= text_info.lines_count() {
+    return None;
+  }
+  let mut display_col = 0;
+  let mut offset = 0;
+  for c in text_info.line_text(line_index).chars() {
+    if display_col >= location.col {
+      break;
+    }
+    // see `get_text_info_location`
+    display_col += if c == '\t' { 4 } else { 1 };
+    offset += c.len_utf8();
+  }
+  Some(text_info.line_start(line_index) + offset)
+}
+
+fn collect_module_item_ranges(
+  ranges: &mut Vec<SourceRange>,
+  items: &[ModuleItem],
+) {
+  for item in items {
+    ranges.push(item.range());
+    match item {
+      ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+        collect_decl_ranges(ranges, &export_decl.decl)
+      }
+      ModuleItem::Stmt(Stmt::Decl(decl)) => collect_decl_ranges(ranges, decl),
+      _ => {}
+    }
+  }
+}
+
+fn collect_decl_ranges(ranges: &mut Vec<SourceRange>, decl: &Decl) {
+  ranges.push(decl.range());
+  match decl {
+    Decl::Class(class_decl) => {
+      ranges.push(class_decl.class.range());
+      for member in &class_decl.class.body {
+        if !matches!(member, ClassMember::Empty(_)) {
+          ranges.push(member.range());
+        }
+      }
+    }
+    Decl::TsInterface(interface_decl) => {
+      for element in &interface_decl.body.body {
+        ranges.push(element.range());
+      }
+    }
+    Decl::TsEnum(enum_decl) => {
+      for member in &enum_decl.members {
+        ranges.push(member.range());
+      }
+    }
+    Decl::TsModule(module_decl) => {
+      let mut body = module_decl.body.as_ref();
+      while let Some(namespace_body) = body {
+        match namespace_body {
+          TsNamespaceBody::TsModuleBlock(block) => {
+            collect_module_item_ranges(ranges, &block.body);
+            body = None;
+          }
+          TsNamespaceBody::TsNamespaceDecl(namespace_decl) => {
+            ranges.push(namespace_decl.range());
+            body = Some(&namespace_decl.body);
+          }
+        }
+      }
+    }
+    Decl::Fn(_) | Decl::Var(_) | Decl::TsTypeAlias(_) | Decl::Using(_) => {}
+  }
+}
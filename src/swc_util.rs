@@ -1,8 +1,12 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::ast::Lit;
+use deno_ast::swc::ast::MemberProp;
 use deno_ast::swc::ast::ModuleExportName;
 use deno_ast::swc::common::comments::Comment;
 use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::MediaType;
 use deno_ast::ParsedSource;
 use deno_ast::SourcePos;
 use deno_ast::SourceRange;
@@ -12,17 +16,94 @@ use regex::Regex;
 
 use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
+use crate::js_doc::RawJsDoc;
+use crate::node::LeadingComment;
+use crate::node::LeadingCommentKind;
 use crate::node::Location;
 
 lazy_static! {
   static ref JS_DOC_RE: Regex = Regex::new(r"\s*\* ?").unwrap();
+  static ref JS_DOC_MODULE_NAME_RE: Regex =
+    Regex::new(r"^\s*@module\s+([a-zA-Z_$][\w$]*)").unwrap();
+}
+
+/// The well-known symbols, which are the static properties of `Symbol`
+/// holding symbols used as keys of members with special meaning.
+const WELL_KNOWN_SYMBOLS: &[&str] = &[
+  "asyncDispose",
+  "asyncIterator",
+  "dispose",
+  "hasInstance",
+  "isConcatSpreadable",
+  "iterator",
+  "match",
+  "matchAll",
+  "replace",
+  "search",
+  "species",
+  "split",
+  "toPrimitive",
+  "toStringTag",
+  "unscopables",
+];
+
+/// If the expression is a well-known symbol, like `Symbol.iterator` or
+/// `Symbol["iterator"]`, returns its canonical name.
+pub(crate) fn well_known_symbol_name(expr: &Expr) -> Option<String> {
+  let Expr::Member(member_expr) = expr else {
+    return None;
+  };
+  let Expr::Ident(obj) = &*member_expr.obj else {
+    return None;
+  };
+  if &*obj.sym != "Symbol" {
+    return None;
+  }
+  let prop = match &member_expr.prop {
+    MemberProp::Ident(ident) => ident.sym.to_string(),
+    MemberProp::Computed(computed) => match &*computed.expr {
+      Expr::Lit(Lit::Str(str_)) => str_.value.to_string(),
+      _ => return None,
+    },
+    MemberProp::PrivateName(_) => return None,
+  };
+  if WELL_KNOWN_SYMBOLS.contains(&prop.as_str()) {
+    Some(format!("Symbol.{}", prop))
+  } else {
+    None
+  }
+}
+
+pub(crate) fn is_well_known_symbol(expr: &Expr) -> bool {
+  well_known_symbol_name(expr).is_some()
+}
+
+/// Whether the module is JavaScript, which types declarations with JSDoc
+/// tags instead of TypeScript syntax.
+pub(crate) fn is_javascript(parsed_source: &ParsedSource) -> bool {
+  matches!(
+    parsed_source.media_type(),
+    MediaType::JavaScript | MediaType::Jsx | MediaType::Mjs | MediaType::Cjs
+  )
 }
 
 pub(crate) fn is_false(b: &bool) -> bool {
   !b
 }
 
-fn parse_js_doc(js_doc_comment: &Comment) -> Option<JsDoc> {
+pub(crate) fn parse_js_doc(js_doc_comment: &Comment) -> Option<JsDoc> {
+  let js_doc = parse_js_doc_with_ignored(js_doc_comment);
+  if js_doc.tags.contains(&JsDocTag::Ignore) {
+    None
+  } else {
+    Some(js_doc)
+  }
+}
+
+/// Like [`parse_js_doc`], but also parses the documentation of declarations
+/// marked `@ignore`, keeping the tag.
+fn parse_js_doc_with_ignored(js_doc_comment: &Comment) -> JsDoc {
+  trace_span!(TRACE, "parse_js_doc");
   let txt = js_doc_comment
     .text
     .split('\n')
@@ -31,7 +112,14 @@ fn parse_js_doc(js_doc_comment: &Comment) -> Option<JsDoc> {
     .join("\n")
     .trim()
     .to_string();
-  let js_doc: JsDoc = txt.into();
+  txt.into()
+}
+
+pub(crate) fn js_doc_for_range(
+  parsed_source: &ParsedSource,
+  range: &SourceRange,
+) -> Option<JsDoc> {
+  let js_doc = js_doc_for_range_with_ignored(parsed_source, range);
   if js_doc.tags.contains(&JsDocTag::Ignore) {
     None
   } else {
@@ -39,10 +127,12 @@ fn parse_js_doc(js_doc_comment: &Comment) -> Option<JsDoc> {
   }
 }
 
-pub(crate) fn js_doc_for_range(
+/// Like [`js_doc_for_range`], but also gets the documentation of
+/// declarations marked `@ignore`, keeping the tag.
+pub(crate) fn js_doc_for_range_with_ignored(
   parsed_source: &ParsedSource,
   range: &SourceRange,
-) -> Option<JsDoc> {
+) -> JsDoc {
   let comments = parsed_source
     .comments()
     .get_leading(range.start)
@@ -51,25 +141,105 @@ pub(crate) fn js_doc_for_range(
   if let Some(js_doc_comment) = comments.iter().rev().find(|comment| {
     comment.kind == CommentKind::Block && comment.text.starts_with('*')
   }) {
-    parse_js_doc(js_doc_comment)
+    // the doc of a package is never the doc of its first declaration
+    if is_package_documentation(js_doc_comment) {
+      return JsDoc::default();
+    }
+    let mut js_doc = parse_js_doc_with_ignored(js_doc_comment);
+    js_doc.raw = Some(raw_js_doc(parsed_source, js_doc_comment));
+    js_doc
   } else {
-    Some(JsDoc::default())
+    JsDoc::default()
+  }
+}
+
+/// The comments which precede the position, as written, including the ones
+/// which are not JSDoc.
+pub(crate) fn leading_comments_for_pos(
+  parsed_source: &ParsedSource,
+  pos: SourcePos,
+) -> Vec<LeadingComment> {
+  parsed_source
+    .comments()
+    .get_leading(pos)
+    .into_iter()
+    .flatten()
+    .map(|comment| {
+      let range = comment.range();
+      LeadingComment {
+        kind: match comment.kind {
+          CommentKind::Line => LeadingCommentKind::Line,
+          CommentKind::Block => LeadingCommentKind::Block,
+        },
+        text: comment.text.to_string(),
+        location: get_location(parsed_source, range.start),
+        end: get_location(parsed_source, range.end),
+      }
+    })
+    .collect()
+}
+
+/// The comment as it is written in the source.
+fn raw_js_doc(parsed_source: &ParsedSource, comment: &Comment) -> RawJsDoc {
+  let range = comment.range();
+  RawJsDoc {
+    text: parsed_source.text_info().range_text(&range).to_string(),
+    location: get_location(parsed_source, range.start),
+    end: get_location(parsed_source, range.end),
+  }
+}
+
+fn is_package_documentation(comment: &Comment) -> bool {
+  comment.text.contains("@packageDocumentation")
+}
+
+/// The text of a `//` comment which trails the range on the line where it
+/// ends, separated from it by no more than a `,` or `;`, like the comment of
+/// `a: string; // The a.`.
+pub(crate) fn trailing_line_comment_for_range(
+  parsed_source: &ParsedSource,
+  range: &SourceRange,
+) -> Option<String> {
+  let text_info = parsed_source.text_info();
+  let line_end = text_info.line_end(text_info.line_index(range.end));
+  let rest = text_info
+    .range_text(&SourceRange::new(range.end, line_end))
+    .trim_start();
+  let rest = rest.strip_prefix(&[',', ';'][..]).unwrap_or(rest);
+  let comment = rest.trim_start().strip_prefix("//")?.trim();
+  if comment.is_empty() {
+    None
+  } else {
+    Some(comment.to_string())
   }
 }
 
 /// Inspects leading comments in the source and returns the first JSDoc comment
 /// with a `@module` tag along with its associated range, otherwise returns
-/// `None`.
+/// `None`. A `@packageDocumentation` comment is also found after other JSDoc
+/// comments, like a `@license` comment.
 pub(crate) fn module_js_doc_for_source(
   parsed_source: &ParsedSource,
 ) -> Option<Option<(JsDoc, SourceRange)>> {
   let comments = parsed_source.get_leading_comments();
-  if let Some(js_doc_comment) = comments.iter().find(|comment| {
+  let mut js_doc_comments = comments.iter().filter(|comment| {
     comment.kind == CommentKind::Block && comment.text.starts_with('*')
-  }) {
+  });
+  if let Some(js_doc_comment) = js_doc_comments
+    .clone()
+    .skip(1)
+    .find(|comment| is_package_documentation(comment))
+  {
+    if let Some(mut js_doc) = parse_js_doc(js_doc_comment) {
+      js_doc.raw = Some(raw_js_doc(parsed_source, js_doc_comment));
+      return Some(Some((js_doc, js_doc_comment.range())));
+    }
+  }
+  if let Some(js_doc_comment) = js_doc_comments.next() {
     let leading_js_doc = parse_js_doc(js_doc_comment);
-    if let Some(js_doc) = leading_js_doc {
-      if js_doc.tags.contains(&JsDocTag::Module) {
+    if let Some(mut js_doc) = leading_js_doc {
+      if js_doc.is_module_doc() {
+        js_doc.raw = Some(raw_js_doc(parsed_source, js_doc_comment));
         return Some(Some((js_doc, js_doc_comment.range())));
       }
     } else {
@@ -79,6 +249,24 @@ pub(crate) fn module_js_doc_for_source(
   None
 }
 
+/// The name given to the `@module` tag of the module doc of the source, like
+/// `config` for `@module config`.
+pub(crate) fn module_js_doc_name(
+  parsed_source: &ParsedSource,
+) -> Option<String> {
+  module_js_doc_for_source(parsed_source).flatten()?;
+  let comments = parsed_source.get_leading_comments();
+  let js_doc_comment = comments.iter().find(|comment| {
+    comment.kind == CommentKind::Block && comment.text.starts_with('*')
+  })?;
+  js_doc_comment.text.split('\n').find_map(|line| {
+    let line = JS_DOC_RE.replace(line, "");
+    JS_DOC_MODULE_NAME_RE
+      .captures(&line)
+      .map(|caps| caps[1].to_string())
+  })
+}
+
 pub fn get_location(parsed_source: &ParsedSource, pos: SourcePos) -> Location {
   get_text_info_location(
     parsed_source.specifier(),
@@ -100,6 +288,7 @@ pub fn get_text_info_location(
     // todo(#150): make 0-indexed
     line: line_and_column_index.line_number,
     col: line_and_column_index.column_number - 1,
+    requested_filename: None,
   }
 }
 
@@ -0,0 +1,140 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::fmt::Write;
+
+use deno_ast::swc::ast::VarDeclKind;
+use deno_graph::ModuleSpecifier;
+
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::node::Location;
+
+struct Tag {
+  name: String,
+  file: String,
+  line: usize,
+  kind: char,
+  scope: Option<(&'static str, String)>,
+}
+
+/// Writes a tags file in the extended ctags format for the exported symbols
+/// and their members, so editors can jump to their definitions.
+///
+/// The kinds are `c` for classes, `C` for constants, `e` for enum members, `f`
+/// for functions, `g` for enums, `i` for interfaces, `m` for methods, `n` for
+/// namespaces, `p` for properties, `t` for type aliases and `v` for other
+/// variables. Members and namespace elements have a scope, like `class:A`.
+/// Lines are taken as 1-indexed, as with the default position encoding, and
+/// local files are referred to by their path.
+pub fn to_ctags(doc_nodes: &[DocNode]) -> String {
+  let mut tags = Vec::new();
+  collect_tags(&mut tags, None, doc_nodes);
+  tags.sort_by(|a, b| {
+    (&a.name, &a.file, a.line).cmp(&(&b.name, &b.file, b.line))
+  });
+
+  let mut text = String::new();
+  writeln!(text, "!_TAG_FILE_FORMAT\t2\t/extended format/").unwrap();
+  writeln!(text, "!_TAG_FILE_SORTED\t1\t/0=unsorted, 1=sorted/").unwrap();
+  for tag in tags {
+    write!(
+      text,
+      "{}\t{}\t{};\"\t{}",
+      tag.name, tag.file, tag.line, tag.kind
+    )
+    .unwrap();
+    if let Some((scope_kind, scope)) = tag.scope {
+      write!(text, "\t{}:{}", scope_kind, scope).unwrap();
+    }
+    writeln!(text).unwrap();
+  }
+  text
+}
+
+fn collect_tags(
+  tags: &mut Vec<Tag>,
+  namespace: Option<&str>,
+  doc_nodes: &[DocNode],
+) {
+  for doc_node in doc_nodes {
+    if doc_node.declaration_kind == DeclarationKind::Private {
+      continue;
+    }
+    let kind = match doc_node.kind {
+      DocNodeKind::Class => 'c',
+      DocNodeKind::Enum => 'g',
+      DocNodeKind::Function => 'f',
+      DocNodeKind::Interface => 'i',
+      DocNodeKind::Namespace => 'n',
+      DocNodeKind::TypeAlias => 't',
+      DocNodeKind::Variable => match &doc_node.variable_def {
+        Some(variable_def) if variable_def.kind == VarDeclKind::Const => 'C',
+        _ => 'v',
+      },
+      DocNodeKind::ModuleDoc
+      | DocNodeKind::Import
+      | DocNodeKind::UmdNamespace => continue,
+    };
+    tags.push(Tag {
+      name: doc_node.name.clone(),
+      file: tag_file(&doc_node.location),
+      line: doc_node.location.line,
+      kind,
+      scope: namespace.map(|namespace| ("namespace", namespace.to_string())),
+    });
+
+    let qualified_name = match namespace {
+      Some(namespace) => format!("{}.{}", namespace, doc_node.name),
+      None => doc_node.name.clone(),
+    };
+    let mut push_member =
+      |scope_kind: &'static str, name: &str, location: &Location, kind| {
+        tags.push(Tag {
+          name: name.to_string(),
+          file: tag_file(location),
+          line: location.line,
+          kind,
+          scope: Some((scope_kind, qualified_name.clone())),
+        })
+      };
+    if let Some(class_def) = &doc_node.class_def {
+      for constructor in &class_def.constructors {
+        push_member("class", "constructor", &constructor.location, 'm');
+      }
+      for property in &class_def.properties {
+        push_member("class", &property.name, &property.location, 'p');
+      }
+      for method in &class_def.methods {
+        push_member("class", &method.name, &method.location, 'm');
+      }
+    }
+    if let Some(interface_def) = &doc_node.interface_def {
+      for property in &interface_def.properties {
+        push_member("interface", &property.name, &property.location, 'p');
+      }
+      for method in &interface_def.methods {
+        push_member("interface", &method.name, &method.location, 'm');
+      }
+    }
+    if let Some(enum_def) = &doc_node.enum_def {
+      for member in &enum_def.members {
+        push_member("enum", &member.name, &member.location, 'e');
+      }
+    }
+
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_tags(tags, Some(&qualified_name), &namespace_def.elements);
+    }
+  }
+}
+
+/// The path of a local file, or the specifier of a remote module.
+fn tag_file(location: &Location) -> String {
+  ModuleSpecifier::parse(&location.filename)
+    .ok()
+    .filter(|specifier| specifier.scheme() == "file")
+    .and_then(|specifier| specifier.to_file_path().ok())
+    .map(|path| path.display().to_string())
+    .unwrap_or_else(|| location.filename.clone())
+}
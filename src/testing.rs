@@ -0,0 +1,98 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+//! Helpers for testing code which builds on doc nodes, against module graphs
+//! built from in-memory sources.
+
+use deno_graph::source::MemoryLoader;
+use deno_graph::source::Source;
+use deno_graph::BuildOptions;
+use deno_graph::CapturingModuleAnalyzer;
+use deno_graph::DefaultModuleParser;
+use deno_graph::GraphKind;
+use deno_graph::ModuleGraph;
+use deno_graph::ModuleSpecifier;
+
+#[doc(hidden)]
+pub use serde_json;
+
+/// The headers of a source, for example a `content-type`.
+pub type MaybeHeaders<S> = Option<Vec<(S, S)>>;
+
+/// Builds a type only module graph from `root`, with the modules of
+/// `sources`, which are tuples of specifier, headers and content.
+///
+/// Returns the graph, an analyzer to pass to the parser, and the parsed root
+/// specifier.
+pub async fn setup<S: AsRef<str> + Copy>(
+  root: S,
+  sources: Vec<(S, MaybeHeaders<S>, S)>,
+) -> (ModuleGraph, CapturingModuleAnalyzer, ModuleSpecifier) {
+  let sources = sources
+    .into_iter()
+    .map(|(s, h, c)| {
+      (
+        s,
+        Source::Module {
+          specifier: s,
+          maybe_headers: h,
+          content: c,
+        },
+      )
+    })
+    .collect();
+  let mut memory_loader = MemoryLoader::new(sources, vec![]);
+  let root = ModuleSpecifier::parse(root.as_ref()).unwrap();
+  let analyzer = create_analyzer();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      vec![root.clone()],
+      &mut memory_loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        ..Default::default()
+      },
+    )
+    .await;
+  (graph, analyzer, root)
+}
+
+/// An analyzer which captures the parsed sources, as the doc parser needs.
+pub fn create_analyzer() -> CapturingModuleAnalyzer {
+  let source_parser = DefaultModuleParser::new_for_analysis();
+  CapturingModuleAnalyzer::new(Some(Box::new(source_parser)), None)
+}
+
+/// Asserts that a string contains any of the given patterns.
+#[macro_export]
+macro_rules! assert_contains {
+  ($string:expr, $($test:expr),+ $(,)?) => {
+    let string = &$string; // This might be a function call or something
+    if !($(string.contains($test))||+) {
+      panic!("{:?} does not contain any of {:?}", string, [$($test),+]);
+    }
+  }
+}
+
+/// Asserts that a string contains none of the given patterns.
+#[macro_export]
+macro_rules! assert_not_contains {
+  ($string:expr, $($test:expr),+ $(,)?) => {
+    let string = &$string; // This might be a function call or something
+    if !($(!string.contains($test))||+) {
+      panic!("{:?} contained {:?}", string, [$($test),+]);
+    }
+  }
+}
+
+/// Asserts that doc nodes serialize to the given JSON, which is written as
+/// for `serde_json::json!`. The `assert_eq!` in scope at the call site is
+/// used, so `pretty_assertions` can be swapped in.
+#[macro_export]
+macro_rules! assert_json {
+  ($doc_nodes:expr, $json:tt) => {
+    let actual = $crate::testing::serde_json::to_value(&$doc_nodes).unwrap();
+    let expected = $crate::testing::serde_json::json!($json);
+    assert_eq!(actual, expected);
+  };
+}
@@ -1,71 +1,33 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::assert_contains;
+use crate::inherit::InheritedMembers;
+use crate::js_doc::JsDocTag;
+use crate::module_filter::ModuleFilter;
+use crate::node::DeclarationKind;
+use crate::node::DocNodeKind;
+use crate::node::ReleaseTag;
+use crate::parser::DocDiagnosticKind;
+use crate::parser::DocError;
 use crate::parser::DocParser;
+use crate::parser::DocParserOptions;
+use crate::parser::StrictMode;
+use crate::parser::VisibilityFilter;
 use crate::printer::DocPrinter;
+use crate::testing::create_analyzer;
+use crate::testing::setup;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use deno_ast::MediaType;
 use deno_graph::source::MemoryLoader;
 use deno_graph::source::Source;
 use deno_graph::BuildOptions;
-use deno_graph::CapturingModuleAnalyzer;
-use deno_graph::DefaultModuleParser;
 use deno_graph::GraphKind;
 use deno_graph::ModuleGraph;
 use deno_graph::ModuleSpecifier;
 use pretty_assertions::assert_eq;
 use serde_json::json;
-
-type MaybeHeaders<S> = Option<Vec<(S, S)>>;
-
-pub(crate) async fn setup<S: AsRef<str> + Copy>(
-  root: S,
-  sources: Vec<(S, MaybeHeaders<S>, S)>,
-) -> (ModuleGraph, CapturingModuleAnalyzer, ModuleSpecifier) {
-  let sources = sources
-    .into_iter()
-    .map(|(s, h, c)| {
-      (
-        s,
-        Source::Module {
-          specifier: s,
-          maybe_headers: h,
-          content: c,
-        },
-      )
-    })
-    .collect();
-  let mut memory_loader = MemoryLoader::new(sources, vec![]);
-  let root = ModuleSpecifier::parse(root.as_ref()).unwrap();
-  let analyzer = create_analyzer();
-  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
-  graph
-    .build(
-      vec![root.clone()],
-      &mut memory_loader,
-      BuildOptions {
-        module_analyzer: Some(&analyzer),
-        ..Default::default()
-      },
-    )
-    .await;
-  (graph, analyzer, root)
-}
-
-macro_rules! assert_contains {
-  ($string:expr, $($test:expr),+ $(,)?) => {
-    let string = &$string; // This might be a function call or something
-    if !($(string.contains($test))||+) {
-      panic!("{:?} does not contain any of {:?}", string, [$($test),+]);
-    }
-  }
-}
-
-macro_rules! assert_not_contains {
-  ($string:expr, $($test:expr),+ $(,)?) => {
-    let string = &$string; // This might be a function call or something
-    if !($(!string.contains($test))||+) {
-      panic!("{:?} contained {:?}", string, [$($test),+]);
-    }
-  }
-}
+use std::collections::HashMap;
 
 macro_rules! doc_test {
   ( $name:ident, $source:expr; $block:expr ) => {
@@ -76,22 +38,23 @@ macro_rules! doc_test {
     doc_test!($name, $source, true; $block);
   };
 
-  ( $name:ident, $source:expr, $private:expr; $block:expr ) => {
-    doc_test!($name, $source, $private; $block, vec![]);
+  ( $name:ident, $source:expr, options: $options:expr; $block:expr ) => {
+    doc_test!($name, $source, options: $options; $block, vec![]);
   };
 
-  ( $name:ident, $source:expr, $private:expr; $block:expr, $diagnostics:expr ) => {
+  ( $name:ident, $source:expr, options: $options:expr; $block:expr, $diagnostics:expr ) => {
     #[tokio::test]
     async fn $name() {
       use super::setup;
 
       let source_code = $source;
-      let private = $private;
+      let options: DocParserOptions = $options;
+      let private = options.private;
 
       let (graph, analyzer, specifier) = setup("file:///test.ts", vec![
         ("file:///test.ts", None, source_code)
       ]).await;
-      let parser = DocParser::new(&graph, private, analyzer.as_capturing_parser()).unwrap();
+      let parser = DocParser::new_with_options(&graph, analyzer.as_capturing_parser(), options).unwrap();
       let entries = parser
         .parse(&specifier)
         .unwrap();
@@ -111,6 +74,17 @@ macro_rules! doc_test {
       assert_eq!(actual_diagnostics, expected_diagnostics, "Diagnostics match.");
     }
   };
+
+  ( $name:ident, $source:expr, $private:expr; $block:expr ) => {
+    doc_test!($name, $source, $private; $block, vec![]);
+  };
+
+  ( $name:ident, $source:expr, $private:expr; $block:expr, $diagnostics:expr ) => {
+    doc_test!($name, $source, options: DocParserOptions {
+      private: $private,
+      ..Default::default()
+    }; $block, $diagnostics);
+  };
 }
 
 macro_rules! contains_test {
@@ -148,16 +122,26 @@ macro_rules! json_test {
     json_test!($name, $source, true; $json);
   };
 
+  ( $name:ident, $source:expr, options: $options:expr; $json:tt ) => {
+    json_test!($name, $source, options: $options; $json, vec![]);
+  };
+
+  ( $name:ident, $source:expr, options: $options:expr; $json:tt, $diagnostics:expr ) => {
+    doc_test!($name, $source, options: $options; |entries, _doc| {
+      use pretty_assertions::assert_eq;
+      $crate::assert_json!(entries, $json);
+    }, $diagnostics);
+  };
+
   ( $name:ident, $source:expr, $private:expr; $json:tt ) => {
     json_test!($name, $source, $private; $json, vec![]);
   };
 
   ( $name:ident, $source:expr, $private:expr; $json:tt, $diagnostics:expr ) => {
-    doc_test!($name, $source, $private; |entries, _doc| {
-      let actual = serde_json::to_value(&entries).unwrap();
-      let expected_json = json!($json);
-      pretty_assertions::assert_eq!(actual, expected_json);
-    }, $diagnostics);
+    json_test!($name, $source, options: DocParserOptions {
+      private: $private,
+      ..Default::default()
+    }; $json, $diagnostics);
   };
 }
 
@@ -419,6 +403,44 @@ export function fooFn(a: number) {
     .contains("function fooFn(a: number)"));
 }
 
+#[tokio::test]
+async fn print_node_subsets() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function f() {}
+
+export namespace ns {
+  /** The class. */
+  export class A {
+    b(): void {}
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let printer = DocPrinter::new(&entries, false, false);
+  let f = entries.iter().find(|n| n.name == "f").unwrap();
+  let output = printer.print_nodes(std::slice::from_ref(f), 0);
+  assert!(output.contains("function f()"));
+  assert!(!output.contains("namespace ns"));
+
+  let ns = entries.iter().find(|n| n.name == "ns").unwrap();
+  let output = printer.print_namespace_elements(ns);
+  let lines = output.lines().collect::<Vec<_>>();
+  assert!(lines.iter().any(|line| line.starts_with("  class A")));
+  assert!(lines.iter().any(|line| line.starts_with("    The class.")));
+  assert!(lines.iter().any(|line| line.starts_with("    b(")));
+  assert!(printer.print_namespace_elements(f).is_empty());
+}
+
 #[tokio::test]
 async fn reexports_has_same_name() {
   let reexport_source_code = r#"
@@ -539,6 +561,40 @@ async fn deep_reexports() {
     .contains("const foo"))
 }
 
+#[tokio::test]
+async fn symbol_aliases() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      ("file:///foo.ts", None, "export function foo() {}"),
+      (
+        "file:///reexport.ts",
+        None,
+        "export { foo as bar } from './foo.ts';",
+      ),
+      (
+        "file:///mod.ts",
+        None,
+        "export { bar as baz } from './reexport.ts';\nconst a = 1;\nexport { a, a as b };",
+      ),
+    ],
+  )
+  .await;
+  let doc_parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = doc_parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(entries.len(), 3);
+  let aliases = doc_parser
+    .symbol_aliases()
+    .iter()
+    .map(|alias| alias.to_string())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    aliases,
+    vec!["b -> file:///mod.ts#a", "baz -> file:///foo.ts#foo"]
+  );
+}
+
 #[tokio::test]
 async fn reexport_module_doc() {
   let mod_doc_source_code = r#"
@@ -983,584 +1039,5046 @@ async fn variable_syntax() {
 }
 
 #[tokio::test]
-async fn json_module() {
+async fn document_globals() {
   let (graph, analyzer, specifier) = setup(
-    "file:///foo.ts",
-    vec![
-      ("file:///foo.ts", None, "export { default as configFile } from './bar.json' assert { type: 'json' };"),
-      ("file:///bar.json", None, r#"{ "a": 5, "b": "text", "c": null, "d": [1, 2], "e": { "a": 1 } }"#),
-    ],
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"export declare const a: string;
+
+declare global {
+  /** A global variable. */
+  var myGlobal: string;
+  function myGlobalFn(): void;
+}
+"#,
+    )],
   )
   .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      document_globals: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let declared = entries
+    .iter()
+    .filter(|n| n.declaration_kind == DeclarationKind::Declare)
+    .collect::<Vec<_>>();
+  assert_eq!(declared.len(), 2);
+  assert_eq!(declared[0].name, "myGlobal");
+  assert_eq!(
+    declared[0].js_doc.doc.as_deref(),
+    Some("A global variable.")
+  );
+  assert_eq!(declared[1].name, "myGlobalFn");
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///globals.d.ts",
+    vec![(
+      "file:///globals.d.ts",
+      None,
+      "interface Window {}\nfunction fetchIt(input: string): void;\n",
+    )],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      document_globals: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let names = entries
+    .iter()
+    .map(|n| (n.name.as_str(), n.declaration_kind))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    names,
+    vec![
+      ("Window", DeclarationKind::Declare),
+      ("fetchIt", DeclarationKind::Declare),
+    ]
+  );
 
+  // without the option, implicitly declared items are not documented
   let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
     .unwrap()
-    .parse_with_reexports(&specifier)
+    .parse(&specifier)
     .unwrap();
-
-  let expected_json = json!([
-    {
-      "kind": "variable",
-      "name": "configFile",
-      "location": {
-        "filename": "file:///bar.json",
-        "line": 1,
-        "col": 0
-      },
-      "declarationKind": "export",
-      "variableDef": {
-        "tsType": {
-          "repr": "",
-          "kind": "typeLiteral",
-          "typeLiteral": {
-            "methods": [],
-            "properties": [{
-              "name": "a",
-              "params": [],
-              "computed": false,
-              "optional": false,
-              "tsType": {
-                "repr": "5",
-                "kind": "literal",
-                "literal": {
-                  "kind": "number",
-                  "number": 5.0,
-                },
-              },
-              "typeParams": []
-            }, {
-              "name": "b",
-              "params": [],
-              "computed": false,
-              "optional": false,
-              "tsType": {
-                "repr": "text",
-                "kind": "literal",
-                "literal": {
-                  "kind": "string",
-                  "string": "text",
-                },
-              },
-              "typeParams": []
-            }, {
-              "name": "c",
-              "params": [],
-              "computed": false,
-              "optional": false,
-              "tsType": {
-                "repr": "null",
-                "kind": "keyword",
-                "keyword": "null",
-              },
-              "typeParams": []
-            }, {
-              "name": "d",
-              "params": [],
-              "computed": false,
-              "optional": false,
-              "tsType": {
-                "repr": "unknown[]",
-                "kind": "array",
-                "array": {
-                  "repr": "unknown",
-                  "kind": "keyword",
-                  "keyword": "unknown",
-                },
-              },
-              "typeParams": []
-            }, {
-              "name": "e",
-              "params": [],
-              "computed": false,
-              "optional": false,
-              "tsType": {
-                "repr": "",
-                "kind": "typeLiteral",
-                "typeLiteral": {
-                  "methods": [],
-                  "properties": [{
-                    "name": "a",
-                    "params": [],
-                    "computed": false,
-                    "optional": false,
-                    "tsType": {
-                      "repr": "1",
-                      "kind": "literal",
-                      "literal": {
-                        "kind": "number",
-                        "number": 1.0,
-                      },
-                    },
-                    "typeParams": []
-                  }],
-                  "callSignatures": [],
-                  "indexSignatures": [],
-                },
-              },
-              "typeParams": []
-            }],
-            "callSignatures": [],
-            "indexSignatures": [],
-          },
-        },
-        "kind": "var",
-      },
-    },
-  ]);
-  let actual = serde_json::to_value(&entries).unwrap();
-  assert_eq!(actual, expected_json);
+  assert!(entries.is_empty());
 }
 
-mod serialization {
-  use crate::*;
+#[tokio::test]
+async fn group_by_category() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** @module */
 
-  json_test!(module_docs,
-  r#"/* a non-jsdoc comment */
+/** @category Streams */
+export function a() {}
 
 /**
- * A leading JSDoc comment
- * @module
+ * @category IO
+ * @group Streams
  */
+export function b() {}
 
-/** One associated with a class */
-export class A {}
-    "#;
-  [
-    {
-      "kind": "moduleDoc",
-      "name": "",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 3,
-        "col": 0,
-      },
-      "declarationKind": "export",
-      "jsDoc": {
-        "doc": "A leading JSDoc comment",
-        "tags": [{
-          "kind": "module"
-        }],
-      }
-    }, {
-      "kind": "class",
-      "name": "A",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 9,
-        "col": 0,
-      },
-      "declarationKind": "export",
-      "jsDoc": {
-        "doc": "One associated with a class",
-      },
-      "classDef": {
-        "isAbstract": false,
-        "constructors": [],
-        "properties": [],
-        "indexSignatures": [],
-        "methods": [],
-        "extends": null,
-        "implements": [],
-        "typeParams": [],
-        "superTypeParams": []
-      }
-    }
-  ]);
+export function c() {}
 
-  json_test!(module_docs_ignore,
-  r#"/* a non-jsdoc comment */
+/** @group IO */
+export function d() {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let groups = crate::group_nodes_by_category(&entries)
+    .into_iter()
+    .map(|group| {
+      (
+        group.category,
+        group
+          .doc_nodes
+          .iter()
+          .map(|node| node.name.as_str())
+          .collect::<Vec<_>>(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    groups,
+    vec![
+      (Some("IO".to_string()), vec!["b", "d"]),
+      (Some("Streams".to_string()), vec!["a", "b"]),
+      (None, vec!["c"]),
+    ]
+  );
+}
 
-/**
- * A leading JSDoc comment
- * @module
- * @ignore
+#[tokio::test]
+async fn merge_synthetic_doc_nodes() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export namespace Runtime {
+  export function exit(code: number): never;
+}
+"#,
+    )],
+  )
+  .await;
+  let mut entries =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+  let pid: crate::VariableDef = serde_json::from_value(json!({
+    "tsType": { "repr": "number", "kind": "keyword", "keyword": "number" },
+    "kind": "const"
+  }))
+  .unwrap();
+  let node = crate::DocNodeBuilder::variable("pid", pid.clone())
+    .location("ext:runtime/globals.d.ts", 2, 2)
+    .declaration_kind(DeclarationKind::Declare)
+    .doc("The process id.\n\n@since 1.0.0")
+    .build()
+    .unwrap();
+  assert_eq!(node.js_doc.since(), Some("1.0.0"));
+  let runtime = crate::DocNodeBuilder::namespace(
+    "Runtime",
+    crate::NamespaceDef {
+      elements: vec![node],
+      ambient_module: false,
+    },
+  )
+  .location("ext:runtime/globals.d.ts", 1, 0)
+  .build()
+  .unwrap();
+  crate::merge_doc_nodes(&mut entries, vec![runtime]).unwrap();
+  assert_eq!(entries.len(), 1);
+  let elements = &entries[0].namespace_def.as_ref().unwrap().elements;
+  let names = elements.iter().map(|n| n.name.as_str()).collect::<Vec<_>>();
+  assert_eq!(names, vec!["exit", "pid"]);
+
+  assert_eq!(
+    crate::DocNodeBuilder::variable("not valid", pid.clone())
+      .location("ext:runtime/globals.d.ts", 1, 0)
+      .build()
+      .unwrap_err(),
+    crate::DocNodeBuildError::InvalidName("not valid".to_string())
+  );
+  assert_eq!(
+    crate::DocNodeBuilder::variable("pid", pid.clone())
+      .build()
+      .unwrap_err(),
+    crate::DocNodeBuildError::MissingFilename("pid".to_string())
+  );
+  let conflict = crate::DocNodeBuilder::variable("Runtime", pid)
+    .location("ext:runtime/globals.d.ts", 1, 0)
+    .build()
+    .unwrap();
+  assert_eq!(
+    crate::merge_doc_nodes(&mut entries, vec![conflict]).unwrap_err(),
+    crate::DocNodeBuildError::Conflict("Runtime".to_string())
+  );
+  assert_eq!(entries[0].kind, DocNodeKind::Namespace);
+}
+
+#[tokio::test]
+async fn param_docs() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * @param b the second
+ * @param a the first
  */
+export function f(a: string, b?: number) {}
 
-/** One associated with a class */
-export class A {}
-    "#;
-  []);
+/**
+ * @param {Object} options the options
+ * @param {Object} options.retry how to retry
+ * @param {number} [options.retry.count=3] the attempts
+ */
+export function g({ retry: { count } }: any = {}) {}
 
-  json_test!(declare_namespace,
-    r#"
-/** Namespace JSdoc */
-declare namespace RootNs {
-    declare const a = "a";
+export class C {
+  /**
+   * @param options the options
+   * @param options.verbose whether to log
+   */
+  constructor({ verbose }: { verbose: boolean }) {}
 
-    /** Nested namespace JSDoc */
-    declare namespace NestedNs {
-      declare enum Foo {
-        a = 1,
-        b = 2,
-        c = 3,
-      }
-    }
+  /** @param x the value */
+  m(x: number, y: number) {}
 }
-    "#;
-    [{
-    "kind": "namespace",
-    "name": "RootNs",
-    "location": {
-      "filename": "file:///test.ts",
-      "line": 3,
-      "col": 0
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      param_docs: true,
+      ..Default::default()
     },
-    "declarationKind": "declare",
-    "jsDoc": {
-      "doc": "Namespace JSdoc",
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let f = entries.iter().find(|n| n.name == "f").unwrap();
+  let params = &f.function_def.as_ref().unwrap().params;
+  assert_eq!(params[0].doc(), Some("the first"));
+  assert_eq!(params[1].doc(), Some("the second"));
+  let json = serde_json::to_value(&params[0]).unwrap();
+  assert_eq!(json["doc"], json!("the first"));
+
+  let g = entries.iter().find(|n| n.name == "g").unwrap();
+  let param = &g.function_def.as_ref().unwrap().params[0];
+  assert_eq!(param.doc(), Some("the options"));
+  let retry = &serde_json::to_value(param).unwrap()["left"]["props"][0];
+  assert_eq!(retry["value"]["doc"], json!("how to retry"));
+  assert_eq!(retry["value"]["props"][0]["doc"], json!("the attempts"));
+
+  let class_def = entries
+    .iter()
+    .find(|n| n.name == "C")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  let param = &class_def.constructors[0].params[0].param;
+  assert_eq!(param.doc(), Some("the options"));
+  assert_eq!(
+    serde_json::to_value(param).unwrap()["props"][0]["doc"],
+    json!("whether to log")
+  );
+  let params = &class_def.methods[0].function_def.params;
+  assert_eq!(params[0].doc(), Some("the value"));
+  assert_eq!(params[1].doc(), None);
+}
+
+#[tokio::test]
+async fn inline_links() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export interface A {
+  /** Like {@link B | b}. */
+  a: string;
+}
+
+/** Links to {@link A}. */
+export interface B {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      inline_links: true,
+      ..Default::default()
     },
-    "namespaceDef": {
-      "elements": [
-        {
-          "kind": "variable",
-          "name": "a",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 4,
-            "col": 18
-          },
-          "declarationKind": "declare",
-          "variableDef": {
-            "tsType": {
-              "repr": "a",
-              "kind": "literal",
-              "literal": {
-                "kind": "string",
-                "string": "a"
-              }
-            },
-            "kind": "const"
-          }
-        },
-        {
-          "kind": "namespace",
-          "name": "NestedNs",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 7,
-            "col": 4
-          },
-          "jsDoc": {
-            "doc": "Nested namespace JSDoc",
-          },
-          "declarationKind": "declare",
-          "namespaceDef": {
-            "elements": [
-              {
-                "kind": "enum",
-                "name": "Foo",
-                "location": {
-                  "filename": "file:///test.ts",
-                  "line": 8,
-                  "col": 6
-                },
-                "declarationKind": "declare",
-                "enumDef": {
-                  "members": [
-                    {
-                      "name": "a",
-                      "init": {
-                        "repr": "1",
-                        "kind": "literal",
-                        "literal": {
-                          "kind": "number",
-                          "number": 1.0,
-                        }
-                      },
-                      "location": {
-                        "filename": "file:///test.ts",
-                        "line": 9,
-                        "col": 8,
-                      },
-                    },
-                    {
-                      "name": "b",
-                      "init": {
-                        "repr": "2",
-                        "kind": "literal",
-                        "literal": {
-                          "kind": "number",
-                          "number": 2.0,
-                        }
-                      },
-                      "location": {
-                        "filename": "file:///test.ts",
-                        "line": 10,
-                        "col": 8,
-                      },
-                    },
-                    {
-                      "name": "c",
-                      "init": {
-                        "repr": "3",
-                        "kind": "literal",
-                        "literal": {
-                          "kind": "number",
-                          "number": 3.0,
-                        }
-                      },
-                      "location": {
-                        "filename": "file:///test.ts",
-                        "line": 11,
-                        "col": 8,
-                      },
-                    }
-                  ]
-                }
-              }
-            ]
-          }
-        }
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let a = entries.iter().find(|n| n.name == "A").unwrap();
+  let property = &a.interface_def.as_ref().unwrap().properties[0];
+  assert_eq!(
+    serde_json::to_value(&property.js_doc).unwrap(),
+    json!({
+      "doc": "Like {@link B | b}.",
+      "docSegments": [
+        { "kind": "text", "text": "Like " },
+        { "kind": "link", "target": "B", "label": "b" },
+        { "kind": "text", "text": "." },
       ]
-    }
-  }]);
+    })
+  );
+  let b = entries.iter().find(|n| n.name == "B").unwrap();
+  assert_eq!(
+    serde_json::to_value(&b.js_doc).unwrap()["docSegments"][1],
+    json!({ "kind": "link", "target": "A" })
+  );
+}
 
-  json_test!(declare_namespace_ignore,
-    r#"
-/** Namespace JSdoc */
-declare namespace RootNs {
-    declare const a = "a";
+#[tokio::test]
+async fn export_assignment_of_declared_variable() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"/** The library. */
+declare const MyLib: MyLib.Static;
+
+declare namespace MyLib {
+  interface Static {
+    /** Runs the library. */
+    run(): void;
+    version: string;
+  }
+}
 
-    /** Nested namespace JSDoc
-     * @ignore
-     */
-    declare namespace NestedNs {
-      declare enum Foo {
-        a = 1,
-        b = 2,
-        c = 3,
-      }
-    }
+export = MyLib;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let my_lib = entries
+    .iter()
+    .filter(|n| n.name == "MyLib")
+    .collect::<Vec<_>>();
+  assert_eq!(my_lib.len(), 1);
+  assert_eq!(my_lib[0].kind, DocNodeKind::Namespace);
+  assert_eq!(my_lib[0].declaration_kind, DeclarationKind::Export);
+  assert_eq!(my_lib[0].js_doc.doc.as_deref(), Some("The library."));
+  let elements = &my_lib[0].namespace_def.as_ref().unwrap().elements;
+  let names = elements.iter().map(|n| n.name.as_str()).collect::<Vec<_>>();
+  assert_eq!(names, vec!["run", "version", "Static"]);
+  assert_eq!(elements[0].kind, DocNodeKind::Function);
+  assert_eq!(elements[0].js_doc.doc.as_deref(), Some("Runs the library."));
 }
-    "#;
-    [{
-    "kind": "namespace",
-    "name": "RootNs",
-    "location": {
-      "filename": "file:///test.ts",
-      "line": 3,
-      "col": 0
-    },
-    "declarationKind": "declare",
-    "jsDoc": {
-      "doc": "Namespace JSdoc",
-    },
-    "namespaceDef": {
-      "elements": [
-        {
-          "kind": "variable",
-          "name": "a",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 4,
-            "col": 18
-          },
-          "declarationKind": "declare",
-          "variableDef": {
-            "tsType": {
-              "repr": "a",
-              "kind": "literal",
-              "literal": {
-                "kind": "string",
-                "string": "a"
-              }
-            },
-            "kind": "const"
-          }
-        }
-      ]
-    }
-  }]);
 
-  json_test!(structured_jsdoc,
-  r#"
-/** Class doc */
-export class A {
-  /** @private */
-  p = false;
-
-  /**
-   * Some leading documentation here.
-   *
-   * @param {string} name some comment
-   * @param {string} a    some other comment that
-   *                      spans two lines
-   * @param {number} b    a number
-   */
-  constructor(name, a, b) {}
+#[tokio::test]
+async fn export_assignment_of_function_and_namespace() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** Parses the text. */
+function parse(text: string): parse.Result {
+  return { ok: text.length > 0 };
+}
 
-  /**
-   * @returns {Promise<void>}
-   */
-  a() {}
+namespace parse {
+  export interface Result {
+    ok: boolean;
+  }
 }
-  "#;
-  [{
-    "kind": "class",
-    "name": "A",
-    "location": {
-      "filename": "file:///test.ts",
-      "line": 3,
-      "col": 0,
-    },
-    "jsDoc": {
-      "doc": "Class doc",
-    },
-    "declarationKind": "export",
-    "classDef": {
-      "isAbstract": false,
-      "constructors": [
-        {
-          "jsDoc": {
-            "doc": "Some leading documentation here.\n",
-            "tags": [
-              {
-                "kind": "param",
-                "name": "name",
-                "type": "string",
-                "doc": "some comment",
-              },
-              {
-                "kind": "param",
-                "name": "a",
-                "type": "string",
-                "doc": "some other comment that\nspans two lines",
-              },
-              {
-                "kind": "param",
-                "name": "b",
-                "type": "number",
-                "doc": "a number",
-              },
-            ],
-          },
-          "accessibility": null,
-          "hasBody": true,
-          "name": "constructor",
-          "params": [
-            {
-              "kind": "identifier",
-              "name": "name",
-              "optional": false,
-              "tsType": null,
-            },
-            {
-              "kind": "identifier",
-              "name": "a",
-              "optional": false,
-              "tsType": null,
-            },
-            {
-              "kind": "identifier",
-              "name": "b",
-              "optional": false,
-              "tsType": null,
-            }
-          ],
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 15,
-            "col": 2,
-          }
-        }
-      ],
-      "properties": [
-        {
-          "jsDoc": {
-            "tags": [
-              {
-                "kind": "private"
-              }
-            ]
-          },
-          "tsType": {
-            "repr": "boolean",
-            "kind": "keyword",
-            "keyword": "boolean",
-          },
-          "readonly": false,
-          "accessibility": null,
-          "optional": false,
-          "isAbstract": false,
-          "isStatic": false,
-          "name": "p",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 5,
-            "col": 2,
-          }
-        }
-      ],
-      "indexSignatures": [],
-      "methods": [
-        {
-          "jsDoc": {
-            "tags": [
-              {
-                "kind": "return",
-                "type": "Promise<void>"
-              }
-            ]
-          },
-          "accessibility": null,
-          "optional": false,
-          "isAbstract": false,
-          "isStatic": false,
-          "name": "a",
-          "kind": "method",
-          "functionDef": {
-            "params": [],
-            "returnType": null,
-            "hasBody": true,
-            "isAsync": false,
-            "isGenerator": false,
-            "typeParams": [],
-          },
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 20,
-            "col": 2,
-          }
-        }
-      ],
-      "extends": null,
-      "implements": [],
-      "typeParams": [],
-      "superTypeParams": [],
-    }
-  }]);
 
-  json_test!(export_class,
-   r#"
-/** Class doc */
-export class Foobar extends Fizz implements Buzz, Aldrin {
-    private private1?: boolean;
-    protected protected1: number;
-    public public1: boolean;
-    public2: number;
-    override public3: string;
-    m = new Map<string, string>();
-    s = "foo";
-    a = [1, "foo"];
-    fn = (a: string): void => {};
+class Internal {}
 
-    /** Constructor js doc */
-    constructor(name: string, private private2: number, protected protected2: number) {}
+export = parse;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let kinds = entries
+    .iter()
+    .map(|n| (n.name.as_str(), n.kind.clone(), n.declaration_kind))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    kinds,
+    vec![
+      ("parse", DocNodeKind::Function, DeclarationKind::Export),
+      ("parse", DocNodeKind::Namespace, DeclarationKind::Export),
+    ]
+  );
+  assert_eq!(entries[0].js_doc.doc.as_deref(), Some("Parses the text."));
+}
 
-    /** Async foo method */
-    async foo(): Promise<void> {
-        //
-    }
+#[tokio::test]
+async fn export_as_namespace() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"export declare function parse(text: string): boolean;
+
+/** The global of the library when loaded with a script tag. */
+export as namespace MyLib;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let umd_namespace = entries
+    .iter()
+    .find(|n| n.kind == DocNodeKind::UmdNamespace)
+    .unwrap();
+  assert_eq!(umd_namespace.name, "MyLib");
+  assert_eq!(umd_namespace.declaration_kind, DeclarationKind::Export);
+  assert_eq!(umd_namespace.location.line, 4);
+  assert_eq!(
+    umd_namespace.js_doc.doc.as_deref(),
+    Some("The global of the library when loaded with a script tag.")
+  );
+  assert_eq!(
+    serde_json::to_value(umd_namespace).unwrap()["kind"],
+    json!("umdNamespace")
+  );
+  assert!(DocPrinter::new(&entries, false, false)
+    .to_string()
+    .contains("export as namespace MyLib"));
+}
 
-    /** Sync bar method */
-    bar?(): void {
-        //
-    }
+#[tokio::test]
+async fn module_filter() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { a } from "./lib.ts";
+export { b } from "./vendor/dep.ts";
+export * as dep from "./vendor/dep.ts";
+"#,
+      ),
+      ("file:///lib.ts", None, "export const a = 1;"),
+      ("file:///vendor/dep.ts", None, "export const b = 2;"),
+    ],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      module_filter: ModuleFilter {
+        include: Vec::new(),
+        exclude: vec!["**/vendor/**".to_string()],
+      },
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse_with_reexports(&specifier)
+  .unwrap();
+  let names = entries.iter().map(|n| n.name.as_str()).collect::<Vec<_>>();
+  assert_eq!(names, vec!["a"]);
+}
 
-    override s() {}
+#[tokio::test]
+async fn import_equals_require() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![
+      (
+        "file:///mod.d.ts",
+        None,
+        r#"import fs = require("fs");
+/** The library. */
+import lib = require("./lib.d.ts");
+
+export declare function read(path: string): lib.Buffer;
+"#,
+      ),
+      ("file:///lib.d.ts", None, "export interface Buffer {}"),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, true, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let imports = entries
+    .iter()
+    .filter(|n| n.kind == DocNodeKind::Import)
+    .collect::<Vec<_>>();
+  assert_eq!(imports.len(), 2);
+  assert_eq!(
+    serde_json::to_value(&imports[0].import_def).unwrap(),
+    json!({ "src": "fs", "imported": null, "require": true })
+  );
+  assert_eq!(imports[1].name, "lib");
+  assert_eq!(imports[1].js_doc.doc.as_deref(), Some("The library."));
+  assert_eq!(
+    serde_json::to_value(&imports[1].import_def).unwrap(),
+    json!({
+      "src": "file:///lib.d.ts",
+      "imported": null,
+      "namespace": true,
+      "require": true,
+    })
+  );
+  let namespace = parser.expand_namespace_import(imports[1]).unwrap().unwrap();
+  let elements = &namespace.namespace_def.unwrap().elements;
+  assert_eq!(elements[0].name, "Buffer");
 }
-  "#;
-  [{
+
+#[tokio::test]
+async fn import_equals_require_reexports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![
+      (
+        "file:///mod.d.ts",
+        None,
+        r#"/** The utilities. */
+export import util = require("./util.d.ts");
+"#,
+      ),
+      (
+        "file:///util.d.ts",
+        None,
+        r#"import core = require("./core.d.ts");
+export = core;
+"#,
+      ),
+      (
+        "file:///core.d.ts",
+        None,
+        "export declare function run(): void;",
+      ),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(entries.len(), 1);
+  assert_eq!(entries[0].name, "util");
+  assert_eq!(entries[0].kind, DocNodeKind::Namespace);
+  assert_eq!(entries[0].declaration_kind, DeclarationKind::Export);
+  assert_eq!(entries[0].js_doc.doc.as_deref(), Some("The utilities."));
+  let elements = &entries[0].namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements.len(), 1);
+  assert_eq!(elements[0].name, "run");
+
+  let util = ModuleSpecifier::parse("file:///util.d.ts").unwrap();
+  let entries = parser.parse_with_reexports(&util).unwrap();
+  let names = entries.iter().map(|n| n.name.as_str()).collect::<Vec<_>>();
+  assert_eq!(names, vec!["run"]);
+  assert_eq!(
+    serde_json::to_value(parser.parse_module(&util).unwrap().reexports)
+      .unwrap(),
+    json!([{ "kind": "all", "src": "./core.d.ts" }])
+  );
+}
+
+#[tokio::test]
+async fn dependencies() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import type { Bytes } from "./bytes.ts";
+import * as path from "./path.ts";
+export { join } from "./lib.ts";
+
+export function concat(a: Bytes, b: path.Path): Bytes {
+  return a;
+}
+
+export const a = 1;
+"#,
+      ),
+      ("file:///bytes.ts", None, "export type Bytes = Uint8Array;"),
+      ("file:///path.ts", None, "export type Path = string;"),
+      (
+        "file:///lib.ts",
+        None,
+        r#"import type { Path } from "./path.ts";
+
+export function join(...paths: Path[]): Path {
+  return paths.join("/");
+}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      dependencies: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse_with_reexports(&specifier)
+  .unwrap();
+  let dependencies = |name: &str| {
+    entries
+      .iter()
+      .find(|n| n.name == name)
+      .unwrap()
+      .dependencies
+      .clone()
+  };
+  assert_eq!(
+    dependencies("concat"),
+    vec!["file:///bytes.ts", "file:///path.ts"]
+  );
+  assert_eq!(dependencies("join"), vec!["file:///path.ts"]);
+  assert!(dependencies("a").is_empty());
+}
+
+#[tokio::test]
+async fn hidden_ignored() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** @ignore */
+export function hiddenFn() {}
+
+export namespace ns {
+  /** @ignore */
+  export const hiddenConst = 1;
+  export const shownConst = 2;
+}
+
+export const visible = 1;
+"#,
+    )],
+  )
+  .await;
+  let parse = |hidden_ignored| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        hidden_ignored,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+
+  let entries = parse(true);
+  let hidden_fn = entries.iter().find(|n| n.name == "hiddenFn").unwrap();
+  assert!(hidden_fn.hidden);
+  assert!(hidden_fn.js_doc.tags.contains(&JsDocTag::Ignore));
+  assert!(!entries.iter().find(|n| n.name == "visible").unwrap().hidden);
+  let ns = entries.iter().find(|n| n.name == "ns").unwrap();
+  let elements = &ns.namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements.len(), 2);
+  assert!(elements.iter().any(|n| n.name == "hiddenConst" && n.hidden));
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert!(!output.contains("hiddenFn"));
+  assert!(!output.contains("hiddenConst"));
+  assert!(output.contains("shownConst"));
+
+  let entries = parse(false);
+  assert!(entries.iter().all(|n| n.name != "hiddenFn" && !n.hidden));
+}
+
+#[tokio::test]
+async fn raw_js_doc() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export const first = 0;
+
+/**
+ * The a.
+ * @deprecated
+ */
+export type A = {
+  /** The b. */
+  b: { /** The c. */ c: string };
+};
+"#,
+    )],
+  )
+  .await;
+  let parse = |raw_js_doc| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        raw_js_doc,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let entries = parse(true);
+  let a = entries.iter().find(|n| n.name == "A").unwrap();
+  let raw = a.js_doc.raw.as_ref().unwrap();
+  assert_eq!(raw.text, "/**\n * The a.\n * @deprecated\n */");
+  assert_eq!(raw.location.line, 3);
+  assert_eq!(raw.location.col, 0);
+  assert_eq!(raw.end.line, 6);
+  assert_eq!(raw.end.col, 3);
+  let type_literal = |ts_type: &TsTypeDef| ts_type.type_literal.clone();
+  let a_literal =
+    type_literal(&a.type_alias_def.as_ref().unwrap().ts_type).unwrap();
+  let b = &a_literal.properties[0];
+  assert_eq!(b.js_doc.raw.as_ref().unwrap().text, "/** The b. */");
+  let b_literal = type_literal(b.ts_type.as_ref().unwrap()).unwrap();
+  let c = &b_literal.properties[0];
+  assert_eq!(c.js_doc.raw.as_ref().unwrap().text, "/** The c. */");
+
+  let entries = parse(false);
+  let a = entries.iter().find(|n| n.name == "A").unwrap();
+  assert!(a.js_doc.raw.is_none());
+  let json = serde_json::to_string(&entries).unwrap();
+  assert!(!json.contains("\"raw\""));
+}
+
+#[tokio::test]
+async fn package_documentation() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"/** @license MIT */
+
+/**
+ * The overview of the package.
+ * @packageDocumentation
+ */
+export declare function a(): void;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(entries.len(), 2);
+  assert_eq!(entries[0].kind, DocNodeKind::ModuleDoc);
+  assert_eq!(
+    entries[0].js_doc.doc.as_deref(),
+    Some("The overview of the package.")
+  );
+  assert_eq!(entries[0].js_doc.tags, vec![JsDocTag::PackageDocumentation]);
+  assert_eq!(entries[0].location.line, 3);
+  assert_eq!(entries[1].name, "a");
+  assert!(entries[1].js_doc.is_empty());
+}
+
+#[tokio::test]
+async fn module_headers() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import "./other.ts";
+
+export const a = 1;
+"#,
+      ),
+      (
+        "file:///other.ts",
+        None,
+        r#"/**
+ * The other module.
+ * @module
+ */
+export const b = 1;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let headers = |headers: &[(&str, &str)]| {
+    headers
+      .iter()
+      .map(|(name, value)| (name.to_string(), value.to_string()))
+      .collect::<HashMap<_, _>>()
+  };
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      module_headers: HashMap::from([
+        (
+          specifier.clone(),
+          headers(&[
+            ("content-type", "application/typescript"),
+            ("X-Deno-Warning", "Deprecated, use other."),
+            ("x-doc-owner", "team"),
+          ]),
+        ),
+        (
+          ModuleSpecifier::parse("file:///other.ts").unwrap(),
+          headers(&[("x-doc-owner", "other-team")]),
+        ),
+      ]),
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  assert_eq!(entries[0].kind, DocNodeKind::ModuleDoc);
+  assert!(entries[0].js_doc.is_empty());
+  assert_eq!(
+    serde_json::to_value(&entries[0].module_doc_def).unwrap(),
+    json!({
+      "warning": "Deprecated, use other.",
+      "metadata": { "owner": "team" },
+    })
+  );
+
+  let entries = parser
+    .parse(&ModuleSpecifier::parse("file:///other.ts").unwrap())
+    .unwrap();
+  assert_eq!(entries[0].kind, DocNodeKind::ModuleDoc);
+  assert_eq!(entries[0].js_doc.doc.as_deref(), Some("The other module."));
+  assert_eq!(
+    entries[0].module_doc_def.as_ref().unwrap().metadata["owner"],
+    "other-team"
+  );
+}
+
+#[tokio::test]
+async fn trailing_comments() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export enum Color {
+  Red, // The red.
+  /** The green. */
+  Green, // Ignored.
+  Blue,
+}
+
+export interface Options {
+  name: string; // The name.
+  run(): void // Runs.
+  url: string;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      trailing_comments: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let enum_def = entries[0].enum_def.as_ref().unwrap();
+  let docs = enum_def
+    .members
+    .iter()
+    .map(|m| m.js_doc.doc.as_deref())
+    .collect::<Vec<_>>();
+  assert_eq!(docs, vec![Some("The red."), Some("The green."), None]);
+  let interface_def = entries[1].interface_def.as_ref().unwrap();
+  assert_eq!(
+    interface_def
+      .properties
+      .iter()
+      .map(|p| p.js_doc.doc.as_deref())
+      .collect::<Vec<_>>(),
+    vec![Some("The name."), None]
+  );
+  assert_eq!(
+    interface_def.methods[0].js_doc.doc.as_deref(),
+    Some("Runs.")
+  );
+}
+
+#[tokio::test]
+async fn diff_doc_nodes() {
+  async fn parse(source: &str) -> Vec<crate::DocNode> {
+    let (graph, analyzer, specifier) =
+      setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+    let doc_nodes =
+      DocParser::new(&graph, false, analyzer.as_capturing_parser())
+        .unwrap()
+        .parse(&specifier)
+        .unwrap();
+    // as read back from the JSON output
+    serde_json::from_value(serde_json::to_value(doc_nodes).unwrap()).unwrap()
+  }
+
+  let old = parse(
+    r#"/** Adds. */
+export function add(a: number, b: number): number { return a + b; }
+export function remove(): void {}
+export namespace ns {
+  export const a = 1;
+}
+"#,
+  )
+  .await;
+  let new = parse(
+    r#"/** Adds two numbers. */
+export function add(a: number, b: number): number { return a + b; }
+export namespace ns {
+  export const a = "1";
+  export const b = 2;
+}
+"#,
+  )
+  .await;
+  let diff = crate::diff_doc_nodes(&old, &new);
+  assert_eq!(
+    serde_json::to_value(&diff).unwrap(),
+    json!({
+      "changes": [
+        { "name": "add", "nodeKind": "function", "kind": "docsChanged", "impact": "patch" },
+        { "name": "ns.a", "nodeKind": "variable", "kind": "changed", "impact": "major" },
+        { "name": "ns.b", "nodeKind": "variable", "kind": "added", "impact": "minor" },
+        { "name": "remove", "nodeKind": "function", "kind": "removed", "impact": "major" },
+      ],
+      "impact": "major",
+    })
+  );
+  assert_eq!(crate::diff_doc_nodes(&old, &old).impact, None);
+}
+
+#[tokio::test]
+async fn render_doc_node_diff() {
+  async fn parse(source: &str) -> Vec<crate::DocNode> {
+    let (graph, analyzer, specifier) =
+      setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+    DocParser::new(&graph, false, analyzer.as_capturing_parser())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap()
+  }
+
+  let expected = parse(
+    r#"export function f(a: string) {}
+export const b = 1;
+"#,
+  )
+  .await;
+  let actual = parse(
+    r#"export function f(a: number) {}
+export class C {}
+"#,
+  )
+  .await;
+  assert_eq!(
+    crate::render_doc_node_diff(&expected, &actual).unwrap(),
+    r#"+ C (class)
+- b (variable)
+~ f (function)
+  - functionDef.params[0].tsType.keyword: "string"
+  + functionDef.params[0].tsType.keyword: "number"
+  - functionDef.params[0].tsType.repr: "string"
+  + functionDef.params[0].tsType.repr: "number"
+"#
+  );
+  assert_eq!(crate::render_doc_node_diff(&expected, &expected), None);
+}
+
+#[tokio::test]
+async fn stability() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** @experimental */
+export function a() {}
+
+/**
+ * @internal
+ * @experimental
+ */
+export function b() {}
+
+export namespace C {
+  /** @internal */
+  export const d = 1;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      stability: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  assert_eq!(entries[0].stability, Some(crate::Stability::Experimental));
+  assert_eq!(entries[1].stability, Some(crate::Stability::Internal));
+  assert_eq!(entries[2].stability, None);
+  let json = serde_json::to_value(&entries[2]).unwrap();
+  assert_eq!(
+    json["namespaceDef"]["elements"][0]["stability"],
+    json!("internal")
+  );
+  assert!(json.get("stability").is_none());
+
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert!(entries.iter().all(|node| node.stability.is_none()));
+}
+
+#[tokio::test]
+async fn release_tags() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** @public */
+export function a() {}
+
+/** @beta */
+export namespace B {
+  export const c = 1;
+  /** @alpha */
+  export const d = 1;
+}
+
+/** @alpha */
+export function e() {}
+
+export function f() {}
+"#,
+    )],
+  )
+  .await;
+  let parse = |min_release_tag| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        release_tags: true,
+        min_release_tag,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let entries = parse(None);
+  let release_tags = entries
+    .iter()
+    .map(|node| (node.name.as_str(), node.release_tag))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    release_tags,
+    vec![
+      ("a", Some(ReleaseTag::Public)),
+      ("B", Some(ReleaseTag::Beta)),
+      ("e", Some(ReleaseTag::Alpha)),
+      ("f", None),
+    ]
+  );
+  let elements = &entries[1].namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements[0].release_tag, Some(ReleaseTag::Beta));
+  assert_eq!(elements[1].release_tag, Some(ReleaseTag::Alpha));
+
+  let entries = parse(Some(ReleaseTag::Beta));
+  let names = entries.iter().map(|n| n.name.as_str()).collect::<Vec<_>>();
+  assert_eq!(names, vec!["a", "B", "f"]);
+  let elements = &entries[1].namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements.len(), 1);
+}
+
+#[tokio::test]
+async fn doc_overlay() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export namespace Deno {
+  export interface Conn {
+    /** Closes it. */
+    closeWrite(): Promise<void>;
+  }
+}
+
+/** @deprecated */
+export function a(): void;
+export function a(b: string): void;
+export function a(b?: string) {}
+"#,
+    )],
+  )
+  .await;
+  let mut entries =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap();
+  let overlay = crate::DocOverlay::from_json(
+    r#"{
+      "Deno.Conn.closeWrite": {
+        "doc": "Closes the write side of the connection.",
+        "tags": ["@since 1.0.0"]
+      },
+      "a": { "tags": ["@category Util"], "replaceTags": true },
+      "Missing": { "doc": "Not there." }
+    }"#,
+  )
+  .unwrap();
+  let unmatched = crate::apply_overlay(&mut entries, &overlay);
+  assert_eq!(unmatched, vec!["Missing"]);
+  let deno = &entries[0].namespace_def.as_ref().unwrap().elements;
+  let close_write = &deno[0].interface_def.as_ref().unwrap().methods[0];
+  assert_eq!(
+    serde_json::to_value(&close_write.js_doc).unwrap(),
+    json!({
+      "doc": "Closes the write side of the connection.",
+      "tags": [{ "kind": "since", "version": "1.0.0" }]
+    })
+  );
+  let overloads = entries.iter().filter(|node| node.name == "a");
+  for overload in overloads {
+    assert_eq!(
+      overload.js_doc.tags,
+      vec![JsDocTag::Category {
+        doc: Some("Util".to_string())
+      }]
+    );
+  }
+}
+
+#[tokio::test]
+async fn printer_message_catalog() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * @deprecated
+ * @example
+ * ```ts
+ * a();
+ * ```
+ */
+export function a() {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let messages = crate::MessageCatalog::from_json(
+    r#"{
+      "definedIn": "Definiert in",
+      "tagLabels": { "deprecated": "veraltet" }
+    }"#,
+  )
+  .unwrap();
+  let output = DocPrinter::new(&entries, false, false)
+    .with_messages(messages)
+    .to_string();
+  assert_contains!(output, "Definiert in file:///mod.ts:8:0");
+  assert_contains!(output, "@veraltet");
+  assert_contains!(output, "@example");
+  assert!(!output.contains("Defined in"));
+}
+
+#[tokio::test]
+async fn json_module_star_reexport() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///foo.ts",
+    vec![
+      ("file:///foo.ts", None, "export * from './bar.json' assert { type: 'json' };\nexport const a = 1;"),
+      ("file:///bar.json", None, r#"{ "a": "shadowed", "b": 2 }"#),
+    ],
+  )
+  .await;
+
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+  assert_eq!(entries.len(), 2);
+  assert_eq!(entries[0].name, "a");
+  assert_eq!(entries[0].location.filename, "file:///foo.ts");
+  assert_eq!(
+    serde_json::to_value(&entries[1]).unwrap(),
+    json!({
+      "kind": "variable",
+      "name": "b",
+      "location": {
+        "filename": "file:///bar.json",
+        "line": 1,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "variableDef": {
+        "tsType": {
+          "repr": "2",
+          "kind": "literal",
+          "literal": {
+            "kind": "number",
+            "number": 2.0,
+          },
+        },
+        "kind": "var"
+      }
+    })
+  );
+}
+
+#[tokio::test]
+async fn js_typedef() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.js",
+    vec![(
+      "file:///mod.js",
+      None,
+      r#"/**
+ * The options of a request.
+ * @typedef {Object} Options
+ * @property {string} method The HTTP method.
+ * @property {number} timeout
+ */
+
+/** @typedef {string | number} Id */
+
+/**
+ * @param {Options} options
+ */
+export function request(options) {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(entries.len(), 3);
+  let options = entries.iter().find(|n| n.name == "Options").unwrap();
+  assert_eq!(options.kind, DocNodeKind::TypeAlias);
+  assert_eq!(options.location.line, 1);
+  assert_eq!(
+    options.js_doc.doc.as_deref(),
+    Some("The options of a request.")
+  );
+  assert!(options.js_doc.tags.is_empty());
+  let ts_type = &options.type_alias_def.as_ref().unwrap().ts_type;
+  let properties = &ts_type.type_literal.as_ref().unwrap().properties;
+  assert_eq!(properties.len(), 2);
+  assert_eq!(properties[0].name, "method");
+  assert_eq!(
+    properties[0].js_doc.doc.as_deref(),
+    Some("The HTTP method.")
+  );
+  assert_eq!(
+    properties[0].ts_type.as_ref().unwrap().keyword.as_deref(),
+    Some("string")
+  );
+  assert_eq!(properties[1].name, "timeout");
+  assert_eq!(
+    properties[1].ts_type.as_ref().unwrap().keyword.as_deref(),
+    Some("number")
+  );
+  let id = entries.iter().find(|n| n.name == "Id").unwrap();
+  let ts_type = &id.type_alias_def.as_ref().unwrap().ts_type;
+  assert_eq!(ts_type.kind, Some(TsTypeDefKind::Union));
+  assert_eq!(ts_type.union.as_ref().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn js_variable_type_tag() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.js",
+    vec![(
+      "file:///mod.js",
+      None,
+      r#"/** @type {Map<string, number>} */
+export const counts = new Map();
+
+/**
+ * The name.
+ * @type {string}
+ */
+export let name;
+
+/** @type {Foo<} */
+export const invalid = 1;
+
+/** @type {?string} */
+export let nullable;
+
+/** @type {function(string, ...number): boolean} */
+export let callback;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let ts_type = |name: &str| {
+    entries
+      .iter()
+      .find(|n| n.name == name)
+      .unwrap()
+      .variable_def
+      .as_ref()
+      .unwrap()
+      .ts_type
+      .clone()
+      .unwrap()
+  };
+  let counts = ts_type("counts");
+  assert_eq!(counts.kind, Some(TsTypeDefKind::TypeRef));
+  assert_eq!(counts.repr, "Map");
+  let type_params = counts.type_ref.unwrap().type_params.unwrap();
+  assert_eq!(type_params[1].keyword.as_deref(), Some("number"));
+  let name = ts_type("name");
+  assert_eq!(name.keyword.as_deref(), Some("string"));
+  let invalid = ts_type("invalid");
+  assert_eq!(invalid.kind, Some(TsTypeDefKind::Literal));
+  let nullable = ts_type("nullable");
+  assert_eq!(nullable.kind, Some(TsTypeDefKind::Union));
+  assert_eq!(nullable.union.unwrap()[1].keyword.as_deref(), Some("null"));
+  let callback = ts_type("callback");
+  assert_eq!(callback.kind, Some(TsTypeDefKind::FnOrConstructor));
+}
+
+#[tokio::test]
+async fn js_class_extends_tag() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.js",
+    vec![(
+      "file:///mod.js",
+      None,
+      r#"class Base {}
+
+/**
+ * @template T
+ * @extends {Base<T>}
+ */
+export class A extends Base {}
+
+/** @augments {Base<string>} */
+export class B {}
+
+/** @extends {Other<string>} */
+export class C extends Base {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let class_def = |name: &str| {
+    entries
+      .iter()
+      .find(|n| n.name == name)
+      .unwrap()
+      .class_def
+      .clone()
+      .unwrap()
+  };
+  let a = class_def("A");
+  assert_eq!(a.extends.as_deref(), Some("Base"));
+  assert_eq!(a.super_type_params.len(), 1);
+  assert_eq!(a.super_type_params[0].repr, "T");
+  let b = class_def("B");
+  assert_eq!(b.extends.as_deref(), Some("Base"));
+  assert_eq!(b.super_type_params[0].keyword.as_deref(), Some("string"));
+  let c = class_def("C");
+  assert_eq!(c.extends.as_deref(), Some("Base"));
+  assert!(c.super_type_params.is_empty());
+}
+
+#[tokio::test]
+async fn js_typedef_nested_properties_and_namespace() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.js",
+    vec![(
+      "file:///mod.js",
+      None,
+      r#"/**
+ * @typedef {Object} Options
+ * @property {Object} retry
+ * @property {number} [retry.count=3] The number of retries.
+ * @property {boolean} [verbose]
+ */
+
+/**
+ * Helpers for colors.
+ * @namespace colors
+ * @property {string} red The red escape code.
+ */
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(entries.len(), 2);
+  let options = entries.iter().find(|n| n.name == "Options").unwrap();
+  let ts_type = &options.type_alias_def.as_ref().unwrap().ts_type;
+  let properties = &ts_type.type_literal.as_ref().unwrap().properties;
+  assert_eq!(properties.len(), 2);
+  assert_eq!(properties[0].name, "retry");
+  let retry_type = properties[0].ts_type.as_ref().unwrap();
+  let retry_properties = &retry_type.type_literal.as_ref().unwrap().properties;
+  assert_eq!(retry_properties.len(), 1);
+  assert_eq!(retry_properties[0].name, "count");
+  assert!(retry_properties[0].optional);
+  assert_eq!(
+    retry_properties[0].js_doc.tags,
+    vec![JsDocTag::Default {
+      value: "3".to_string(),
+      doc: None,
+    }]
+  );
+  assert_eq!(properties[1].name, "verbose");
+  assert!(properties[1].optional);
+
+  let colors = entries.iter().find(|n| n.name == "colors").unwrap();
+  assert_eq!(colors.kind, DocNodeKind::Namespace);
+  assert_eq!(colors.name, "colors");
+  assert_eq!(colors.js_doc.doc.as_deref(), Some("Helpers for colors."));
+  let elements = &colors.namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements.len(), 1);
+  assert_eq!(elements[0].kind, DocNodeKind::Variable);
+  assert_eq!(elements[0].name, "red");
+  assert_eq!(
+    elements[0].js_doc.doc.as_deref(),
+    Some("The red escape code.")
+  );
+}
+
+#[tokio::test]
+async fn json_module() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///foo.ts",
+    vec![
+      ("file:///foo.ts", None, "export { default as configFile } from './bar.json' assert { type: 'json' };"),
+      ("file:///bar.json", None, r#"{ "a": 5, "b": "text", "c": null, "d": [1, 2], "e": { "a": 1 } }"#),
+    ],
+  )
+  .await;
+
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let expected_json = json!([
+    {
+      "kind": "variable",
+      "name": "configFile",
+      "location": {
+        "filename": "file:///bar.json",
+        "line": 1,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "variableDef": {
+        "tsType": {
+          "repr": "",
+          "kind": "typeLiteral",
+          "typeLiteral": {
+            "methods": [],
+            "properties": [{
+              "name": "a",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "5",
+                "kind": "literal",
+                "literal": {
+                  "kind": "number",
+                  "number": 5.0,
+                },
+              },
+              "typeParams": []
+            }, {
+              "name": "b",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "text",
+                "kind": "literal",
+                "literal": {
+                  "kind": "string",
+                  "string": "text",
+                },
+              },
+              "typeParams": []
+            }, {
+              "name": "c",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "null",
+                "kind": "keyword",
+                "keyword": "null",
+              },
+              "typeParams": []
+            }, {
+              "name": "d",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "unknown[]",
+                "kind": "array",
+                "array": {
+                  "repr": "unknown",
+                  "kind": "keyword",
+                  "keyword": "unknown",
+                },
+              },
+              "typeParams": []
+            }, {
+              "name": "e",
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "",
+                "kind": "typeLiteral",
+                "typeLiteral": {
+                  "methods": [],
+                  "properties": [{
+                    "name": "a",
+                    "params": [],
+                    "computed": false,
+                    "optional": false,
+                    "tsType": {
+                      "repr": "1",
+                      "kind": "literal",
+                      "literal": {
+                        "kind": "number",
+                        "number": 1.0,
+                      },
+                    },
+                    "typeParams": []
+                  }],
+                  "callSignatures": [],
+                  "indexSignatures": [],
+                },
+              },
+              "typeParams": []
+            }],
+            "callSignatures": [],
+            "indexSignatures": [],
+          },
+        },
+        "kind": "var",
+      },
+    },
+  ]);
+  let actual = serde_json::to_value(&entries).unwrap();
+  assert_eq!(actual, expected_json);
+}
+
+#[tokio::test]
+async fn docsearch_records() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * The module.
+ * @module
+ */
+
+/** A class. */
+export class A {
+  /** A property. */
+  b = 1;
+}
+
+export namespace Ns {
+  /** A function. */
+  export function c() {}
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let records = crate::docsearch::docsearch_records(
+    "mod",
+    "https://example.com/mod",
+    &entries,
+  );
+  let actual = records
+    .iter()
+    .map(|r| {
+      (
+        r.record_type.as_str(),
+        r.url.as_str(),
+        r.content.as_deref().unwrap_or_default(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    actual,
+    vec![
+      ("lvl0", "https://example.com/mod", "The module."),
+      ("lvl1", "https://example.com/mod#A", "A class."),
+      ("lvl2", "https://example.com/mod#A.b", "A property."),
+      ("lvl1", "https://example.com/mod#Ns", ""),
+      ("lvl2", "https://example.com/mod#Ns.c", "A function."),
+    ]
+  );
+  assert_eq!(records[4].hierarchy.lvl1.as_deref(), Some("Ns"));
+  assert_eq!(records[4].hierarchy.lvl2.as_deref(), Some("c"));
+}
+
+#[tokio::test]
+async fn plain_text_corpus() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** Adds numbers. */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+/** A class. */
+export class A {
+  /** A property. */
+  b = 1;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(
+    crate::to_plain_text(&entries),
+    r#"## A
+
+```ts
+class A
+```
+
+A class.
+
+## A.b
+
+```ts
+b: number
+```
+
+A property.
+
+## add
+
+```ts
+function add(a: number, b: number): number
+```
+
+Adds numbers.
+
+"#
+  );
+}
+
+#[tokio::test]
+async fn doc_score() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/**
+ * The module.
+ * @module
+ */
+
+/**
+ * Adds numbers.
+ *
+ * @param a the first number
+ * @example
+ * ```ts
+ * add(1, 2);
+ * ```
+ */
+export function add(a: number, b: number): number {
+  return a + b;
+}
+
+export const c = 1;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let score = crate::score_module(
+    specifier.as_str(),
+    &entries,
+    &crate::ScoreWeights::default(),
+  );
+  assert!(score.has_module_doc);
+  assert!(score.has_examples);
+  assert_eq!(score.documented_symbols, 1);
+  assert_eq!(score.total_symbols, 2);
+  assert_eq!(score.documented_params, 1);
+  assert_eq!(score.total_params, 2);
+  // (1 * 1.0 + 2 * 0.5 + 1 * 0.5 + 1 * 1.0) / 5
+  assert_eq!(score.score, 0.7);
+
+  let report = crate::score_modules(
+    &[(specifier.to_string(), entries)],
+    &crate::ScoreWeights::default(),
+  );
+  assert_eq!(report.score, 0.7);
+}
+
+#[tokio::test]
+async fn api_metrics() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Options, Result } from "./types.ts";
+
+export function run(): void;
+export function run(options: Options): void;
+export function run(options?: Options) {}
+
+export class Runner {
+  /** @deprecated */
+  name = "runner";
+  start(options: Options): Result {}
+}
+
+/** @deprecated use `run` */
+export enum Mode {
+  A,
+  B,
+}
+
+function internal() {}
+"#,
+      ),
+      (
+        "file:///types.ts",
+        None,
+        "export interface Options {}\nexport interface Result {}",
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let metrics = crate::api_metrics(&entries);
+  assert_eq!(metrics.functions, 1);
+  assert_eq!(metrics.classes, 1);
+  assert_eq!(metrics.enums, 1);
+  assert_eq!(metrics.members, 4);
+  assert_eq!(metrics.overloads, 2);
+  assert_eq!(metrics.deprecated, 2);
+  assert_eq!(
+    metrics.external_type_references,
+    vec![crate::ExternalTypeReference {
+      specifier: "file:///types.ts".to_string(),
+      count: 4,
+    }]
+  );
+}
+
+#[tokio::test]
+async fn referenced_types() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Options } from "./options.ts";
+import * as levels from "./level.ts";
+
+export function run(options: Options, level: levels.Level) {}
+"#,
+      ),
+      (
+        "file:///options.ts",
+        None,
+        r#"import { Level } from "./level.ts";
+
+/** The options. */
+export interface Options {
+  level: Level;
+}
+"#,
+      ),
+      (
+        "file:///level.ts",
+        None,
+        r#"export type Level = "a" | "b";"#,
+      ),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      referenced_types: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+  let mut names = module_doc
+    .referenced_types
+    .iter()
+    .map(|referenced_type| referenced_type.name.as_str())
+    .collect::<Vec<_>>();
+  names.sort();
+  assert_eq!(names, vec!["Level", "Options"]);
+
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+  assert!(module_doc.referenced_types.is_empty());
+}
+
+#[tokio::test]
+async fn referenced_types_with_reexports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      ("file:///mod.ts", None, r#"export { run } from "./run.ts";"#),
+      (
+        "file:///run.ts",
+        None,
+        r#"import { Options } from "./options.ts";
+
+export function run(options: Options) {}
+"#,
+      ),
+      (
+        "file:///options.ts",
+        None,
+        r#"interface Inner {}
+
+export interface Options {
+  inner: Inner;
+}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      referenced_types: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let doc_nodes = parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(doc_nodes.len(), 1);
+  let mut referenced_types = parser
+    .referenced_types()
+    .into_iter()
+    .map(|referenced_type| {
+      (
+        referenced_type.name,
+        referenced_type.doc_node.declaration_kind,
+      )
+    })
+    .collect::<Vec<_>>();
+  referenced_types.sort_by(|a, b| a.0.cmp(&b.0));
+  assert_eq!(
+    referenced_types,
+    vec![
+      ("Inner".to_string(), DeclarationKind::Private),
+      ("Options".to_string(), DeclarationKind::Export),
+    ]
+  );
+}
+
+#[tokio::test]
+async fn inline_external_types() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Options } from "./options.ts";
+
+export function run(options: Options) {}
+"#,
+      ),
+      (
+        "file:///options.ts",
+        None,
+        r#"import { Level } from "./level.ts";
+
+export interface Options {
+  level: Level;
+}
+"#,
+      ),
+      (
+        "file:///level.ts",
+        None,
+        r#"export type Level = "a" | "b";"#,
+      ),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      referenced_types: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+
+  let mut doc_nodes = module_doc.definitions.clone();
+  let count = crate::inline_external_types(
+    &mut doc_nodes,
+    &module_doc.referenced_types,
+    &crate::InlineOptions::default(),
+  );
+  assert_eq!(count, 1);
+  let run = doc_nodes.iter().find(|n| n.name == "run").unwrap();
+  assert_eq!(
+    run.function_def.as_ref().unwrap().params[0].to_string(),
+    "options: { level: Level; }"
+  );
+
+  let mut doc_nodes = module_doc.definitions.clone();
+  let count = crate::inline_external_types(
+    &mut doc_nodes,
+    &module_doc.referenced_types,
+    &crate::InlineOptions {
+      type_names: None,
+      max_depth: 2,
+    },
+  );
+  assert_eq!(count, 2);
+  let run = doc_nodes.iter().find(|n| n.name == "run").unwrap();
+  assert_eq!(
+    run.function_def.as_ref().unwrap().params[0].to_string(),
+    r#"options: { level: "a" | "b"; }"#
+  );
+
+  let mut doc_nodes = module_doc.definitions.clone();
+  let count = crate::inline_external_types(
+    &mut doc_nodes,
+    &module_doc.referenced_types,
+    &crate::InlineOptions {
+      type_names: Some(vec!["Level".to_string()]),
+      max_depth: 2,
+    },
+  );
+  assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn inline_external_types_through_imports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Options as Opts } from "./options.ts";
+import * as levels from "./level.ts";
+
+type Level = number;
+declare namespace Ns {
+  type Level = string;
+}
+
+export function run(
+  options: Opts,
+  level: levels.Level,
+  local: Level,
+  qualified: Ns.Level,
+) {}
+"#,
+      ),
+      (
+        "file:///options.ts",
+        None,
+        r#"import { Level } from "./level.ts";
+
+interface Inner {
+  level: Level;
+}
+
+export interface Options {
+  inner: Inner;
+}
+"#,
+      ),
+      (
+        "file:///level.ts",
+        None,
+        r#"export type Level = "a" | "b";"#,
+      ),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      referenced_types: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+
+  let mut doc_nodes = module_doc.definitions.clone();
+  let count = crate::inline_external_types(
+    &mut doc_nodes,
+    &module_doc.referenced_types,
+    &crate::InlineOptions {
+      type_names: None,
+      max_depth: 3,
+    },
+  );
+  assert_eq!(count, 4);
+  let run = doc_nodes.iter().find(|n| n.name == "run").unwrap();
+  let params = run
+    .function_def
+    .as_ref()
+    .unwrap()
+    .params
+    .iter()
+    .map(|param| param.to_string())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    params,
+    vec![
+      r#"options: { inner: { level: "a" | "b"; }; }"#,
+      r#"level: "a" | "b""#,
+      "local: Level",
+      "qualified: Ns.Level",
+    ]
+  );
+}
+
+#[tokio::test]
+async fn position_encoding() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![("file:///mod.ts", None, "/* 😀 */ export function a() {}")],
+  )
+  .await;
+  let locations = |position_encoding| {
+    let parser = DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        position_encoding,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let entries = parser.parse(&specifier).unwrap();
+    (entries[0].location.line, entries[0].location.col)
+  };
+  assert_eq!(locations(Default::default()), (1, 9));
+  assert_eq!(
+    locations(crate::PositionEncoding {
+      columns: crate::ColumnEncoding::Utf8,
+      zero_based_lines: false,
+    }),
+    (1, 12)
+  );
+  assert_eq!(
+    locations(crate::PositionEncoding {
+      columns: crate::ColumnEncoding::Utf16,
+      zero_based_lines: true,
+    }),
+    (0, 10)
+  );
+}
+
+#[tokio::test]
+async fn source_text() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** A class. */
+export class A {
+  b(): void {}
+}
+
+namespace C {
+  export const d = 1;
+}
+"#,
+    )],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, true, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+
+  let class = entries.iter().find(|n| n.name == "A").unwrap();
+  assert_eq!(
+    crate::source_text(&parser, &class.location).unwrap(),
+    "export class A {\n  b(): void {}\n}"
+  );
+  let method = &class.class_def.as_ref().unwrap().methods[0];
+  assert_eq!(
+    crate::source_text(&parser, &method.location).unwrap(),
+    "b(): void {}"
+  );
+  let namespace = entries.iter().find(|n| n.name == "C").unwrap();
+  let element = &namespace.namespace_def.as_ref().unwrap().elements[0];
+  assert_eq!(
+    crate::source_text(&parser, &element.location).unwrap(),
+    "export const d = 1;"
+  );
+}
+
+#[tokio::test]
+async fn doc_node_index() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export namespace Deno {
+  export interface Conn {
+    closeWrite(): void;
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let index = crate::DocNodeIndex::new(&entries);
+  let found = index.find("Deno.Conn.closeWrite");
+  assert_eq!(found.len(), 1);
+  let entry = found[0];
+  assert!(matches!(entry.node, crate::IndexedNode::InterfaceMethod(_)));
+  let owners = index
+    .owner_chain(entry.id)
+    .into_iter()
+    .map(|id| index.get(id).unwrap().qualified_name.as_str())
+    .collect::<Vec<_>>();
+  assert_eq!(owners, vec!["Deno.Conn", "Deno"]);
+  assert_eq!(index.parent(entry.id).unwrap().qualified_name, "Deno.Conn");
+  assert!(index.find("Conn").is_empty());
+}
+
+#[tokio::test]
+async fn qualified_names() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export namespace Deno {
+  export interface Conn {
+    closeWrite(): void;
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      qualified_names: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let namespace = &entries[0];
+  assert_eq!(namespace.qualified_name.as_deref(), Some("Deno"));
+  let interface = &namespace.namespace_def.as_ref().unwrap().elements[0];
+  assert_eq!(interface.qualified_name.as_deref(), Some("Deno.Conn"));
+  let json = serde_json::to_value(interface).unwrap();
+  assert_eq!(
+    json["interfaceDef"]["methods"][0]["qualifiedName"],
+    json!("Deno.Conn.closeWrite")
+  );
+
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let json = serde_json::to_value(&entries[0]).unwrap();
+  assert!(json.get("qualifiedName").is_none());
+}
+
+#[tokio::test]
+async fn reexport_js_doc() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"/**
+ * Use `b` directly.
+ * @deprecated
+ */
+export { a as b } from "./a.ts";
+
+/** The a module. */
+export * as ns from "./a.ts";
+
+/** @ignore */
+export { c } from "./a.ts";
+"#,
+      ),
+      (
+        "file:///a.ts",
+        None,
+        r#"/** A function. */
+export function a() {}
+
+/** C function. */
+export function c() {}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+  let b = entries.iter().find(|n| n.name == "b").unwrap();
+  assert_eq!(b.js_doc.doc.as_deref(), Some("Use `b` directly."));
+  assert_eq!(b.js_doc.tags, vec![JsDocTag::Deprecated { doc: None }]);
+  let ns = entries.iter().find(|n| n.name == "ns").unwrap();
+  assert_eq!(ns.js_doc.doc.as_deref(), Some("The a module."));
+  assert!(!entries.iter().any(|n| n.name == "c"));
+}
+
+#[tokio::test]
+async fn output_backends() {
+  struct CountBackend;
+
+  impl crate::OutputBackend for CountBackend {
+    fn name(&self) -> &str {
+      "count"
+    }
+
+    fn render(
+      &self,
+      doc_nodes: &[crate::DocNode],
+      options: &crate::BackendOptions,
+    ) -> Result<Vec<crate::Artifact>, anyhow::Error> {
+      Ok(vec![crate::Artifact {
+        path: options.settings["path"].clone(),
+        content: doc_nodes.len().to_string(),
+      }])
+    }
+  }
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![("file:///mod.ts", None, "export function a() {}")],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+
+  let mut registry = crate::BackendRegistry::default();
+  assert_eq!(
+    registry.names(),
+    vec!["terminal", "json", "text", "semanticTokens", "ctags"]
+  );
+  let artifacts = registry
+    .render("json", &entries, &Default::default())
+    .unwrap();
+  assert_eq!(artifacts[0].path, "doc.json");
+  assert_eq!(
+    artifacts[0].content,
+    serde_json::to_string(&entries).unwrap()
+  );
+  let artifacts = registry
+    .render("terminal", &entries, &Default::default())
+    .unwrap();
+  assert_eq!(
+    artifacts[0].content,
+    DocPrinter::new(&entries, false, false).to_string()
+  );
+
+  registry.register(Box::new(CountBackend));
+  let mut options = crate::BackendOptions::default();
+  options
+    .settings
+    .insert("path".to_string(), "count.txt".to_string());
+  let artifacts = registry.render("count", &entries, &options).unwrap();
+  assert_eq!(
+    artifacts,
+    vec![crate::Artifact {
+      path: "count.txt".to_string(),
+      content: "1".to_string(),
+    }]
+  );
+  assert!(registry.render("html", &entries, &options).is_err());
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn ffi_parse() {
+  use std::ffi::CStr;
+  use std::ffi::CString;
+
+  let root = CString::new("file:///mod.ts").unwrap();
+  let sources = CString::new(
+    json!({
+      "file:///mod.ts": "export { a } from \"./a.ts\";",
+      "file:///a.ts": "/** A. */\nexport const a = 1;",
+    })
+    .to_string(),
+  )
+  .unwrap();
+  let result = unsafe {
+    let ptr =
+      crate::ffi::deno_doc_parse(root.as_ptr(), sources.as_ptr(), false);
+    let result: serde_json::Value =
+      serde_json::from_str(CStr::from_ptr(ptr).to_str().unwrap()).unwrap();
+    crate::ffi::deno_doc_free(ptr);
+    result
+  };
+  assert_eq!(result["nodes"][0]["name"], "a");
+  assert_eq!(result["nodes"][0]["jsDoc"]["doc"], "A.");
+
+  let result = unsafe {
+    let ptr =
+      crate::ffi::deno_doc_parse(root.as_ptr(), std::ptr::null(), false);
+    let result: serde_json::Value =
+      serde_json::from_str(CStr::from_ptr(ptr).to_str().unwrap()).unwrap();
+    crate::ffi::deno_doc_free(ptr);
+    result
+  };
+  assert!(result["error"].is_string());
+}
+
+#[tokio::test]
+async fn parser_metrics() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"interface Private {}
+export const a: Private = {};
+export namespace ns {
+  export const b = 1;
+}"#,
+    )],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  parser.parse_module(&specifier).unwrap();
+  parser.parse_module(&specifier).unwrap();
+  let metrics = parser.metrics();
+  let modules = metrics
+    .modules
+    .iter()
+    .map(|module| (module.specifier.as_str(), module.node_count))
+    .collect::<Vec<_>>();
+  // `Private` is included, being referenced by `a`
+  assert_eq!(modules, vec![("file:///mod.ts", 4), ("file:///mod.ts", 4)]);
+  assert_eq!(metrics.node_count, 8);
+  // the second parse reuses the doc nodes of the first one
+  assert_eq!(metrics.cache_hits, 1);
+  assert_eq!(metrics.diagnostic_count, 1);
+}
+
+#[tokio::test]
+async fn graph_stats() {
+  let mod_source = r#"import "./side_effect.ts";
+export * from "https://example.com/a.ts";
+export { b } from "./b.js";
+export const c = 1;
+"#;
+  let b_source = "export const b = 1;\n";
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      ("file:///mod.ts", None, mod_source),
+      ("https://example.com/a.ts", None, "export const a = 1;"),
+      ("file:///b.js", None, b_source),
+      ("file:///side_effect.ts", None, "globalThis.loaded = true;"),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let (entries, stats) =
+    parser.parse_with_reexports_and_stats(&specifier).unwrap();
+  assert_eq!(entries.len(), 3);
+  // the module imported for its side effects is not documented
+  assert_eq!(stats.module_count, 3);
+  assert_eq!(stats.remote_count, 1);
+  assert_eq!(stats.local_count, 2);
+  assert_eq!(
+    stats.total_bytes,
+    mod_source.len() + "export const a = 1;".len() + b_source.len()
+  );
+  assert_eq!(stats.media_types.get("TypeScript"), Some(&2));
+  assert_eq!(stats.media_types.get("JavaScript"), Some(&1));
+  // the summary is only of the latest parse
+  let (_, stats_again) =
+    parser.parse_with_reexports_and_stats(&specifier).unwrap();
+  assert_eq!(stats_again, stats);
+}
+
+#[tokio::test]
+async fn reachable_only() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import { Used, Unused } from "./a.ts";
+interface B { c: C }
+interface C {}
+interface D {}
+export interface A {
+  b: B;
+  used: Used;
+}"#,
+      ),
+      (
+        "file:///a.ts",
+        None,
+        "export interface Used {}\nexport interface Unused {}",
+      ),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      reachable_only: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let names = parser
+    .parse(&specifier)
+    .unwrap()
+    .into_iter()
+    .map(|doc_node| doc_node.name)
+    .collect::<Vec<_>>();
+  assert_eq!(names, vec!["A", "B", "C", "Used"]);
+}
+
+#[tokio::test]
+async fn list_modules() {
+  let (graph, analyzer, _) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"/**
+ * The entrypoint.
+ *
+ * @module
+ */
+
+export * from "./b.js";
+import data from "./data.json" assert { type: "json" };
+export { data };"#,
+      ),
+      ("file:///b.js", None, "export const b = 1;"),
+      ("file:///data.json", None, r#"{ "a": 1 }"#),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let modules = parser
+    .modules()
+    .into_iter()
+    .map(|module| {
+      (
+        module.specifier.to_string(),
+        module.media_type,
+        module.module_doc.and_then(|js_doc| js_doc.doc),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    modules,
+    vec![
+      ("file:///b.js".to_string(), MediaType::JavaScript, None),
+      ("file:///data.json".to_string(), MediaType::Json, None),
+      (
+        "file:///mod.ts".to_string(),
+        MediaType::TypeScript,
+        Some("The entrypoint.".to_string())
+      ),
+    ]
+  );
+}
+
+#[tokio::test]
+async fn list_asset_modules() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"import "./style.css";
+
+export const a = 1;"#,
+      ),
+      ("file:///style.css", None, "body { margin: 0; }"),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let modules = parser
+    .modules()
+    .into_iter()
+    .map(|module| (module.specifier.to_string(), module.asset))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    modules,
+    vec![
+      ("file:///mod.ts".to_string(), false),
+      ("file:///style.css".to_string(), true),
+    ]
+  );
+  let entries = parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(entries.len(), 1);
+  let css = ModuleSpecifier::parse("file:///style.css").unwrap();
+  assert!(parser.parse_module(&css).unwrap().definitions.is_empty());
+}
+
+#[tokio::test]
+async fn visibility_filter() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { remote } from "https://example.com/remote.ts";
+/** @internal */
+export function internal() {}
+export class A {
+  #secret = 1;
+  /** @internal */
+  hidden(): void {}
+  shown(): void {}
+}
+function notExported() {}"#,
+      ),
+      (
+        "https://example.com/remote.ts",
+        None,
+        "export const remote = 1;",
+      ),
+    ],
+  )
+  .await;
+  let parse = |visibility: VisibilityFilter| {
+    let parser = DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        visibility: Some(visibility),
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let mut names = Vec::new();
+    for doc_node in parser.parse_with_reexports(&specifier).unwrap() {
+      if let Some(class_def) = &doc_node.class_def {
+        for property in &class_def.properties {
+          names.push(format!("{}.{}", doc_node.name, property.name));
+        }
+        for method in &class_def.methods {
+          names.push(format!("{}.{}", doc_node.name, method.name));
+        }
+      }
+      names.push(doc_node.name);
+    }
+    names.sort();
+    names
+  };
+
+  assert_eq!(
+    parse(VisibilityFilter::default()),
+    vec!["A", "A.hidden", "A.shown", "internal", "remote"]
+  );
+  assert_eq!(
+    parse(VisibilityFilter {
+      non_exported: true,
+      internal: false,
+      private_members: true,
+      remote: false,
+    }),
+    vec!["A", "A.#secret", "A.shown", "notExported"]
+  );
+}
+
+#[tokio::test]
+async fn dynamic_imports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export async function load() {
+  const { a } = await import("./a.ts");
+  await import("./a.ts");
+  return a;
+}"#,
+      ),
+      ("file:///a.ts", None, "/** A. */\nexport const a = 1;"),
+    ],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      dynamic_imports: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+  assert_eq!(module_doc.definitions.len(), 1);
+  assert_eq!(module_doc.dynamic_imports.len(), 1);
+  let dynamic_import = &module_doc.dynamic_imports[0];
+  assert_eq!(dynamic_import.src, "file:///a.ts");
+  assert_eq!(dynamic_import.definitions[0].name, "a");
+
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let module_doc = parser.parse_module(&specifier).unwrap();
+  assert!(module_doc.dynamic_imports.is_empty());
+}
+
+#[tokio::test]
+async fn package_roots_from_manifest() {
+  let manifest_specifier = ModuleSpecifier::parse("file:///deno.json").unwrap();
+  let roots = crate::package_roots(
+    &manifest_specifier,
+    r#"{ "exports": { ".": "./mod.ts", "./util": "./util.ts" } }"#,
+  )
+  .unwrap();
+  assert_eq!(
+    roots
+      .iter()
+      .map(|root| (root.name.as_str(), root.specifier.as_str()))
+      .collect::<Vec<_>>(),
+    vec![(".", "file:///mod.ts"), ("./util", "file:///util.ts")]
+  );
+
+  let package_roots = crate::package_roots(
+    &ModuleSpecifier::parse("file:///pkg/package.json").unwrap(),
+    r#"{ "exports": {
+      ".": { "types": "./index.d.ts", "default": "./index.js" },
+      "./feature": { "import": "./feature.js" },
+      "./*": "./lib/*.js"
+    } }"#,
+  )
+  .unwrap();
+  assert_eq!(
+    package_roots
+      .iter()
+      .map(|root| (root.name.as_str(), root.specifier.as_str()))
+      .collect::<Vec<_>>(),
+    vec![
+      (".", "file:///pkg/index.d.ts"),
+      ("./feature", "file:///pkg/feature.js")
+    ]
+  );
+  assert!(crate::package_roots(&manifest_specifier, "{}").is_err());
+
+  let sources = vec![
+    ("file:///mod.ts", None, "export const a = 1;"),
+    ("file:///util.ts", None, "export function b() {}"),
+  ];
+  let mut memory_loader = MemoryLoader::new(
+    sources
+      .into_iter()
+      .map(|(s, h, c)| {
+        (
+          s,
+          Source::Module {
+            specifier: s,
+            maybe_headers: h,
+            content: c,
+          },
+        )
+      })
+      .collect(),
+    vec![],
+  );
+  let analyzer = create_analyzer();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      roots.iter().map(|root| root.specifier.clone()).collect(),
+      &mut memory_loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        ..Default::default()
+      },
+    )
+    .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse_package_roots(&roots).unwrap();
+  assert_eq!(
+    entries
+      .iter()
+      .map(|(root, doc_nodes)| (root.name.as_str(), doc_nodes[0].name.as_str()))
+      .collect::<Vec<_>>(),
+    vec![(".", "a"), ("./util", "b")]
+  );
+}
+
+#[tokio::test]
+async fn redirected_locations() {
+  let sources = vec![
+    (
+      "file:///mod.ts",
+      Source::Module {
+        specifier: "file:///mod.ts",
+        maybe_headers: None,
+        content: r#"import { a } from "https://example.com/lib.ts";
+export { a };"#,
+      },
+    ),
+    (
+      "https://example.com/lib.ts",
+      Source::Redirect("https://example.com/lib@1.0.0.ts"),
+    ),
+    (
+      "https://example.com/lib@1.0.0.ts",
+      Source::Module {
+        specifier: "https://example.com/lib@1.0.0.ts",
+        maybe_headers: None,
+        content: "export const a = 1;",
+      },
+    ),
+  ];
+  let mut memory_loader = MemoryLoader::new(sources, vec![]);
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let analyzer = create_analyzer();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      vec![root.clone()],
+      &mut memory_loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        ..Default::default()
+      },
+    )
+    .await;
+  let entries = DocParser::new(&graph, true, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse_with_reexports(&root)
+    .unwrap();
+
+  let import = entries
+    .iter()
+    .find(|node| node.kind == DocNodeKind::Import)
+    .unwrap();
+  let import_def = import.import_def.as_ref().unwrap();
+  assert_eq!(import_def.src, "https://example.com/lib@1.0.0.ts");
+  assert_eq!(
+    import_def.requested_src.as_deref(),
+    Some("https://example.com/lib.ts")
+  );
+  assert_eq!(import.location.requested_filename, None);
+
+  let variable = entries
+    .iter()
+    .find(|node| node.kind == DocNodeKind::Variable)
+    .unwrap();
+  assert_eq!(
+    serde_json::to_value(&variable.location).unwrap(),
+    json!({
+      "filename": "https://example.com/lib@1.0.0.ts",
+      "line": 1,
+      "col": 13,
+      "requestedFilename": "https://example.com/lib.ts"
+    })
+  );
+}
+
+#[tokio::test]
+async fn semantic_tokens() {
+  use crate::SemanticTokenKind::*;
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function add(a: number, b?: Value): Promise<"done"> {}
+
+export interface Value {
+  readonly count: number;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let signatures = crate::semantic_signatures(&entries);
+  let tokens = signatures
+    .iter()
+    .map(|signature| {
+      (
+        signature.qualified_name.as_str(),
+        signature
+          .tokens
+          .iter()
+          .map(|token| (&signature.text[token.start..token.end], token.kind))
+          .collect::<Vec<_>>(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    tokens,
+    vec![
+      ("Value", vec![("interface", Keyword), ("Value", Type)]),
+      (
+        "Value.count",
+        vec![("readonly", Keyword), ("count", Member), ("number", Type)]
+      ),
+      (
+        "add",
+        vec![
+          ("function", Keyword),
+          ("add", Member),
+          ("a", Parameter),
+          ("number", Type),
+          ("b", Parameter),
+          ("Value", Type),
+          ("Promise", Type),
+          ("\"done\"", String),
+        ]
+      ),
+    ]
+  );
+}
+
+#[tokio::test]
+async fn ctags() {
+  let (graph, analyzer, specifier) = setup(
+    "https://example.com/mod.ts",
+    vec![(
+      "https://example.com/mod.ts",
+      None,
+      r#"export class A {
+  b = 1;
+  c() {}
+}
+
+export namespace Ns {
+  export const d = 1;
+}
+
+function e() {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, true, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(
+    crate::to_ctags(&entries),
+    r#"!_TAG_FILE_FORMAT	2	/extended format/
+!_TAG_FILE_SORTED	1	/0=unsorted, 1=sorted/
+A	https://example.com/mod.ts	1;"	c
+Ns	https://example.com/mod.ts	6;"	n
+b	https://example.com/mod.ts	2;"	p	class:A
+c	https://example.com/mod.ts	3;"	m	class:A
+d	https://example.com/mod.ts	7;"	C	namespace:Ns
+"#
+  );
+}
+
+#[tokio::test]
+async fn document_symbols() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"/** A class. */
+export class A {
+  b(): void {}
+}
+
+namespace C {
+  export const d = 1;
+}
+"#,
+    )],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, true, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let symbols = crate::document_symbols(&parser, &entries);
+  assert_eq!(
+    serde_json::to_value(symbols).unwrap(),
+    json!([
+      {
+        "name": "A",
+        "kind": 5,
+        "range": {
+          "start": { "line": 1, "character": 0 },
+          "end": { "line": 3, "character": 1 }
+        },
+        "selectionRange": {
+          "start": { "line": 1, "character": 13 },
+          "end": { "line": 1, "character": 14 }
+        },
+        "children": [{
+          "name": "b",
+          "kind": 6,
+          "range": {
+            "start": { "line": 2, "character": 2 },
+            "end": { "line": 2, "character": 14 }
+          },
+          "selectionRange": {
+            "start": { "line": 2, "character": 2 },
+            "end": { "line": 2, "character": 3 }
+          }
+        }]
+      },
+      {
+        "name": "C",
+        "kind": 3,
+        "range": {
+          "start": { "line": 5, "character": 0 },
+          "end": { "line": 7, "character": 1 }
+        },
+        "selectionRange": {
+          "start": { "line": 5, "character": 10 },
+          "end": { "line": 5, "character": 11 }
+        },
+        "children": [{
+          "name": "d",
+          "kind": 13,
+          "range": {
+            "start": { "line": 6, "character": 2 },
+            "end": { "line": 6, "character": 21 }
+          },
+          "selectionRange": {
+            "start": { "line": 6, "character": 15 },
+            "end": { "line": 6, "character": 16 }
+          }
+        }]
+      }
+    ])
+  );
+}
+
+#[tokio::test]
+async fn deno_types_directive() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"// @deno-types="./a.d.ts"
+import { a } from "./a.js";
+// @deno-types="./b.d.ts"
+export { b } from "./b.js";
+// @deno-types="./c.d.ts"
+export * as c from "./c.js";
+export { a };
+"#,
+      ),
+      ("file:///a.js", None, "export function a(value) {}"),
+      (
+        "file:///a.d.ts",
+        None,
+        "export function a(value: string): void;",
+      ),
+      ("file:///b.js", None, "export const b = 1;"),
+      ("file:///b.d.ts", None, "export const b: number;"),
+      ("file:///c.js", None, "export class C {}"),
+      ("file:///c.d.ts", None, "export class C {}"),
+    ],
+  )
+  .await;
+  let entries = DocParser::new(&graph, true, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse_with_reexports(&specifier)
+    .unwrap();
+
+  let location = |kind: DocNodeKind, name: &str| {
+    entries
+      .iter()
+      .find(|node| node.kind == kind && node.name == name)
+      .map(|node| node.location.filename.as_str())
+  };
+  assert_eq!(location(DocNodeKind::Function, "a"), Some("file:///a.d.ts"));
+  assert_eq!(location(DocNodeKind::Variable, "b"), Some("file:///b.d.ts"));
+  let import = entries
+    .iter()
+    .find(|node| node.kind == DocNodeKind::Import)
+    .unwrap();
+  assert_eq!(import.import_def.as_ref().unwrap().src, "file:///a.d.ts");
+  let namespace = entries
+    .iter()
+    .find(|node| node.kind == DocNodeKind::Namespace)
+    .unwrap();
+  let elements = &namespace.namespace_def.as_ref().unwrap().elements;
+  assert_eq!(elements[0].location.filename, "file:///c.d.ts");
+}
+
+#[tokio::test]
+async fn wildcard_ambient_modules() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"/** Stylesheets, as CSS modules. */
+declare module "*.css" {
+  const classes: Record<string, string>;
+  export default classes;
+}
+
+declare module "*.svg" {
+  export const url: string;
+}
+
+/** @ignore */
+declare module "*.txt" {
+  const text: string;
+  export default text;
+}
+
+declare module "lib" {
+  export function f(): void;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  assert_eq!(
+    serde_json::to_value(&entries).unwrap(),
+    json!([
+      {
+        "kind": "namespace",
+        "name": "*.css",
+        "location": {
+          "filename": "file:///mod.d.ts",
+          "line": 2,
+          "col": 0
+        },
+        "declarationKind": "declare",
+        "jsDoc": {
+          "doc": "Stylesheets, as CSS modules."
+        },
+        "namespaceDef": {
+          "elements": [
+            {
+              "kind": "variable",
+              "name": "classes",
+              "location": {
+                "filename": "file:///mod.d.ts",
+                "line": 3,
+                "col": 8
+              },
+              "declarationKind": "declare",
+              "variableDef": {
+                "tsType": {
+                  "repr": "Record",
+                  "kind": "typeRef",
+                  "typeRef": {
+                    "typeParams": [
+                      {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      },
+                      {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      }
+                    ],
+                    "typeName": "Record"
+                  }
+                },
+                "kind": "const"
+              }
+            },
+            {
+              "kind": "variable",
+              "name": "default",
+              "location": {
+                "filename": "file:///mod.d.ts",
+                "line": 3,
+                "col": 8
+              },
+              "declarationKind": "export",
+              "variableDef": {
+                "tsType": {
+                  "repr": "Record",
+                  "kind": "typeRef",
+                  "typeRef": {
+                    "typeParams": [
+                      {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      },
+                      {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      }
+                    ],
+                    "typeName": "Record"
+                  }
+                },
+                "kind": "const"
+              }
+            }
+          ],
+          "ambientModule": true
+        }
+      },
+      {
+        "kind": "namespace",
+        "name": "*.svg",
+        "location": {
+          "filename": "file:///mod.d.ts",
+          "line": 7,
+          "col": 0
+        },
+        "declarationKind": "declare",
+        "namespaceDef": {
+          "elements": [
+            {
+              "kind": "variable",
+              "name": "url",
+              "location": {
+                "filename": "file:///mod.d.ts",
+                "line": 8,
+                "col": 15
+              },
+              "declarationKind": "export",
+              "variableDef": {
+                "tsType": {
+                  "repr": "string",
+                  "kind": "keyword",
+                  "keyword": "string"
+                },
+                "kind": "const"
+              }
+            }
+          ],
+          "ambientModule": true
+        }
+      }
+    ])
+  );
+}
+
+#[tokio::test]
+async fn effective_js_doc() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export interface Shape {
+  /** The area of the shape. */
+  area(): number;
+}
+
+/** A base shape. */
+export class Base implements Shape {
+  area(): number {
+    return 0;
+  }
+}
+
+export class Square extends Base {
+  /**
+   * @inheritDoc
+   * @deprecated
+   */
+  area(): number {
+    return 1;
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      effective_js_doc: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let effective = |name: &str| {
+    let node = entries.iter().find(|n| n.name == name).unwrap();
+    let method = node
+      .class_def
+      .as_ref()
+      .map(|class_def| &class_def.methods[0].effective_js_doc)
+      .or_else(|| {
+        node
+          .interface_def
+          .as_ref()
+          .map(|interface_def| &interface_def.methods[0].effective_js_doc)
+      })
+      .unwrap();
+    serde_json::to_value((&node.effective_js_doc, method)).unwrap()
+  };
+
+  assert_eq!(
+    effective("Shape"),
+    json!([null, { "doc": "The area of the shape." }])
+  );
+  assert_eq!(
+    effective("Base"),
+    json!([{ "doc": "A base shape." }, { "doc": "The area of the shape." }])
+  );
+  assert_eq!(
+    effective("Square"),
+    json!([
+      { "doc": "A base shape." },
+      { "doc": "The area of the shape.", "tags": [{ "kind": "deprecated" }] }
+    ])
+  );
+}
+
+#[tokio::test]
+async fn inherited_members() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export class Emitter {
+  on(event: string): void {}
+  emit(event: string): void {}
+}
+
+export class Base extends Emitter {
+  name = "base";
+  emit(event: string): void {}
+}
+
+export class Widget extends Base {
+  render(): void {}
+}
+
+export interface Named {
+  name: string;
+}
+
+export interface Labeled extends Named {
+  label(): string;
+}
+"#,
+    )],
+  )
+  .await;
+  let parse = |exclude_bases: Vec<String>| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        inherited_members: Some(InheritedMembers {
+          exclude_bases,
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let members = |entries: &[crate::DocNode], name: &str| {
+    let node = entries.iter().find(|n| n.name == name).unwrap();
+    let mut members = Vec::new();
+    if let Some(class_def) = &node.class_def {
+      for property in &class_def.properties {
+        members
+          .push(format!("{} {:?}", property.name, property.inherited_from));
+      }
+      for method in &class_def.methods {
+        members.push(format!("{} {:?}", method.name, method.inherited_from));
+      }
+    }
+    if let Some(interface_def) = &node.interface_def {
+      for property in &interface_def.properties {
+        members
+          .push(format!("{} {:?}", property.name, property.inherited_from));
+      }
+      for method in &interface_def.methods {
+        members.push(format!("{} {:?}", method.name, method.inherited_from));
+      }
+    }
+    members
+  };
+
+  let entries = parse(Vec::new());
+  assert_eq!(
+    members(&entries, "Widget"),
+    vec![
+      "name Some(\"Base\")",
+      "render None",
+      "emit Some(\"Base\")",
+      "on Some(\"Emitter\")",
+    ]
+  );
+  assert_eq!(
+    members(&entries, "Labeled"),
+    vec!["name Some(\"Named\")", "label None"]
+  );
+
+  let entries = parse(vec!["Emitter".to_string()]);
+  assert_eq!(
+    members(&entries, "Widget"),
+    vec!["name Some(\"Base\")", "render None", "emit Some(\"Base\")"]
+  );
+  assert_eq!(members(&entries, "Base"), vec!["name None", "emit None"]);
+}
+
+#[tokio::test]
+async fn inherited_members_type_params() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export class Item {}
+
+export class Collection<K, V = Item> {
+  entries: [K, V][] = [];
+  get(key: K): V | undefined {
+    return undefined;
+  }
+  map<V>(f: (value: V) => V): V[] {
+    return [];
+  }
+}
+
+export class Items extends Collection<string> {}
+
+export interface Store<T> {
+  load(): Promise<T>;
+}
+
+export interface Cache<T> extends Store<T[]> {}
+
+export interface Users extends Cache<Item> {}
+"#,
+    )],
+  )
+  .await;
+  let parse = |substitute_type_params: bool| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        inherited_members: Some(InheritedMembers {
+          substitute_type_params,
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let signatures = |entries: &[crate::DocNode], name: &str| {
+    let node = entries.iter().find(|n| n.name == name).unwrap();
+    let mut signatures = Vec::new();
+    if let Some(class_def) = &node.class_def {
+      signatures.extend(class_def.properties.iter().map(|p| p.to_string()));
+      signatures.extend(class_def.methods.iter().map(|m| m.to_string()));
+    }
+    if let Some(interface_def) = &node.interface_def {
+      signatures.extend(interface_def.methods.iter().map(|m| m.to_string()));
+    }
+    signatures
+  };
+
+  let entries = parse(true);
+  assert_eq!(
+    signatures(&entries, "Items"),
+    vec![
+      "entries: [string, Item][]",
+      "get(key: string): Item | undefined",
+      "map(f: (value: V) => V): V[]",
+    ]
+  );
+  assert_eq!(
+    signatures(&entries, "Users"),
+    vec!["load(): Promise<Item[]>"]
+  );
+
+  let entries = parse(false);
+  assert_eq!(
+    signatures(&entries, "Items"),
+    vec![
+      "entries: [K, V][]",
+      "get(key: K): V | undefined",
+      "map(f: (value: V) => V): V[]",
+    ]
+  );
+}
+
+#[tokio::test]
+async fn events() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export interface SocketEventMap {
+  open: Event;
+  message: MessageEvent;
+}
+
+export interface Socket {
+  addEventListener<K extends keyof SocketEventMap>(
+    type: K,
+    listener: (event: SocketEventMap[K]) => void,
+  ): void;
+}
+
+export class Stream {
+  /** Emitted with each chunk. */
+  on(event: "data", listener: (chunk: Uint8Array) => void): this;
+  on(event: "end" | "close", listener: () => void): this;
+  on(event: string, listener: (...args: unknown[]) => void): this;
+  on(event: string, listener: (...args: any[]) => void): this {
+    return this;
+  }
+  once(event: "data", listener: (chunk: string) => void): this {
+    return this;
+  }
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      events: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let find = |name: &str| entries.iter().find(|n| n.name == name).unwrap();
+  let table = |events: &[crate::EventDef]| {
+    events
+      .iter()
+      .map(|event| {
+        format!(
+          "{} {} {}",
+          event.method,
+          event.name,
+          event
+            .payload
+            .as_ref()
+            .map(|payload| payload.to_string())
+            .unwrap_or_default()
+        )
+      })
+      .collect::<Vec<_>>()
+  };
+
+  let stream = find("Stream").class_def.as_ref().unwrap();
+  assert_eq!(
+    table(&stream.events),
+    vec!["on data Uint8Array", "on end ", "on close "]
+  );
+  assert_eq!(
+    stream.events[0].js_doc.doc.as_deref(),
+    Some("Emitted with each chunk.")
+  );
+  let socket = find("Socket").interface_def.as_ref().unwrap();
+  assert_eq!(
+    table(&socket.events),
+    vec![
+      "addEventListener open Event",
+      "addEventListener message MessageEvent",
+    ]
+  );
+  assert!(find("SocketEventMap")
+    .interface_def
+    .as_ref()
+    .unwrap()
+    .events
+    .is_empty());
+
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert_contains!(output, "event \"data\": Uint8Array");
+  assert_contains!(output, "event \"open\": Event");
+}
+
+#[tokio::test]
+async fn abstract_members_in_declaration_file() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.d.ts",
+    vec![(
+      "file:///mod.d.ts",
+      None,
+      r#"export declare abstract class Task {
+  constructor(name: string);
+  abstract readonly name: string;
+  abstract get done(): boolean;
+  abstract run(): void;
+  abstract run(times: number): void;
+  cancel(): void;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(&graph, false, analyzer.as_capturing_parser())
+    .unwrap()
+    .parse(&specifier)
+    .unwrap();
+  let class_def = entries[0].class_def.as_ref().unwrap();
+  let constructor = serde_json::to_value(&class_def.constructors[0]).unwrap();
+  assert_eq!(constructor["hasBody"], json!(false));
+  let property = serde_json::to_value(&class_def.properties[0]).unwrap();
+  assert_eq!(property["isAbstract"], json!(true));
+  let methods = class_def
+    .methods
+    .iter()
+    .map(|method| {
+      let value = serde_json::to_value(method).unwrap();
+      (
+        method.name.as_str(),
+        value["isAbstract"].clone(),
+        value["functionDef"]["hasBody"].clone(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    methods,
+    vec![
+      ("done", json!(true), json!(false)),
+      ("run", json!(true), json!(false)),
+      ("run", json!(true), json!(false)),
+      ("cancel", json!(false), json!(false)),
+    ]
+  );
+
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert_contains!(output, "abstract readonly name: string");
+  assert_contains!(output, "abstract get done(): boolean");
+  assert_contains!(output, "abstract run(): void");
+  assert_contains!(output, "abstract run(times: number): void");
+  assert_contains!(output, "cancel(): void");
+}
+
+#[test]
+fn anchors() {
+  assert_eq!(
+    crate::slug(&["Deno", "Conn", "closeWrite"]),
+    "Deno.Conn.closeWrite"
+  );
+  assert_eq!(crate::slug(&["A", "#secret"]), "A.~23secret");
+  assert_eq!(
+    crate::slug(&["A", "[Symbol.iterator]"]),
+    "A.~5BSymbol~2Eiterator~5D"
+  );
+
+  let mut anchors = crate::AnchorGenerator::new();
+  assert_eq!(anchors.anchor(&["foo"]), "foo");
+  assert_eq!(anchors.anchor(&["Foo"]), "Foo-1");
+  // overloads share the anchor of the first declaration
+  assert_eq!(anchors.anchor(&["foo"]), "foo");
+  assert_eq!(anchors.anchor(&["FOO"]), "FOO-2");
+}
+
+#[tokio::test]
+async fn select_overload() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function f(): void;
+export function f(a: string): string;
+export function f(a: number, b?: boolean): number;
+export function f(cb: () => void, ...rest: number[]): void;
+export function f(a?: unknown, ...rest: unknown[]): unknown {
+  return a;
+}
+"#,
+    )],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let select = |arity, argument_kinds| {
+    let hint = crate::OverloadHint {
+      arity,
+      argument_kinds,
+    };
+    crate::select_overload(&entries, &hint)
+      .unwrap()
+      .location
+      .line
+  };
+
+  assert_eq!(select(None, vec![]), 1);
+  assert_eq!(select(Some(1), vec![]), 2);
+  assert_eq!(select(Some(2), vec![]), 3);
+  assert_eq!(select(Some(3), vec![]), 4);
+  assert_eq!(select(None, vec![Some(crate::ArgumentKind::String)]), 2);
+  assert_eq!(select(None, vec![Some(crate::ArgumentKind::Number)]), 3);
+  assert_eq!(select(None, vec![Some(crate::ArgumentKind::Function)]), 4);
+  assert_eq!(
+    select(Some(2), vec![None, Some(crate::ArgumentKind::Boolean)]),
+    3
+  );
+}
+
+#[tokio::test]
+async fn canonical_paths() {
+  let roots = vec![
+    crate::PackageRoot {
+      name: ".".to_string(),
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+    },
+    crate::PackageRoot {
+      name: "./internal".to_string(),
+      specifier: ModuleSpecifier::parse("file:///internal/mod.ts").unwrap(),
+    },
+  ];
+  let sources = vec![
+    (
+      "file:///mod.ts",
+      None,
+      r#"export * as util from "./util.ts";
+export { b as B } from "./internal/b.ts";"#,
+    ),
+    (
+      "file:///internal/mod.ts",
+      None,
+      r#"export * from "./b.ts";
+export { a } from "../util.ts";"#,
+    ),
+    ("file:///internal/b.ts", None, "export class b {}"),
+    ("file:///util.ts", None, "export function a() {}"),
+  ];
+  let mut memory_loader = MemoryLoader::new(
+    sources
+      .into_iter()
+      .map(|(s, h, c)| {
+        (
+          s,
+          Source::Module {
+            specifier: s,
+            maybe_headers: h,
+            content: c,
+          },
+        )
+      })
+      .collect(),
+    vec![],
+  );
+  let analyzer = create_analyzer();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      roots.iter().map(|root| root.specifier.clone()).collect(),
+      &mut memory_loader,
+      BuildOptions {
+        module_analyzer: Some(&analyzer),
+        ..Default::default()
+      },
+    )
+    .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse_package_roots(&roots).unwrap();
+  let paths = crate::CanonicalPaths::new(&entries);
+
+  let b = ModuleSpecifier::parse("file:///internal/b.ts").unwrap();
+  let b = parser.parse(&b).unwrap();
+  let path = paths.get(&b[0].location).unwrap();
+  assert_eq!(path.root, ".");
+  assert_eq!(path.qualified_name, "B");
+
+  let util = ModuleSpecifier::parse("file:///util.ts").unwrap();
+  let a = parser.parse(&util).unwrap();
+  let path = paths.get(&a[0].location).unwrap();
+  assert_eq!(path.root, "./internal");
+  assert_eq!(path.specifier.as_str(), "file:///internal/mod.ts");
+  assert_eq!(path.qualified_name, "a");
+}
+
+#[tokio::test]
+async fn expand_namespace_import() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"/** The levels. */
+import * as levels from "./level.ts";
+/** The options. */
+import { Options } from "./options.ts";
+
+export function run(options: Options, level: levels.Level) {}
+"#,
+      ),
+      (
+        "file:///level.ts",
+        None,
+        r#"/** @module */
+
+export type Level = "a" | "b";
+export * from "./default.ts";
+"#,
+      ),
+      ("file:///default.ts", None, "export const DEFAULT = 1;"),
+      ("file:///options.ts", None, "export interface Options {}"),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, true, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let import = |name: &str| {
+    entries
+      .iter()
+      .find(|node| node.kind == DocNodeKind::Import && node.name == name)
+      .unwrap()
+  };
+  assert!(import("levels").import_def.as_ref().unwrap().namespace);
+  assert!(!import("Options").import_def.as_ref().unwrap().namespace);
+  assert!(parser
+    .expand_namespace_import(import("Options"))
+    .unwrap()
+    .is_none());
+
+  let namespace = parser
+    .expand_namespace_import(import("levels"))
+    .unwrap()
+    .unwrap();
+  assert_eq!(namespace.kind, DocNodeKind::Namespace);
+  assert_eq!(namespace.name, "levels");
+  assert_eq!(namespace.js_doc.doc.as_deref(), Some("The levels."));
+  let mut names = namespace
+    .namespace_def
+    .as_ref()
+    .unwrap()
+    .elements
+    .iter()
+    .map(|node| node.name.as_str())
+    .collect::<Vec<_>>();
+  names.sort();
+  assert_eq!(names, vec!["DEFAULT", "Level"]);
+}
+
+#[tokio::test]
+async fn module_ignore_scope() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export * as util from "./util.ts";"#,
+      ),
+      (
+        "file:///util.ts",
+        None,
+        r#"/**
+ * @module
+ * @ignore
+ */
+
+/** A. */
+export const a = 1;
+"#,
+      ),
+    ],
+  )
+  .await;
+  let util = ModuleSpecifier::parse("file:///util.ts").unwrap();
+  let parse = |module_ignore| {
+    let parser = DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        module_ignore,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let names = |doc_nodes: Vec<crate::DocNode>| {
+      doc_nodes
+        .into_iter()
+        .map(|node| node.name)
+        .collect::<Vec<_>>()
+    };
+    (
+      names(parser.parse_with_reexports(&specifier).unwrap()),
+      names(parser.parse(&util).unwrap()),
+    )
+  };
+
+  let (reexported, direct) = parse(Default::default());
+  assert!(reexported.is_empty());
+  assert!(direct.is_empty());
+
+  let (reexported, direct) = parse(crate::ModuleIgnoreScope {
+    direct: false,
+    namespace_reexports: true,
+  });
+  assert!(reexported.is_empty());
+  assert_eq!(direct, vec!["a"]);
+
+  let (reexported, direct) = parse(crate::ModuleIgnoreScope {
+    direct: true,
+    namespace_reexports: false,
+  });
+  assert_eq!(reexported, vec!["util"]);
+  assert!(direct.is_empty());
+}
+
+#[tokio::test]
+async fn default_export_name() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { default } from "./utils/my-config.ts";
+export { default as renamed } from "./settings.ts";
+"#,
+      ),
+      ("file:///utils/my-config.ts", None, "export default 42;"),
+      (
+        "file:///settings.ts",
+        None,
+        r#"/**
+ * The settings.
+ * @module settings
+ */
+
+export default "a";
+"#,
+      ),
+    ],
+  )
+  .await;
+  let parse = |default_export_name| {
+    let parser = DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        default_export_name,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    let names = |doc_nodes: Vec<crate::DocNode>| {
+      let mut names = doc_nodes
+        .into_iter()
+        .filter(|node| node.kind == DocNodeKind::Variable)
+        .map(|node| node.name)
+        .collect::<Vec<_>>();
+      names.sort();
+      names
+    };
+    let settings = ModuleSpecifier::parse("file:///settings.ts").unwrap();
+    (
+      names(parser.parse_with_reexports(&specifier).unwrap()),
+      names(parser.parse(&settings).unwrap()),
+    )
+  };
+
+  let (reexported, direct) = parse(crate::DefaultExportName::Default);
+  assert_eq!(reexported, vec!["default", "renamed"]);
+  assert_eq!(direct, vec!["default"]);
+
+  let (reexported, direct) = parse(crate::DefaultExportName::Module);
+  assert_eq!(reexported, vec!["myConfig", "renamed"]);
+  assert_eq!(direct, vec!["settings"]);
+}
+
+#[tokio::test]
+async fn member_order() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export class A {
+  b(): void {}
+  Aa(): void {}
+  get c(): number { return 1; }
+  static d(): void {}
+  a = 1;
+  static e = 2;
+  static get F(): number { return 1; }
+  constructor() {}
+}
+
+export interface I {
+  b(): void;
+  a: string;
+  new (): I;
+}
+"#,
+    )],
+  )
+  .await;
+  let parse = |member_order| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        member_order,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+
+  let entries = parse(None);
+  let class_def = entries[0].class_def.as_ref().unwrap();
+  assert_eq!(class_def.methods[0].name, "b");
+  assert_eq!(class_def.methods[0].group, None);
+
+  let entries = parse(Some(crate::MemberOrder::Source));
+  let class_def = entries[0].class_def.as_ref().unwrap();
+  assert_eq!(
+    class_def
+      .methods
+      .iter()
+      .map(|method| (method.name.as_str(), method.group.unwrap()))
+      .collect::<Vec<_>>(),
+    vec![
+      ("F", crate::MemberGroup::StaticAccessor),
+      ("d", crate::MemberGroup::StaticMethod),
+      ("c", crate::MemberGroup::Accessor),
+      ("b", crate::MemberGroup::Method),
+      ("Aa", crate::MemberGroup::Method),
+    ]
+  );
+  assert_eq!(
+    class_def
+      .properties
+      .iter()
+      .map(|property| property.name.as_str())
+      .collect::<Vec<_>>(),
+    vec!["e", "a"]
+  );
+  assert_eq!(
+    class_def.constructors[0].group,
+    Some(crate::MemberGroup::Constructor)
+  );
+  let interface_def = entries[1].interface_def.as_ref().unwrap();
+  assert_eq!(
+    interface_def
+      .methods
+      .iter()
+      .map(|method| (method.name.as_str(), method.group.unwrap()))
+      .collect::<Vec<_>>(),
+    vec![
+      ("new", crate::MemberGroup::Constructor),
+      ("b", crate::MemberGroup::Method),
+    ]
+  );
+
+  let entries = parse(Some(crate::MemberOrder::Alphabetical));
+  let json = serde_json::to_value(&entries[0]).unwrap();
+  let names = json["classDef"]["methods"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|method| method["name"].as_str().unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(names, vec!["F", "d", "c", "Aa", "b"]);
+  assert_eq!(json["classDef"]["methods"][0]["group"], "staticAccessor");
+}
+
+#[tokio::test]
+async fn hide_overload_implementations() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function a(b: string): string;
+export function a(b: number): number;
+export function a(b: string | number): string | number {
+  return b;
+}
+
+export function c(d: string): string {
+  return d;
+}
+
+export class A {
+  constructor(b: string);
+  constructor(b: string | number) {}
+  a(b: string): string;
+  a(b: string | number): string | number {
+    return b;
+  }
+  static a(b: string | number): void {}
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      hide_overload_implementations: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let doc = DocPrinter::new(&entries, false, false).to_string();
+  assert_contains!(doc, "function a(b: string): string");
+  assert_contains!(doc, "function a(b: number): number");
+  assert_contains!(doc, "function c(d: string): string");
+  assert_contains!(doc, "constructor(b: string)");
+  assert_contains!(doc, "a(b: string): string");
+  assert_contains!(doc, "static a(b: string | number): void");
+  assert!(!doc.contains("a(b: string | number): string | number"));
+  assert!(!doc.contains("constructor(b: string | number)"));
+}
+
+#[tokio::test]
+async fn overload_js_doc_fallback() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export function a(b: string): string;
+/** The a. */
+export function a(b: number): number;
+export function a(b: string | number): string | number {
+  return b;
+}
+
+export function c(d: string): string;
+export function c(d: number): number;
+/** The c. */
+export function c(d: string | number): string | number {
+  return d;
+}
+
+export class A {
+  /** The constructor. */
+  constructor(b: string);
+  constructor(b: number);
+  constructor(b: string | number) {}
+  a(b: string): string;
+  /** The method. */
+  a(b: string | number): string | number {
+    return b;
+  }
+  static a(b: string | number): void {}
+}
+"#,
+    )],
+  )
+  .await;
+  let parse = |overload_js_doc_fallback| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        overload_js_doc_fallback,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse(&specifier)
+    .unwrap()
+  };
+  let entries = parse(true);
+  let docs = |name: &str| {
+    entries
+      .iter()
+      .filter(|n| n.name == name)
+      .map(|n| n.js_doc.doc.clone())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(docs("a"), vec![Some("The a.".to_string()); 3]);
+  assert_eq!(docs("c"), vec![Some("The c.".to_string()); 3]);
+  let class_def = entries
+    .iter()
+    .find(|n| n.name == "A")
+    .unwrap()
+    .class_def
+    .as_ref()
+    .unwrap();
+  assert!(class_def
+    .constructors
+    .iter()
+    .all(|c| c.js_doc.doc.as_deref() == Some("The constructor.")));
+  let method_docs = class_def
+    .methods
+    .iter()
+    .map(|m| (m.is_static, m.js_doc.doc.as_deref()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    method_docs,
+    vec![
+      (false, Some("The method.")),
+      (false, Some("The method.")),
+      (true, None)
+    ]
+  );
+
+  let entries = parse(false);
+  let undocumented = entries
+    .iter()
+    .filter(|n| n.name == "a" && n.js_doc.is_empty())
+    .count();
+  assert_eq!(undocumented, 2);
+}
+
+#[tokio::test]
+async fn chainable_methods() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export class Builder<T> {
+  add(item: T): this {
+    return this;
+  }
+  clear(): Builder<T> {
+    return this;
+  }
+  build(): T[] {
+    return [];
+  }
+  static create(): Builder<string> {
+    return new Builder();
+  }
+}
+
+export interface Chain {
+  next(): Chain;
+  end(): void;
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      chainable_methods: true,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse(&specifier)
+  .unwrap();
+  let find = |name: &str| entries.iter().find(|n| n.name == name).unwrap();
+  let chainable = find("Builder")
+    .class_def
+    .as_ref()
+    .unwrap()
+    .methods
+    .iter()
+    .map(|m| (m.name.as_str(), m.chainable))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    chainable,
+    vec![
+      ("add", true),
+      ("clear", true),
+      ("build", false),
+      ("create", false)
+    ]
+  );
+  let chainable = find("Chain")
+    .interface_def
+    .as_ref()
+    .unwrap()
+    .methods
+    .iter()
+    .map(|m| (m.name.as_str(), m.chainable))
+    .collect::<Vec<_>>();
+  assert_eq!(chainable, vec![("next", true), ("end", false)]);
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert_contains!(output, "add(item: T): this (chainable)");
+  assert_contains!(output, "next(): Chain (chainable)");
+  assert!(!output.contains("build(): T[] (chainable)"));
+}
+
+#[tokio::test]
+async fn see_tag_resolution() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export class A {
+  /** @see A#c */
+  b(): void {}
+  c(): void {}
+}
+
+export namespace B {
+  /** @see C */
+  export const C = 1;
+  /** @see {@link A#b} */
+  export const D = 2;
+}
+
+/**
+ * @see B.C the constant
+ * @see https://deno.land/ the website
+ * @see Missing
+ */
+export function e() {}
+"#,
+    )],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  let function = entries.iter().find(|node| node.name == "e").unwrap();
+  assert_eq!(
+    serde_json::to_value(&function.js_doc).unwrap(),
+    json!({
+      "tags": [
+        { "kind": "see", "target": "B.C", "doc": "the constant" },
+        {
+          "kind": "see",
+          "target": "https://deno.land/",
+          "doc": "the website"
+        },
+        { "kind": "see", "target": "Missing" }
+      ]
+    })
+  );
+  let diagnostics = parser
+    .diagnostics()
+    .into_iter()
+    .map(|d| format!("{}:{} {:?}", d.location.line, d.location.col, d.kind))
+    .collect::<Vec<_>>();
+  assert_eq!(diagnostics, vec!["19:0 UnresolvedSeeTarget(\"Missing\")"]);
+  assert_eq!(parser.metrics().diagnostic_count, 1);
+}
+
+#[tokio::test]
+async fn strict_mode() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"interface Options {}
+
+/** @see Missing */
+export function a(options: Options) {}
+"#,
+    )],
+  )
+  .await;
+  let parse = |strict| {
+    DocParser::new_with_options(
+      &graph,
+      analyzer.as_capturing_parser(),
+      DocParserOptions {
+        strict: Some(strict),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse_with_reexports(&specifier)
+  };
+  let err = parse(StrictMode::All).unwrap_err();
+  assert_eq!(
+    err.to_string(),
+    r#"Strict mode failed with 2 diagnostics:
+  file:///mod.ts:1:1 [private-type-ref] Type is not exported, but referenced by an exported type.
+  file:///mod.ts:4:1 [unresolved-see-target] The @see target "Missing" could not be resolved."#
+  );
+  let err =
+    parse(StrictMode::Codes(vec!["private-type-ref".to_string()])).unwrap_err();
+  let DocError::Strict(diagnostics) = err else {
+    panic!("expected a strict mode error");
+  };
+  assert_eq!(diagnostics.len(), 1);
+  assert!(matches!(
+    diagnostics[0].kind,
+    DocDiagnosticKind::PrivateTypeRef
+  ));
+  assert!(parse(StrictMode::Codes(vec!["parse-error".to_string()])).is_ok());
+}
+
+#[tokio::test]
+async fn import_diagnostics() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"import { A } from "./missing.ts";
+import { B } from "bare";
+
+export const a = 1;
+"#,
+    )],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      import_diagnostics: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let entries = parser.parse(&specifier).unwrap();
+  assert_eq!(entries.len(), 1);
+  let diagnostics = parser
+    .diagnostics()
+    .into_iter()
+    .map(|d| match d.kind {
+      DocDiagnosticKind::UnresolvedImport { specifier, .. } => {
+        format!("{}:{} {}", d.location.line, d.location.col, specifier)
+      }
+      kind => panic!("unexpected diagnostic: {:?}", kind),
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(diagnostics, vec!["1:18 ./missing.ts", "2:18 bare"]);
+
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  parser.parse(&specifier).unwrap();
+  assert!(parser.diagnostics().is_empty());
+}
+
+#[tokio::test]
+async fn import_diagnostics_skip_unloadable_reexports() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"export * from "./missing.ts";
+export const a = 1;
+"#,
+    )],
+  )
+  .await;
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      import_diagnostics: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let entries = parser.parse_with_reexports(&specifier).unwrap();
+  assert_eq!(
+    entries.iter().map(|n| n.name.as_str()).collect::<Vec<_>>(),
+    vec!["a"]
+  );
+  assert_eq!(parser.diagnostics().len(), 1);
+}
+
+#[tokio::test]
+async fn recover_parse_errors() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export * as broken from "./broken.ts";
+export * from "./c.ts";
+export const a = 1;
+"#,
+      ),
+      ("file:///broken.ts", None, "export const b = ;"),
+      ("file:///c.ts", None, "export const c = 1;"),
+    ],
+  )
+  .await;
+  let parser =
+    DocParser::new(&graph, false, analyzer.as_capturing_parser()).unwrap();
+  assert!(parser.parse_with_reexports(&specifier).is_err());
+
+  let parser = DocParser::new_with_options(
+    &graph,
+    analyzer.as_capturing_parser(),
+    DocParserOptions {
+      recover_parse_errors: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  let entries = parser.parse_with_reexports(&specifier).unwrap();
+  let mut names = entries
+    .iter()
+    .map(|node| node.name.as_str())
+    .collect::<Vec<_>>();
+  names.sort();
+  assert_eq!(names, vec!["a", "c"]);
+  let diagnostics = parser.diagnostics();
+  assert_eq!(diagnostics.len(), 1);
+  assert_eq!(diagnostics[0].location.filename, "file:///broken.ts");
+  assert_eq!(diagnostics[0].location.line, 1);
+  assert!(matches!(
+    diagnostics[0].kind,
+    DocDiagnosticKind::ParseError {
+      media_type: MediaType::TypeScript,
+      ..
+    }
+  ));
+}
+
+mod serialization {
+  use crate::*;
+
+  json_test!(module_docs,
+  r#"/* a non-jsdoc comment */
+
+/**
+ * A leading JSDoc comment
+ * @module
+ */
+
+/** One associated with a class */
+export class A {}
+    "#;
+  [
+    {
+      "kind": "moduleDoc",
+      "name": "",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 3,
+        "col": 0,
+      },
+      "declarationKind": "export",
+      "jsDoc": {
+        "doc": "A leading JSDoc comment",
+        "tags": [{
+          "kind": "module"
+        }],
+      }
+    }, {
+      "kind": "class",
+      "name": "A",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 9,
+        "col": 0,
+      },
+      "declarationKind": "export",
+      "jsDoc": {
+        "doc": "One associated with a class",
+      },
+      "classDef": {
+        "isAbstract": false,
+        "constructors": [],
+        "properties": [],
+        "indexSignatures": [],
+        "methods": [],
+        "extends": null,
+        "implements": [],
+        "typeParams": [],
+        "superTypeParams": []
+      }
+    }
+  ]);
+
+  json_test!(module_docs_ignore,
+  r#"/* a non-jsdoc comment */
+
+/**
+ * A leading JSDoc comment
+ * @module
+ * @ignore
+ */
+
+/** One associated with a class */
+export class A {}
+    "#;
+  []);
+
+  json_test!(declare_namespace,
+    r#"
+/** Namespace JSdoc */
+declare namespace RootNs {
+    declare const a = "a";
+
+    /** Nested namespace JSDoc */
+    declare namespace NestedNs {
+      declare enum Foo {
+        a = 1,
+        b = 2,
+        c = 3,
+      }
+    }
+}
+    "#;
+    [{
+    "kind": "namespace",
+    "name": "RootNs",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 3,
+      "col": 0
+    },
+    "declarationKind": "declare",
+    "jsDoc": {
+      "doc": "Namespace JSdoc",
+    },
+    "namespaceDef": {
+      "elements": [
+        {
+          "kind": "variable",
+          "name": "a",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 4,
+            "col": 18
+          },
+          "declarationKind": "declare",
+          "variableDef": {
+            "tsType": {
+              "repr": "a",
+              "kind": "literal",
+              "literal": {
+                "kind": "string",
+                "string": "a"
+              }
+            },
+            "kind": "const"
+          }
+        },
+        {
+          "kind": "namespace",
+          "name": "NestedNs",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 7,
+            "col": 4
+          },
+          "jsDoc": {
+            "doc": "Nested namespace JSDoc",
+          },
+          "declarationKind": "declare",
+          "namespaceDef": {
+            "elements": [
+              {
+                "kind": "enum",
+                "name": "Foo",
+                "location": {
+                  "filename": "file:///test.ts",
+                  "line": 8,
+                  "col": 6
+                },
+                "declarationKind": "declare",
+                "enumDef": {
+                  "members": [
+                    {
+                      "name": "a",
+                      "init": {
+                        "repr": "1",
+                        "kind": "literal",
+                        "literal": {
+                          "kind": "number",
+                          "number": 1.0,
+                        }
+                      },
+                      "location": {
+                        "filename": "file:///test.ts",
+                        "line": 9,
+                        "col": 8,
+                      },
+                    },
+                    {
+                      "name": "b",
+                      "init": {
+                        "repr": "2",
+                        "kind": "literal",
+                        "literal": {
+                          "kind": "number",
+                          "number": 2.0,
+                        }
+                      },
+                      "location": {
+                        "filename": "file:///test.ts",
+                        "line": 10,
+                        "col": 8,
+                      },
+                    },
+                    {
+                      "name": "c",
+                      "init": {
+                        "repr": "3",
+                        "kind": "literal",
+                        "literal": {
+                          "kind": "number",
+                          "number": 3.0,
+                        }
+                      },
+                      "location": {
+                        "filename": "file:///test.ts",
+                        "line": 11,
+                        "col": 8,
+                      },
+                    }
+                  ]
+                }
+              }
+            ]
+          }
+        }
+      ]
+    }
+  }]);
+
+  json_test!(declare_namespace_ignore,
+    r#"
+/** Namespace JSdoc */
+declare namespace RootNs {
+    declare const a = "a";
+
+    /** Nested namespace JSDoc
+     * @ignore
+     */
+    declare namespace NestedNs {
+      declare enum Foo {
+        a = 1,
+        b = 2,
+        c = 3,
+      }
+    }
+}
+    "#;
+    [{
+    "kind": "namespace",
+    "name": "RootNs",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 3,
+      "col": 0
+    },
+    "declarationKind": "declare",
+    "jsDoc": {
+      "doc": "Namespace JSdoc",
+    },
+    "namespaceDef": {
+      "elements": [
+        {
+          "kind": "variable",
+          "name": "a",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 4,
+            "col": 18
+          },
+          "declarationKind": "declare",
+          "variableDef": {
+            "tsType": {
+              "repr": "a",
+              "kind": "literal",
+              "literal": {
+                "kind": "string",
+                "string": "a"
+              }
+            },
+            "kind": "const"
+          }
+        }
+      ]
+    }
+  }]);
+
+  json_test!(structured_jsdoc,
+  r#"
+/** Class doc */
+export class A {
+  /** @private */
+  p = false;
+
+  /**
+   * Some leading documentation here.
+   *
+   * @param {string} name some comment
+   * @param {string} a    some other comment that
+   *                      spans two lines
+   * @param {number} b    a number
+   */
+  constructor(name, a, b) {}
+
+  /**
+   * @returns {Promise<void>}
+   */
+  a() {}
+}
+  "#;
+  [{
+    "kind": "class",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 3,
+      "col": 0,
+    },
+    "jsDoc": {
+      "doc": "Class doc",
+    },
+    "declarationKind": "export",
+    "classDef": {
+      "isAbstract": false,
+      "constructors": [
+        {
+          "jsDoc": {
+            "doc": "Some leading documentation here.\n",
+            "tags": [
+              {
+                "kind": "param",
+                "name": "name",
+                "type": "string",
+                "doc": "some comment",
+              },
+              {
+                "kind": "param",
+                "name": "a",
+                "type": "string",
+                "doc": "some other comment that\nspans two lines",
+              },
+              {
+                "kind": "param",
+                "name": "b",
+                "type": "number",
+                "doc": "a number",
+              },
+            ],
+          },
+          "accessibility": null,
+          "hasBody": true,
+          "name": "constructor",
+          "params": [
+            {
+              "kind": "identifier",
+              "name": "name",
+              "optional": false,
+              "tsType": null,
+            },
+            {
+              "kind": "identifier",
+              "name": "a",
+              "optional": false,
+              "tsType": null,
+            },
+            {
+              "kind": "identifier",
+              "name": "b",
+              "optional": false,
+              "tsType": null,
+            }
+          ],
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 15,
+            "col": 2,
+          }
+        }
+      ],
+      "properties": [
+        {
+          "jsDoc": {
+            "tags": [
+              {
+                "kind": "private"
+              }
+            ]
+          },
+          "tsType": {
+            "repr": "boolean",
+            "kind": "keyword",
+            "keyword": "boolean",
+          },
+          "readonly": false,
+          "accessibility": null,
+          "optional": false,
+          "isAbstract": false,
+          "isStatic": false,
+          "name": "p",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 5,
+            "col": 2,
+          }
+        }
+      ],
+      "indexSignatures": [],
+      "methods": [
+        {
+          "jsDoc": {
+            "tags": [
+              {
+                "kind": "return",
+                "type": "Promise<void>"
+              }
+            ]
+          },
+          "accessibility": null,
+          "optional": false,
+          "isAbstract": false,
+          "isStatic": false,
+          "name": "a",
+          "kind": "method",
+          "functionDef": {
+            "params": [],
+            "returnType": null,
+            "hasBody": true,
+            "isAsync": false,
+            "isGenerator": false,
+            "typeParams": [],
+          },
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 20,
+            "col": 2,
+          }
+        }
+      ],
+      "extends": null,
+      "implements": [],
+      "typeParams": [],
+      "superTypeParams": [],
+    }
+  }]);
+
+  json_test!(export_class,
+   r#"
+/** Class doc */
+export class Foobar extends Fizz implements Buzz, Aldrin {
+    private private1?: boolean;
+    protected protected1: number;
+    public public1: boolean;
+    public2: number;
+    override public3: string;
+    m = new Map<string, string>();
+    s = "foo";
+    a = [1, "foo"];
+    fn = (a: string): void => {};
+
+    /** Constructor js doc */
+    constructor(name: string, private private2: number, protected protected2: number) {}
+
+    /** Async foo method */
+    async foo(): Promise<void> {
+        //
+    }
+
+    /** Sync bar method */
+    bar?(): void {
+        //
+    }
+
+    override s() {}
+}
+  "#;
+  [{
     "kind": "class",
     "name": "Foobar",
     "location": {
@@ -2540,6 +7058,9 @@ export const tpl3 = `Value: ${num}`;
         "typeLiteral":{
           "methods":[{
             "name":"get",
+            "jsDoc": {
+              "doc": "get doc",
+            },
             "kind": "method",
             "params":[
               {
@@ -2573,6 +7094,9 @@ export const tpl3 = `Value: ${num}`;
             "typeParams":[]
           }, {
             "name":"set",
+            "jsDoc": {
+              "doc": "set doc",
+            },
             "kind": "method",
             "params":[
               {
@@ -4242,288 +8766,678 @@ export namespace RootNs.OtherNs {
               }
             ]
           }
-        },
-        {
-          "kind": "namespace",
-          "name": "OtherNs",
+        },
+        {
+          "kind": "namespace",
+          "name": "OtherNs",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 16,
+            "col": 7
+          },
+          "declarationKind": "export",
+          "namespaceDef": {
+            "elements": [
+              {
+                "kind": "class",
+                "name": "Other",
+                "location": {
+                  "filename": "file:///test.ts",
+                  "line": 17,
+                  "col": 2
+                },
+                "declarationKind": "export",
+                "classDef": {
+                  "isAbstract": false,
+                  "constructors": [],
+                  "properties": [],
+                  "indexSignatures": [],
+                  "methods": [],
+                  "extends": null,
+                  "implements": [],
+                  "typeParams": [],
+                  "superTypeParams": [],
+                }
+              }
+            ]
+          }
+        }
+      ]
+    }
+  }]);
+
+  json_test!(export_namespace_enum_same_name,
+    r#"
+export namespace RootNs {
+  export namespace NestedNs {
+    export enum Foo {
+    }
+  }
+
+  export enum Foo {
+  }
+}
+    "#;
+    [{
+    "kind": "namespace",
+    "name": "RootNs",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "namespaceDef": {
+      "elements": [
+        {
+          "kind": "namespace",
+          "name": "NestedNs",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 3,
+            "col": 2
+          },
+          "declarationKind": "export",
+          "namespaceDef": {
+            "elements": [
+              {
+                "kind": "enum",
+                "name": "Foo",
+                "location": {
+                  "filename": "file:///test.ts",
+                  "line": 4,
+                  "col": 4
+                },
+                "declarationKind": "export",
+                "enumDef": {
+                  "members": []
+                }
+              }
+            ]
+          }
+        },
+        {
+          "kind": "enum",
+          "name": "Foo",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 8,
+            "col": 2
+          },
+          "declarationKind": "export",
+          "enumDef": {
+            "members": []
+          }
+        }
+      ]
+    }
+  }]);
+
+  json_test!(export_declaration_merged_namespace,
+    r#"
+namespace Namespace1 {
+  export class Test1 {}
+}
+namespace Namespace1 {
+  export class Test2 {}
+}
+
+export { Namespace1 };
+"#;
+    [{
+      "kind": "namespace",
+      "name": "Namespace1",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 2,
+        "col": 0,
+      },
+      "declarationKind": "export",
+      "namespaceDef": {
+        "elements": [{
+          "kind": "class",
+          "name": "Test1",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 3,
+            "col": 2,
+          },
+          "declarationKind": "export",
+          "classDef": {
+            "isAbstract": false,
+            "constructors": [],
+            "properties": [],
+            "indexSignatures": [],
+            "methods": [],
+            "extends": null,
+            "implements": [],
+            "typeParams": [],
+            "superTypeParams": []
+          }
+        }, {
+          "kind": "class",
+          "name": "Test2",
           "location": {
             "filename": "file:///test.ts",
-            "line": 16,
-            "col": 7
+            "line": 6,
+            "col": 2,
           },
           "declarationKind": "export",
-          "namespaceDef": {
-            "elements": [
-              {
-                "kind": "class",
-                "name": "Other",
-                "location": {
-                  "filename": "file:///test.ts",
-                  "line": 17,
-                  "col": 2
-                },
-                "declarationKind": "export",
-                "classDef": {
-                  "isAbstract": false,
-                  "constructors": [],
-                  "properties": [],
-                  "indexSignatures": [],
-                  "methods": [],
-                  "extends": null,
-                  "implements": [],
-                  "typeParams": [],
-                  "superTypeParams": [],
-                }
-              }
-            ]
+          "classDef": {
+            "isAbstract": false,
+            "constructors": [],
+            "properties": [],
+            "indexSignatures": [],
+            "methods": [],
+            "extends": null,
+            "implements": [],
+            "typeParams": [],
+            "superTypeParams": []
           }
-        }
-      ]
+        }]
+      }
+    }]
+  );
+
+  json_test!(exports_declared_earlier,
+      r#"
+const hello = "world";
+function say(words: string): void { }
+function foo(): void { }
+export { hello, say, foo as bar };
+    "#;
+  [
+    {
+      "kind": "variable",
+      "name": "hello",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 2,
+        "col": 6
+      },
+      "declarationKind": "export",
+      "variableDef": {
+        "tsType": {
+          "repr": "world",
+          "kind": "literal",
+          "literal": {
+            "kind": "string",
+            "string": "world"
+          }
+        },
+        "kind": "const"
+      }
+    },
+    {
+      "kind": "function",
+      "name": "say",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 3,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "functionDef": {
+        "params": [
+          {
+            "kind": "identifier",
+            "name": "words",
+            "optional": false,
+            "tsType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
+            }
+          }
+        ],
+        "returnType": {
+          "repr": "void",
+          "kind": "keyword",
+          "keyword": "void"
+        },
+        "hasBody": true,
+        "isAsync": false,
+        "isGenerator": false,
+        "typeParams": []
+      }
+    },
+    {
+      "kind": "function",
+      "name": "bar",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 4,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "functionDef": {
+        "params": [],
+        "returnType": {
+          "repr": "void",
+          "kind": "keyword",
+          "keyword": "void"
+        },
+        "hasBody": true,
+        "isAsync": false,
+        "isGenerator": false,
+        "typeParams": []
+      }
     }
-  }]);
+  ]
+    );
 
-  json_test!(export_namespace_enum_same_name,
+  json_test!(non_implemented_renamed_exports_declared_earlier,
     r#"
-export namespace RootNs {
-  export namespace NestedNs {
-    export enum Foo {
-    }
-  }
+  declare function foo(): void;
+  export { foo as bar };
+    "#;
+    [
+      {
+        "kind": "function",
+        "name": "bar",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 2
+        },
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void"
+          },
+          "hasBody": false,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      }
+    ]
+  );
 
-  export enum Foo {
-  }
-}
+  json_test!(no_ambient_in_module,
+    r#"
+declare function foo(): number;
+export function bar() {};
+    "#;
+    [
+      {
+        "kind": "function",
+        "name": "bar",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 3,
+          "col": 0
+        },
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": null,
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      }
+    ]
+  );
+
+  json_test!(default_exports_declared_earlier,
+    r#"
+function foo(): void {}
+export default foo;
+    "#;
+    [
+      {
+        "kind": "function",
+        "name": "default",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 0
+        },
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void"
+          },
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      }
+    ]
+  );
+
+  json_test!(reexport_existing_export,
+    r#"
+export function foo(): void {}
+export { foo as bar };
     "#;
-    [{
-    "kind": "namespace",
-    "name": "RootNs",
-    "location": {
-      "filename": "file:///test.ts",
-      "line": 2,
-      "col": 0
-    },
-    "declarationKind": "export",
-    "namespaceDef": {
-      "elements": [
-        {
-          "kind": "namespace",
-          "name": "NestedNs",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 3,
-            "col": 2
+    [
+      {
+        "kind": "function",
+        "name": "foo",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 0
+        },
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void"
           },
-          "declarationKind": "export",
-          "namespaceDef": {
-            "elements": [
-              {
-                "kind": "enum",
-                "name": "Foo",
-                "location": {
-                  "filename": "file:///test.ts",
-                  "line": 4,
-                  "col": 4
-                },
-                "declarationKind": "export",
-                "enumDef": {
-                  "members": []
-                }
-              }
-            ]
-          }
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        }
+      },
+      {
+        "kind": "function",
+        "name": "bar",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 0
         },
-        {
-          "kind": "enum",
-          "name": "Foo",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 8,
-            "col": 2
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "void",
+            "kind": "keyword",
+            "keyword": "void"
           },
-          "declarationKind": "export",
-          "enumDef": {
-            "members": []
-          }
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
         }
-      ]
-    }
-  }]);
+      }
+    ]
+  );
 
-  json_test!(export_declaration_merged_namespace,
+  json_test!(optional_return_type,
     r#"
-namespace Namespace1 {
-  export class Test1 {}
-}
-namespace Namespace1 {
-  export class Test2 {}
-}
-
-export { Namespace1 };
-"#;
+  export function foo(a: number) {
+    return a;
+  }
+    "#;
     [{
-      "kind": "namespace",
-      "name": "Namespace1",
+      "kind": "function",
+      "name": "foo",
       "location": {
         "filename": "file:///test.ts",
         "line": 2,
-        "col": 0,
+        "col": 2
       },
       "declarationKind": "export",
-      "namespaceDef": {
-        "elements": [{
-          "kind": "class",
-          "name": "Test1",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 3,
-            "col": 2,
-          },
-          "declarationKind": "export",
-          "classDef": {
-            "isAbstract": false,
-            "constructors": [],
-            "properties": [],
-            "indexSignatures": [],
-            "methods": [],
-            "extends": null,
-            "implements": [],
-            "typeParams": [],
-            "superTypeParams": []
-          }
-        }, {
-          "kind": "class",
-          "name": "Test2",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 6,
-            "col": 2,
-          },
-          "declarationKind": "export",
-          "classDef": {
-            "isAbstract": false,
-            "constructors": [],
-            "properties": [],
-            "indexSignatures": [],
-            "methods": [],
-            "extends": null,
-            "implements": [],
-            "typeParams": [],
-            "superTypeParams": []
-          }
-        }]
+      "functionDef": {
+        "params": [
+            {
+              "name": "a",
+              "kind": "identifier",
+              "optional": false,
+              "tsType": {
+                "keyword": "number",
+                "kind": "keyword",
+                "repr": "number",
+              },
+            }
+        ],
+        "typeParams": [],
+        "returnType": null,
+        "hasBody": true,
+        "isAsync": false,
+        "isGenerator": false
       }
     }]
   );
 
-  json_test!(exports_declared_earlier,
-      r#"
-const hello = "world";
-function say(words: string): void { }
-function foo(): void { }
-export { hello, say, foo as bar };
+  json_test!(ts_lit_types,
+    r#"
+export type boolLit = false;
+export type strLit = "text";
+export type tplLit = `text`;
+export type tplLitArg = `test${number}`;
+export type numLit = 5;
     "#;
   [
     {
-      "kind": "variable",
-      "name": "hello",
+      "kind": "typeAlias",
+      "name": "boolLit",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 2,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "typeAliasDef": {
+        "tsType": {
+          "repr": "false",
+          "kind": "literal",
+          "literal": {
+            "kind": "boolean",
+            "boolean": false
+          }
+        },
+        "typeParams": []
+      }
+    }, {
+      "kind": "typeAlias",
+      "name": "strLit",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 3,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "typeAliasDef": {
+        "tsType": {
+          "repr": "text",
+          "kind": "literal",
+          "literal": {
+            "kind": "string",
+            "string": "text"
+          }
+        },
+        "typeParams": []
+      }
+    }, {
+      "kind": "typeAlias",
+      "name": "tplLit",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 4,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "typeAliasDef": {
+        "tsType": {
+          "repr": "text",
+          "kind": "literal",
+          "literal": {
+            "kind": "template",
+            "tsTypes": [
+              {
+                "repr": "text",
+                "kind": "literal",
+                "literal": {
+                  "kind": "string",
+                  "string": "text"
+                }
+              }
+            ]
+          }
+        },
+        "typeParams": []
+      }
+    }, {
+      "kind": "typeAlias",
+      "name": "tplLitArg",
       "location": {
         "filename": "file:///test.ts",
-        "line": 2,
-        "col": 6
+        "line": 5,
+        "col": 0,
       },
       "declarationKind": "export",
-      "variableDef": {
+      "typeAliasDef": {
         "tsType": {
-          "repr": "world",
+          "repr": "test${number}",
           "kind": "literal",
           "literal": {
-            "kind": "string",
-            "string": "world"
+            "kind": "template",
+            "tsTypes": [
+              {
+                "repr": "test",
+                "kind": "literal",
+                "literal": {
+                  "kind": "string",
+                  "string": "test",
+                }
+              }, {
+                "repr": "number",
+                "kind": "keyword",
+                "keyword": "number"
+              }, {
+                "repr": "",
+                "kind": "literal",
+                "literal": {
+                  "kind": "string",
+                  "string": ""
+                }
+              }
+            ]
           }
         },
-        "kind": "const"
+        "typeParams": [],
       }
-    },
-    {
-      "kind": "function",
-      "name": "say",
+    }, {
+      "kind": "typeAlias",
+      "name": "numLit",
       "location": {
         "filename": "file:///test.ts",
-        "line": 3,
+        "line": 6,
         "col": 0
       },
       "declarationKind": "export",
-      "functionDef": {
-        "params": [
-          {
-            "kind": "identifier",
-            "name": "words",
-            "optional": false,
-            "tsType": {
-              "repr": "string",
-              "kind": "keyword",
-              "keyword": "string"
-            }
+      "typeAliasDef": {
+        "tsType": {
+          "repr": "5",
+          "kind": "literal",
+          "literal": {
+            "kind": "number",
+            "number": 5.0
           }
-        ],
-        "returnType": {
-          "repr": "void",
-          "kind": "keyword",
-          "keyword": "void"
         },
-        "hasBody": true,
-        "isAsync": false,
-        "isGenerator": false,
         "typeParams": []
       }
-    },
-    {
-      "kind": "function",
-      "name": "bar",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 4,
-        "col": 0
-      },
-      "declarationKind": "export",
-      "functionDef": {
-        "params": [],
-        "returnType": {
-          "repr": "void",
-          "kind": "keyword",
-          "keyword": "void"
+    }
+  ]);
+
+  json_test!(export_private,
+    r#"
+const foo: string = "foo";
+export { foo };
+    "#,
+    private;
+    [
+      {
+        "kind": "variable",
+        "name": "foo",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 6
         },
-        "hasBody": true,
-        "isAsync": false,
-        "isGenerator": false,
-        "typeParams": []
+        "declarationKind": "export",
+        "variableDef": {
+          "tsType": {
+            "repr": "string",
+            "kind": "keyword",
+            "keyword": "string"
+          },
+          "kind": "const"
+        }
       }
-    }
-  ]
-    );
+    ]
+  );
 
-  json_test!(non_implemented_renamed_exports_declared_earlier,
+  json_test!(ts_type_predicate_1,
     r#"
-  declare function foo(): void;
-  export { foo as bar };
+export function foo(bar: A | B): bar is A {}
     "#;
     [
       {
         "kind": "function",
-        "name": "bar",
+        "name": "foo",
         "location": {
           "filename": "file:///test.ts",
           "line": 2,
-          "col": 2
+          "col": 0
         },
         "declarationKind": "export",
         "functionDef": {
-          "params": [],
+          "params": [
+            {
+              "kind": "identifier",
+              "name": "bar",
+              "optional": false,
+              "tsType": {
+                "repr": "",
+                "kind": "union",
+                "union": [
+                  {
+                    "repr": "A",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": null,
+                      "typeName": "A"
+                    }
+                  },
+                  {
+                    "repr": "B",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": null,
+                      "typeName": "B"
+                    }
+                  }
+                ]
+              }
+            }
+          ],
           "returnType": {
-            "repr": "void",
-            "kind": "keyword",
-            "keyword": "void"
+            "repr": "bar is A",
+            "kind": "typePredicate",
+            "typePredicate": {
+              "asserts": false,
+              "param": {
+                "type": "identifier",
+                "name": "bar"
+              },
+              "type": {
+                "repr": "A",
+                "kind": "typeRef",
+                "typeRef": {
+                  "typeParams": null,
+                  "typeName": "A"
+                }
+              }
+            }
           },
+          "hasBody": true,
           "isAsync": false,
           "isGenerator": false,
           "typeParams": []
@@ -4532,24 +9446,69 @@ export { hello, say, foo as bar };
     ]
   );
 
-  json_test!(no_ambient_in_module,
+  json_test!(ts_type_predicate_2,
     r#"
-declare function foo(): number;
-export function bar() {};
+export function foo(bar: A | B): asserts bar is B {}
     "#;
     [
       {
         "kind": "function",
-        "name": "bar",
+        "name": "foo",
         "location": {
           "filename": "file:///test.ts",
-          "line": 3,
+          "line": 2,
           "col": 0
         },
         "declarationKind": "export",
         "functionDef": {
-          "params": [],
-          "returnType": null,
+          "params": [
+            {
+              "kind": "identifier",
+              "name": "bar",
+              "optional": false,
+              "tsType": {
+                "repr": "",
+                "kind": "union",
+                "union": [
+                  {
+                    "repr": "A",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": null,
+                      "typeName": "A"
+                    }
+                  },
+                  {
+                    "repr": "B",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": null,
+                      "typeName": "B"
+                    }
+                  }
+                ]
+              }
+            }
+          ],
+          "returnType": {
+            "repr": "asserts bar is B",
+            "kind": "typePredicate",
+            "typePredicate": {
+              "asserts": true,
+              "param": {
+                "type": "identifier",
+                "name": "bar"
+              },
+              "type": {
+                "repr": "B",
+                "kind": "typeRef",
+                "typeRef": {
+                  "typeParams": null,
+                  "typeName": "B"
+                }
+              }
+            }
+          },
           "hasBody": true,
           "isAsync": false,
           "isGenerator": false,
@@ -4559,41 +9518,79 @@ export function bar() {};
     ]
   );
 
-  json_test!(default_exports_declared_earlier,
+  json_test!(ts_type_predicate_3,
     r#"
-function foo(): void {}
-export default foo;
+export class C {
+  isSomething(): this is Something {}
+}
     "#;
     [
       {
-        "kind": "function",
-        "name": "default",
+        "kind": "class",
+        "name": "C",
         "location": {
           "filename": "file:///test.ts",
           "line": 2,
           "col": 0
         },
         "declarationKind": "export",
-        "functionDef": {
-          "params": [],
-          "returnType": {
-            "repr": "void",
-            "kind": "keyword",
-            "keyword": "void"
-          },
-          "hasBody": true,
-          "isAsync": false,
-          "isGenerator": false,
-          "typeParams": []
+        "classDef": {
+          "isAbstract": false,
+          "constructors": [],
+          "properties": [],
+          "indexSignatures": [],
+          "methods": [
+            {
+              "accessibility": null,
+              "optional": false,
+              "isAbstract": false,
+              "isStatic": false,
+              "name": "isSomething",
+              "kind": "method",
+              "functionDef": {
+                "params": [],
+                "returnType": {
+                  "repr": "this is Something",
+                  "kind": "typePredicate",
+                  "typePredicate": {
+                    "asserts": false,
+                    "param": {
+                      "type": "this"
+                    },
+                    "type": {
+                      "repr": "Something",
+                      "kind": "typeRef",
+                      "typeRef": {
+                        "typeParams": null,
+                        "typeName": "Something",
+                      },
+                    },
+                  },
+                },
+                "hasBody": true,
+                "isAsync": false,
+                "isGenerator": false,
+                "typeParams": [],
+              },
+              "location": {
+                "filename": "file:///test.ts",
+                "line": 3,
+                "col": 2
+              }
+            }
+          ],
+          "extends": null,
+          "implements": [],
+          "typeParams": [],
+          "superTypeParams": []
         }
       }
     ]
   );
 
-  json_test!(reexport_existing_export,
+  json_test!(ts_type_assertion,
     r#"
-export function foo(): void {}
-export { foo as bar };
+export function foo(bar: any): asserts bar {}
     "#;
     [
       {
@@ -4606,1271 +9603,1706 @@ export { foo as bar };
         },
         "declarationKind": "export",
         "functionDef": {
-          "params": [],
+          "params": [
+            {
+              "kind": "identifier",
+              "name": "bar",
+              "optional": false,
+              "tsType": {
+                "repr": "any",
+                "kind": "keyword",
+                "keyword": "any"
+              }
+            }
+          ],
           "returnType": {
-            "repr": "void",
-            "kind": "keyword",
-            "keyword": "void"
+            "repr": "asserts bar",
+            "kind": "typePredicate",
+            "typePredicate": {
+              "asserts": true,
+              "param": {
+                "type": "identifier",
+                "name": "bar"
+              },
+              "type": null,
+            }
           },
           "hasBody": true,
           "isAsync": false,
           "isGenerator": false,
           "typeParams": []
         }
+      }
+    ]
+  );
+
+  json_test!(infer_types,
+  r#"export type Flatten<T> = T extends Array<infer U> ? U : T;"#;
+  [
+    {
+      "kind": "typeAlias",
+      "name": "Flatten",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 1,
+        "col": 0
+      },
+      "declarationKind": "export",
+      "typeAliasDef": {
+        "tsType": {
+          "repr": "",
+          "kind": "conditional",
+          "conditionalType": {
+            "checkType": {
+              "repr": "T",
+              "kind": "typeRef",
+              "typeRef": {
+                "typeParams": null,
+                "typeName": "T"
+              }
+            },
+            "extendsType": {
+              "repr": "Array",
+              "kind": "typeRef",
+              "typeRef": {
+                "typeParams": [
+                  {
+                    "repr": "",
+                    "kind": "infer",
+                    "infer": {
+                      "typeParam": {
+                        "name": "U"
+                      }
+                    }
+                  }
+                ],
+                "typeName": "Array"
+              }
+            },
+            "trueType": {
+              "repr": "U",
+              "kind": "typeRef",
+              "typeRef": {
+                "typeParams": null,
+                "typeName": "U"
+              }
+            },
+            "falseType": {
+              "repr": "T",
+              "kind": "typeRef",
+              "typeRef": {
+                "typeParams": null,
+                "typeName": "T",
+              }
+            }
+          }
+        },
+        "typeParams": [
+          {
+            "name": "T"
+          }
+        ]
+      }
+    }
+  ]);
+
+  json_test!(infer_object_literal,
+    r#"
+    const s: symbol = Symbol.for("s");
+    const t: symbol = Symbol.for("t");
+
+    export const a = {
+      a: "a",
+      b: new Map<string, number>(),
+      c: { d: "d" },
+      d(e: string): void {},
+      f: (g: string): void => {},
+      get h(): string {
+        return "h";
+      },
+      set h(value: string) {
+
       },
+      [s]: [1, 2, 3, "a"],
+      [t](u: string): void {},
+    };"#;
+    [
       {
-        "kind": "function",
-        "name": "bar",
+        "kind": "variable",
+        "name": "a",
         "location": {
           "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0
+          "line": 5,
+          "col": 17
         },
         "declarationKind": "export",
-        "functionDef": {
-          "params": [],
-          "returnType": {
-            "repr": "void",
-            "kind": "keyword",
-            "keyword": "void"
+        "variableDef": {
+          "tsType": {
+            "repr": "",
+            "kind": "typeLiteral",
+            "typeLiteral": {
+              "methods": [
+                {
+                  "name": "d",
+                  "kind": "method",
+                  "params": [
+                    {
+                      "kind": "identifier",
+                      "name": "e",
+                      "optional": false,
+                      "tsType": {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      }
+                    }
+                  ],
+                  "optional": false,
+                  "returnType": {
+                    "repr": "void",
+                    "kind": "keyword",
+                    "keyword": "void"
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "h",
+                  "kind": "getter",
+                  "params": [],
+                  "optional": false,
+                  "returnType": {
+                    "repr": "string",
+                    "kind": "keyword",
+                    "keyword": "string"
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "h",
+                  "kind": "setter",
+                  "params": [
+                    {
+                      "kind": "identifier",
+                      "name": "value",
+                      "optional": false,
+                      "tsType": {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      }
+                    }
+                  ],
+                  "optional": false,
+                  "returnType": null,
+                  "typeParams": []
+                },
+                {
+                  "name": "[t]",
+                  "kind": "method",
+                  "params": [
+                    {
+                      "kind": "identifier",
+                      "name": "u",
+                      "optional": false,
+                      "tsType": {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      }
+                    }
+                  ],
+                  "computed": true,
+                  "optional": false,
+                  "returnType": {
+                    "repr": "void",
+                    "kind": "keyword",
+                    "keyword": "void"
+                  },
+                  "typeParams": []
+                }
+              ],
+              "properties": [
+                {
+                  "name": "a",
+                  "params": [],
+                  "computed": false,
+                  "optional": false,
+                  "tsType": {
+                    "repr": "string",
+                    "kind": "keyword",
+                    "keyword": "string"
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "b",
+                  "params": [],
+                  "computed": false,
+                  "optional": false,
+                  "tsType": {
+                    "repr": "Map",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": [
+                        {
+                          "repr": "string",
+                          "kind": "keyword",
+                          "keyword": "string"
+                        },
+                        {
+                          "repr": "number",
+                          "kind": "keyword",
+                          "keyword": "number"
+                        }
+                      ],
+                      "typeName": "Map"
+                    }
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "c",
+                  "params": [],
+                  "computed": false,
+                  "optional": false,
+                  "tsType": {
+                    "repr": "",
+                    "kind": "typeLiteral",
+                    "typeLiteral": {
+                      "methods": [],
+                      "properties": [
+                        {
+                          "name": "d",
+                          "params": [],
+                          "computed": false,
+                          "optional": false,
+                          "tsType": {
+                            "repr": "string",
+                            "kind": "keyword",
+                            "keyword": "string"
+                          },
+                          "typeParams": []
+                        }
+                      ],
+                      "callSignatures": [],
+                      "indexSignatures": []
+                    }
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "f",
+                  "params": [],
+                  "computed": false,
+                  "optional": false,
+                  "tsType": {
+                    "repr": "",
+                    "kind": "fnOrConstructor",
+                    "fnOrConstructor": {
+                      "constructor": false,
+                      "tsType": {
+                        "repr": "void",
+                        "kind": "keyword",
+                        "keyword": "void"
+                      },
+                      "params": [
+                        {
+                          "kind": "identifier",
+                          "name": "g",
+                          "optional": false,
+                          "tsType": {
+                            "repr": "string",
+                            "kind": "keyword",
+                            "keyword": "string"
+                          }
+                        }
+                      ],
+                      "typeParams": []
+                    }
+                  },
+                  "typeParams": []
+                },
+                {
+                  "name": "[s]",
+                  "params": [],
+                  "computed": true,
+                  "optional": false,
+                  "tsType": {
+                    "repr": "",
+                    "kind": "array",
+                    "array": {
+                      "repr": "",
+                      "kind": "union",
+                      "union": [
+                        {
+                          "repr": "number",
+                          "kind": "keyword",
+                          "keyword": "number"
+                        },
+                        {
+                          "repr": "string",
+                          "kind": "keyword",
+                          "keyword": "string"
+                        }
+                      ]
+                    }
+                  },
+                  "typeParams": []
+                }
+              ],
+              "callSignatures": [],
+              "indexSignatures": []
+            }
           },
-          "hasBody": true,
-          "isAsync": false,
-          "isGenerator": false,
-          "typeParams": []
+          "kind": "const"
         }
       }
     ]
   );
 
-  json_test!(optional_return_type,
-    r#"
-  export function foo(a: number) {
-    return a;
-  }
-    "#;
-    [{
-      "kind": "function",
-      "name": "foo",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 2,
-        "col": 2
-      },
-      "declarationKind": "export",
-      "functionDef": {
-        "params": [
-            {
-              "name": "a",
-              "kind": "identifier",
-              "optional": false,
-              "tsType": {
-                "keyword": "number",
-                "kind": "keyword",
-                "repr": "number",
-              },
-            }
-        ],
-        "typeParams": [],
-        "returnType": null,
-        "hasBody": true,
-        "isAsync": false,
-        "isGenerator": false
-      }
-    }]
-  );
-
-  json_test!(ts_lit_types,
-    r#"
-export type boolLit = false;
-export type strLit = "text";
-export type tplLit = `text`;
-export type tplLitArg = `test${number}`;
-export type numLit = 5;
-    "#;
-  [
-    {
-      "kind": "typeAlias",
-      "name": "boolLit",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 2,
-        "col": 0
-      },
-      "declarationKind": "export",
-      "typeAliasDef": {
-        "tsType": {
-          "repr": "false",
-          "kind": "literal",
-          "literal": {
-            "kind": "boolean",
-            "boolean": false
-          }
-        },
-        "typeParams": []
-      }
-    }, {
-      "kind": "typeAlias",
-      "name": "strLit",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 3,
-        "col": 0
-      },
-      "declarationKind": "export",
-      "typeAliasDef": {
-        "tsType": {
-          "repr": "text",
-          "kind": "literal",
-          "literal": {
-            "kind": "string",
-            "string": "text"
-          }
-        },
-        "typeParams": []
-      }
-    }, {
+  json_test!(mapped_types,
+  r#"
+export type MappedTypeWithNewProperties<Type> = {
+  readonly [Properties in keyof Type as NewKeyType]?: Type[Properties];
+};
+"#;
+  [
+    {
       "kind": "typeAlias",
-      "name": "tplLit",
+      "name": "MappedTypeWithNewProperties",
       "location": {
         "filename": "file:///test.ts",
-        "line": 4,
-        "col": 0
+        "line": 2,
+        "col": 0,
       },
       "declarationKind": "export",
       "typeAliasDef": {
         "tsType": {
-          "repr": "text",
-          "kind": "literal",
-          "literal": {
-            "kind": "template",
-            "tsTypes": [
-              {
-                "repr": "text",
-                "kind": "literal",
-                "literal": {
-                  "kind": "string",
-                  "string": "text"
+          "repr": "",
+          "kind": "mapped",
+          "mappedType": {
+            "readonly": true,
+            "typeParam": {
+              "name": "Properties",
+              "constraint": {
+                "repr": "",
+                "kind": "typeOperator",
+                "typeOperator": {
+                  "operator": "keyof",
+                  "tsType": {
+                    "repr": "Type",
+                    "kind": "typeRef",
+                    "typeRef": {
+                      "typeParams": null,
+                      "typeName": "Type"
+                    }
+                  }
                 }
               }
-            ]
+            },
+            "nameType": {
+              "repr": "NewKeyType",
+              "kind": "typeRef",
+              "typeRef": {
+                "typeParams": null,
+                "typeName": "NewKeyType"
+              }
+            },
+            "optional": true,
+            "tsType": {
+              "repr": "",
+              "kind": "indexedAccess",
+              "indexedAccess": {
+                "readonly": false,
+                "objType": {
+                  "repr": "Type",
+                  "kind": "typeRef",
+                  "typeRef": {
+                    "typeParams": null,
+                    "typeName": "Type",
+                  }
+                },
+                "indexType": {
+                  "repr": "Properties",
+                  "kind": "typeRef",
+                  "typeRef": {
+                    "typeParams": null,
+                    "typeName": "Properties"
+                  }
+                }
+              }
+            }
           }
         },
-        "typeParams": []
+        "typeParams": [
+          {
+            "name": "Type",
+          }
+        ]
       }
-    }, {
-      "kind": "typeAlias",
-      "name": "tplLitArg",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 5,
-        "col": 0,
-      },
-      "declarationKind": "export",
-      "typeAliasDef": {
-        "tsType": {
-          "repr": "test${number}",
-          "kind": "literal",
-          "literal": {
-            "kind": "template",
-            "tsTypes": [
-              {
-                "repr": "test",
-                "kind": "literal",
-                "literal": {
-                  "kind": "string",
-                  "string": "test",
-                }
-              }, {
-                "repr": "number",
-                "kind": "keyword",
-                "keyword": "number"
-              }, {
+    }
+  ]);
+
+  json_test!(import_types,
+    r#"
+export function adopt<T>(p: import("./module.ts").Pet<T>) {
+  console.log(`Adopting ${p.name}...`);
+}
+"#;
+    [
+      {
+        "kind": "function",
+        "name": "adopt",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 0,
+        },
+        "declarationKind": "export",
+        "functionDef": {
+          "params": [
+            {
+              "kind": "identifier",
+              "name": "p",
+              "optional": false,
+              "tsType": {
                 "repr": "",
-                "kind": "literal",
-                "literal": {
-                  "kind": "string",
-                  "string": ""
+                "kind": "importType",
+                "importType": {
+                  "specifier": "./module.ts",
+                  "qualifier": "Pet",
+                  "typeParams": [
+                    {
+                      "repr": "T",
+                      "kind": "typeRef",
+                      "typeRef": {
+                        "typeParams": null,
+                        "typeName": "T"
+                      }
+                    }
+                  ]
+                }
+              }
+            }
+          ],
+          "returnType": null,
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": [
+            {
+              "name": "T"
+            }
+          ]
+        }
+      }
+    ]
+  );
+
+  json_test!(indented_with_tabs,
+      r#"
+/**
+ * Line 1
+ *
+ * Line 2
+ *
+ * 	Indented
+ */
+export namespace Tabs {
+	/**
+	 * Line 1
+	 *
+	 * Line 2
+	 *
+	 * 	Indented
+	 */
+	export interface Tabs{
+		/**
+		 * Line 1
+		 *
+		 * Line 2
+		 *
+		 * 	Indented
+		 */
+		property: string;
+	}
+}
+    "#;
+      [
+        {
+          "kind": "namespace",
+          "name": "Tabs",
+          "location": {
+            "filename": "file:///test.ts",
+            "line": 9,
+            "col": 0
+          },
+          "jsDoc": {
+            "doc": "Line 1\n\nLine 2\n\n\tIndented",
+          },
+          "declarationKind": "export",
+          "namespaceDef": {
+            "elements": [
+              {
+                "kind": "interface",
+                "name": "Tabs",
+                "location": {
+                  "filename": "file:///test.ts",
+                  "line": 17,
+                  "col": 4
+                },
+                "jsDoc": {
+                  "doc": "Line 1\n\nLine 2\n\n\tIndented",
+                },
+                "declarationKind": "export",
+                "interfaceDef": {
+                  "extends": [],
+                  "methods": [],
+                  "properties": [
+                    {
+                      "name": "property",
+                      "location": {
+                        "filename": "file:///test.ts",
+                        "line": 25,
+                        "col": 8
+                      },
+                      "jsDoc": {
+                        "doc": "Line 1\n\nLine 2\n\n\tIndented",
+                      },
+                      "params": [],
+                      "computed": false,
+                      "optional": false,
+                      "tsType": {
+                        "repr": "string",
+                        "kind": "keyword",
+                        "keyword": "string"
+                      },
+                      "typeParams": []
+                    }
+                  ],
+                  "callSignatures": [],
+                  "indexSignatures": [],
+                  "typeParams": []
                 }
               }
             ]
           }
+        }
+  ]
+    );
+
+  json_test!(overloads,
+  r#"
+export function a(b: string): string;
+export function a(b: number): number;
+export function a(b: string | number): string | number {
+  //
+}
+    "#;
+  [{
+    "kind": "function",
+    "name": "a",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "functionDef": {
+      "params": [{
+        "kind": "identifier",
+        "name": "b",
+        "optional": false,
+        "tsType": {
+          "repr": "string",
+          "kind": "keyword",
+          "keyword": "string",
         },
-        "typeParams": [],
-      }
-    }, {
-      "kind": "typeAlias",
-      "name": "numLit",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 6,
-        "col": 0
+      }],
+      "returnType": {
+        "repr": "string",
+        "kind": "keyword",
+        "keyword": "string",
       },
-      "declarationKind": "export",
-      "typeAliasDef": {
+      "hasBody": false,
+      "isAsync": false,
+      "isGenerator": false,
+      "typeParams": [],
+    }
+  }, {
+    "kind": "function",
+    "name": "a",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 3,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "functionDef": {
+      "params": [{
+        "kind": "identifier",
+        "name": "b",
+        "optional": false,
         "tsType": {
-          "repr": "5",
-          "kind": "literal",
-          "literal": {
-            "kind": "number",
-            "number": 5.0
-          }
+          "repr": "number",
+          "kind": "keyword",
+          "keyword": "number",
         },
-        "typeParams": []
-      }
+      }],
+      "returnType": {
+        "repr": "number",
+        "kind": "keyword",
+        "keyword": "number",
+      },
+      "hasBody": false,
+      "isAsync": false,
+      "isGenerator": false,
+      "typeParams": [],
     }
-  ]);
-
-  json_test!(export_private,
-    r#"
-const foo: string = "foo";
-export { foo };
-    "#,
-    private;
-    [
-      {
-        "kind": "variable",
-        "name": "foo",
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 2,
-          "col": 6
-        },
-        "declarationKind": "export",
-        "variableDef": {
-          "tsType": {
+  }, {
+    "kind": "function",
+    "name": "a",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 4,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "functionDef": {
+      "params": [{
+        "kind": "identifier",
+        "name": "b",
+        "optional": false,
+        "tsType": {
+          "repr": "",
+          "kind": "union",
+          "union": [{
             "repr": "string",
             "kind": "keyword",
-            "keyword": "string"
-          },
-          "kind": "const"
+            "keyword": "string",
+          }, {
+            "repr": "number",
+            "kind": "keyword",
+            "keyword": "number"
+          }]
         }
-      }
-    ]
-  );
+      }],
+      "returnType": {
+        "repr": "",
+        "kind": "union",
+        "union": [{
+          "repr": "string",
+          "kind": "keyword",
+          "keyword": "string"
+        }, {
+          "repr": "number",
+          "kind": "keyword",
+          "keyword": "number"
+        }]
+      },
+      "hasBody": true,
+      "isAsync": false,
+      "isGenerator": false,
+      "typeParams": [],
+    }
+  }]);
 
-  json_test!(ts_type_predicate_1,
-    r#"
-export function foo(bar: A | B): bar is A {}
-    "#;
-    [
-      {
-        "kind": "function",
-        "name": "foo",
+  json_test!(ctor_overloads,
+  r#"
+export class A {
+  constructor (a: string);
+  constructor (a: number);
+  constructor (a: string | number) {
+    //
+  }
+}"#;
+  [{
+    "kind": "class",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0,
+    },
+    "declarationKind": "export",
+    "classDef": {
+      "isAbstract": false,
+      "constructors": [{
+        "accessibility": null,
+        "hasBody": false,
+        "name": "constructor",
+        "params": [{
+          "kind": "identifier",
+          "name": "a",
+          "optional": false,
+          "tsType": {
+            "repr": "string",
+            "kind": "keyword",
+            "keyword": "string",
+          }
+        }],
         "location": {
           "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0
-        },
-        "declarationKind": "export",
-        "functionDef": {
-          "params": [
-            {
-              "kind": "identifier",
-              "name": "bar",
-              "optional": false,
-              "tsType": {
-                "repr": "",
-                "kind": "union",
-                "union": [
-                  {
-                    "repr": "A",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": null,
-                      "typeName": "A"
-                    }
-                  },
-                  {
-                    "repr": "B",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": null,
-                      "typeName": "B"
-                    }
-                  }
-                ]
-              }
-            }
-          ],
-          "returnType": {
-            "repr": "bar is A",
-            "kind": "typePredicate",
-            "typePredicate": {
-              "asserts": false,
-              "param": {
-                "type": "identifier",
-                "name": "bar"
-              },
-              "type": {
-                "repr": "A",
-                "kind": "typeRef",
-                "typeRef": {
-                  "typeParams": null,
-                  "typeName": "A"
-                }
-              }
-            }
-          },
-          "hasBody": true,
-          "isAsync": false,
-          "isGenerator": false,
-          "typeParams": []
+          "line": 3,
+          "col": 2,
         }
-      }
-    ]
-  );
-
-  json_test!(ts_type_predicate_2,
-    r#"
-export function foo(bar: A | B): asserts bar is B {}
-    "#;
-    [
-      {
-        "kind": "function",
-        "name": "foo",
+      }, {
+        "accessibility": null,
+        "hasBody": false,
+        "name": "constructor",
+        "params": [{
+          "kind": "identifier",
+          "name": "a",
+          "optional": false,
+          "tsType": {
+            "repr": "number",
+            "kind": "keyword",
+            "keyword": "number",
+          }
+        }],
         "location": {
           "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0
-        },
-        "declarationKind": "export",
-        "functionDef": {
-          "params": [
-            {
-              "kind": "identifier",
-              "name": "bar",
-              "optional": false,
-              "tsType": {
-                "repr": "",
-                "kind": "union",
-                "union": [
-                  {
-                    "repr": "A",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": null,
-                      "typeName": "A"
-                    }
-                  },
-                  {
-                    "repr": "B",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": null,
-                      "typeName": "B"
-                    }
-                  }
-                ]
-              }
-            }
-          ],
-          "returnType": {
-            "repr": "asserts bar is B",
-            "kind": "typePredicate",
-            "typePredicate": {
-              "asserts": true,
-              "param": {
-                "type": "identifier",
-                "name": "bar"
-              },
-              "type": {
-                "repr": "B",
-                "kind": "typeRef",
-                "typeRef": {
-                  "typeParams": null,
-                  "typeName": "B"
-                }
-              }
-            }
-          },
-          "hasBody": true,
-          "isAsync": false,
-          "isGenerator": false,
-          "typeParams": []
+          "line": 4,
+          "col": 2,
         }
-      }
-    ]
-  );
+      }, {
+        "accessibility": null,
+        "hasBody": true,
+        "name": "constructor",
+        "params": [{
+          "kind": "identifier",
+          "name": "a",
+          "optional": false,
+          "tsType": {
+            "repr": "",
+            "kind": "union",
+            "union": [{
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string",
+            }, {
+              "repr": "number",
+              "kind": "keyword",
+              "keyword": "number",
+            }]
+          }
+        }],
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 5,
+          "col": 2,
+        }
+      }],
+      "properties": [],
+      "indexSignatures": [],
+      "methods": [],
+      "extends": null,
+      "implements": [],
+      "typeParams": [],
+      "superTypeParams": [],
+    }
+  }]);
 
-  json_test!(ts_type_predicate_3,
-    r#"
-export class C {
-  isSomething(): this is Something {}
+  json_test!(index_signature_js_docs,
+  r#"
+export class A {
+  /** The values of A. */
+  [key: string]: unknown;
+}
+
+export interface B {
+  /** The values of B. */
+  readonly [index: number]: string;
 }
+
+export type C = {
+  /** The values of C. */
+  [name: string]: number;
+};
     "#;
-    [
-      {
-        "kind": "class",
-        "name": "C",
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0
+  [{
+    "kind": "class",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "classDef": {
+      "isAbstract": false,
+      "constructors": [],
+      "properties": [],
+      "indexSignatures": [{
+        "jsDoc": {
+          "doc": "The values of A.",
         },
-        "declarationKind": "export",
-        "classDef": {
-          "isAbstract": false,
-          "constructors": [],
-          "properties": [],
-          "indexSignatures": [],
-          "methods": [
-            {
-              "accessibility": null,
-              "optional": false,
-              "isAbstract": false,
-              "isStatic": false,
-              "name": "isSomething",
-              "kind": "method",
-              "functionDef": {
-                "params": [],
-                "returnType": {
-                  "repr": "this is Something",
-                  "kind": "typePredicate",
-                  "typePredicate": {
-                    "asserts": false,
-                    "param": {
-                      "type": "this"
-                    },
-                    "type": {
-                      "repr": "Something",
-                      "kind": "typeRef",
-                      "typeRef": {
-                        "typeParams": null,
-                        "typeName": "Something",
-                      },
-                    },
-                  },
-                },
-                "hasBody": true,
-                "isAsync": false,
-                "isGenerator": false,
-                "typeParams": [],
-              },
-              "location": {
-                "filename": "file:///test.ts",
-                "line": 3,
-                "col": 2
+        "readonly": false,
+        "params": [{
+          "kind": "identifier",
+          "name": "key",
+          "optional": false,
+          "tsType": {
+            "repr": "string",
+            "kind": "keyword",
+            "keyword": "string"
+          }
+        }],
+        "tsType": {
+          "repr": "unknown",
+          "kind": "keyword",
+          "keyword": "unknown"
+        }
+      }],
+      "methods": [],
+      "extends": null,
+      "implements": [],
+      "typeParams": [],
+      "superTypeParams": []
+    }
+  }, {
+    "kind": "interface",
+    "name": "B",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 7,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [],
+      "properties": [],
+      "callSignatures": [],
+      "indexSignatures": [{
+        "jsDoc": {
+          "doc": "The values of B.",
+        },
+        "readonly": true,
+        "params": [{
+          "kind": "identifier",
+          "name": "index",
+          "optional": false,
+          "tsType": {
+            "repr": "number",
+            "kind": "keyword",
+            "keyword": "number"
+          }
+        }],
+        "tsType": {
+          "repr": "string",
+          "kind": "keyword",
+          "keyword": "string"
+        }
+      }],
+      "typeParams": []
+    }
+  }, {
+    "kind": "typeAlias",
+    "name": "C",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 12,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "typeAliasDef": {
+      "tsType": {
+        "repr": "",
+        "kind": "typeLiteral",
+        "typeLiteral": {
+          "methods": [],
+          "properties": [],
+          "callSignatures": [],
+          "indexSignatures": [{
+            "jsDoc": {
+              "doc": "The values of C.",
+            },
+            "readonly": false,
+            "params": [{
+              "kind": "identifier",
+              "name": "name",
+              "optional": false,
+              "tsType": {
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
               }
+            }],
+            "tsType": {
+              "repr": "number",
+              "kind": "keyword",
+              "keyword": "number"
             }
-          ],
-          "extends": null,
-          "implements": [],
-          "typeParams": [],
-          "superTypeParams": []
+          }]
         }
-      }
-    ]
-  );
+      },
+      "typeParams": []
+    }
+  }]);
 
-  json_test!(ts_type_assertion,
-    r#"
-export function foo(bar: any): asserts bar {}
+  json_test!(type_literal_member_js_docs,
+  r#"
+export declare const env: {
+  /** Gets a variable. */
+  get(key: string): string | undefined;
+  /** The home directory. */
+  home: string;
+};
+
+export type Options = {
+  /** Nested options. */
+  nested: {
+    /** Whether to recurse. */
+    recursive?: boolean;
+  };
+} | undefined;
     "#;
-    [
-      {
-        "kind": "function",
-        "name": "foo",
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0
-        },
-        "declarationKind": "export",
-        "functionDef": {
-          "params": [
-            {
+  [{
+    "kind": "variable",
+    "name": "env",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 21
+    },
+    "declarationKind": "export",
+    "variableDef": {
+      "tsType": {
+        "repr": "",
+        "kind": "typeLiteral",
+        "typeLiteral": {
+          "methods": [{
+            "name": "get",
+            "jsDoc": {
+              "doc": "Gets a variable.",
+            },
+            "kind": "method",
+            "params": [{
               "kind": "identifier",
-              "name": "bar",
+              "name": "key",
               "optional": false,
               "tsType": {
-                "repr": "any",
+                "repr": "string",
                 "kind": "keyword",
-                "keyword": "any"
+                "keyword": "string"
               }
-            }
-          ],
-          "returnType": {
-            "repr": "asserts bar",
-            "kind": "typePredicate",
-            "typePredicate": {
-              "asserts": true,
-              "param": {
-                "type": "identifier",
-                "name": "bar"
-              },
-              "type": null,
-            }
-          },
-          "hasBody": true,
-          "isAsync": false,
-          "isGenerator": false,
-          "typeParams": []
+            }],
+            "optional": false,
+            "returnType": {
+              "repr": "",
+              "kind": "union",
+              "union": [{
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
+              }, {
+                "repr": "undefined",
+                "kind": "keyword",
+                "keyword": "undefined"
+              }]
+            },
+            "typeParams": []
+          }],
+          "properties": [{
+            "name": "home",
+            "jsDoc": {
+              "doc": "The home directory.",
+            },
+            "params": [],
+            "computed": false,
+            "optional": false,
+            "tsType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
+            },
+            "typeParams": []
+          }],
+          "callSignatures": [],
+          "indexSignatures": []
         }
-      }
-    ]
-  );
-
-  json_test!(infer_types,
-  r#"export type Flatten<T> = T extends Array<infer U> ? U : T;"#;
-  [
-    {
-      "kind": "typeAlias",
-      "name": "Flatten",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 1,
-        "col": 0
       },
-      "declarationKind": "export",
-      "typeAliasDef": {
-        "tsType": {
+      "kind": "const"
+    }
+  }, {
+    "kind": "typeAlias",
+    "name": "Options",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 9,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "typeAliasDef": {
+      "tsType": {
+        "repr": "",
+        "kind": "union",
+        "union": [{
           "repr": "",
-          "kind": "conditional",
-          "conditionalType": {
-            "checkType": {
-              "repr": "T",
-              "kind": "typeRef",
-              "typeRef": {
-                "typeParams": null,
-                "typeName": "T"
-              }
+          "kind": "typeLiteral",
+          "typeLiteral": {
+            "methods": [],
+            "properties": [{
+              "name": "nested",
+              "jsDoc": {
+                "doc": "Nested options.",
+              },
+              "params": [],
+              "computed": false,
+              "optional": false,
+              "tsType": {
+                "repr": "",
+                "kind": "typeLiteral",
+                "typeLiteral": {
+                  "methods": [],
+                  "properties": [{
+                    "name": "recursive",
+                    "jsDoc": {
+                      "doc": "Whether to recurse.",
+                    },
+                    "params": [],
+                    "computed": false,
+                    "optional": true,
+                    "tsType": {
+                      "repr": "boolean",
+                      "kind": "keyword",
+                      "keyword": "boolean"
+                    },
+                    "typeParams": []
+                  }],
+                  "callSignatures": [],
+                  "indexSignatures": []
+                }
+              },
+              "typeParams": []
+            }],
+            "callSignatures": [],
+            "indexSignatures": []
+          }
+        }, {
+          "repr": "undefined",
+          "kind": "keyword",
+          "keyword": "undefined"
+        }]
+      },
+      "typeParams": []
+    }
+  }]);
+
+  json_test!(object_literal_property_js_doc,
+  r#"
+export const api = {
+  /** Does x. */
+  get(): string {
+    return "";
+  },
+  /** The value. */
+  value: "a",
+  /** @ignore */
+  hidden: 1,
+};
+    "#;
+  [{
+    "kind": "variable",
+    "name": "api",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 13
+    },
+    "declarationKind": "export",
+    "variableDef": {
+      "tsType": {
+        "repr": "",
+        "kind": "typeLiteral",
+        "typeLiteral": {
+          "methods": [{
+            "name": "get",
+            "jsDoc": {
+              "doc": "Does x.",
             },
-            "extendsType": {
-              "repr": "Array",
-              "kind": "typeRef",
-              "typeRef": {
-                "typeParams": [
-                  {
-                    "repr": "",
-                    "kind": "infer",
-                    "infer": {
-                      "typeParam": {
-                        "name": "U"
-                      }
-                    }
-                  }
-                ],
-                "typeName": "Array"
-              }
+            "kind": "method",
+            "params": [],
+            "optional": false,
+            "returnType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
             },
-            "trueType": {
-              "repr": "U",
-              "kind": "typeRef",
-              "typeRef": {
-                "typeParams": null,
-                "typeName": "U"
-              }
+            "typeParams": []
+          }],
+          "properties": [{
+            "name": "value",
+            "jsDoc": {
+              "doc": "The value.",
+            },
+            "params": [],
+            "computed": false,
+            "optional": false,
+            "tsType": {
+              "repr": "string",
+              "kind": "keyword",
+              "keyword": "string"
             },
-            "falseType": {
-              "repr": "T",
-              "kind": "typeRef",
-              "typeRef": {
-                "typeParams": null,
-                "typeName": "T",
-              }
-            }
-          }
-        },
-        "typeParams": [
-          {
-            "name": "T"
-          }
-        ]
-      }
+            "typeParams": []
+          }],
+          "callSignatures": [],
+          "indexSignatures": []
+        }
+      },
+      "kind": "const"
     }
-  ]);
-
-  json_test!(infer_object_literal,
-    r#"
-    const s: symbol = Symbol.for("s");
-    const t: symbol = Symbol.for("t");
+  }]);
 
-    export const a = {
-      a: "a",
-      b: new Map<string, number>(),
-      c: { d: "d" },
-      d(e: string): void {},
-      f: (g: string): void => {},
-      get h(): string {
-        return "h";
-      },
-      set h(value: string) {
+  json_test!(namespace_object_literal_accessors,
+  r#"
+export namespace A {
+  export const config = {
+    /** The current level. */
+    get level(): number {
+      return 1;
+    },
+    /** Sets the level. */
+    set level(value: number) {},
+  };
 
+  export namespace B {
+    export const state = {
+      /** Whether it is enabled. */
+      get enabled(): boolean {
+        return true;
       },
-      [s]: [1, 2, 3, "a"],
-      [t](u: string): void {},
-    };"#;
-    [
-      {
+    };
+  }
+}
+    "#;
+  [{
+    "kind": "namespace",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "namespaceDef": {
+      "elements": [{
         "kind": "variable",
-        "name": "a",
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 5,
-          "col": 17
-        },
-        "declarationKind": "export",
-        "variableDef": {
-          "tsType": {
-            "repr": "",
-            "kind": "typeLiteral",
-            "typeLiteral": {
-              "methods": [
-                {
-                  "name": "d",
-                  "kind": "method",
-                  "params": [
-                    {
-                      "kind": "identifier",
-                      "name": "e",
-                      "optional": false,
-                      "tsType": {
-                        "repr": "string",
-                        "kind": "keyword",
-                        "keyword": "string"
-                      }
-                    }
-                  ],
-                  "optional": false,
-                  "returnType": {
-                    "repr": "void",
-                    "kind": "keyword",
-                    "keyword": "void"
-                  },
-                  "typeParams": []
-                },
-                {
-                  "name": "h",
-                  "kind": "getter",
-                  "params": [],
-                  "optional": false,
-                  "returnType": {
-                    "repr": "string",
-                    "kind": "keyword",
-                    "keyword": "string"
-                  },
-                  "typeParams": []
-                },
-                {
-                  "name": "h",
-                  "kind": "setter",
-                  "params": [
-                    {
-                      "kind": "identifier",
-                      "name": "value",
-                      "optional": false,
-                      "tsType": {
-                        "repr": "string",
-                        "kind": "keyword",
-                        "keyword": "string"
-                      }
-                    }
-                  ],
-                  "optional": false,
-                  "returnType": null,
-                  "typeParams": []
-                },
-                {
-                  "name": "[t]",
-                  "kind": "method",
-                  "params": [
-                    {
-                      "kind": "identifier",
-                      "name": "u",
-                      "optional": false,
-                      "tsType": {
-                        "repr": "string",
-                        "kind": "keyword",
-                        "keyword": "string"
-                      }
-                    }
-                  ],
-                  "computed": true,
-                  "optional": false,
-                  "returnType": {
-                    "repr": "void",
-                    "kind": "keyword",
-                    "keyword": "void"
-                  },
-                  "typeParams": []
-                }
-              ],
-              "properties": [
-                {
-                  "name": "a",
-                  "params": [],
-                  "computed": false,
-                  "optional": false,
-                  "tsType": {
-                    "repr": "string",
-                    "kind": "keyword",
-                    "keyword": "string"
-                  },
-                  "typeParams": []
-                },
-                {
-                  "name": "b",
-                  "params": [],
-                  "computed": false,
-                  "optional": false,
-                  "tsType": {
-                    "repr": "Map",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": [
-                        {
-                          "repr": "string",
-                          "kind": "keyword",
-                          "keyword": "string"
-                        },
-                        {
-                          "repr": "number",
-                          "kind": "keyword",
-                          "keyword": "number"
-                        }
-                      ],
-                      "typeName": "Map"
-                    }
-                  },
-                  "typeParams": []
-                },
-                {
-                  "name": "c",
-                  "params": [],
-                  "computed": false,
-                  "optional": false,
-                  "tsType": {
-                    "repr": "",
-                    "kind": "typeLiteral",
-                    "typeLiteral": {
-                      "methods": [],
-                      "properties": [
-                        {
-                          "name": "d",
-                          "params": [],
-                          "computed": false,
-                          "optional": false,
-                          "tsType": {
-                            "repr": "string",
-                            "kind": "keyword",
-                            "keyword": "string"
-                          },
-                          "typeParams": []
-                        }
-                      ],
-                      "callSignatures": [],
-                      "indexSignatures": []
-                    }
-                  },
-                  "typeParams": []
-                },
-                {
-                  "name": "f",
-                  "params": [],
-                  "computed": false,
-                  "optional": false,
-                  "tsType": {
-                    "repr": "",
-                    "kind": "fnOrConstructor",
-                    "fnOrConstructor": {
-                      "constructor": false,
-                      "tsType": {
-                        "repr": "void",
-                        "kind": "keyword",
-                        "keyword": "void"
-                      },
-                      "params": [
-                        {
-                          "kind": "identifier",
-                          "name": "g",
-                          "optional": false,
-                          "tsType": {
-                            "repr": "string",
-                            "kind": "keyword",
-                            "keyword": "string"
-                          }
-                        }
-                      ],
-                      "typeParams": []
-                    }
-                  },
-                  "typeParams": []
+        "name": "config",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 3,
+          "col": 15
+        },
+        "declarationKind": "export",
+        "variableDef": {
+          "tsType": {
+            "repr": "",
+            "kind": "typeLiteral",
+            "typeLiteral": {
+              "methods": [{
+                "name": "level",
+                "jsDoc": {
+                  "doc": "The current level.",
                 },
-                {
-                  "name": "[s]",
-                  "params": [],
-                  "computed": true,
+                "kind": "getter",
+                "params": [],
+                "optional": false,
+                "returnType": {
+                  "repr": "number",
+                  "kind": "keyword",
+                  "keyword": "number"
+                },
+                "typeParams": []
+              }, {
+                "name": "level",
+                "jsDoc": {
+                  "doc": "Sets the level.",
+                },
+                "kind": "setter",
+                "params": [{
+                  "kind": "identifier",
+                  "name": "value",
                   "optional": false,
                   "tsType": {
-                    "repr": "",
-                    "kind": "array",
-                    "array": {
-                      "repr": "",
-                      "kind": "union",
-                      "union": [
-                        {
-                          "repr": "number",
-                          "kind": "keyword",
-                          "keyword": "number"
-                        },
-                        {
-                          "repr": "string",
-                          "kind": "keyword",
-                          "keyword": "string"
-                        }
-                      ]
-                    }
-                  },
-                  "typeParams": []
-                }
-              ],
+                    "repr": "number",
+                    "kind": "keyword",
+                    "keyword": "number"
+                  }
+                }],
+                "optional": false,
+                "returnType": null,
+                "typeParams": []
+              }],
+              "properties": [],
               "callSignatures": [],
               "indexSignatures": []
             }
           },
           "kind": "const"
         }
-      }
-    ]
-  );
-
-  json_test!(mapped_types,
-  r#"
-export type MappedTypeWithNewProperties<Type> = {
-  readonly [Properties in keyof Type as NewKeyType]?: Type[Properties];
-};
-"#;
-  [
-    {
-      "kind": "typeAlias",
-      "name": "MappedTypeWithNewProperties",
-      "location": {
-        "filename": "file:///test.ts",
-        "line": 2,
-        "col": 0,
-      },
-      "declarationKind": "export",
-      "typeAliasDef": {
-        "tsType": {
-          "repr": "",
-          "kind": "mapped",
-          "mappedType": {
-            "readonly": true,
-            "typeParam": {
-              "name": "Properties",
-              "constraint": {
-                "repr": "",
-                "kind": "typeOperator",
-                "typeOperator": {
-                  "operator": "keyof",
-                  "tsType": {
-                    "repr": "Type",
-                    "kind": "typeRef",
-                    "typeRef": {
-                      "typeParams": null,
-                      "typeName": "Type"
-                    }
-                  }
-                }
-              }
-            },
-            "nameType": {
-              "repr": "NewKeyType",
-              "kind": "typeRef",
-              "typeRef": {
-                "typeParams": null,
-                "typeName": "NewKeyType"
-              }
+      }, {
+        "kind": "namespace",
+        "name": "B",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 12,
+          "col": 2
+        },
+        "declarationKind": "export",
+        "namespaceDef": {
+          "elements": [{
+            "kind": "variable",
+            "name": "state",
+            "location": {
+              "filename": "file:///test.ts",
+              "line": 13,
+              "col": 17
             },
-            "optional": true,
-            "tsType": {
-              "repr": "",
-              "kind": "indexedAccess",
-              "indexedAccess": {
-                "readonly": false,
-                "objType": {
-                  "repr": "Type",
-                  "kind": "typeRef",
-                  "typeRef": {
-                    "typeParams": null,
-                    "typeName": "Type",
-                  }
-                },
-                "indexType": {
-                  "repr": "Properties",
-                  "kind": "typeRef",
-                  "typeRef": {
-                    "typeParams": null,
-                    "typeName": "Properties"
-                  }
+            "declarationKind": "export",
+            "variableDef": {
+              "tsType": {
+                "repr": "",
+                "kind": "typeLiteral",
+                "typeLiteral": {
+                  "methods": [{
+                    "name": "enabled",
+                    "jsDoc": {
+                      "doc": "Whether it is enabled.",
+                    },
+                    "kind": "getter",
+                    "params": [],
+                    "optional": false,
+                    "returnType": {
+                      "repr": "boolean",
+                      "kind": "keyword",
+                      "keyword": "boolean"
+                    },
+                    "typeParams": []
+                  }],
+                  "properties": [],
+                  "callSignatures": [],
+                  "indexSignatures": []
                 }
-              }
+              },
+              "kind": "const"
             }
-          }
-        },
-        "typeParams": [
-          {
-            "name": "Type",
-          }
-        ]
-      }
+          }]
+        }
+      }]
     }
-  ]);
+  }]);
 
-  json_test!(import_types,
-    r#"
-export function adopt<T>(p: import("./module.ts").Pet<T>) {
-  console.log(`Adopting ${p.name}...`);
+  json_test!(well_known_symbol_members,
+  r#"
+export class A {
+  [Symbol.iterator](): Iterator<string> {}
+  [ Symbol["asyncIterator"] ](): AsyncIterator<string> {}
+  [Symbol.for("a")]: string;
 }
-"#;
-    [
-      {
-        "kind": "function",
-        "name": "adopt",
+export interface B {
+  [Symbol.dispose](): void;
+}
+    "#;
+  [{
+    "kind": "class",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "classDef": {
+      "isAbstract": false,
+      "constructors": [],
+      "properties": [{
+        "tsType": {
+          "repr": "string",
+          "kind": "keyword",
+          "keyword": "string"
+        },
+        "readonly": false,
+        "accessibility": null,
+        "optional": false,
+        "isAbstract": false,
+        "isStatic": false,
+        "name": "[Symbol.for(\"a\")]",
         "location": {
           "filename": "file:///test.ts",
-          "line": 2,
-          "col": 0,
-        },
-        "declarationKind": "export",
+          "line": 5,
+          "col": 2
+        }
+      }],
+      "indexSignatures": [],
+      "methods": [{
+        "accessibility": null,
+        "optional": false,
+        "isAbstract": false,
+        "isStatic": false,
+        "name": "[Symbol.iterator]",
+        "wellKnownSymbol": true,
+        "kind": "method",
         "functionDef": {
-          "params": [
-            {
-              "kind": "identifier",
-              "name": "p",
-              "optional": false,
-              "tsType": {
-                "repr": "",
-                "kind": "importType",
-                "importType": {
-                  "specifier": "./module.ts",
-                  "qualifier": "Pet",
-                  "typeParams": [
-                    {
-                      "repr": "T",
-                      "kind": "typeRef",
-                      "typeRef": {
-                        "typeParams": null,
-                        "typeName": "T"
-                      }
-                    }
-                  ]
-                }
-              }
+          "params": [],
+          "returnType": {
+            "repr": "Iterator",
+            "kind": "typeRef",
+            "typeRef": {
+              "typeParams": [{
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
+              }],
+              "typeName": "Iterator"
+            }
+          },
+          "hasBody": true,
+          "isAsync": false,
+          "isGenerator": false,
+          "typeParams": []
+        },
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 3,
+          "col": 2
+        }
+      }, {
+        "accessibility": null,
+        "optional": false,
+        "isAbstract": false,
+        "isStatic": false,
+        "name": "[Symbol.asyncIterator]",
+        "wellKnownSymbol": true,
+        "kind": "method",
+        "functionDef": {
+          "params": [],
+          "returnType": {
+            "repr": "AsyncIterator",
+            "kind": "typeRef",
+            "typeRef": {
+              "typeParams": [{
+                "repr": "string",
+                "kind": "keyword",
+                "keyword": "string"
+              }],
+              "typeName": "AsyncIterator"
             }
-          ],
-          "returnType": null,
+          },
           "hasBody": true,
           "isAsync": false,
           "isGenerator": false,
-          "typeParams": [
-            {
-              "name": "T"
-            }
-          ]
-        }
-      }
-    ]
-  );
-
-  json_test!(indented_with_tabs,
-      r#"
-/**
- * Line 1
- *
- * Line 2
- *
- * 	Indented
- */
-export namespace Tabs {
-	/**
-	 * Line 1
-	 *
-	 * Line 2
-	 *
-	 * 	Indented
-	 */
-	export interface Tabs{
-		/**
-		 * Line 1
-		 *
-		 * Line 2
-		 *
-		 * 	Indented
-		 */
-		property: string;
-	}
-}
-    "#;
-      [
-        {
-          "kind": "namespace",
-          "name": "Tabs",
-          "location": {
-            "filename": "file:///test.ts",
-            "line": 9,
-            "col": 0
-          },
-          "jsDoc": {
-            "doc": "Line 1\n\nLine 2\n\n\tIndented",
-          },
-          "declarationKind": "export",
-          "namespaceDef": {
-            "elements": [
-              {
-                "kind": "interface",
-                "name": "Tabs",
-                "location": {
-                  "filename": "file:///test.ts",
-                  "line": 17,
-                  "col": 4
-                },
-                "jsDoc": {
-                  "doc": "Line 1\n\nLine 2\n\n\tIndented",
-                },
-                "declarationKind": "export",
-                "interfaceDef": {
-                  "extends": [],
-                  "methods": [],
-                  "properties": [
-                    {
-                      "name": "property",
-                      "location": {
-                        "filename": "file:///test.ts",
-                        "line": 25,
-                        "col": 8
-                      },
-                      "jsDoc": {
-                        "doc": "Line 1\n\nLine 2\n\n\tIndented",
-                      },
-                      "params": [],
-                      "computed": false,
-                      "optional": false,
-                      "tsType": {
-                        "repr": "string",
-                        "kind": "keyword",
-                        "keyword": "string"
-                      },
-                      "typeParams": []
-                    }
-                  ],
-                  "callSignatures": [],
-                  "indexSignatures": [],
-                  "typeParams": []
-                }
-              }
-            ]
-          }
+          "typeParams": []
+        },
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 4,
+          "col": 2
         }
-  ]
-    );
+      }],
+      "extends": null,
+      "implements": [],
+      "typeParams": [],
+      "superTypeParams": []
+    }
+  }, {
+    "kind": "interface",
+    "name": "B",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 7,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [{
+        "name": "Symbol.dispose",
+        "kind": "method",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 8,
+          "col": 2
+        },
+        "computed": true,
+        "wellKnownSymbol": true,
+        "optional": false,
+        "params": [],
+        "returnType": {
+          "repr": "void",
+          "kind": "keyword",
+          "keyword": "void"
+        },
+        "typeParams": []
+      }],
+      "properties": [],
+      "callSignatures": [],
+      "indexSignatures": [],
+      "typeParams": []
+    }
+  }]);
 
-  json_test!(overloads,
+  json_test!(reachable_only_multiple_private_types,
   r#"
-export function a(b: string): string;
-export function a(b: number): number;
-export function a(b: string | number): string | number {
-  //
+interface F { g: G }
+interface Unused {}
+interface E {}
+interface G {}
+export interface A {
+  e: E;
+  f: F;
 }
-    "#;
+    "#,
+  options: DocParserOptions {
+    reachable_only: true,
+    ..Default::default()
+  };
   [{
-    "kind": "function",
-    "name": "a",
+    "kind": "interface",
+    "name": "A",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 6,
+      "col": 0
+    },
+    "declarationKind": "export",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [],
+      "properties": [{
+        "name": "e",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 7,
+          "col": 2
+        },
+        "params": [],
+        "computed": false,
+        "optional": false,
+        "tsType": {
+          "repr": "E",
+          "kind": "typeRef",
+          "typeRef": {
+            "typeParams": null,
+            "typeName": "E"
+          }
+        },
+        "typeParams": []
+      }, {
+        "name": "f",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 8,
+          "col": 2
+        },
+        "params": [],
+        "computed": false,
+        "optional": false,
+        "tsType": {
+          "repr": "F",
+          "kind": "typeRef",
+          "typeRef": {
+            "typeParams": null,
+            "typeName": "F"
+          }
+        },
+        "typeParams": []
+      }],
+      "callSignatures": [],
+      "indexSignatures": [],
+      "typeParams": []
+    }
+  }, {
+    "kind": "interface",
+    "name": "F",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 2,
+      "col": 0
+    },
+    "declarationKind": "private",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [],
+      "properties": [{
+        "name": "g",
+        "location": {
+          "filename": "file:///test.ts",
+          "line": 2,
+          "col": 14
+        },
+        "params": [],
+        "computed": false,
+        "optional": false,
+        "tsType": {
+          "repr": "G",
+          "kind": "typeRef",
+          "typeRef": {
+            "typeParams": null,
+            "typeName": "G"
+          }
+        },
+        "typeParams": []
+      }],
+      "callSignatures": [],
+      "indexSignatures": [],
+      "typeParams": []
+    }
+  }, {
+    "kind": "interface",
+    "name": "E",
+    "location": {
+      "filename": "file:///test.ts",
+      "line": 4,
+      "col": 0
+    },
+    "declarationKind": "private",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [],
+      "properties": [],
+      "callSignatures": [],
+      "indexSignatures": [],
+      "typeParams": []
+    }
+  }, {
+    "kind": "interface",
+    "name": "G",
     "location": {
       "filename": "file:///test.ts",
-      "line": 2,
+      "line": 5,
       "col": 0
     },
-    "declarationKind": "export",
-    "functionDef": {
-      "params": [{
-        "kind": "identifier",
-        "name": "b",
-        "optional": false,
-        "tsType": {
-          "repr": "string",
-          "kind": "keyword",
-          "keyword": "string",
-        },
-      }],
-      "returnType": {
-        "repr": "string",
-        "kind": "keyword",
-        "keyword": "string",
-      },
-      "isAsync": false,
-      "isGenerator": false,
-      "typeParams": [],
+    "declarationKind": "private",
+    "interfaceDef": {
+      "extends": [],
+      "methods": [],
+      "properties": [],
+      "callSignatures": [],
+      "indexSignatures": [],
+      "typeParams": []
     }
-  }, {
-    "kind": "function",
-    "name": "a",
+  }]);
+
+  json_test!(leading_comments,
+  r#"
+export const first = 0;
+
+// eslint-disable-next-line
+/** The a. */
+export const a = 1;
+
+/* @sealed */
+export class B {}
+    "#,
+  options: DocParserOptions {
+    leading_comments: true,
+    ..Default::default()
+  };
+  [{
+    "kind": "variable",
+    "name": "first",
     "location": {
       "filename": "file:///test.ts",
-      "line": 3,
-      "col": 0
+      "line": 2,
+      "col": 13
     },
     "declarationKind": "export",
-    "functionDef": {
-      "params": [{
-        "kind": "identifier",
-        "name": "b",
-        "optional": false,
-        "tsType": {
-          "repr": "number",
-          "kind": "keyword",
-          "keyword": "number",
-        },
-      }],
-      "returnType": {
-        "repr": "number",
-        "kind": "keyword",
-        "keyword": "number",
+    "variableDef": {
+      "tsType": {
+        "repr": "0",
+        "kind": "literal",
+        "literal": {
+          "kind": "number",
+          "number": 0.0
+        }
       },
-      "isAsync": false,
-      "isGenerator": false,
-      "typeParams": [],
+      "kind": "const"
     }
   }, {
-    "kind": "function",
+    "kind": "variable",
     "name": "a",
     "location": {
       "filename": "file:///test.ts",
-      "line": 4,
-      "col": 0
+      "line": 6,
+      "col": 13
     },
     "declarationKind": "export",
-    "functionDef": {
-      "params": [{
-        "kind": "identifier",
-        "name": "b",
-        "optional": false,
-        "tsType": {
-          "repr": "",
-          "kind": "union",
-          "union": [{
-            "repr": "string",
-            "kind": "keyword",
-            "keyword": "string",
-          }, {
-            "repr": "number",
-            "kind": "keyword",
-            "keyword": "number"
-          }]
+    "jsDoc": {
+      "doc": "The a.",
+    },
+    "leadingComments": [{
+      "kind": "line",
+      "text": " eslint-disable-next-line",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 4,
+        "col": 0
+      },
+      "end": {
+        "filename": "file:///test.ts",
+        "line": 4,
+        "col": 27
+      }
+    }, {
+      "kind": "block",
+      "text": "* The a. ",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 5,
+        "col": 0
+      },
+      "end": {
+        "filename": "file:///test.ts",
+        "line": 5,
+        "col": 13
+      }
+    }],
+    "variableDef": {
+      "tsType": {
+        "repr": "1",
+        "kind": "literal",
+        "literal": {
+          "kind": "number",
+          "number": 1.0
         }
-      }],
-      "returnType": {
-        "repr": "",
-        "kind": "union",
-        "union": [{
-          "repr": "string",
-          "kind": "keyword",
-          "keyword": "string"
-        }, {
-          "repr": "number",
-          "kind": "keyword",
-          "keyword": "number"
-        }]
       },
-      "hasBody": true,
-      "isAsync": false,
-      "isGenerator": false,
-      "typeParams": [],
+      "kind": "const"
     }
-  }]);
-
-  json_test!(ctor_overloads,
-  r#"
-export class A {
-  constructor (a: string);
-  constructor (a: number);
-  constructor (a: string | number) {
-    //
-  }
-}"#;
-  [{
+  }, {
     "kind": "class",
-    "name": "A",
+    "name": "B",
     "location": {
       "filename": "file:///test.ts",
-      "line": 2,
-      "col": 0,
+      "line": 9,
+      "col": 0
     },
     "declarationKind": "export",
+    "leadingComments": [{
+      "kind": "block",
+      "text": " @sealed ",
+      "location": {
+        "filename": "file:///test.ts",
+        "line": 8,
+        "col": 0
+      },
+      "end": {
+        "filename": "file:///test.ts",
+        "line": 8,
+        "col": 13
+      }
+    }],
     "classDef": {
       "isAbstract": false,
-      "constructors": [{
-        "accessibility": null,
-        "name": "constructor",
-        "params": [{
-          "kind": "identifier",
-          "name": "a",
-          "optional": false,
-          "tsType": {
-            "repr": "string",
-            "kind": "keyword",
-            "keyword": "string",
-          }
-        }],
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 3,
-          "col": 2,
-        }
-      }, {
-        "accessibility": null,
-        "name": "constructor",
-        "params": [{
-          "kind": "identifier",
-          "name": "a",
-          "optional": false,
-          "tsType": {
-            "repr": "number",
-            "kind": "keyword",
-            "keyword": "number",
-          }
-        }],
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 4,
-          "col": 2,
-        }
-      }, {
-        "accessibility": null,
-        "hasBody": true,
-        "name": "constructor",
-        "params": [{
-          "kind": "identifier",
-          "name": "a",
-          "optional": false,
-          "tsType": {
-            "repr": "",
-            "kind": "union",
-            "union": [{
-              "repr": "string",
-              "kind": "keyword",
-              "keyword": "string",
-            }, {
-              "repr": "number",
-              "kind": "keyword",
-              "keyword": "number",
-            }]
-          }
-        }],
-        "location": {
-          "filename": "file:///test.ts",
-          "line": 5,
-          "col": 2,
-        }
-      }],
+      "constructors": [],
       "properties": [],
       "indexSignatures": [],
       "methods": [],
       "extends": null,
       "implements": [],
       "typeParams": [],
-      "superTypeParams": [],
+      "superTypeParams": []
     }
   }]);
 }
@@ -6359,6 +11791,31 @@ export interface I {
     "new(name: string)"
   );
 
+  contains_test!(interface_call_and_construct_signatures,
+    r#"
+export interface I {
+  /** Calls it. */
+  <T>(value: T): string;
+  new <T>(value: T): I;
+  method<U>(value: U): void;
+}
+    "#;
+    "<T>(value: T): string",
+    "Calls it.",
+    "new<T>(value: T): I",
+    "method<U>(value: U): void"
+  );
+
+  contains_test!(type_literal_call_signatures,
+    r#"
+export type T = {
+  <U>(value: U): string;
+  new <U>(value: U): T;
+};
+    "#;
+    "{ <U>(value: U): string; new<U>(value: U): T; }"
+  );
+
   contains_test!(jsdoc,
     r#"
 /**
@@ -6411,6 +11868,19 @@ export function a(b, c, d) {}
     "returning doc"
   );
 
+  contains_test!(jsdoc_since,
+    r#"
+/**
+ * a is a function
+ *
+ * @since 1.2.0 with the options
+ */
+export function a(options) {}
+    "#;
+    "@since 1.2.0",
+    "with the options"
+  );
+
   contains_test!(namespace_declaration,
   "export namespace Namespace {}";
     "namespace Namespace"
@@ -6581,9 +12051,34 @@ import Options = Test.Options;
 export { Options };";
     "interface Options"
   );
+  contains_test!(index_signature_js_docs,
+    r#"
+export class A {
+  /** The values of A. */
+  [key: string]: unknown;
+}
+
+export interface B {
+  /** The values of B. */
+  readonly [index: number]: string;
 }
+    "#;
+    "The values of A.",
+    "The values of B."
+  );
 
-fn create_analyzer() -> CapturingModuleAnalyzer {
-  let source_parser = DefaultModuleParser::new_for_analysis();
-  CapturingModuleAnalyzer::new(Some(Box::new(source_parser)), None)
+  contains_test!(well_known_symbol_members,
+    r#"
+export class A {
+  [Symbol.iterator](): Iterator<string> {}
+  [ Symbol["asyncIterator"] ](): AsyncIterator<string> {}
+}
+export interface B {
+  [Symbol.dispose](): void;
+}
+    "#;
+    "[Symbol.iterator](): Iterator<string>",
+    "[Symbol.asyncIterator](): AsyncIterator<string>",
+    "[Symbol.dispose](): void"
+  );
 }
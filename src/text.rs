@@ -0,0 +1,136 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use std::fmt::Write;
+
+use crate::js_doc::JsDoc;
+use crate::node::DocNode;
+use crate::node::DocNodeKind;
+use crate::printer::SignatureDisplayer;
+
+pub(crate) struct Section {
+  pub qualified_name: String,
+  pub signature: String,
+  pub doc: Option<String>,
+}
+
+/// Flattens the doc nodes into a deterministic plain-text corpus, with one
+/// section per symbol and member containing its qualified name, signature and
+/// documentation. Sections are sorted by qualified name so the output does
+/// not depend on declaration order.
+pub fn to_plain_text(doc_nodes: &[DocNode]) -> String {
+  let mut text = String::new();
+  for section in sections(doc_nodes) {
+    writeln!(text, "## {}", section.qualified_name).unwrap();
+    writeln!(text).unwrap();
+    writeln!(text, "```ts").unwrap();
+    writeln!(text, "{}", section.signature).unwrap();
+    writeln!(text, "```").unwrap();
+    if let Some(doc) = section.doc {
+      writeln!(text).unwrap();
+      writeln!(text, "{}", doc.trim()).unwrap();
+    }
+    writeln!(text).unwrap();
+  }
+  text
+}
+
+/// The sections of the doc nodes and their members, sorted by qualified name.
+pub(crate) fn sections(doc_nodes: &[DocNode]) -> Vec<Section> {
+  let mut sections = Vec::new();
+  collect_sections(&mut sections, None, doc_nodes);
+  sections.sort_by(|a, b| a.qualified_name.cmp(&b.qualified_name));
+  sections
+}
+
+fn collect_sections(
+  sections: &mut Vec<Section>,
+  namespace: Option<&str>,
+  doc_nodes: &[DocNode],
+) {
+  for doc_node in doc_nodes {
+    if matches!(
+      doc_node.kind,
+      DocNodeKind::ModuleDoc | DocNodeKind::Import | DocNodeKind::UmdNamespace
+    ) {
+      continue;
+    }
+    let qualified_name = match namespace {
+      Some(namespace) => format!("{}.{}", namespace, doc_node.name),
+      None => doc_node.name.clone(),
+    };
+    let has_overloads = doc_node.kind == DocNodeKind::Function
+      && doc_nodes
+        .iter()
+        .filter(|n| n.kind == DocNodeKind::Function && n.name == doc_node.name)
+        .count()
+        > 1;
+    let signature = SignatureDisplayer {
+      node: doc_node,
+      has_overloads,
+    }
+    .to_string();
+    // implementation signatures of overloaded functions are not displayed
+    if !signature.trim().is_empty() {
+      sections.push(Section {
+        qualified_name: qualified_name.clone(),
+        signature: signature.trim().to_string(),
+        doc: doc_text(&doc_node.js_doc),
+      });
+    }
+
+    let mut push_member = |name: &str, signature: String, js_doc: &JsDoc| {
+      sections.push(Section {
+        qualified_name: format!("{}.{}", qualified_name, name),
+        signature,
+        doc: doc_text(js_doc),
+      })
+    };
+    if let Some(class_def) = &doc_node.class_def {
+      for property in &class_def.properties {
+        push_member(&property.name, property.to_string(), &property.js_doc);
+      }
+      for method in &class_def.methods {
+        if method.function_def.has_body
+          && class_def
+            .methods
+            .iter()
+            .filter(|m| m.name == method.name)
+            .count()
+            > 1
+        {
+          continue;
+        }
+        push_member(&method.name, method.to_string(), &method.js_doc);
+      }
+    }
+    if let Some(interface_def) = &doc_node.interface_def {
+      for property in &interface_def.properties {
+        push_member(&property.name, property.to_string(), &property.js_doc);
+      }
+      for method in &interface_def.methods {
+        push_member(&method.name, method.to_string(), &method.js_doc);
+      }
+    }
+    if let Some(enum_def) = &doc_node.enum_def {
+      for member in &enum_def.members {
+        push_member(&member.name, member.name.clone(), &member.js_doc);
+      }
+    }
+
+    if let Some(namespace_def) = &doc_node.namespace_def {
+      collect_sections(
+        sections,
+        Some(&qualified_name),
+        &namespace_def.elements,
+      );
+    }
+  }
+}
+
+fn doc_text(js_doc: &JsDoc) -> Option<String> {
+  js_doc
+    .doc
+    .as_ref()
+    .map(|doc| doc.trim().to_string())
+    .filter(|doc| !doc.is_empty())
+}
@@ -0,0 +1,281 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::node::DocNode;
+use crate::text::sections;
+
+const KEYWORDS: &[&str] = &[
+  "abstract",
+  "accessor",
+  "as",
+  "asserts",
+  "async",
+  "class",
+  "const",
+  "declare",
+  "default",
+  "enum",
+  "export",
+  "extends",
+  "false",
+  "function",
+  "get",
+  "implements",
+  "import",
+  "in",
+  "infer",
+  "interface",
+  "is",
+  "keyof",
+  "let",
+  "namespace",
+  "new",
+  "out",
+  "override",
+  "private",
+  "protected",
+  "public",
+  "readonly",
+  "set",
+  "static",
+  "true",
+  "type",
+  "typeof",
+  "unique",
+  "var",
+];
+
+/// The keywords after which the declared name is a type.
+const TYPE_DECLARATION_KEYWORDS: &[&str] =
+  &["class", "enum", "interface", "namespace", "type"];
+
+/// The semantic kind of a [`SemanticToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SemanticTokenKind {
+  Keyword,
+  /// A type reference, type parameter, primitive type, or the declared name
+  /// of a class, enum, interface, namespace or type alias.
+  Type,
+  Parameter,
+  /// A property or method, or the declared name of a function or variable.
+  Member,
+  String,
+  Number,
+}
+
+/// A range of a signature, in UTF-8 code units (bytes), and its kind. Text
+/// which is not covered by a token, like punctuation, has no semantic kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticToken {
+  pub start: usize,
+  pub end: usize,
+  pub kind: SemanticTokenKind,
+}
+
+/// The printed signature of a symbol or member, with its semantic tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SemanticSignature {
+  pub qualified_name: String,
+  pub text: String,
+  pub tokens: Vec<SemanticToken>,
+}
+
+/// Prints the signatures of the doc nodes and their members, like
+/// [`crate::to_plain_text`], along with a stream of semantic tokens, so they
+/// can be colorized by editors without being lexed again. Signatures are
+/// sorted by qualified name.
+pub fn semantic_signatures(doc_nodes: &[DocNode]) -> Vec<SemanticSignature> {
+  sections(doc_nodes)
+    .into_iter()
+    .map(|section| SemanticSignature {
+      qualified_name: section.qualified_name,
+      tokens: semantic_tokens(&section.signature),
+      text: section.signature,
+    })
+    .collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Bracket {
+  /// The parameters of a function or method.
+  Params,
+  /// Parentheses around a type, or the parameters of a function type.
+  Group,
+  Braces,
+  Square,
+  Angle,
+}
+
+/// Classifies the words and literals of a printed signature.
+pub(crate) fn semantic_tokens(text: &str) -> Vec<SemanticToken> {
+  let bytes = text.as_bytes();
+  let mut tokens = Vec::new();
+  let mut brackets: Vec<Bracket> = Vec::new();
+  let mut seen_bracket = false;
+  let mut seen_name = false;
+  let mut last_keyword: Option<&str> = None;
+  let mut previous = None;
+  let mut after_keyword = false;
+  let mut i = 0;
+  while i < bytes.len() {
+    let c = bytes[i];
+    let start = i;
+    match c {
+      b'"' | b'\'' | b'`' => {
+        i += 1;
+        while i < bytes.len() && bytes[i] != c {
+          i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+        i = (i + 1).min(bytes.len());
+        after_keyword = false;
+        tokens.push(SemanticToken {
+          start,
+          end: i,
+          kind: SemanticTokenKind::String,
+        });
+      }
+      b'0'..=b'9' => {
+        while i < bytes.len()
+          && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.')
+        {
+          i += 1;
+        }
+        after_keyword = false;
+        tokens.push(SemanticToken {
+          start,
+          end: i,
+          kind: SemanticTokenKind::Number,
+        });
+      }
+      c if is_ident_start(c) => {
+        i += 1;
+        while i < bytes.len() && is_ident_part(bytes[i]) {
+          i += 1;
+        }
+        let word = &text[start..i];
+        let next = next_significant(bytes, i);
+        // property and parameter names start a member or parameter, so
+        // follow a separator or a modifier
+        let starts_member = after_keyword
+          || matches!(previous, None | Some(b'(' | b',' | b'{' | b';' | b'.'));
+        let kind = classify(
+          word,
+          next,
+          brackets.last().copied().filter(|_| starts_member),
+          !seen_bracket && !seen_name,
+          last_keyword,
+        );
+        after_keyword = kind == SemanticTokenKind::Keyword;
+        if after_keyword {
+          last_keyword = Some(word);
+        } else if brackets.is_empty() && !seen_bracket {
+          seen_name = true;
+        }
+        tokens.push(SemanticToken {
+          start,
+          end: i,
+          kind,
+        });
+      }
+      _ => {
+        i += 1;
+        if !c.is_ascii_whitespace() {
+          after_keyword = false;
+        }
+        match c {
+          b'(' => {
+            let is_call = previous
+              .map(|p: u8| is_ident_part(p) || p == b'>')
+              .unwrap_or(false);
+            brackets.push(if is_call {
+              Bracket::Params
+            } else {
+              Bracket::Group
+            });
+          }
+          b'{' => brackets.push(Bracket::Braces),
+          b'[' => brackets.push(Bracket::Square),
+          b'<' => brackets.push(Bracket::Angle),
+          b'=' if bytes.get(i) == Some(&b'>') => i += 1,
+          b')' | b'}' | b']' | b'>' => {
+            if c != b'>' || brackets.last() == Some(&Bracket::Angle) {
+              brackets.pop();
+            }
+          }
+          _ => {}
+        }
+        if matches!(c, b'(' | b'{' | b'[' | b'<') {
+          seen_bracket = true;
+        }
+      }
+    }
+    if !bytes[i - 1].is_ascii_whitespace() {
+      previous = Some(bytes[i - 1]);
+    }
+  }
+  tokens
+}
+
+fn classify(
+  word: &str,
+  next: Option<u8>,
+  bracket: Option<Bracket>,
+  is_declared_name: bool,
+  last_keyword: Option<&str>,
+) -> SemanticTokenKind {
+  let is_keyword = KEYWORDS.contains(&word);
+  if word == "new" && matches!(next, Some(b'(' | b'<')) {
+    return SemanticTokenKind::Keyword;
+  }
+  match bracket {
+    Some(Bracket::Params | Bracket::Group) if next == Some(b':') => {
+      return SemanticTokenKind::Parameter;
+    }
+    Some(Bracket::Params) if matches!(next, Some(b',' | b')')) => {
+      return SemanticTokenKind::Parameter;
+    }
+    Some(Bracket::Braces)
+      if matches!(next, Some(b':' | b'?' | b'(' | b'<')) =>
+    {
+      return SemanticTokenKind::Member;
+    }
+    _ => {}
+  }
+  if is_keyword {
+    SemanticTokenKind::Keyword
+  } else if is_declared_name {
+    match last_keyword {
+      Some(keyword) if TYPE_DECLARATION_KEYWORDS.contains(&keyword) => {
+        SemanticTokenKind::Type
+      }
+      _ => SemanticTokenKind::Member,
+    }
+  } else {
+    SemanticTokenKind::Type
+  }
+}
+
+/// The next byte which is not whitespace, skipping the `?` of an optional
+/// parameter.
+fn next_significant(bytes: &[u8], mut i: usize) -> Option<u8> {
+  while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+    i += 1;
+  }
+  if bytes.get(i) == Some(&b'?') && bytes.get(i + 1) == Some(&b':') {
+    i += 1;
+  }
+  bytes.get(i).copied()
+}
+
+fn is_ident_start(c: u8) -> bool {
+  c.is_ascii_alphabetic() || matches!(c, b'_' | b'$' | b'#') || c >= 0x80
+}
+
+fn is_ident_part(c: u8) -> bool {
+  c.is_ascii_alphanumeric() || matches!(c, b'_' | b'$') || c >= 0x80
+}
@@ -1,16 +1,21 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
+use crate::closure_type::closure_type_to_ts;
 use crate::colors;
 use crate::display::display_computed;
 use crate::display::display_optional;
 use crate::display::display_readonly;
 use crate::display::SliceDisplayer;
 use crate::interface::expr_to_name;
+use crate::js_doc::JsDoc;
 use crate::params::param_to_param_def;
 use crate::params::pat_to_param_def;
 use crate::params::prop_name_to_string;
+use crate::params::prop_name_well_known_symbol;
 use crate::params::ts_fn_param_to_param_def;
 use crate::swc_util::is_false;
+use crate::swc_util::is_well_known_symbol;
+use crate::swc_util::js_doc_for_range;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
 use crate::ParamDef;
@@ -375,9 +380,12 @@ impl From<&TsTypeLit> for TsTypeDef {
           let name = expr_to_name(&ts_method_sig.key);
           let method_def = LiteralMethodDef {
             name,
+            js_doc: JsDoc::default(),
             kind: deno_ast::swc::ast::MethodKind::Method,
             params,
             computed: ts_method_sig.computed,
+            well_known_symbol: ts_method_sig.computed
+              && is_well_known_symbol(&ts_method_sig.key),
             optional: ts_method_sig.optional,
             return_type: maybe_return_type,
             type_params,
@@ -393,9 +401,12 @@ impl From<&TsTypeLit> for TsTypeDef {
           let name = expr_to_name(&ts_getter_sig.key);
           let method_def = LiteralMethodDef {
             name,
+            js_doc: JsDoc::default(),
             kind: deno_ast::swc::ast::MethodKind::Getter,
             params: vec![],
             computed: ts_getter_sig.computed,
+            well_known_symbol: ts_getter_sig.computed
+              && is_well_known_symbol(&ts_getter_sig.key),
             optional: ts_getter_sig.optional,
             return_type: maybe_return_type,
             type_params: vec![],
@@ -410,9 +421,12 @@ impl From<&TsTypeLit> for TsTypeDef {
 
           let method_def = LiteralMethodDef {
             name,
+            js_doc: JsDoc::default(),
             kind: deno_ast::swc::ast::MethodKind::Setter,
             params,
             computed: ts_setter_sig.computed,
+            well_known_symbol: ts_setter_sig.computed
+              && is_well_known_symbol(&ts_setter_sig.key),
             optional: ts_setter_sig.optional,
             return_type: None,
             type_params: vec![],
@@ -439,10 +453,13 @@ impl From<&TsTypeLit> for TsTypeDef {
           );
           let prop_def = LiteralPropertyDef {
             name,
+            js_doc: JsDoc::default(),
             params,
             ts_type,
             readonly: ts_prop_sig.readonly,
             computed: ts_prop_sig.computed,
+            well_known_symbol: ts_prop_sig.computed
+              && is_well_known_symbol(&ts_prop_sig.key),
             optional: ts_prop_sig.optional,
             type_params,
           };
@@ -484,6 +501,7 @@ impl From<&TsTypeLit> for TsTypeDef {
             .map(|rt| (&*rt.type_ann).into());
 
           let index_sig_def = LiteralIndexSignatureDef {
+            js_doc: JsDoc::default(),
             readonly: ts_index_sig.readonly,
             params,
             ts_type,
@@ -508,8 +526,10 @@ impl From<&TsTypeLit> for TsTypeDef {
 
           let construct_sig_def = LiteralMethodDef {
             name: "new".to_string(),
+            js_doc: JsDoc::default(),
             kind: deno_ast::swc::ast::MethodKind::Method,
             computed: false,
+            well_known_symbol: false,
             optional: false,
             params,
             return_type: maybe_return_type,
@@ -843,10 +863,15 @@ pub struct TsMappedTypeDef {
 #[serde(rename_all = "camelCase")]
 pub struct LiteralMethodDef {
   pub name: String,
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
   pub kind: deno_ast::swc::ast::MethodKind,
   pub params: Vec<ParamDef>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub computed: bool,
+  /// Whether the key is a well-known symbol, like `Symbol.iterator`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
   pub optional: bool,
   pub return_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
@@ -856,11 +881,18 @@ impl Display for LiteralMethodDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(
       f,
-      "{}{}({})",
+      "{}{}",
       display_computed(self.computed, &self.name),
       display_optional(self.optional),
-      SliceDisplayer::new(&self.params, ", ", false)
     )?;
+    if !self.type_params.is_empty() {
+      write!(
+        f,
+        "<{}>",
+        SliceDisplayer::new(&self.type_params, ", ", false)
+      )?;
+    }
+    write!(f, "({})", SliceDisplayer::new(&self.params, ", ", false))?;
     if let Some(return_type) = &self.return_type {
       write!(f, ": {}", return_type)?;
     }
@@ -872,10 +904,15 @@ impl Display for LiteralMethodDef {
 #[serde(rename_all = "camelCase")]
 pub struct LiteralPropertyDef {
   pub name: String,
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
   pub params: Vec<ParamDef>,
-  #[serde(skip_serializing_if = "is_false")]
+  #[serde(skip_serializing_if = "is_false", default)]
   pub readonly: bool,
   pub computed: bool,
+  /// Whether the key is a well-known symbol, like `Symbol.iterator`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub well_known_symbol: bool,
   pub optional: bool,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Vec<TsTypeParamDef>,
@@ -900,6 +937,13 @@ pub struct LiteralCallSignatureDef {
 
 impl Display for LiteralCallSignatureDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if !self.type_params.is_empty() {
+      write!(
+        f,
+        "<{}>",
+        SliceDisplayer::new(&self.type_params, ", ", false)
+      )?;
+    }
     write!(f, "({})", SliceDisplayer::new(&self.params, ", ", false))?;
     if let Some(ts_type) = &self.ts_type {
       write!(f, ": {}", ts_type)?;
@@ -911,6 +955,8 @@ impl Display for LiteralCallSignatureDef {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LiteralIndexSignatureDef {
+  #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
+  pub js_doc: JsDoc,
   pub readonly: bool,
   pub params: Vec<ParamDef>,
   pub ts_type: Option<TsTypeDef>,
@@ -1296,6 +1342,228 @@ impl TsTypeDef {
       ..Default::default()
     }
   }
+
+  /// The full representation of the type, rendered from its structure, so
+  /// it is not affected by a truncated `repr`.
+  pub fn full_repr(&self) -> String {
+    self.to_string()
+  }
+
+  /// Calls `f` with each type directly nested in this type, including the
+  /// types of params, type params and type literal members.
+  pub(crate) fn for_each_child_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut TsTypeDef),
+  ) {
+    fn type_params(
+      type_params: &mut [TsTypeParamDef],
+      f: &mut dyn FnMut(&mut TsTypeDef),
+    ) {
+      for type_param in type_params {
+        type_param.for_each_ts_type_mut(f);
+      }
+    }
+
+    fn params(params: &mut [ParamDef], f: &mut dyn FnMut(&mut TsTypeDef)) {
+      for param in params {
+        param.for_each_ts_type_mut(f);
+      }
+    }
+
+    if let Some(literal) = &mut self.literal {
+      literal.ts_types.iter_mut().flatten().for_each(&mut *f);
+    }
+    if let Some(type_ref) = &mut self.type_ref {
+      type_ref.type_params.iter_mut().flatten().for_each(&mut *f);
+    }
+    self.union.iter_mut().flatten().for_each(&mut *f);
+    self.intersection.iter_mut().flatten().for_each(&mut *f);
+    self.tuple.iter_mut().flatten().for_each(&mut *f);
+    for ts_type in [
+      &mut self.array,
+      &mut self.parenthesized,
+      &mut self.rest,
+      &mut self.optional,
+    ]
+    .into_iter()
+    .flatten()
+    {
+      f(ts_type);
+    }
+    if let Some(type_operator) = &mut self.type_operator {
+      f(&mut type_operator.ts_type);
+    }
+    if let Some(fn_or_constructor) = &mut self.fn_or_constructor {
+      f(&mut fn_or_constructor.ts_type);
+      params(&mut fn_or_constructor.params, f);
+      type_params(&mut fn_or_constructor.type_params, f);
+    }
+    if let Some(conditional) = &mut self.conditional_type {
+      f(&mut conditional.check_type);
+      f(&mut conditional.extends_type);
+      f(&mut conditional.true_type);
+      f(&mut conditional.false_type);
+    }
+    if let Some(infer) = &mut self.infer {
+      type_params(std::slice::from_mut(&mut *infer.type_param), f);
+    }
+    if let Some(indexed_access) = &mut self.indexed_access {
+      f(&mut indexed_access.obj_type);
+      f(&mut indexed_access.index_type);
+    }
+    if let Some(mapped_type) = &mut self.mapped_type {
+      type_params(std::slice::from_mut(&mut *mapped_type.type_param), f);
+      if let Some(name_type) = &mut mapped_type.name_type {
+        f(name_type);
+      }
+      if let Some(ts_type) = &mut mapped_type.ts_type {
+        f(ts_type);
+      }
+    }
+    if let Some(type_literal) = &mut self.type_literal {
+      for method in &mut type_literal.methods {
+        params(&mut method.params, f);
+        if let Some(return_type) = &mut method.return_type {
+          f(return_type);
+        }
+        type_params(&mut method.type_params, f);
+      }
+      for property in &mut type_literal.properties {
+        params(&mut property.params, f);
+        if let Some(ts_type) = &mut property.ts_type {
+          f(ts_type);
+        }
+        type_params(&mut property.type_params, f);
+      }
+      for call_signature in &mut type_literal.call_signatures {
+        params(&mut call_signature.params, f);
+        if let Some(ts_type) = &mut call_signature.ts_type {
+          f(ts_type);
+        }
+        type_params(&mut call_signature.type_params, f);
+      }
+      for index_signature in &mut type_literal.index_signatures {
+        params(&mut index_signature.params, f);
+        if let Some(ts_type) = &mut index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+    }
+    if let Some(type_predicate) = &mut self.type_predicate {
+      if let Some(ts_type) = &mut type_predicate.r#type {
+        f(ts_type);
+      }
+    }
+    if let Some(import_type) = &mut self.import_type {
+      import_type
+        .type_params
+        .iter_mut()
+        .flatten()
+        .for_each(&mut *f);
+    }
+  }
+
+  /// Like [`Self::for_each_child_mut`], but with shared references.
+  pub(crate) fn for_each_child(&self, f: &mut dyn FnMut(&TsTypeDef)) {
+    fn type_params(
+      type_params: &[TsTypeParamDef],
+      f: &mut dyn FnMut(&TsTypeDef),
+    ) {
+      for type_param in type_params {
+        type_param.for_each_ts_type(f);
+      }
+    }
+
+    fn params(params: &[ParamDef], f: &mut dyn FnMut(&TsTypeDef)) {
+      for param in params {
+        param.for_each_ts_type(f);
+      }
+    }
+
+    if let Some(literal) = &self.literal {
+      literal.ts_types.iter().flatten().for_each(&mut *f);
+    }
+    if let Some(type_ref) = &self.type_ref {
+      type_ref.type_params.iter().flatten().for_each(&mut *f);
+    }
+    self.union.iter().flatten().for_each(&mut *f);
+    self.intersection.iter().flatten().for_each(&mut *f);
+    self.tuple.iter().flatten().for_each(&mut *f);
+    for ts_type in
+      [&self.array, &self.parenthesized, &self.rest, &self.optional]
+        .into_iter()
+        .flatten()
+    {
+      f(ts_type);
+    }
+    if let Some(type_operator) = &self.type_operator {
+      f(&type_operator.ts_type);
+    }
+    if let Some(fn_or_constructor) = &self.fn_or_constructor {
+      f(&fn_or_constructor.ts_type);
+      params(&fn_or_constructor.params, f);
+      type_params(&fn_or_constructor.type_params, f);
+    }
+    if let Some(conditional) = &self.conditional_type {
+      f(&conditional.check_type);
+      f(&conditional.extends_type);
+      f(&conditional.true_type);
+      f(&conditional.false_type);
+    }
+    if let Some(infer) = &self.infer {
+      type_params(std::slice::from_ref(&*infer.type_param), f);
+    }
+    if let Some(indexed_access) = &self.indexed_access {
+      f(&indexed_access.obj_type);
+      f(&indexed_access.index_type);
+    }
+    if let Some(mapped_type) = &self.mapped_type {
+      type_params(std::slice::from_ref(&*mapped_type.type_param), f);
+      if let Some(name_type) = &mapped_type.name_type {
+        f(name_type);
+      }
+      if let Some(ts_type) = &mapped_type.ts_type {
+        f(ts_type);
+      }
+    }
+    if let Some(type_literal) = &self.type_literal {
+      for method in &type_literal.methods {
+        params(&method.params, f);
+        if let Some(return_type) = &method.return_type {
+          f(return_type);
+        }
+        type_params(&method.type_params, f);
+      }
+      for property in &type_literal.properties {
+        params(&property.params, f);
+        if let Some(ts_type) = &property.ts_type {
+          f(ts_type);
+        }
+        type_params(&property.type_params, f);
+      }
+      for call_signature in &type_literal.call_signatures {
+        params(&call_signature.params, f);
+        if let Some(ts_type) = &call_signature.ts_type {
+          f(ts_type);
+        }
+        type_params(&call_signature.type_params, f);
+      }
+      for index_signature in &type_literal.index_signatures {
+        params(&index_signature.params, f);
+        if let Some(ts_type) = &index_signature.ts_type {
+          f(ts_type);
+        }
+      }
+    }
+    if let Some(type_predicate) = &self.type_predicate {
+      if let Some(ts_type) = &type_predicate.r#type {
+        f(ts_type);
+      }
+    }
+    if let Some(import_type) = &self.import_type {
+      import_type.type_params.iter().flatten().for_each(&mut *f);
+    }
+  }
 }
 
 pub fn ts_type_ann_to_def(type_ann: &TsTypeAnn) -> TsTypeDef {
@@ -1325,6 +1593,133 @@ pub fn ts_type_ann_to_def(type_ann: &TsTypeAnn) -> TsTypeDef {
   }
 }
 
+/// Sets the JSDoc of the members of the type literals of a type converted
+/// from `ts_type`, which the conversion leaves empty, as it has no access to
+/// the comments of the source. Type literals nested in unions,
+/// intersections, arrays, parentheses and the types of properties are
+/// included.
+pub(crate) fn set_type_literal_js_docs(
+  parsed_source: &ParsedSource,
+  ts_type: &TsType,
+  def: &mut TsTypeDef,
+) {
+  match ts_type {
+    TsType::TsTypeLit(type_lit) => {
+      let Some(type_literal) = &mut def.type_literal else {
+        return;
+      };
+      let mut methods = type_literal.methods.iter_mut();
+      let mut properties = type_literal.properties.iter_mut();
+      let mut index_signatures = type_literal.index_signatures.iter_mut();
+      for member in &type_lit.members {
+        let js_doc = || {
+          js_doc_for_range(parsed_source, &member.range()).unwrap_or_default()
+        };
+        match member {
+          TsTypeElement::TsPropertySignature(prop_sig) => {
+            let Some(prop_def) = properties.next() else {
+              return;
+            };
+            prop_def.js_doc = js_doc();
+            if let (Some(type_ann), Some(prop_type)) =
+              (&prop_sig.type_ann, &mut prop_def.ts_type)
+            {
+              set_type_literal_js_docs(
+                parsed_source,
+                &type_ann.type_ann,
+                prop_type,
+              );
+            }
+          }
+          TsTypeElement::TsMethodSignature(_)
+          | TsTypeElement::TsGetterSignature(_)
+          | TsTypeElement::TsSetterSignature(_)
+          | TsTypeElement::TsConstructSignatureDecl(_) => {
+            let Some(method_def) = methods.next() else {
+              return;
+            };
+            method_def.js_doc = js_doc();
+          }
+          TsTypeElement::TsIndexSignature(_) => {
+            let Some(index_sig_def) = index_signatures.next() else {
+              return;
+            };
+            index_sig_def.js_doc = js_doc();
+          }
+          TsTypeElement::TsCallSignatureDecl(_) => {}
+        }
+      }
+    }
+    TsType::TsUnionOrIntersectionType(union_or_intersection) => {
+      let (types, defs) = match union_or_intersection {
+        TsUnionOrIntersectionType::TsUnionType(union_type) => {
+          (&union_type.types, &mut def.union)
+        }
+        TsUnionOrIntersectionType::TsIntersectionType(intersection_type) => {
+          (&intersection_type.types, &mut def.intersection)
+        }
+      };
+      if let Some(defs) = defs {
+        for (ts_type, def) in types.iter().zip(defs.iter_mut()) {
+          set_type_literal_js_docs(parsed_source, ts_type, def);
+        }
+      }
+    }
+    TsType::TsArrayType(array_type) => {
+      if let Some(elem_def) = &mut def.array {
+        set_type_literal_js_docs(
+          parsed_source,
+          &array_type.elem_type,
+          elem_def,
+        );
+      }
+    }
+    TsType::TsParenthesizedType(paren_type) => {
+      if let Some(inner_def) = &mut def.parenthesized {
+        set_type_literal_js_docs(
+          parsed_source,
+          &paren_type.type_ann,
+          inner_def,
+        );
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Parses a type given as text, like the type of a JSDoc tag, returning
+/// `None` when it is not valid TypeScript nor Closure Compiler syntax, like
+/// `?string`. The JSDoc wildcard `*` is `any`.
+pub(crate) fn ts_type_from_str(type_str: &str) -> Option<TsTypeDef> {
+  let type_str = type_str.trim();
+  if type_str == "*" {
+    return Some(TsTypeDef::keyword("any"));
+  }
+  parse_ts_type_str(type_str)
+    .or_else(|| parse_ts_type_str(&closure_type_to_ts(type_str)?))
+}
+
+fn parse_ts_type_str(type_str: &str) -> Option<TsTypeDef> {
+  let parsed_source = deno_ast::parse_module(deno_ast::ParseParams {
+    specifier: "file:///type.ts".to_string(),
+    text_info: deno_ast::SourceTextInfo::from_string(format!(
+      "type T = {};",
+      type_str
+    )),
+    media_type: deno_ast::MediaType::TypeScript,
+    capture_tokens: false,
+    scope_analysis: false,
+    maybe_syntax: None,
+  })
+  .ok()?;
+  match parsed_source.module().body.as_slice() {
+    [ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(type_alias_decl)))] => {
+      Some(type_alias_decl.type_ann.as_ref().into())
+    }
+    _ => None,
+  }
+}
+
 pub fn infer_ts_type_from_expr(
   parsed_source: &ParsedSource,
   expr: &Expr,
@@ -1557,6 +1952,18 @@ fn infer_ts_type_from_obj(
   }
 }
 
+/// The name of a member of an object literal, and whether its key is a
+/// well-known symbol, which is named without the brackets of the computed key.
+fn literal_member_name(
+  parsed_source: &ParsedSource,
+  key: &PropName,
+) -> (String, bool) {
+  match prop_name_well_known_symbol(key) {
+    Some(name) => (name, true),
+    None => (prop_name_to_string(Some(parsed_source), key), false),
+  }
+}
+
 fn infer_ts_type_from_obj_inner(
   parsed_source: &ParsedSource,
   obj: &ObjectLit,
@@ -1565,92 +1972,115 @@ fn infer_ts_type_from_obj_inner(
   let mut properties = Vec::<LiteralPropertyDef>::new();
   for obj_prop in &obj.props {
     match obj_prop {
-      PropOrSpread::Prop(prop) => match &**prop {
-        Prop::Shorthand(shorthand) => {
-          // TODO(@crowlKats) we should pass previous nodes and take the type
-          // from the previous symbol.
-          properties.push(LiteralPropertyDef {
-            name: shorthand.sym.to_string(),
-            params: vec![],
-            readonly: false,
-            computed: false,
-            optional: false,
-            ts_type: None,
-            type_params: vec![],
-          });
-        }
-        Prop::KeyValue(kv) => {
-          properties.push(LiteralPropertyDef {
-            name: prop_name_to_string(Some(parsed_source), &kv.key),
-            params: vec![],
-            readonly: false,
-            computed: kv.key.is_computed(),
-            optional: false,
-            ts_type: infer_ts_type_from_expr(parsed_source, &kv.value, false),
-            type_params: vec![],
-          });
-        }
-        Prop::Assign(_) => unreachable!("This is invalid for object literal!"),
-        Prop::Getter(getter) => {
-          let name = prop_name_to_string(Some(parsed_source), &getter.key);
-          let computed = getter.key.is_computed();
-          let return_type = getter
-            .type_ann
-            .as_ref()
-            .map(|type_ann| ts_type_ann_to_def(type_ann.as_ref()));
-          methods.push(LiteralMethodDef {
-            name,
-            kind: MethodKind::Getter,
-            params: vec![],
-            computed,
-            optional: false,
-            return_type,
-            type_params: vec![],
-          });
-        }
-        Prop::Setter(setter) => {
-          let name = prop_name_to_string(Some(parsed_source), &setter.key);
-          let computed = setter.key.is_computed();
-          let param =
-            pat_to_param_def(Some(parsed_source), setter.param.as_ref());
-          methods.push(LiteralMethodDef {
-            name,
-            kind: MethodKind::Setter,
-            params: vec![param],
-            computed,
-            optional: false,
-            return_type: None,
-            type_params: vec![],
-          });
-        }
-        Prop::Method(method) => {
-          let name = prop_name_to_string(Some(parsed_source), &method.key);
-          let computed = method.key.is_computed();
-          let params = method
-            .function
-            .params
-            .iter()
-            .map(|param| param_to_param_def(parsed_source, param))
-            .collect();
-          let return_type = method
-            .function
-            .return_type
-            .as_ref()
-            .map(|type_ann| ts_type_ann_to_def(type_ann.as_ref()));
-          let type_params = maybe_type_param_decl_to_type_param_defs(
-            method.function.type_params.as_deref(),
-          );
-          methods.push(LiteralMethodDef {
-            name,
-            kind: MethodKind::Method,
-            params,
-            computed,
-            optional: false,
-            return_type,
-            type_params,
-          });
+      PropOrSpread::Prop(prop) => {
+        let Some(js_doc) = js_doc_for_range(parsed_source, &prop.range())
+        else {
+          continue;
+        };
+        match &**prop {
+          Prop::Shorthand(shorthand) => {
+            // TODO(@crowlKats) we should pass previous nodes and take the type
+            // from the previous symbol.
+            properties.push(LiteralPropertyDef {
+              name: shorthand.sym.to_string(),
+              js_doc,
+              params: vec![],
+              readonly: false,
+              computed: false,
+              well_known_symbol: false,
+              optional: false,
+              ts_type: None,
+              type_params: vec![],
+            });
+          }
+          Prop::KeyValue(kv) => {
+            let (name, well_known_symbol) =
+              literal_member_name(parsed_source, &kv.key);
+            properties.push(LiteralPropertyDef {
+              name,
+              js_doc,
+              params: vec![],
+              readonly: false,
+              computed: kv.key.is_computed(),
+              well_known_symbol,
+              optional: false,
+              ts_type: infer_ts_type_from_expr(parsed_source, &kv.value, false),
+              type_params: vec![],
+            });
+          }
+          Prop::Assign(_) => {
+            unreachable!("This is invalid for object literal!")
+          }
+          Prop::Getter(getter) => {
+            let (name, well_known_symbol) =
+              literal_member_name(parsed_source, &getter.key);
+            let computed = getter.key.is_computed();
+            let return_type = getter
+              .type_ann
+              .as_ref()
+              .map(|type_ann| ts_type_ann_to_def(type_ann.as_ref()));
+            methods.push(LiteralMethodDef {
+              name,
+              js_doc,
+              kind: MethodKind::Getter,
+              params: vec![],
+              computed,
+              well_known_symbol,
+              optional: false,
+              return_type,
+              type_params: vec![],
+            });
+          }
+          Prop::Setter(setter) => {
+            let (name, well_known_symbol) =
+              literal_member_name(parsed_source, &setter.key);
+            let computed = setter.key.is_computed();
+            let param =
+              pat_to_param_def(Some(parsed_source), setter.param.as_ref());
+            methods.push(LiteralMethodDef {
+              name,
+              js_doc,
+              kind: MethodKind::Setter,
+              params: vec![param],
+              computed,
+              well_known_symbol,
+              optional: false,
+              return_type: None,
+              type_params: vec![],
+            });
+          }
+          Prop::Method(method) => {
+            let (name, well_known_symbol) =
+              literal_member_name(parsed_source, &method.key);
+            let computed = method.key.is_computed();
+            let params = method
+              .function
+              .params
+              .iter()
+              .map(|param| param_to_param_def(parsed_source, param))
+              .collect();
+            let return_type = method
+              .function
+              .return_type
+              .as_ref()
+              .map(|type_ann| ts_type_ann_to_def(type_ann.as_ref()));
+            let type_params = maybe_type_param_decl_to_type_param_defs(
+              method.function.type_params.as_deref(),
+            );
+            methods.push(LiteralMethodDef {
+              name,
+              js_doc,
+              kind: MethodKind::Method,
+              params,
+              computed,
+              well_known_symbol,
+              optional: false,
+              return_type,
+              type_params,
+            });
+          }
         }
-      },
+      }
       PropOrSpread::Spread(spread) => {
         if let Expr::Object(obj) = &*spread.expr {
           let (spread_methods, spread_properties) =
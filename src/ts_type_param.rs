@@ -17,6 +17,31 @@ pub struct TsTypeParamDef {
   pub default: Option<TsTypeDef>,
 }
 
+impl TsTypeParamDef {
+  /// Calls `f` with the constraint and the default of this type param.
+  pub(crate) fn for_each_ts_type_mut(
+    &mut self,
+    f: &mut dyn FnMut(&mut TsTypeDef),
+  ) {
+    if let Some(constraint) = &mut self.constraint {
+      f(constraint);
+    }
+    if let Some(default) = &mut self.default {
+      f(default);
+    }
+  }
+
+  /// Like [`Self::for_each_ts_type_mut`], but with shared references.
+  pub(crate) fn for_each_ts_type(&self, f: &mut dyn FnMut(&TsTypeDef)) {
+    if let Some(constraint) = &self.constraint {
+      f(constraint);
+    }
+    if let Some(default) = &self.default {
+      f(default);
+    }
+  }
+}
+
 impl Display for TsTypeParamDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(f, "{}", self.name)?;
@@ -1,4 +1,5 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+use crate::ts_type::set_type_literal_js_docs;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type_param::maybe_type_param_decl_to_type_param_defs;
 use crate::ts_type_param::TsTypeParamDef;
@@ -13,11 +14,16 @@ pub struct TypeAliasDef {
 }
 
 pub fn get_doc_for_ts_type_alias_decl(
-  _parsed_source: &ParsedSource,
+  parsed_source: &ParsedSource,
   type_alias_decl: &deno_ast::swc::ast::TsTypeAliasDecl,
 ) -> (String, TypeAliasDef) {
   let alias_name = type_alias_decl.id.sym.to_string();
-  let ts_type = type_alias_decl.type_ann.as_ref().into();
+  let mut ts_type = type_alias_decl.type_ann.as_ref().into();
+  set_type_literal_js_docs(
+    parsed_source,
+    &type_alias_decl.type_ann,
+    &mut ts_type,
+  );
   let type_params = maybe_type_param_decl_to_type_param_defs(
     type_alias_decl.type_params.as_deref(),
   );
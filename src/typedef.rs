@@ -0,0 +1,217 @@
+// Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::ast::VarDeclKind;
+use deno_ast::swc::common::comments::CommentKind;
+use deno_ast::ParsedSource;
+use deno_ast::SourceRangedForSpanned;
+
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DeclarationKind;
+use crate::node::DocNode;
+use crate::node::Location;
+use crate::node::NamespaceDef;
+use crate::swc_util::get_location;
+use crate::swc_util::is_javascript;
+use crate::swc_util::parse_js_doc;
+use crate::ts_type::ts_type_from_str;
+use crate::ts_type::LiteralPropertyDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::type_alias::TypeAliasDef;
+use crate::variable::VariableDef;
+
+/// Synthesizes doc nodes from the JSDoc comments of a JavaScript module
+/// which declare types or namespaces without TypeScript syntax:
+///
+/// - a `@typedef` is a type alias, and a typedef of `Object` or `object`
+///   gets a type literal with a property for each `@property` tag of the
+///   comment.
+/// - a named `@namespace` is a namespace with a variable for each
+///   `@property` tag of the comment.
+///
+/// Properties can be nested with paths like `options.timeout`, after their
+/// parent property.
+pub(crate) fn get_doc_nodes_for_typedefs(
+  parsed_source: &ParsedSource,
+) -> Vec<DocNode> {
+  if !is_javascript(parsed_source) {
+    return Vec::new();
+  }
+  let mut comments = parsed_source.comments().get_vec();
+  comments.sort_by_key(|comment| comment.span.lo);
+  let mut doc_nodes = Vec::new();
+  for comment in comments {
+    if comment.kind != CommentKind::Block || !comment.text.starts_with('*') {
+      continue;
+    }
+    let Some(js_doc) = parse_js_doc(&comment) else {
+      continue;
+    };
+    let location = get_location(parsed_source, comment.range().start);
+    if let Some(doc_node) = typedef_doc_node(&js_doc, location.clone()) {
+      doc_nodes.push(doc_node);
+    } else if let Some(doc_node) = namespace_doc_node(&js_doc, location) {
+      doc_nodes.push(doc_node);
+    }
+  }
+  doc_nodes
+}
+
+fn typedef_doc_node(js_doc: &JsDoc, location: Location) -> Option<DocNode> {
+  let (name, type_ref, doc) = js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::TypeDef {
+      name,
+      type_ref,
+      doc,
+    } => Some((name, type_ref, doc)),
+    _ => None,
+  })?;
+  let ts_type = if matches!(type_ref.trim(), "Object" | "object") {
+    TsTypeDef::object(Vec::new(), property_defs(js_doc))
+  } else {
+    type_from_str(type_ref)
+  };
+  Some(DocNode::type_alias(
+    name.clone(),
+    location,
+    DeclarationKind::Export,
+    remaining_js_doc(js_doc, doc),
+    TypeAliasDef {
+      ts_type,
+      type_params: Vec::new(),
+    },
+  ))
+}
+
+fn namespace_doc_node(js_doc: &JsDoc, location: Location) -> Option<DocNode> {
+  let name = js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::Namespace { name: Some(name) } => Some(name),
+    _ => None,
+  })?;
+  let elements = property_defs(js_doc)
+    .into_iter()
+    .map(|property| {
+      DocNode::variable(
+        property.name,
+        location.clone(),
+        DeclarationKind::Export,
+        property.js_doc,
+        VariableDef {
+          ts_type: property.ts_type,
+          kind: VarDeclKind::Const,
+        },
+      )
+    })
+    .collect();
+  Some(DocNode::namespace(
+    name.clone(),
+    location,
+    DeclarationKind::Export,
+    remaining_js_doc(js_doc, &None),
+    NamespaceDef {
+      elements,
+      ambient_module: false,
+    },
+  ))
+}
+
+/// The documentation of a synthesized node, without the tags it was
+/// synthesized from.
+fn remaining_js_doc(js_doc: &JsDoc, doc: &Option<String>) -> JsDoc {
+  JsDoc {
+    doc: js_doc.doc.clone().or_else(|| doc.clone()),
+    tags: js_doc
+      .tags
+      .iter()
+      .filter(|tag| {
+        !matches!(
+          tag,
+          JsDocTag::TypeDef { .. }
+            | JsDocTag::Property { .. }
+            | JsDocTag::Namespace { .. }
+        )
+      })
+      .cloned()
+      .collect(),
+    doc_segments: None,
+    raw: None,
+  }
+}
+
+/// The properties given by the `@property` tags of a comment, with nested
+/// properties in the type literals of their parents.
+fn property_defs(js_doc: &JsDoc) -> Vec<LiteralPropertyDef> {
+  let mut properties = Vec::new();
+  for tag in &js_doc.tags {
+    let JsDocTag::Property {
+      name,
+      type_ref,
+      optional,
+      default,
+      doc,
+    } = tag
+    else {
+      continue;
+    };
+    let mut path = name.split('.').collect::<Vec<_>>();
+    let name = path.pop().unwrap();
+    let mut tags = Vec::new();
+    if let Some(default) = default {
+      tags.push(JsDocTag::Default {
+        value: default.clone(),
+        doc: None,
+      });
+    }
+    let property = LiteralPropertyDef {
+      name: name.to_string(),
+      js_doc: JsDoc {
+        doc: doc.clone(),
+        tags,
+        doc_segments: None,
+        raw: None,
+      },
+      params: Vec::new(),
+      readonly: false,
+      computed: false,
+      well_known_symbol: false,
+      optional: *optional,
+      ts_type: Some(type_from_str(type_ref)),
+      type_params: Vec::new(),
+    };
+    insert_property(&mut properties, &path, property);
+  }
+  properties
+}
+
+/// Adds a property to the type literal of the property at `path`, which is
+/// dropped if there is no such property.
+fn insert_property(
+  properties: &mut Vec<LiteralPropertyDef>,
+  path: &[&str],
+  property: LiteralPropertyDef,
+) {
+  let Some((parent_name, path)) = path.split_first() else {
+    properties.push(property);
+    return;
+  };
+  let Some(parent) = properties.iter_mut().find(|p| p.name == *parent_name)
+  else {
+    return;
+  };
+  let ts_type = parent
+    .ts_type
+    .get_or_insert_with(|| TsTypeDef::object(Vec::new(), Vec::new()));
+  if ts_type.kind != Some(TsTypeDefKind::TypeLiteral) {
+    *ts_type = TsTypeDef::object(Vec::new(), Vec::new());
+  }
+  let type_literal = ts_type.type_literal.as_mut().unwrap();
+  insert_property(&mut type_literal.properties, path, property);
+}
+
+fn type_from_str(type_ref: &str) -> TsTypeDef {
+  ts_type_from_str(type_ref).unwrap_or_else(|| TsTypeDef {
+    repr: type_ref.to_string(),
+    ..Default::default()
+  })
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2022 the Deno authors. All rights reserved. MIT license.
 
 use deno_ast::swc::ast::Pat;
+use deno_ast::ParsedSource;
 use deno_ast::SourceRange;
 use deno_ast::SourceRangedForSpanned;
 use deno_graph::type_tracer::EsmModuleSymbol;
@@ -8,8 +9,13 @@ use deno_graph::type_tracer::SymbolNodeRef;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::swc_util::is_javascript;
 use crate::ts_type::infer_simple_ts_type_from_var_decl;
+use crate::ts_type::set_type_literal_js_docs;
 use crate::ts_type::ts_type_ann_to_def;
+use crate::ts_type::ts_type_from_str;
 use crate::ts_type::TsTypeDef;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -40,7 +46,15 @@ pub fn get_docs_for_var_declarator(
     _ => None,
   };
   let maybe_ts_type = maybe_ts_type_ann
-    .map(|def| ts_type_ann_to_def(def.as_ref()))
+    .map(|type_ann| {
+      let mut ts_type = ts_type_ann_to_def(type_ann);
+      set_type_literal_js_docs(
+        module_symbol.source(),
+        &type_ann.type_ann,
+        &mut ts_type,
+      );
+      ts_type
+    })
     .or_else(|| {
       if let Some(ref_name) = ref_name {
         module_symbol.symbol_from_swc(&ref_name).and_then(|symbol| {
@@ -132,3 +146,22 @@ pub fn get_docs_for_var_declarator(
   }
   items
 }
+
+/// The type given by the `@type` tag of the JSDoc of a variable of a
+/// JavaScript module, like `/** @type {Map<string, number>} */`, when the
+/// variable is not destructured.
+pub(crate) fn js_doc_type_for_var_declarator(
+  parsed_source: &ParsedSource,
+  var_declarator: &deno_ast::swc::ast::VarDeclarator,
+  js_doc: &JsDoc,
+) -> Option<TsTypeDef> {
+  if !is_javascript(parsed_source)
+    || !matches!(var_declarator.name, Pat::Ident(_))
+  {
+    return None;
+  }
+  js_doc.tags.iter().find_map(|tag| match tag {
+    JsDocTag::TypeRef { type_ref, .. } => ts_type_from_str(type_ref),
+    _ => None,
+  })
+}